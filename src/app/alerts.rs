@@ -0,0 +1,200 @@
+use crate::audio::{alarm_beeps, open_sound_output};
+use crate::data::{coin_display_name, AlertCondition, AlertRule, AlertTracker, CoinId, PriceProvider, COINS};
+use crate::i18n::{tr, tr_args};
+use crate::ui::a11y::accessible_name;
+use crate::notify;
+use crate::task::{FetchEvent, FetchPipeline, FetchRequest, RequestId};
+use eframe::egui::{self, Color32};
+use rodio::{OutputStream, Sink};
+use std::sync::Arc;
+
+// Intervalle entre deux relevés des cryptos surveillées
+const REFRESH_SECONDS: f64 = 60.0;
+
+// Surveille les règles d'alerte sur toutes les pages : relève régulièrement les prix, fait sonner
+// l'alarme et empile les bandeaux jusqu'à ce qu'on les ferme
+pub struct AlertMonitor {
+    tracker: AlertTracker,
+    fetches: FetchPipeline,
+    pending: Vec<RequestId>,
+    // Instant (horloge egui) du prochain relevé, None pour relever tout de suite
+    next_check_at: Option<f64>,
+    banners: Vec<String>,
+    // Ouverte à la première alarme, indépendante du son du graphique en cours
+    sound_output: Option<(OutputStream, Sink)>,
+    // Règle en cours de saisie dans l'éditeur
+    draft_coin: CoinId,
+    draft_condition: AlertCondition,
+}
+
+impl AlertMonitor {
+    pub fn new() -> Self {
+        Self {
+            tracker: AlertTracker::new(),
+            fetches: FetchPipeline::new(),
+            pending: Vec::new(),
+            next_check_at: None,
+            banners: Vec::new(),
+            sound_output: None,
+            draft_coin: "bitcoin".to_string(),
+            draft_condition: AlertCondition::Above(100_000.0),
+        }
+    }
+
+    // Lit les relevés arrivés et relance ceux des cryptos surveillées quand l'intervalle est écoulé
+    pub fn update(&mut self, ctx: &egui::Context, provider: Arc<dyn PriceProvider>, rules: &[AlertRule]) {
+        if self.tracker.set_rules(rules) {
+            self.fetches.cancel_all();
+            self.pending.clear();
+            self.next_check_at = None;
+        }
+
+        for event in self.fetches.poll() {
+            let FetchEvent::Done { id, coin, result } = event else {
+                continue;
+            };
+            self.pending.retain(|pending| *pending != id);
+            match result {
+                Ok(data) => {
+                    for rule in self.tracker.observe(&data) {
+                        println!("{}", rule.message(&data));
+                        notify::notify(tr("notification-alert"), &rule.message(&data));
+                        self.banners.push(rule.message(&data));
+                        self.ring();
+                    }
+                },
+                Err(e) => println!("{}", tr_args("log-alert-failed", &[("coin", &coin), ("error", &e.to_string())])),
+            }
+        }
+
+        if self.tracker.rules().is_empty() {
+            return;
+        }
+        let now = ctx.input(|i| i.time);
+        let due = self.next_check_at.is_none_or(|at| now >= at);
+        if due && self.pending.is_empty() {
+            let mut watched: Vec<(CoinId, String)> = self.tracker
+                .rules()
+                .iter()
+                .map(|rule| (rule.coin.clone(), rule.currency.clone()))
+                .collect();
+            watched.sort();
+            watched.dedup();
+            for (coin, currency) in watched {
+                // Un jour suffit : seuls le dernier prix et la variation sur 24 h sont comparés
+                let id = self.fetches.fetch(ctx, FetchRequest { provider: provider.clone(), coin, days: 1, currency, range: None });
+                self.pending.push(id);
+            }
+            self.next_check_at = Some(now + REFRESH_SECONDS);
+        }
+        if let Some(at) = self.next_check_at {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64((at - now).max(0.0)));
+        }
+    }
+
+    fn ring(&mut self) {
+        if self.sound_output.is_none() {
+            match open_sound_output(1.0) {
+                Ok(output) => self.sound_output = Some(output),
+                Err(e) => {
+                    println!("{}", tr_args("log-no-audio", &[("error", &e.to_string())]));
+                    return;
+                },
+            }
+        }
+        if let Some((_, sink)) = &self.sound_output {
+            for beep in alarm_beeps() {
+                sink.append(beep);
+            }
+        }
+    }
+
+    // Bandeau des alertes déclenchées, en haut de toutes les pages ; à appeler avant leurs panneaux
+    pub fn show_banners(&mut self, ctx: &egui::Context) {
+        if self.banners.is_empty() {
+            return;
+        }
+        let mut dismissed = None;
+        let mut dismiss_all = false;
+        egui::TopBottomPanel::top("alert_banners")
+            .frame(egui::Frame::default().fill(Color32::from_rgb(150, 30, 30)).inner_margin(6.0))
+            .show(ctx, |ui| {
+                for (i, message) in self.banners.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(egui::RichText::new(format!("🔔 {}", message)).color(Color32::WHITE).strong());
+                        let dismiss = accessible_name(ui.small_button("✖"), egui::WidgetType::Button, tr("alert-dismiss"));
+                        if dismiss.on_hover_text(tr("alert-dismiss")).clicked() {
+                            dismissed = Some(i);
+                        }
+                    });
+                }
+                if self.banners.len() > 1 && ui.small_button(tr("alert-dismiss-all")).clicked() {
+                    dismiss_all = true;
+                }
+            });
+        if dismiss_all {
+            self.banners.clear();
+        } else if let Some(i) = dismissed {
+            self.banners.remove(i);
+        }
+    }
+
+    // Liste des règles et saisie d'une nouvelle ; renvoie true si les règles ont changé
+    pub fn show_editor(&mut self, ui: &mut egui::Ui, rules: &mut Vec<AlertRule>, currency: &str) -> bool {
+        let mut changed = false;
+        egui::CollapsingHeader::new(tr("alerts")).show(ui, |ui| {
+            if rules.is_empty() {
+                ui.label(egui::RichText::new(tr("alert-no-rules")).italics().color(Color32::GRAY));
+            }
+            let mut removed = None;
+            for (i, rule) in rules.iter().enumerate() {
+                ui.horizontal(|ui| {
+                    ui.label(rule.describe());
+                    let remove = ui.small_button("🗑");
+                    if accessible_name(remove, egui::WidgetType::Button, &tr_args("a11y-remove", &[("item", &rule.describe())])).clicked() {
+                        removed = Some(i);
+                    }
+                });
+            }
+            if let Some(i) = removed {
+                rules.remove(i);
+                changed = true;
+            }
+
+            ui.horizontal_wrapped(|ui| {
+                egui::ComboBox::from_id_source("alert_coin")
+                    .selected_text(coin_display_name(&self.draft_coin))
+                    .show_ui(ui, |ui| {
+                        for meta in COINS {
+                            ui.selectable_value(&mut self.draft_coin, meta.id.to_string(), coin_display_name(meta.id));
+                        }
+                    });
+                egui::ComboBox::from_id_source("alert_condition")
+                    .selected_text(tr(self.draft_condition.label_key()))
+                    .show_ui(ui, |ui| {
+                        for option in AlertCondition::variants(self.draft_condition.value()) {
+                            ui.selectable_value(&mut self.draft_condition, option, tr(option.label_key()));
+                        }
+                    });
+                let suffix = if self.draft_condition.is_percent() { " %".to_string() } else { format!(" {}", currency.to_uppercase()) };
+                let threshold = ui.add(egui::DragValue::new(self.draft_condition.value_mut()).clamp_range(0.0..=f64::MAX).suffix(suffix));
+                accessible_name(threshold, egui::WidgetType::DragValue, tr("a11y-alert-threshold"));
+                if ui.button(tr("alert-add")).clicked() {
+                    rules.push(AlertRule {
+                        coin: self.draft_coin.clone(),
+                        currency: currency.to_string(),
+                        condition: self.draft_condition,
+                    });
+                    changed = true;
+                }
+            });
+        });
+        changed
+    }
+}
+
+impl Default for AlertMonitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,1366 @@
+mod alerts;
+mod navigation;
+mod prefetch;
+mod search;
+mod settings;
+mod settings_page;
+mod transition;
+
+pub use navigation::NavigationHistory;
+pub use settings::{apply_custom_font, Settings, Theme};
+pub use transition::{PageTransition, TransitionDirection};
+
+use alerts::AlertMonitor;
+use prefetch::Prefetcher;
+use search::CoinSearch;
+
+use crate::audio::{SoundOptions, SoundPack, ViewMode};
+use crate::data::{
+    coin_accent, coin_display_name, coin_meta, discover_plugins, parse_price_csv, portfolio_series, set_http_policy, AssetPack, ChartData, CoinGeckoProvider, CoinId, Holding,
+    DateRange, IndexProvider, MockProvider, MockScenario, PriceProvider, COINS, DEFAULT_CURRENCY, PORTFOLIO_ID,
+};
+use crate::error::{Error, Result};
+use crate::i18n::{tr, tr_args, Language};
+use crate::task::{FetchEvent, FetchPipeline, FetchRequest, RequestId};
+use crate::ui::a11y::{accessible_name, shortcut_pressed};
+use crate::ui::chart::ChartApp;
+use crate::ui::browse::BrowsePage;
+use crate::ui::overview::OverviewPage;
+use crate::ui::dashboard::DashboardPage;
+use crate::ui::selection::{coin_card, CardAction, SelectionPage};
+use crate::ui::recipe::{list_recipes, load_recipe, Recipe};
+use crate::ui::session::{list_sessions, load_session, parse_session, SavedSession};
+use crate::ui::textures::TextureCache;
+use crate::ui::ticker::MiniTicker;
+use crate::ui::widgets::{paint_skeleton_chart, reduce_motion, secondary_text_color, set_reduce_motion};
+use eframe::egui::{self, Color32};
+use eframe::App;
+use std::collections::{HashMap, HashSet};
+use std::path::Path;
+use std::sync::Arc;
+
+#[derive(Clone, PartialEq)]
+enum Page {
+    Selection,
+    // Graphique d'une crypto, retrouvé dans MainApp::charts
+    Chart(CoinId),
+    Dashboard,
+    Browse,
+    Overview,
+    Settings,
+}
+
+enum LoadingState {
+    NotLoading,
+    // Le jeton est levé dès que ce chargement n'est plus attendu
+    Loading { id: RequestId, params: FetchParams, progress: LoadingProgress },
+    // Une requête par crypto détenue ; la valeur du portefeuille est calculée quand toutes sont arrivées
+    LoadingPortfolio { ids: Vec<RequestId>, params: FetchParams, received: Vec<ChartData>, progress: LoadingProgress },
+    // Écran d'erreur à la place de l'attente, jusqu'à « Réessayer » ou « Retour »
+    Failed { params: FetchParams, error: Error },
+}
+
+// Paramètres d'un chargement, gardés pour le relancer à l'identique après une erreur
+#[derive(Clone)]
+struct FetchParams {
+    coin: CoinId,
+    name: String,
+    provider: Arc<dyn PriceProvider>,
+    options: ChartOptions,
+    // Lecture lancée dès l'ouverture du graphique
+    playing: bool,
+}
+
+// Avancement affiché sur l'écran d'attente : part de la barre remplie et étape en cours
+struct LoadingProgress {
+    fraction: f32,
+    message: Option<String>,
+}
+
+impl Default for LoadingProgress {
+    fn default() -> Self {
+        Self { fraction: 0.0, message: None }
+    }
+}
+
+// Options passées en ligne de commande au lancement de l'interface, prioritaires sur les préférences enregistrées
+#[derive(Clone)]
+pub struct LaunchOptions {
+    pub coin: Option<String>,
+    pub days: Option<i64>,
+    pub speed: f64,
+    pub currency: String,
+    pub autoplay: bool,
+    // Fermeture à la fin de la lecture, pour les scripts
+    pub exit_when_done: bool,
+}
+
+impl Default for LaunchOptions {
+    fn default() -> Self {
+        Self {
+            coin: None,
+            days: None,
+            speed: 1.0,
+            currency: DEFAULT_CURRENCY.to_string(),
+            autoplay: false,
+            exit_when_done: false,
+        }
+    }
+}
+
+// Période, devise, vitesse, preset et son d'un graphique à charger : ceux du moment, ou ceux d'une
+// recette pour ce seul graphique, sans rien changer aux options de lancement ni aux préférences
+#[derive(Debug, Clone, PartialEq)]
+pub struct ChartOptions {
+    pub days: i64,
+    // Période fixe d'une recette, à la place des `days` derniers jours
+    pub range: Option<DateRange>,
+    pub currency: String,
+    pub speed: f64,
+    pub preset: ViewMode,
+    pub sound: SoundOptions,
+}
+
+impl ChartOptions {
+    // Options de lancement, sinon préférences enregistrées
+    pub fn current(launch: &LaunchOptions, settings: &Settings) -> Self {
+        Self {
+            days: launch.days.unwrap_or(settings.days),
+            range: None,
+            currency: launch.currency.clone(),
+            speed: launch.speed,
+            preset: settings.preset,
+            sound: settings.sound,
+        }
+    }
+
+    pub fn from_recipe(recipe: &Recipe) -> Self {
+        Self {
+            days: recipe.range.days(),
+            range: Some(recipe.range),
+            currency: recipe.currency.clone(),
+            speed: recipe.speed,
+            preset: recipe.preset,
+            sound: recipe.sound,
+        }
+    }
+
+    // Seules les recettes donnent une période fixe ; leur preset et leur son ne sont pas ceux de l'utilisateur
+    pub fn is_recipe(&self) -> bool {
+        self.range.is_some()
+    }
+}
+
+// Taille de la fenêtre principale à l'ouverture, et la plus petite utilisable (hors bandeau compact)
+pub const WINDOW_SIZE: egui::Vec2 = egui::vec2(1000.0, 660.0);
+pub const MIN_WINDOW_SIZE: egui::Vec2 = egui::vec2(400.0, 300.0);
+
+// Graphiques gardés en mémoire pour y revenir par l'historique ; au-delà, les plus lointains sont
+// libérés (avec leur sortie audio) et rechargés si l'on y retourne
+const MAX_OPEN_CHARTS: usize = 3;
+// Attente sans changement avant d'écrire les réglages suivis sur le graphique, en secondes
+const SETTINGS_SAVE_DELAY: f64 = 1.0;
+
+pub struct MainApp {
+    // Page affichée et pages visitées avant et après elle
+    navigation: NavigationHistory<Page>,
+    // Page affichée à l'image précédente, et transition en cours vers la page actuelle
+    shown_page: Page,
+    transition: Option<PageTransition>,
+    selection_page: SelectionPage,
+    charts: HashMap<CoinId, ChartApp>,
+    // Graphiques ouverts depuis une recette : leur preset et leur son ne sont pas retenus dans les préférences
+    recipe_charts: HashSet<CoinId>,
+    // Graphiques sortis dans leur propre fenêtre, hors de l'historique de navigation
+    popped_out: HashMap<CoinId, ChartApp>,
+    // Bandeau compact affiché à la place des pages, et taille de la fenêtre à lui rendre en sortant
+    ticker: Option<MiniTicker>,
+    ticker_restore_size: Option<egui::Vec2>,
+    loading_state: LoadingState,
+    fetches: FetchPipeline,
+    prefetcher: Prefetcher,
+    search: CoinSearch,
+    alerts: AlertMonitor,
+    asset_packs: Vec<AssetPack>,
+    selected_pack: usize,
+    // Packs de sons trouvés au démarrage ; aucun sélectionné, la synthèse joue seule
+    sound_packs: Vec<Arc<SoundPack>>,
+    selected_sound_pack: Option<usize>,
+    // Textures partagées entre la page de sélection et les graphiques
+    textures: TextureCache,
+    dashboard: Option<DashboardPage>,
+    browse: Option<BrowsePage>,
+    overview: Option<OverviewPage>,
+    // CoinGecko en premier, puis les plugins trouvés au démarrage
+    providers: Vec<Arc<dyn PriceProvider>>,
+    selected_provider: usize,
+    // Menu de débogage caché (Ctrl+Maj+D) : séries synthétiques à la place de CoinGecko
+    debug_menu_open: bool,
+    mock_scenario: Option<MockScenario>,
+    settings: Settings,
+    settings_applied: bool,
+    font_path_input: String,
+    // Identifiant saisi pour l'ajouter à la liste de suivi
+    watchlist_input: String,
+    // Recette collée par l'utilisateur
+    recipe_input: String,
+    // Périphériques de sortie listés à la première ouverture du choix de copie du son
+    #[cfg(not(target_arch = "wasm32"))]
+    output_devices: Option<Vec<String>>,
+    settings_error: Option<String>,
+    // Dernier changement des réglages suivis sur le graphique, pas encore écrit sur le disque
+    settings_changed_at: Option<f64>,
+    // Icône de la zone de notification, et vraie fermeture demandée depuis son menu
+    #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+    tray: Option<crate::tray::Tray>,
+    #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+    quitting: bool,
+    // Erreur hors chargement (session, recette...), affichée dans une boîte de dialogue
+    error_dialog: Option<Error>,
+    launch: LaunchOptions,
+    launch_fetched: bool,
+    // Le graphique ouvert par --coin reste en pause sauf avec --autoplay
+    next_chart_playing: bool,
+}
+
+impl MainApp {
+    pub fn new() -> Self {
+        Self::with_launch_options(LaunchOptions::default())
+    }
+
+    pub fn with_launch_options(launch: LaunchOptions) -> Self {
+        Language::from_env().set();
+        let settings = Settings::load();
+
+        Self {
+            navigation: NavigationHistory::new(Page::Selection),
+            shown_page: Page::Selection,
+            transition: None,
+            selection_page: SelectionPage::new(),
+            charts: HashMap::new(),
+            recipe_charts: HashSet::new(),
+            popped_out: HashMap::new(),
+            ticker: None,
+            ticker_restore_size: None,
+            loading_state: LoadingState::NotLoading,
+            fetches: FetchPipeline::new(),
+            prefetcher: Prefetcher::new(),
+            search: CoinSearch::new(),
+            alerts: AlertMonitor::new(),
+            asset_packs: AssetPack::discover(),
+            selected_pack: 0,
+            sound_packs: SoundPack::discover(),
+            selected_sound_pack: None,
+            textures: TextureCache::new(),
+            dashboard: None,
+            browse: None,
+            overview: None,
+            providers: [Arc::new(CoinGeckoProvider) as Arc<dyn PriceProvider>, Arc::new(IndexProvider)]
+                .into_iter()
+                .chain(discover_plugins())
+                .collect(),
+            selected_provider: 0,
+            debug_menu_open: false,
+            mock_scenario: None,
+            font_path_input: settings.font_path.clone().unwrap_or_default(),
+            watchlist_input: String::new(),
+            recipe_input: String::new(),
+            #[cfg(not(target_arch = "wasm32"))]
+            output_devices: None,
+            settings,
+            settings_applied: false,
+            settings_error: None,
+            settings_changed_at: None,
+            #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+            tray: None,
+            #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+            quitting: false,
+            error_dialog: None,
+            launch,
+            launch_fetched: false,
+            next_chart_playing: true,
+        }
+    }
+}
+
+impl Default for MainApp {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl MainApp {
+    fn apply_settings(&mut self, ctx: &egui::Context) {
+        ctx.set_zoom_factor(self.settings.ui_scale);
+        self.settings.theme.apply(ctx);
+        crate::notify::set_enabled(self.settings.notifications);
+        set_http_policy(self.settings.http);
+        set_reduce_motion(ctx, self.settings.reduce_motion());
+        if let Err(e) = apply_custom_font(ctx, self.settings.font_path.as_deref()) {
+            self.settings_error = Some(tr_args("font-failed", &[("error", &e.to_string())]));
+            self.settings.font_path = None;
+        }
+        // L'icône a besoin du contexte pour réveiller l'interface : elle apparaît à la première image
+        #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+        match crate::tray::Tray::new(ctx, &self.settings.watchlist, self.days()) {
+            Ok(tray) => self.tray = Some(tray),
+            Err(e) => println!("{}", tr_args("log-tray-failed", &[("error", &e.to_string())])),
+        }
+        self.settings_applied = true;
+    }
+
+    // --exit-when-done : la fenêtre se ferme quand le graphique affiché a fini de jouer. Un échec de
+    // chargement termine tout de suite avec un code d'erreur, personne n'étant là pour réessayer
+    fn exit_when_done(&mut self, ctx: &egui::Context) {
+        if !self.launch.exit_when_done {
+            return;
+        }
+        if let LoadingState::Failed { params, error } = &self.loading_state {
+            eprintln!("{}", tr_args("log-play-failed", &[("coin", params.name.as_str()), ("error", &error.to_string())]));
+            std::process::exit(1);
+        }
+        if self.current_chart().is_some_and(ChartApp::playback_finished) {
+            #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+            {
+                self.quitting = true;
+            }
+            ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+        }
+    }
+
+    // Commandes du menu de la zone de notification ; sonifier ou ouvrir le tableau de bord fait
+    // réapparaître la fenêtre. La fermer la cache seulement, « Quitter » ferme vraiment
+    #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+    fn handle_tray(&mut self, ctx: &egui::Context) {
+        use crate::tray::TrayCommand;
+
+        let Some(tray) = &self.tray else {
+            return;
+        };
+        let commands: Vec<TrayCommand> = std::iter::from_fn(|| tray.poll()).collect();
+        let show_window = |ctx: &egui::Context| {
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(true));
+            ctx.send_viewport_cmd(egui::ViewportCommand::Focus);
+        };
+        for command in commands {
+            match command {
+                TrayCommand::Sonify(coin) => {
+                    show_window(ctx);
+                    if self.ticker.is_some() {
+                        self.close_ticker(ctx);
+                    }
+                    self.start_fetch(ctx, &coin, &coin_display_name(&coin));
+                },
+                TrayCommand::OpenDashboard => {
+                    show_window(ctx);
+                    if self.ticker.is_some() {
+                        self.close_ticker(ctx);
+                    }
+                    self.cancel_loading();
+                    self.navigation.navigate(Page::Dashboard);
+                },
+                TrayCommand::ShowWindow => show_window(ctx),
+                TrayCommand::HideWindow => ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false)),
+                TrayCommand::Quit => {
+                    self.quitting = true;
+                    ctx.send_viewport_cmd(egui::ViewportCommand::Close);
+                },
+            }
+        }
+        if self.settings.close_to_tray && !self.quitting && ctx.input(|i| i.viewport().close_requested()) {
+            ctx.send_viewport_cmd(egui::ViewportCommand::CancelClose);
+            ctx.send_viewport_cmd(egui::ViewportCommand::Visible(false));
+        }
+    }
+
+    fn days(&self) -> i64 {
+        self.launch.days.unwrap_or(self.settings.days)
+    }
+
+    fn current_chart(&self) -> Option<&ChartApp> {
+        match self.navigation.current() {
+            Page::Chart(coin) => self.charts.get(coin),
+            Page::Selection | Page::Dashboard | Page::Browse | Page::Overview | Page::Settings => None,
+        }
+    }
+
+    fn open_chart(&mut self, coin: CoinId, chart: ChartApp, sound: SoundOptions) {
+        // Le portefeuille a son propre bouton, il n'est pas une crypto récente
+        if coin != PORTFOLIO_ID {
+            self.settings.recent.retain(|recent| *recent != coin);
+            self.settings.recent.insert(0, coin.clone());
+            self.settings.recent.truncate(Settings::MAX_RECENT);
+            self.save_settings();
+        }
+        let mut chart = chart
+            .with_captions(self.settings.captions)
+            .with_sound_options(sound)
+            .with_sound_pack(self.selected_sound_pack.map(|i| self.sound_packs[i].clone()))
+            .with_mirror_output(self.settings.mirror_output.as_deref());
+        chart.set_export_loudness(self.settings.export_loudness);
+        self.recipe_charts.remove(&coin);
+        self.charts.insert(coin.clone(), chart);
+        self.navigation.navigate(Page::Chart(coin));
+    }
+
+    // Affiche le graphique de la crypto ; un graphique libéré entre-temps est rechargé avec les réglages du moment
+    fn show_chart(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame, coin: &CoinId) {
+        // Rouvert depuis l'historique alors qu'il est dans sa fenêtre : il revient dans la fenêtre principale
+        if let Some(mut chart) = self.popped_out.remove(coin) {
+            chart.set_popped_out(false);
+            self.charts.insert(coin.clone(), chart);
+        }
+        let Some(chart) = self.charts.get_mut(coin) else {
+            if matches!(self.loading_state, LoadingState::NotLoading) {
+                self.start_fetch(ctx, coin, &coin_display_name(coin));
+            }
+            egui::CentralPanel::default().show(ctx, |_| {});
+            return;
+        };
+        chart.update(ctx, frame);
+        if std::mem::take(&mut chart.should_return_home) {
+            self.navigation.navigate(Page::Selection);
+        }
+        if std::mem::take(&mut chart.should_open_ticker) {
+            let ticker = MiniTicker::new(chart.coin(), chart.currency(), chart.daily_prices(), chart.volume());
+            chart.suspend();
+            self.open_ticker(ctx, ticker);
+        } else if std::mem::take(&mut chart.should_pop_out) {
+            // La fenêtre principale revient à la page d'avant, le graphique continue dans la sienne
+            if let Some(mut chart) = self.charts.remove(coin) {
+                chart.set_popped_out(true);
+                self.popped_out.insert(coin.clone(), chart);
+            }
+            self.go_back();
+        }
+    }
+
+    // La fenêtre principale devient le bandeau : sans bordure, toujours au premier plan, à sa taille
+    fn open_ticker(&mut self, ctx: &egui::Context, ticker: MiniTicker) {
+        self.ticker_restore_size = ctx.input(|i| i.viewport().inner_rect).map(|rect| rect.size());
+        ctx.send_viewport_cmd(egui::ViewportCommand::Decorations(false));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(egui::WindowLevel::AlwaysOnTop));
+        ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(MiniTicker::SIZE));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(MiniTicker::SIZE));
+        self.ticker = Some(ticker);
+    }
+
+    // Retour au graphique quitté, dans la fenêtre d'avant
+    fn close_ticker(&mut self, ctx: &egui::Context) {
+        self.ticker = None;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Decorations(true));
+        ctx.send_viewport_cmd(egui::ViewportCommand::WindowLevel(egui::WindowLevel::Normal));
+        ctx.send_viewport_cmd(egui::ViewportCommand::MinInnerSize(MIN_WINDOW_SIZE));
+        ctx.send_viewport_cmd(egui::ViewportCommand::InnerSize(self.ticker_restore_size.take().unwrap_or(WINDOW_SIZE)));
+    }
+
+    // Chaque graphique sorti a sa fenêtre native, dessinée avec la fenêtre principale. La fermer
+    // arrête le graphique, son bouton de retour le ramène dans la fenêtre principale
+    fn show_popped_out_charts(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        let mut closed = Vec::new();
+        let mut docked = Vec::new();
+        for (coin, chart) in &mut self.popped_out {
+            let builder = egui::ViewportBuilder::default()
+                .with_title(coin_display_name(coin))
+                .with_inner_size(WINDOW_SIZE)
+                .with_min_inner_size(MIN_WINDOW_SIZE);
+            ctx.show_viewport_immediate(egui::ViewportId::from_hash_of(("chart_window", coin)), builder, |ctx, _| {
+                chart.update(ctx, frame);
+                if ctx.input(|i| i.viewport().close_requested()) {
+                    closed.push(coin.clone());
+                }
+            });
+            if std::mem::take(&mut chart.should_return_home) {
+                docked.push(coin.clone());
+            }
+        }
+        for coin in closed {
+            if let Some(mut chart) = self.popped_out.remove(&coin) {
+                chart.suspend();
+            }
+        }
+        for coin in docked {
+            if let Some(mut chart) = self.popped_out.remove(&coin) {
+                chart.set_popped_out(false);
+                self.charts.insert(coin.clone(), chart);
+                self.navigation.navigate(Page::Chart(coin));
+            }
+        }
+    }
+
+    // Retour et avance dans l'historique : Alt+flèches, boutons latéraux de la souris ou boutons en bas à gauche
+    fn show_navigation_buttons(&mut self, ctx: &egui::Context) {
+        let mut back = ctx.input_mut(|i| {
+            i.consume_key(egui::Modifiers::ALT, egui::Key::ArrowLeft) || i.pointer.button_pressed(egui::PointerButton::Extra1)
+        });
+        let mut forward = ctx.input_mut(|i| {
+            i.consume_key(egui::Modifiers::ALT, egui::Key::ArrowRight) || i.pointer.button_pressed(egui::PointerButton::Extra2)
+        });
+        if self.navigation.can_go_back() || self.navigation.can_go_forward() {
+            egui::Area::new(egui::Id::new("navigation_buttons"))
+                .anchor(egui::Align2::LEFT_BOTTOM, [8.0, -8.0])
+                .show(ctx, |ui| {
+                    ui.horizontal(|ui| {
+                        let button = ui.add_enabled(self.navigation.can_go_back(), egui::Button::new("◀").small());
+                        back |= accessible_name(button, egui::WidgetType::Button, tr("nav-back")).on_hover_text(tr("nav-back-hint")).clicked();
+                        let button = ui.add_enabled(self.navigation.can_go_forward(), egui::Button::new("▶").small());
+                        forward |= accessible_name(button, egui::WidgetType::Button, tr("nav-forward")).on_hover_text(tr("nav-forward-hint")).clicked();
+                    });
+                });
+        }
+        if back {
+            self.go_back();
+        } else if forward {
+            self.navigation.forward();
+        }
+    }
+
+    // Un chargement en cours est abandonné : la page précédente reste celle qu'on quitte
+    fn go_back(&mut self) {
+        self.cancel_loading();
+        if !self.navigation.back() {
+            self.navigation.navigate(Page::Selection);
+        }
+    }
+
+    // Met en pause le graphique quitté et libère ceux que l'historique a oubliés ou qui sont trop loin
+    fn page_changed(&mut self, previous: &Page) {
+        if let Page::Chart(coin) = previous {
+            if let Some(chart) = self.charts.get_mut(coin) {
+                chart.suspend();
+            }
+        }
+        let mut kept: Vec<CoinId> = Vec::new();
+        for page in self.navigation.pages() {
+            if let Page::Chart(coin) = page {
+                if kept.len() < MAX_OPEN_CHARTS && !kept.contains(coin) {
+                    kept.push(coin.clone());
+                }
+            }
+        }
+        self.charts.retain(|coin, _| kept.contains(coin));
+    }
+
+    // Retient la crypto ouverte, le volume, le preset, les sous-titres et les options de son du graphique
+    // affiché. Un curseur qu'on glisse change ces réglages à chaque frame : ils ne sont écrits qu'après
+    // SETTINGS_SAVE_DELAY secondes sans changement, ou à la fermeture. Les autres pages gardent la
+    // dernière crypto ouverte, un graphique ouvert depuis une recette garde le preset et le son enregistrés
+    fn remember_chart_state(&mut self, ctx: &egui::Context) {
+        let now = ctx.input(|i| i.time);
+        if let Some(chart) = self.current_chart() {
+            let from_recipe = self.recipe_charts.contains(chart.coin());
+            let coin = Some(chart.coin()).filter(|coin| self.settings.last_coin.as_deref() != Some(*coin)).map(str::to_string);
+            let (volume, captions) = (chart.volume(), chart.captions());
+            let (preset, sound) = if from_recipe {
+                (self.settings.preset, self.settings.sound)
+            } else {
+                (chart.view_mode(), chart.sound_options())
+            };
+            let settings = &mut self.settings;
+            if coin.is_some()
+                || settings.volume != volume
+                || settings.preset != preset
+                || settings.captions != captions
+                || settings.sound != sound
+            {
+                if let Some(coin) = coin {
+                    settings.last_coin = Some(coin);
+                }
+                settings.volume = volume;
+                settings.preset = preset;
+                settings.captions = captions;
+                settings.sound = sound;
+                self.settings_changed_at = Some(now);
+            }
+        }
+        match self.settings_changed_at {
+            Some(changed_at) if now - changed_at >= SETTINGS_SAVE_DELAY => self.flush_settings(),
+            // Sans autre raison de redessiner, une frame est quand même demandée pour l'écriture
+            Some(changed_at) => ctx.request_repaint_after(std::time::Duration::from_secs_f64(SETTINGS_SAVE_DELAY - (now - changed_at))),
+            None => {},
+        }
+    }
+
+    // Écrit les réglages suivis sur le graphique s'ils ont changé depuis la dernière écriture
+    fn flush_settings(&mut self) {
+        if self.settings_changed_at.take().is_some() {
+            self.save_settings();
+        }
+    }
+
+    // Écrit tous les réglages, y compris ceux du graphique en attente
+    fn save_settings(&mut self) {
+        self.settings_changed_at = None;
+        if let Err(e) = self.settings.save() {
+            self.settings_error = Some(tr_args("settings-save-failed", &[("error", &e.to_string())]));
+        }
+    }
+
+    // Quantité détenue de chaque crypto connue et bouton d'écoute du portefeuille
+    fn show_portfolio_settings(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new(tr("portfolio-header"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut save = false;
+                egui::Grid::new("holdings").num_columns(2).show(ui, |ui| {
+                    for meta in COINS {
+                        let position = self.settings.holdings.iter().position(|holding| holding.coin == meta.id);
+                        let mut amount = position.map_or(0.0, |i| self.settings.holdings[i].amount);
+                        ui.label(coin_display_name(meta.id));
+                        let response = ui.add(egui::DragValue::new(&mut amount).clamp_range(0.0..=f64::MAX).speed(0.01).suffix(format!(" {}", meta.symbol)));
+                        let response = accessible_name(response, egui::WidgetType::DragValue, &tr_args("a11y-holding", &[("coin", meta.name)]));
+                        ui.end_row();
+
+                        // Une quantité nulle retire la crypto du portefeuille
+                        match position {
+                            Some(i) if amount <= 0.0 => {
+                                self.settings.holdings.remove(i);
+                            },
+                            Some(i) => self.settings.holdings[i].amount = amount,
+                            None if amount > 0.0 => self.settings.holdings.push(Holding { coin: meta.id.to_string(), amount }),
+                            None => {},
+                        }
+                        // Enregistré au relâchement plutôt qu'à chaque pas du glisser
+                        save |= response.drag_released() || (response.changed() && !response.dragged());
+                    }
+                });
+                if save {
+                    self.save_settings();
+                }
+
+                let has_holdings = self.settings.holdings.iter().any(|holding| holding.amount > 0.0);
+                if ui.add_enabled(has_holdings, egui::Button::new(tr("portfolio-listen"))).clicked() {
+                    self.start_fetch(ctx, PORTFOLIO_ID, tr("portfolio"));
+                }
+            });
+    }
+
+    fn provider(&self) -> Arc<dyn PriceProvider> {
+        match self.mock_scenario {
+            Some(scenario) => Arc::new(MockProvider::new(scenario)),
+            None => self.providers[self.selected_provider].clone(),
+        }
+    }
+
+    fn show_debug_menu(&mut self, ctx: &egui::Context) {
+        if ctx.input(|i| i.modifiers.ctrl && i.modifiers.shift && i.key_pressed(egui::Key::D)) {
+            self.debug_menu_open = !self.debug_menu_open;
+        }
+
+        let mut open = self.debug_menu_open;
+        egui::Window::new(tr("debug-menu"))
+            .open(&mut open)
+            .resizable(false)
+            .show(ctx, |ui| {
+                ui.label(tr("data-source"));
+                ui.radio_value(&mut self.mock_scenario, None, tr("source-live"));
+                for scenario in MockScenario::ALL {
+                    ui.radio_value(&mut self.mock_scenario, Some(scenario), tr(scenario.label_key()));
+                }
+            });
+        self.debug_menu_open = open;
+    }
+
+    // Source, période et devise du moment ; `fetch` les garde pour un éventuel nouvel essai
+    fn start_fetch(&mut self, ctx: &egui::Context, coin: &str, name: &str) {
+        let params = FetchParams {
+            coin: coin.to_string(),
+            name: name.to_string(),
+            provider: self.provider(),
+            options: ChartOptions::current(&self.launch, &self.settings),
+            playing: std::mem::replace(&mut self.next_chart_playing, true),
+        };
+        self.fetch(ctx, params);
+    }
+
+    fn fetch(&mut self, ctx: &egui::Context, params: FetchParams) {
+        // Un seul chargement à la fois : un clic sur une autre crypto remplace le précédent
+        self.cancel_loading();
+        if params.coin == PORTFOLIO_ID {
+            self.fetch_portfolio(ctx, params);
+            return;
+        }
+
+        // Série déjà préchargée : le graphique s'ouvre tout de suite, sans requête (le préchargement
+        // couvre les derniers jours, jamais une période fixe)
+        let now = ctx.input(|i| i.time);
+        let options = &params.options;
+        let prefetched = options
+            .range
+            .is_none()
+            .then(|| self.prefetcher.get(params.provider.as_ref(), &params.coin, options.days, &options.currency, now))
+            .flatten();
+        if let Some(data) = prefetched {
+            self.open_loaded_chart(params, Ok(data));
+            return;
+        }
+
+        let id = self.fetches.fetch(ctx, FetchRequest {
+            provider: params.provider.clone(),
+            coin: params.coin.clone(),
+            days: params.options.days,
+            currency: params.options.currency.clone(),
+            range: params.options.range,
+        });
+        self.loading_state = LoadingState::Loading { id, params, progress: LoadingProgress::default() };
+    }
+
+    // Charge en parallèle chaque crypto détenue pour sonifier la valeur totale du portefeuille
+    fn fetch_portfolio(&mut self, ctx: &egui::Context, params: FetchParams) {
+        let ids = self.settings.holdings
+            .iter()
+            .filter(|holding| holding.amount > 0.0)
+            .map(|holding| self.fetches.fetch(ctx, FetchRequest {
+                provider: params.provider.clone(),
+                coin: holding.coin.clone(),
+                days: params.options.days,
+                currency: params.options.currency.clone(),
+                range: params.options.range,
+            }))
+            .collect::<Vec<_>>();
+        // Portefeuille vidé depuis (ouverture au lancement) : rien à charger
+        if ids.is_empty() {
+            self.error_dialog = Some(Error::NoData(tr("portfolio").to_string()));
+            return;
+        }
+        self.loading_state = LoadingState::LoadingPortfolio { ids, params, received: Vec::new(), progress: LoadingProgress::default() };
+    }
+
+    // Abandonne le chargement en cours, sa réponse éventuelle sera ignorée
+    fn cancel_loading(&mut self) {
+        match std::mem::replace(&mut self.loading_state, LoadingState::NotLoading) {
+            LoadingState::Loading { id, .. } => self.fetches.cancel(id),
+            LoadingState::LoadingPortfolio { ids, .. } => {
+                for id in ids {
+                    self.fetches.cancel(id);
+                }
+            },
+            LoadingState::Failed { .. } | LoadingState::NotLoading => {},
+        }
+    }
+
+    // Met à jour le message de progression et ouvre le graphique une fois la série reçue
+    fn receive_fetches(&mut self) {
+        for event in self.fetches.poll() {
+            match event {
+                FetchEvent::Progress { id, stage, message } => match &mut self.loading_state {
+                    LoadingState::Loading { id: loading_id, progress, .. } if *loading_id == id => {
+                        *progress = LoadingProgress { fraction: stage.fraction(), message: Some(message) };
+                    },
+                    // Pour un portefeuille, la barre avance avec les séries reçues
+                    LoadingState::LoadingPortfolio { ids, progress, .. } if ids.contains(&id) => progress.message = Some(message),
+                    _ => {},
+                },
+                FetchEvent::Done { result, .. } if matches!(self.loading_state, LoadingState::LoadingPortfolio { .. }) => {
+                    self.receive_portfolio_part(result);
+                },
+                FetchEvent::Done { result, .. } => {
+                    if let LoadingState::Loading { params, .. } = std::mem::replace(&mut self.loading_state, LoadingState::NotLoading) {
+                        self.open_loaded_chart(params, result);
+                    }
+                },
+            }
+        }
+    }
+
+    // Ajoute une des séries du portefeuille ; le graphique s'ouvre à la dernière, la première erreur abandonne le reste
+    fn receive_portfolio_part(&mut self, result: Result<ChartData>) {
+        let LoadingState::LoadingPortfolio { ids, params, received, progress } = &mut self.loading_state else {
+            return;
+        };
+        match result {
+            Ok(data) => {
+                received.push(data);
+                if received.len() < ids.len() {
+                    *progress = LoadingProgress {
+                        fraction: received.len() as f32 / ids.len() as f32,
+                        message: Some(tr_args("portfolio-progress", &[
+                            ("done", &received.len().to_string()),
+                            ("total", &ids.len().to_string()),
+                        ])),
+                    };
+                    return;
+                }
+                let (series, params) = (std::mem::take(received), params.clone());
+                self.loading_state = LoadingState::NotLoading;
+                let result = portfolio_series(&self.settings.holdings, &series);
+                self.open_loaded_chart(params, result);
+            },
+            Err(error) => {
+                let params = params.clone();
+                self.cancel_loading();
+                self.loading_state = LoadingState::Failed { params, error };
+            },
+        }
+    }
+
+    fn open_loaded_chart(&mut self, params: FetchParams, result: Result<ChartData>) {
+        let asset_pack = self.asset_packs[self.selected_pack].clone();
+
+        match result.map(|data| ChartApp::new_from_data(
+            data,
+            asset_pack,
+            params.options.preset,
+            self.settings.volume,
+            params.options.speed,
+            params.playing,
+            self.textures.clone(),
+        )) {
+            Ok(chart) => {
+                self.open_chart(params.coin.clone(), chart, params.options.sound);
+                if params.options.is_recipe() {
+                    self.recipe_charts.insert(params.coin);
+                }
+            },
+            Err(error) => self.loading_state = LoadingState::Failed { params, error },
+        }
+    }
+
+    // Rouvre un graphique sauvegardé avec « Sauvegarder la session »
+    fn open_session(&mut self, session: Result<SavedSession>) {
+        self.cancel_loading();
+        match session {
+            Ok(session) => {
+                let coin = session.data.coin.clone();
+                let asset_pack = self.asset_packs[self.selected_pack].clone();
+                self.open_chart(coin, ChartApp::from_session(session, asset_pack, self.textures.clone()), self.settings.sound);
+            },
+            Err(error) => self.error_dialog = Some(error),
+        }
+    }
+
+    // Fichier glissé sur la fenêtre : un historique de prix en CSV s'ouvre dans un nouveau graphique,
+    // un JSON est relu comme session sauvegardée, sinon comme recette
+    fn open_dropped_file(&mut self, ctx: &egui::Context, file: &egui::DroppedFile) {
+        let name = file.path.as_deref().and_then(Path::file_name).map_or(file.name.clone(), |name| name.to_string_lossy().to_string());
+        let path = Path::new(&name);
+        let contents = match (&file.bytes, &file.path) {
+            (Some(bytes), _) => Ok(String::from_utf8_lossy(bytes).to_string()),
+            (None, Some(path)) => std::fs::read_to_string(path).map_err(Error::from),
+            (None, None) => return,
+        };
+        let contents = match contents {
+            Ok(contents) => contents,
+            Err(error) => {
+                self.error_dialog = Some(error);
+                return;
+            },
+        };
+
+        let extension = path.extension().map(|ext| ext.to_string_lossy().to_lowercase());
+        if extension.as_deref() == Some("json") {
+            let session = parse_session(&contents);
+            match Recipe::from_json(&contents) {
+                Ok(recipe) if session.is_err() => self.open_recipe(ctx, Ok(recipe)),
+                _ => self.open_session(session),
+            }
+            return;
+        }
+
+        self.cancel_loading();
+        let coin = path.file_stem().map_or(name.clone(), |stem| stem.to_string_lossy().to_string());
+        let chart = parse_price_csv(&coin, &self.launch.currency, &contents).map(|data| ChartApp::new_from_data(
+            data,
+            self.asset_packs[self.selected_pack].clone(),
+            self.settings.preset,
+            self.settings.volume,
+            self.launch.speed,
+            true,
+            self.textures.clone(),
+        ));
+        match chart {
+            Ok(chart) => self.open_chart(coin, chart, self.settings.sound),
+            Err(error) => self.error_dialog = Some(error),
+        }
+    }
+
+    // Invitation affichée pendant le survol de la fenêtre avec des fichiers
+    fn show_drop_hint(&self, ctx: &egui::Context) {
+        if ctx.input(|i| i.raw.hovered_files.is_empty()) {
+            return;
+        }
+        let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new("drop_hint")));
+        let screen = ctx.screen_rect();
+        painter.rect_filled(screen, 0.0, Color32::from_black_alpha(192));
+        painter.rect_stroke(screen.shrink(16.0), 12.0, egui::Stroke::new(2.0, Color32::from_rgb(255, 215, 0)));
+        painter.text(
+            screen.center(),
+            egui::Align2::CENTER_CENTER,
+            tr("drop-hint"),
+            egui::FontId::proportional(22.0),
+            Color32::WHITE,
+        );
+    }
+
+    // Recharge la sonification décrite par une recette, avec sa période, sa devise, sa vitesse, son preset
+    // et son son pour ce graphique seulement : les chargements suivants reprennent les réglages du moment
+    fn open_recipe(&mut self, ctx: &egui::Context, recipe: Result<Recipe>) {
+        let recipe = match recipe {
+            Ok(recipe) => recipe,
+            Err(error) => {
+                self.error_dialog = Some(error);
+                return;
+            },
+        };
+        let params = FetchParams {
+            coin: recipe.coin.clone(),
+            name: coin_display_name(&recipe.coin),
+            provider: self.provider(),
+            options: ChartOptions::from_recipe(&recipe),
+            playing: std::mem::replace(&mut self.next_chart_playing, true),
+        };
+        self.fetch(ctx, params);
+    }
+
+    // Recette collée (reçue d'un autre utilisateur) ou enregistrée dans recipes/
+    fn show_recipe_import(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+        ui.horizontal(|ui| {
+            let response = ui.add(egui::TextEdit::singleline(&mut self.recipe_input).hint_text(tr("recipe-hint")));
+            let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+            let valid = !self.recipe_input.trim().is_empty();
+            if (ui.add_enabled(valid, egui::Button::new(tr("open-recipe"))).clicked() || submitted) && valid {
+                let recipe = Recipe::from_json(&self.recipe_input);
+                if recipe.is_ok() {
+                    self.recipe_input.clear();
+                }
+                self.open_recipe(ctx, recipe);
+            }
+        });
+
+        let mut opened_recipe = None;
+        egui::ComboBox::from_label(tr("saved-recipes"))
+            .selected_text(tr("choose-recipe"))
+            .show_ui(ui, |ui| {
+                let recipes = list_recipes();
+                if recipes.is_empty() {
+                    ui.label(tr("no-recipes"));
+                }
+                for path in recipes {
+                    let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                    if ui.selectable_label(false, name).clicked() {
+                        opened_recipe = Some(path);
+                    }
+                }
+            });
+        if let Some(path) = opened_recipe {
+            self.open_recipe(ctx, load_recipe(&path));
+        }
+    }
+
+    // Squelette et avancement pendant un chargement, écran d'erreur s'il a échoué ; par-dessus
+    // n'importe quelle page, puisqu'un graphique se charge aussi depuis l'historique ou l'exploration
+    fn show_loading_overlay(&mut self, ctx: &egui::Context) {
+        if matches!(self.loading_state, LoadingState::NotLoading) {
+            return;
+        }
+        egui::Area::new(egui::Id::new("loading_overlay"))
+            .order(egui::Order::Foreground)
+            .fixed_pos(egui::Pos2::ZERO)
+            .show(ctx, |ui| {
+                ui.set_min_size(ctx.screen_rect().size());
+                self.show_loading_progress(ctx, ui);
+            });
+    }
+
+    fn show_loading_progress(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+        let (crypto_name, progress) = match &self.loading_state {
+            LoadingState::Loading { params, progress, .. } | LoadingState::LoadingPortfolio { params, progress, .. } => (params.name.as_str(), progress),
+            LoadingState::Failed { .. } => return self.show_failed_fetch(ctx, ui),
+            LoadingState::NotLoading => return,
+        };
+        let screen_rect = ctx.screen_rect();
+
+        // Overlay sombre semi-transparent
+        ui.painter().rect_filled(
+            screen_rect,
+            0.0,
+            Color32::from_black_alpha(192)
+        );
+
+        // Squelette du graphique à venir, avec l'étape réelle du chargement en dessous
+        let time = ui.input(|i| i.time);
+        let card = egui::Rect::from_center_size(
+            screen_rect.center() - egui::vec2(0.0, 60.0),
+            egui::vec2(screen_rect.width().min(520.0) - 40.0, 220.0),
+        );
+        paint_skeleton_chart(ui.painter(), card, time);
+
+        let text_rect = egui::Rect::from_min_size(
+            egui::pos2(card.left(), card.bottom() + 16.0),
+            egui::vec2(card.width(), 40.0),
+        );
+        ui.put(text_rect, egui::Label::new(
+            egui::RichText::new(tr_args("loading", &[("coin", crypto_name)]))
+                .size(24.0)
+                .color(Color32::WHITE)
+                .text_style(egui::TextStyle::Heading)
+        ));
+        let mut bar = egui::ProgressBar::new(progress.fraction).animate(!reduce_motion());
+        if let Some(message) = &progress.message {
+            bar = bar.text(message.as_str());
+        }
+        ui.put(text_rect.translate(egui::vec2(0.0, 44.0)).shrink2(egui::vec2(0.0, 8.0)), bar);
+
+        ctx.request_repaint();  // Pour le reflet du squelette
+    }
+
+    // « Impossible de charger... » : Réessayer relance la même requête (source, période, devise,
+    // lecture automatique), Retour revient à la page d'où le chargement est parti
+    fn show_failed_fetch(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+        let LoadingState::Failed { params, error } = &self.loading_state else {
+            return;
+        };
+        let screen_rect = ctx.screen_rect();
+        ui.painter().rect_filled(screen_rect, 0.0, Color32::from_black_alpha(192));
+
+        let mut retry = shortcut_pressed(ctx, egui::Key::Enter);
+        let mut back = shortcut_pressed(ctx, egui::Key::Backspace);
+        let card = egui::Rect::from_center_size(screen_rect.center(), egui::vec2(screen_rect.width().min(520.0) - 40.0, 200.0));
+        ui.allocate_ui_at_rect(card, |ui| {
+            egui::Frame::group(ui.style()).fill(ui.visuals().window_fill).show(ui, |ui| {
+                ui.set_width(card.width());
+                ui.vertical_centered(|ui| {
+                    ui.label(egui::RichText::new(tr(error.title_key())).color(Color32::from_rgb(255, 88, 88)));
+                    ui.label(egui::RichText::new(tr_args("fetch-failed", &[("coin", &params.name)])).size(22.0).strong());
+                    ui.add_space(6.0);
+                    ui.label(egui::RichText::new(error.to_string()).color(secondary_text_color(ui)));
+                    ui.add_space(12.0);
+                    ui.horizontal(|ui| {
+                        retry |= ui.button(tr("retry")).clicked();
+                        back |= ui.button(tr("fetch-failed-back")).clicked();
+                    });
+                });
+            });
+        });
+
+        if retry {
+            let params = params.clone();
+            self.fetch(ctx, params);
+        } else if back {
+            self.loading_state = LoadingState::NotLoading;
+            // Un graphique oublié rouvert depuis l'historique : sans retour, il serait rechargé aussitôt
+            if self.current_chart().is_none() && matches!(self.navigation.current(), Page::Chart(_)) {
+                self.go_back();
+            }
+        }
+    }
+
+    fn show_error_dialog(&mut self, ctx: &egui::Context) {
+        let Some(error) = &self.error_dialog else {
+            return;
+        };
+        let mut dismissed = false;
+
+        egui::Window::new(tr(error.title_key()))
+            .collapsible(false)
+            .resizable(false)
+            .anchor(egui::Align2::CENTER_CENTER, [0.0, 0.0])
+            .show(ctx, |ui| {
+                ui.label(error.to_string());
+                ui.add_space(8.0);
+                if ui.button(tr("dismiss")).clicked() {
+                    dismissed = true;
+                }
+            });
+
+        if dismissed {
+            self.error_dialog = None;
+        }
+    }
+}
+
+impl eframe::App for MainApp {
+    fn update(&mut self, ctx: &egui::Context, frame: &mut eframe::Frame) {
+        crate::profiling::new_frame();
+        crate::profile_function!();
+
+        if !self.settings_applied {
+            self.apply_settings(ctx);
+        }
+        #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+        self.handle_tray(ctx);
+
+        // Crypto demandée avec --coin, sinon celle ouverte à la dernière fermeture
+        if !self.launch_fetched {
+            self.launch_fetched = true;
+            if let Some(coin) = self.launch.coin.clone().or_else(|| self.settings.last_coin.clone()) {
+                self.next_chart_playing = self.launch.autoplay;
+                self.start_fetch(ctx, &coin, &coin_display_name(&coin));
+            }
+        }
+
+        self.receive_fetches();
+        for file in ctx.input(|i| i.raw.dropped_files.clone()) {
+            self.open_dropped_file(ctx, &file);
+        }
+
+        // Les alertes sont surveillées et affichées quelle que soit la page ouverte
+        self.alerts.update(ctx, self.provider(), &self.settings.alerts);
+        self.alerts.show_banners(ctx);
+
+        // Bandeau compact : les pages ne sont pas dessinées, les graphiques détachés restent ouverts
+        if let Some(ticker) = &mut self.ticker {
+            if ticker.update(ctx) {
+                self.close_ticker(ctx);
+            }
+            self.show_popped_out_charts(ctx, frame);
+            return;
+        }
+
+        // Ctrl+, (Cmd+, sur macOS) ouvre les préférences depuis n'importe quelle page
+        if ctx.input_mut(|i| i.consume_key(egui::Modifiers::COMMAND, egui::Key::Comma)) {
+            self.navigation.navigate(Page::Settings);
+        }
+
+        // La page a changé depuis la dernière image : la nouvelle apparaît sous un voile qui se retire
+        if *self.navigation.current() != self.shown_page {
+            let previous = std::mem::replace(&mut self.shown_page, self.navigation.current().clone());
+            self.page_changed(&previous);
+            let direction = if self.navigation.moved_back() || self.shown_page == Page::Selection {
+                TransitionDirection::Back
+            } else {
+                TransitionDirection::Forward
+            };
+            self.transition = (!reduce_motion()).then(|| PageTransition::new(ctx.input(|i| i.time), direction));
+        }
+
+        match self.navigation.current().clone() {
+            Page::Selection => {
+                self.selection_page.load_images_if_needed(ctx, &self.asset_packs[self.selected_pack], &self.textures, &self.settings.watchlist);
+                if self.settings.prefetch {
+                    self.prefetcher.update(ctx, self.provider(), &self.settings.watchlist, self.days(), &self.launch.currency);
+                }
+                
+                egui::CentralPanel::default().show(ctx, |ui| {
+                    egui::ScrollArea::vertical().show(ui, |ui| {
+                        ui.vertical_centered(|ui| {
+                            // Les tailles s'adaptent aux petites fenêtres
+                            let compact = ui.available_width() < 600.0;
+                            ui.add_space(if compact { 15.0 } else { 40.0 });
+                        
+                            // Titre principal
+                            ui.heading(egui::RichText::new(tr("app-title"))
+                                .size((ui.available_width() / 14.0).clamp(24.0, 40.0))
+                                .color(Color32::from_rgb(255, 215, 0)));
+                        
+                            ui.add_space(if compact { 10.0 } else { 20.0 });
+                        
+                            // Description de l'application
+                            ui.label(egui::RichText::new(tr("app-description")).size(if compact { 13.0 } else { 16.0 })
+                            .color(secondary_text_color(ui)));
+                        
+                            ui.add_space(if compact { 15.0 } else { 40.0 });
+                        
+                            // Sous-titre
+                            ui.heading(egui::RichText::new(tr("choose-side"))
+                                .size(if compact { 18.0 } else { 24.0 })
+                                .strong());
+                        
+                            ui.add_space(if compact { 10.0 } else { 30.0 });
+
+                            // Cartes des cryptos de la liste de suivi, réparties en lignes selon la largeur disponible
+                            // Favoris en tête, l'ordre de la liste de suivi est gardé au sein de chaque groupe
+                            let mut coins: Vec<&CoinId> = self.settings.watchlist.iter().collect();
+                            coins.sort_by_key(|coin| !self.settings.favorites.contains(coin));
+                            let cards: Vec<_> = coins
+                                .into_iter()
+                                .map(|coin| {
+                                    let name = coin_meta(coin).map_or(coin.as_str(), |meta| meta.name).to_string();
+                                    let tagline = coin_meta(coin).map_or("", |meta| tr(meta.tagline_key));
+                                    let texture = self.selection_page.portrait(coin).or_else(|| self.textures.logo(ctx, coin));
+                                    (coin.clone(), name, coin_display_name(coin), coin_accent(coin), tagline, texture)
+                                })
+                                .collect();
+                            if cards.is_empty() {
+                                ui.label(egui::RichText::new(tr("watchlist-empty")).italics().color(Color32::GRAY));
+                                ui.add_space(20.0);
+                            }
+                            let available_width = ui.available_width();
+                            let spacing = (available_width * 0.05).min(50.0);
+                            let card_width = (available_width - 20.0).clamp(120.0, 250.0);
+                            let per_row = (((available_width + spacing) / (card_width + spacing)).floor() as usize)
+                                .clamp(1, cards.len().max(1));
+
+                            let mut clicked = None;
+                            let mut toggled_favorite = None;
+                            for row in cards.chunks(per_row) {
+                                let row_width = row.len() as f32 * card_width + (row.len() - 1) as f32 * spacing;
+                                ui.horizontal(|ui| {
+                                    ui.spacing_mut().item_spacing.x = spacing;
+                                    ui.add_space(((ui.available_width() - row_width) / 2.0 - spacing).max(0.0));
+                                    for (coin, name, label, color, tagline, texture) in row {
+                                        let favorite = self.settings.favorites.contains(coin);
+                                        match coin_card(ui, texture.as_ref(), label, *color, tagline, card_width, favorite) {
+                                            Some(CardAction::Open) => clicked = Some((coin.clone(), name.clone())),
+                                            Some(CardAction::ToggleFavorite) => toggled_favorite = Some(coin.clone()),
+                                            None => {},
+                                        }
+                                    }
+                                });
+                                ui.add_space(20.0);
+                            }
+                            // Touches 1 à 9 : ouvre la carte correspondante, dans l'ordre d'affichage
+                            let digits = [
+                                egui::Key::Num1, egui::Key::Num2, egui::Key::Num3, egui::Key::Num4, egui::Key::Num5,
+                                egui::Key::Num6, egui::Key::Num7, egui::Key::Num8, egui::Key::Num9,
+                            ];
+                            for ((coin, name, ..), key) in cards.iter().zip(digits) {
+                                if shortcut_pressed(ctx, key) {
+                                    clicked = Some((coin.clone(), name.clone()));
+                                }
+                            }
+                            if let Some((coin, name)) = clicked {
+                                self.start_fetch(ctx, &coin, &name);
+                            }
+                            if let Some(coin) = toggled_favorite {
+                                match self.settings.favorites.iter().position(|favorite| *favorite == coin) {
+                                    Some(i) => {
+                                        self.settings.favorites.remove(i);
+                                    },
+                                    None => self.settings.favorites.push(coin),
+                                }
+                                self.save_settings();
+                            }
+
+                            // Accès rapide aux dernières cryptos ouvertes
+                            if !self.settings.recent.is_empty() {
+                                let mut reopened = None;
+                                ui.horizontal_wrapped(|ui| {
+                                    ui.label(egui::RichText::new(tr("recent")).color(secondary_text_color(ui)));
+                                    for coin in &self.settings.recent {
+                                        let chip = egui::Button::new(egui::RichText::new(coin_display_name(coin)).color(coin_accent(coin)))
+                                            .rounding(12.0);
+                                        if ui.add(chip).clicked() {
+                                            reopened = Some(coin.clone());
+                                        }
+                                    }
+                                });
+                                if let Some(coin) = reopened {
+                                    self.start_fetch(ctx, &coin, &coin_display_name(&coin));
+                                }
+                                ui.add_space(10.0);
+                            }
+
+                            // N'importe quelle crypto de CoinGecko, hors liste de suivi
+                            if let Some((coin, name)) = self.search.show(ctx, ui) {
+                                self.start_fetch(ctx, &coin, &name);
+                            }
+
+                            ui.add_space(if compact { 10.0 } else { 20.0 });
+                            ui.label(
+                                egui::RichText::new(tr("click-hint"))
+                                    .size(14.0)
+                                    .italics()
+                                    .color(Color32::GRAY)
+                            );
+
+                            ui.add_space(10.0);
+                            if ui.button(egui::RichText::new(tr("open-dashboard")).size(16.0)).clicked() {
+                                self.cancel_loading();
+                                self.dashboard = Some(DashboardPage::new(self.provider()));
+                                self.navigation.navigate(Page::Dashboard);
+                            }
+                            if ui.button(egui::RichText::new(tr("open-browse")).size(16.0)).clicked() {
+                                self.browse = Some(BrowsePage::new(&self.launch.currency));
+                                self.navigation.navigate(Page::Browse);
+                            }
+                            if ui.button(egui::RichText::new(tr("open-overview")).size(16.0)).clicked() {
+                                self.overview = Some(OverviewPage::new(&self.launch.currency));
+                                self.navigation.navigate(Page::Overview);
+                            }
+                            if ui.button(egui::RichText::new(tr("open-settings")).size(16.0)).clicked() {
+                                self.navigation.navigate(Page::Settings);
+                            }
+
+                            // Sessions sauvegardées, relues à l'ouverture de la liste
+                            ui.add_space(10.0);
+                            let mut opened_session = None;
+                            egui::ComboBox::from_label(tr("open-session"))
+                                .selected_text(tr("choose-session"))
+                                .show_ui(ui, |ui| {
+                                    let sessions = list_sessions();
+                                    if sessions.is_empty() {
+                                        ui.label(tr("no-sessions"));
+                                    }
+                                    for path in sessions {
+                                        let name = path.file_stem().unwrap_or_default().to_string_lossy().to_string();
+                                        if ui.selectable_label(false, name).clicked() {
+                                            opened_session = Some(path);
+                                        }
+                                    }
+                                });
+                            if let Some(path) = opened_session {
+                                self.open_session(load_session(&path));
+                            }
+                            self.show_recipe_import(ctx, ui);
+
+                            ui.add_space(10.0);
+                            self.show_portfolio_settings(ctx, ui);
+
+                            ui.add_space(10.0);
+                            if self.alerts.show_editor(ui, &mut self.settings.alerts, &self.launch.currency) {
+                                self.save_settings();
+                            }
+                        });
+                    });
+                });
+            },
+            Page::Chart(coin) => self.show_chart(ctx, frame, &coin),
+            Page::Settings => self.show_settings_page(ctx),
+            // Les pages restent en mémoire pour l'historique ; l'application relancée, elles sont recréées
+            Page::Dashboard => {
+                if self.dashboard.is_none() {
+                    self.dashboard = Some(DashboardPage::new(self.provider()));
+                }
+                if let Some(dashboard) = &mut self.dashboard {
+                    dashboard.update(ctx);
+                    if std::mem::take(&mut dashboard.should_return_home) {
+                        self.navigation.navigate(Page::Selection);
+                    }
+                }
+            },
+            Page::Browse => {
+                let browse = self.browse.get_or_insert_with(|| BrowsePage::new(&self.launch.currency));
+                let chosen = browse.update(ctx);
+                if std::mem::take(&mut browse.should_return_home) {
+                    self.navigation.navigate(Page::Selection);
+                }
+                // Le chargement s'affiche par-dessus la page, puis le graphique s'ouvre
+                if let Some((coin, name)) = chosen {
+                    self.start_fetch(ctx, &coin, &name);
+                }
+            },
+            Page::Overview => {
+                let overview = self.overview.get_or_insert_with(|| OverviewPage::new(&self.launch.currency));
+                let chosen = overview.update(ctx);
+                if std::mem::take(&mut overview.should_return_home) {
+                    self.navigation.navigate(Page::Selection);
+                }
+                if let Some((coin, name)) = chosen {
+                    self.start_fetch(ctx, &coin, &name);
+                }
+            },
+        }
+
+        self.remember_chart_state(ctx);
+        self.show_debug_menu(ctx);
+        let now = ctx.input(|i| i.time);
+        if let Some(transition) = &self.transition {
+            transition.paint(ctx, now);
+        }
+        if self.transition.as_ref().is_some_and(|transition| transition.is_done(now)) {
+            self.transition = None;
+        }
+        self.show_popped_out_charts(ctx, frame);
+        self.exit_when_done(ctx);
+        self.show_navigation_buttons(ctx);
+        self.show_loading_overlay(ctx);
+        self.show_drop_hint(ctx);
+        self.show_error_dialog(ctx);
+    }
+
+    // Réglages du graphique changés juste avant la fermeture, pas encore écrits
+    fn on_exit(&mut self, _gl: Option<&eframe::glow::Context>) {
+        self.flush_settings();
+    }
+}
@@ -0,0 +1,71 @@
+// Historique de navigation façon navigateur : pages précédentes, page actuelle et pages suivantes.
+// Ouvrir une page vide les pages suivantes, comme un lien suivi après un retour en arrière
+pub struct NavigationHistory<P> {
+    back: Vec<P>,
+    current: P,
+    forward: Vec<P>,
+    // Dernier déplacement vers l'arrière, pour le sens de la transition
+    moved_back: bool,
+}
+
+impl<P: Clone + PartialEq> NavigationHistory<P> {
+    // Au-delà, les pages les plus anciennes sont oubliées
+    pub const MAX_PAGES: usize = 50;
+
+    pub fn new(start: P) -> Self {
+        Self { back: Vec::new(), current: start, forward: Vec::new(), moved_back: false }
+    }
+
+    pub fn current(&self) -> &P {
+        &self.current
+    }
+
+    // Sans effet si la page est déjà affichée
+    pub fn navigate(&mut self, page: P) {
+        if page == self.current {
+            return;
+        }
+        self.back.push(std::mem::replace(&mut self.current, page));
+        if self.back.len() > Self::MAX_PAGES {
+            self.back.remove(0);
+        }
+        self.forward.clear();
+        self.moved_back = false;
+    }
+
+    pub fn can_go_back(&self) -> bool {
+        !self.back.is_empty()
+    }
+
+    pub fn can_go_forward(&self) -> bool {
+        !self.forward.is_empty()
+    }
+
+    pub fn back(&mut self) -> bool {
+        let Some(page) = self.back.pop() else {
+            return false;
+        };
+        self.forward.push(std::mem::replace(&mut self.current, page));
+        self.moved_back = true;
+        true
+    }
+
+    pub fn forward(&mut self) -> bool {
+        let Some(page) = self.forward.pop() else {
+            return false;
+        };
+        self.back.push(std::mem::replace(&mut self.current, page));
+        self.moved_back = false;
+        true
+    }
+
+    pub fn moved_back(&self) -> bool {
+        self.moved_back
+    }
+
+    // Pages de l'historique, de la plus proche à la plus lointaine : l'actuelle, puis les précédentes
+    // (la plus récente d'abord), puis les suivantes
+    pub fn pages(&self) -> impl Iterator<Item = &P> {
+        std::iter::once(&self.current).chain(self.back.iter().rev()).chain(self.forward.iter().rev())
+    }
+}
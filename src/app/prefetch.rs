@@ -0,0 +1,100 @@
+use crate::data::{ChartData, CoinId, PriceProvider};
+use crate::task::{FetchEvent, FetchPipeline, FetchRequest};
+use eframe::egui;
+use std::collections::{HashMap, HashSet};
+use std::sync::Arc;
+
+// Au-delà, une série préchargée est considérée comme périmée et rechargée
+const MAX_AGE_SECONDS: f64 = 300.0;
+
+// Source, période et devise des séries en cache : si l'une change, tout est rechargé
+type PrefetchKey = (String, i64, String);
+
+// Charge en arrière-plan les cryptos de la liste de suivi pour ouvrir leur graphique sans attente
+pub struct Prefetcher {
+    key: Option<PrefetchKey>,
+    // Série et instant de réception (horloge egui)
+    data: HashMap<String, (ChartData, f64)>,
+    pending: HashSet<String>,
+    // Les échecs ne sont pas retentés : le clic relancera un chargement normal avec son message d'erreur
+    failed: HashSet<String>,
+    fetches: FetchPipeline,
+}
+
+impl Prefetcher {
+    pub fn new() -> Self {
+        Self {
+            key: None,
+            data: HashMap::new(),
+            pending: HashSet::new(),
+            failed: HashSet::new(),
+            fetches: FetchPipeline::new(),
+        }
+    }
+
+    // Récupère les réponses arrivées et lance le chargement des cryptos absentes ou périmées
+    pub fn update(&mut self, ctx: &egui::Context, provider: Arc<dyn PriceProvider>, coins: &[CoinId], days: i64, currency: &str) {
+        let now = ctx.input(|i| i.time);
+        let key = (provider.name(), days, currency.to_string());
+        if self.key.as_ref() != Some(&key) {
+            self.clear();
+            self.key = Some(key);
+        }
+
+        for event in self.fetches.poll() {
+            let FetchEvent::Done { coin, result, .. } = event else {
+                continue;
+            };
+            self.pending.remove(&coin);
+            match result {
+                Ok(data) => {
+                    self.data.insert(coin, (data, now));
+                },
+                Err(_) => {
+                    self.failed.insert(coin);
+                },
+            }
+        }
+
+        for coin in coins {
+            let fresh = self.data.get(coin).is_some_and(|(_, fetched_at)| now - fetched_at < MAX_AGE_SECONDS);
+            if fresh || self.pending.contains(coin) || self.failed.contains(coin) {
+                continue;
+            }
+            self.pending.insert(coin.clone());
+            self.fetches.fetch(ctx, FetchRequest {
+                provider: provider.clone(),
+                coin: coin.clone(),
+                days,
+                currency: currency.to_string(),
+                range: None,
+            });
+        }
+    }
+
+    // Série déjà chargée pour cette source, cette période et cette devise, si elle n'est pas périmée
+    pub fn get(&self, provider: &dyn PriceProvider, coin: &str, days: i64, currency: &str, now: f64) -> Option<ChartData> {
+        if self.key.as_ref() != Some(&(provider.name(), days, currency.to_string())) {
+            return None;
+        }
+        self.data
+            .get(coin)
+            .filter(|(_, fetched_at)| now - fetched_at < MAX_AGE_SECONDS)
+            .map(|(data, _)| data.clone())
+    }
+
+    // Vide le cache et abandonne les chargements en cours
+    pub fn clear(&mut self) {
+        self.fetches.cancel_all();
+        self.key = None;
+        self.data.clear();
+        self.pending.clear();
+        self.failed.clear();
+    }
+}
+
+impl Default for Prefetcher {
+    fn default() -> Self {
+        Self::new()
+    }
+}
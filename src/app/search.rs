@@ -0,0 +1,117 @@
+use crate::data::{load_coin_list_async, search_coins, CoinListing};
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui::{self, Color32};
+use std::sync::mpsc;
+
+// Nombre de suggestions affichées sous le champ
+const MAX_SUGGESTIONS: usize = 8;
+
+enum CoinList {
+    NotLoaded,
+    Loading(mpsc::Receiver<crate::error::Result<Vec<CoinListing>>>),
+    Loaded(Vec<CoinListing>),
+    Failed(String),
+}
+
+// Champ de recherche de la page de sélection, avec suggestions parmi toutes les cryptos de CoinGecko.
+// La liste n'est chargée qu'au premier clic dans le champ
+pub struct CoinSearch {
+    list: CoinList,
+    query: String,
+    // Suggestions de la dernière recherche, recalculées seulement quand le texte change
+    suggestions: Vec<CoinListing>,
+    suggestions_query: Option<String>,
+}
+
+impl CoinSearch {
+    pub fn new() -> Self {
+        Self {
+            list: CoinList::NotLoaded,
+            query: String::new(),
+            suggestions: Vec::new(),
+            suggestions_query: None,
+        }
+    }
+
+    fn start_loading(&mut self, ctx: &egui::Context) {
+        self.list = CoinList::Loading(spawn_request(ctx, load_coin_list_async()));
+    }
+
+    fn receive_list(&mut self) {
+        if let CoinList::Loading(rx) = &self.list {
+            match rx.try_recv() {
+                Ok(Ok(list)) => {
+                    self.list = CoinList::Loaded(list);
+                    self.suggestions_query = None;
+                },
+                Ok(Err(e)) => self.list = CoinList::Failed(e.to_string()),
+                Err(mpsc::TryRecvError::Empty) => {},
+                Err(mpsc::TryRecvError::Disconnected) => self.list = CoinList::NotLoaded,
+            }
+        }
+    }
+
+    // Affiche le champ et ses suggestions ; renvoie l'identifiant et le nom de la crypto choisie
+    pub fn show(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) -> Option<(String, String)> {
+        self.receive_list();
+
+        let response = ui.add(
+            egui::TextEdit::singleline(&mut self.query)
+                .hint_text(tr("search-hint"))
+                .desired_width(300.0)
+        );
+        if response.gained_focus() && matches!(self.list, CoinList::NotLoaded) {
+            self.start_loading(ctx);
+        }
+        let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+
+        let mut chosen = None;
+        match &self.list {
+            CoinList::NotLoaded => {},
+            CoinList::Loading(_) => {
+                if !self.query.trim().is_empty() {
+                    ui.label(egui::RichText::new(tr("search-loading")).italics().color(Color32::GRAY));
+                }
+            },
+            CoinList::Failed(error) => {
+                let error = error.clone();
+                ui.horizontal(|ui| {
+                    ui.colored_label(Color32::from_rgb(255, 88, 88), tr_args("search-failed", &[("error", &error)]));
+                    if ui.small_button(tr("retry")).clicked() {
+                        self.start_loading(ctx);
+                    }
+                });
+            },
+            CoinList::Loaded(list) => {
+                if self.suggestions_query.as_deref() != Some(self.query.as_str()) {
+                    self.suggestions = search_coins(list, &self.query, MAX_SUGGESTIONS).into_iter().cloned().collect();
+                    self.suggestions_query = Some(self.query.clone());
+                }
+                if !self.query.trim().is_empty() && self.suggestions.is_empty() {
+                    ui.label(egui::RichText::new(tr("search-no-results")).italics().color(Color32::GRAY));
+                }
+                for coin in &self.suggestions {
+                    let label = format!("{} ({})", coin.name, coin.symbol.to_uppercase());
+                    if ui.selectable_label(false, label).clicked() {
+                        chosen = Some(coin.clone());
+                    }
+                }
+                // Entrée ouvre la première suggestion
+                if submitted && chosen.is_none() {
+                    chosen = self.suggestions.first().cloned();
+                }
+            },
+        }
+
+        let coin = chosen?;
+        self.query.clear();
+        Some((coin.id, coin.name))
+    }
+}
+
+impl Default for CoinSearch {
+    fn default() -> Self {
+        Self::new()
+    }
+}
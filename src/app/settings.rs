@@ -0,0 +1,230 @@
+use crate::audio::{SoundOptions, ViewMode, DEFAULT_LOUDNESS_TARGET};
+use crate::data::{AlertRule, CoinId, Holding, HttpPolicy, DEFAULT_DAYS, INTRADAY_DAYS};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::ui::widgets::ChartPalette;
+use eframe::egui::{self, Color32};
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// Préférences utilisateur, enregistrées en JSON dans le dossier de configuration
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    pub ui_scale: f32,
+    pub font_path: Option<String>,
+    // Crypto ouverte à la fermeture de l'application, rouverte au prochain lancement
+    pub last_coin: Option<String>,
+    pub days: i64,
+    pub volume: f32,
+    pub theme: Theme,
+    pub preset: ViewMode,
+    // Préchargement en arrière-plan des cryptos de la page de sélection
+    pub prefetch: bool,
+    // Règles d'alerte de prix, surveillées sur toutes les pages
+    pub alerts: Vec<AlertRule>,
+    // Quantités détenues, sonifiées ensemble en mode portefeuille
+    pub holdings: Vec<Holding>,
+    // Cryptos affichées sur la page de sélection, dans cet ordre
+    pub watchlist: Vec<CoinId>,
+    // Cryptos épinglées en tête de la page de sélection
+    pub favorites: Vec<CoinId>,
+    // Dernières cryptos ouvertes, la plus récente en premier
+    pub recent: Vec<CoinId>,
+    // Notifications du bureau à la fin des rendus et quand une alerte se déclenche
+    pub notifications: bool,
+    // Mouvements réduits ; None suit la préférence du système
+    pub reduce_motion: Option<bool>,
+    // Sous-titres de la lecture, retenus d'un graphique à l'autre
+    pub captions: bool,
+    // Durée des notes et fin de la lecture, retenues comme les sous-titres
+    pub sound: SoundOptions,
+    // Périphérique qui reçoit une copie du son des graphiques (câble virtuel pour OBS...)
+    pub mirror_output: Option<String>,
+    // Sonie visée (en LUFS) des fichiers audio et vidéo exportés ; None les laisse tels quels
+    pub export_loudness: Option<f64>,
+    // Délais et nouvelles tentatives des requêtes réseau
+    pub http: HttpPolicy,
+    // Fermer la fenêtre la cache dans la zone de notification (versions compilées avec `tray`)
+    pub close_to_tray: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            ui_scale: 1.0,
+            font_path: None,
+            last_coin: None,
+            days: DEFAULT_DAYS,
+            volume: 1.0,
+            theme: Theme::Dark,
+            preset: ViewMode::Price,
+            prefetch: true,
+            alerts: Vec::new(),
+            holdings: Vec::new(),
+            watchlist: ["ethereum", "bitcoin", "ripple"].map(String::from).to_vec(),
+            favorites: Vec::new(),
+            recent: Vec::new(),
+            notifications: true,
+            reduce_motion: None,
+            captions: false,
+            sound: SoundOptions::default(),
+            mirror_output: None,
+            export_loudness: Some(DEFAULT_LOUDNESS_TARGET),
+            http: HttpPolicy::default(),
+            close_to_tray: true,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Theme {
+    Dark,
+    Light,
+    // Fond noir, texte blanc et contours marqués pour les vues faibles
+    HighContrast,
+}
+
+impl Theme {
+    pub const ALL: [Theme; 3] = [Theme::Dark, Theme::Light, Theme::HighContrast];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            Theme::Dark => "theme-dark",
+            Theme::Light => "theme-light",
+            Theme::HighContrast => "theme-high-contrast",
+        }
+    }
+
+    pub fn visuals(&self) -> egui::Visuals {
+        let mut visuals = match self {
+            Theme::Dark => {
+                // Fond plus sombre que le thème par défaut d'egui
+                let mut visuals = egui::Visuals::dark();
+                visuals.extreme_bg_color = Color32::from_rgb(18, 18, 18);
+                visuals.panel_fill = Color32::from_rgb(24, 24, 24);
+                visuals
+            },
+            Theme::Light => egui::Visuals::light(),
+            Theme::HighContrast => {
+                let mut visuals = egui::Visuals::dark();
+                visuals.override_text_color = Some(Color32::WHITE);
+                visuals.panel_fill = Color32::BLACK;
+                visuals.window_fill = Color32::BLACK;
+                visuals.extreme_bg_color = Color32::BLACK;
+                visuals.faint_bg_color = Color32::from_gray(24);
+                visuals.window_stroke = egui::Stroke::new(1.5, Color32::WHITE);
+                visuals.hyperlink_color = Color32::from_rgb(0, 200, 255);
+                visuals.selection.bg_fill = Color32::from_rgb(0, 80, 200);
+                visuals.selection.stroke = egui::Stroke::new(2.0, Color32::from_rgb(255, 230, 0));
+                // Chaque widget garde un contour blanc, même au repos
+                for widget in [&mut visuals.widgets.noninteractive, &mut visuals.widgets.inactive] {
+                    widget.bg_fill = Color32::BLACK;
+                    widget.weak_bg_fill = Color32::BLACK;
+                    widget.bg_stroke = egui::Stroke::new(1.0, Color32::WHITE);
+                    widget.fg_stroke = egui::Stroke::new(1.5, Color32::WHITE);
+                }
+                visuals.widgets.hovered.bg_stroke = egui::Stroke::new(2.0, Color32::from_rgb(255, 230, 0));
+                visuals.widgets.hovered.fg_stroke = egui::Stroke::new(2.0, Color32::WHITE);
+                visuals
+            },
+        };
+        // egui dessine le widget qui a le focus clavier avec le style « active » : un contour épais
+        // le rend repérable pendant la navigation à la touche Tab
+        visuals.widgets.active.bg_stroke = egui::Stroke::new(2.0, visuals.selection.stroke.color);
+        visuals
+    }
+
+    // Applique le thème à l'interface et aux couleurs des graphiques
+    pub fn apply(&self, ctx: &egui::Context) {
+        ctx.set_visuals(self.visuals());
+        ChartPalette::set_high_contrast(*self == Theme::HighContrast);
+    }
+}
+
+impl Settings {
+    pub const MIN_UI_SCALE: f32 = 1.0;
+    pub const MAX_UI_SCALE: f32 = 2.0;
+    pub const RANGES: [i64; 5] = [INTRADAY_DAYS, 7, 30, 90, 365];
+    pub const MAX_RECENT: usize = 8;
+
+    // Libellé d'une période du menu, la plus courte étant la vue sur 24 h
+    pub fn range_label(days: i64) -> String {
+        if days <= INTRADAY_DAYS {
+            tr("range-24h").to_string()
+        } else {
+            tr_args("range-days", &[("days", days.to_string())])
+        }
+    }
+
+    // Choix de l'utilisateur, sinon préférence d'accessibilité du système
+    pub fn reduce_motion(&self) -> bool {
+        self.reduce_motion.or_else(system_reduce_motion).unwrap_or(false)
+    }
+
+    fn path() -> PathBuf {
+        dirs::config_dir()
+            .map(|dir| dir.join("crypto-price-sonifier"))
+            .unwrap_or_default()
+            .join("settings.json")
+    }
+
+    pub fn load() -> Self {
+        std::fs::read_to_string(Self::path())
+            .ok()
+            .and_then(|content| serde_json::from_str(&content).ok())
+            .unwrap_or_default()
+    }
+
+    pub fn save(&self) -> Result<()> {
+        // Pas de système de fichiers dans le navigateur : les préférences durent le temps de la page
+        if cfg!(target_arch = "wasm32") {
+            return Ok(());
+        }
+        let path = Self::path();
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+}
+
+// Remplace la police de l'interface par un fichier TTF/OTF de l'utilisateur
+pub fn apply_custom_font(ctx: &egui::Context, font_path: Option<&str>) -> Result<()> {
+    let mut fonts = egui::FontDefinitions::default();
+    if let Some(path) = font_path {
+        let data = std::fs::read(path)?;
+        fonts.font_data.insert("custom".to_string(), egui::FontData::from_owned(data));
+        for family in [egui::FontFamily::Proportional, egui::FontFamily::Monospace] {
+            fonts.families.entry(family).or_default().insert(0, "custom".to_string());
+        }
+    }
+    ctx.set_fonts(fonts);
+    Ok(())
+}
+
+// Réglage « réduire les animations » du bureau, None quand il est introuvable
+#[cfg(not(target_arch = "wasm32"))]
+fn system_reduce_motion() -> Option<bool> {
+    use std::process::Command;
+
+    let read = |command: &mut Command| {
+        let output = command.output().ok().filter(|output| output.status.success())?;
+        Some(String::from_utf8_lossy(&output.stdout).trim().to_string())
+    };
+    if cfg!(target_os = "macos") {
+        read(Command::new("defaults").args(["read", "com.apple.universalaccess", "reduceMotion"])).map(|value| value == "1")
+    } else if cfg!(target_os = "windows") {
+        // Animations des fenêtres coupées dans les options d'ergonomie
+        read(Command::new("reg").args(["query", r"HKCU\Control Panel\Desktop\WindowMetrics", "/v", "MinAnimate"]))
+            .map(|value| value.ends_with('0'))
+    } else {
+        read(Command::new("gsettings").args(["get", "org.gnome.desktop.interface", "enable-animations"])).map(|value| value == "false")
+    }
+}
+
+#[cfg(target_arch = "wasm32")]
+fn system_reduce_motion() -> Option<bool> {
+    None
+}
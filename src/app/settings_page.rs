@@ -0,0 +1,349 @@
+use super::{apply_custom_font, MainApp, Page, Settings, Theme};
+use crate::audio::DEFAULT_LOUDNESS_TARGET;
+use crate::data::coin_display_name;
+#[cfg(not(target_arch = "wasm32"))]
+use crate::data::{set_http_policy, HttpPolicy};
+use crate::i18n::{tr, tr_args, Language};
+use crate::ui::a11y::{accessible_name, shortcut_pressed};
+use crate::ui::selection::SelectionPage;
+use crate::ui::widgets::{reduce_motion, secondary_text_color, set_reduce_motion};
+use eframe::egui::{self, Color32};
+
+// Largeur des sections, centrées dans la page
+const SECTION_WIDTH: f32 = 560.0;
+
+// Page des préférences, rangées par section ; chaque changement s'applique et s'enregistre aussitôt
+impl MainApp {
+    pub(super) fn show_settings_page(&mut self, ctx: &egui::Context) {
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button(
+                    egui::RichText::new(tr("back-home"))
+                        .size(16.0)
+                        .color(Color32::from_rgb(255, 215, 0))
+                ).clicked() || shortcut_pressed(ctx, egui::Key::Backspace) {
+                    self.navigation.navigate(Page::Selection);
+                }
+                ui.separator();
+                ui.heading(tr("settings-title"));
+            });
+            ui.label(egui::RichText::new(tr("settings-live-hint")).size(12.0).color(secondary_text_color(ui)));
+            // Police illisible ou préférences impossibles à enregistrer
+            if let Some(error) = &self.settings_error {
+                ui.colored_label(Color32::from_rgb(255, 88, 88), error);
+            }
+            ui.add_space(8.0);
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                let margin = ((ui.available_width() - SECTION_WIDTH) / 2.0).max(0.0);
+                egui::Frame::none().inner_margin(egui::Margin::symmetric(margin, 0.0)).show(ui, |ui| {
+                    settings_section(ui, "settings-data", |ui| self.show_data_settings(ui));
+                    settings_section(ui, "settings-audio", |ui| self.show_audio_settings(ui));
+                    settings_section(ui, "settings-appearance", |ui| self.show_appearance_settings(ctx, ui));
+                    settings_section(ui, "settings-accessibility", |ui| self.show_accessibility_settings(ctx, ui));
+                    settings_section(ui, "settings-notifications", |ui| self.show_notification_settings(ui));
+                });
+            });
+        });
+    }
+
+    // Source des prix, période chargée, préchargement, liste de suivi et réseau
+    fn show_data_settings(&mut self, ui: &mut egui::Ui) {
+        // CoinGecko, l'indice multi-plateformes ou un plugin
+        if self.providers.len() > 1 {
+            egui::ComboBox::from_label(tr("data-source"))
+                .selected_text(self.providers[self.selected_provider].name())
+                .show_ui(ui, |ui| {
+                    for (i, provider) in self.providers.iter().enumerate() {
+                        ui.selectable_value(&mut self.selected_provider, i, provider.name());
+                    }
+                });
+        }
+
+        let mut days = self.days();
+        egui::ComboBox::from_label(tr("range"))
+            .selected_text(Settings::range_label(days))
+            .show_ui(ui, |ui| {
+                for option in Settings::RANGES {
+                    ui.selectable_value(&mut days, option, Settings::range_label(option));
+                }
+            });
+        if days != self.days() {
+            self.launch.days = None;
+            self.settings.days = days;
+            self.save_settings();
+        }
+
+        if ui.checkbox(&mut self.settings.prefetch, tr("prefetch")).changed() {
+            if !self.settings.prefetch {
+                self.prefetcher.clear();
+            }
+            self.save_settings();
+        }
+
+        self.show_watchlist_settings(ui);
+        // Le navigateur ne laisse régler ni les délais de fetch() ni les attentes entre deux tentatives
+        #[cfg(not(target_arch = "wasm32"))]
+        self.show_network_settings(ui);
+    }
+
+    // Cryptos affichées sur la page de sélection : ajout par identifiant CoinGecko, ordre et suppression
+    fn show_watchlist_settings(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new(tr("watchlist"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let watchlist = &mut self.settings.watchlist;
+                let mut changed = false;
+                let mut moved = None;
+                let mut removed = None;
+                for (i, coin) in watchlist.iter().enumerate() {
+                    ui.horizontal(|ui| {
+                        ui.label(coin_display_name(coin));
+                        let name = coin_display_name(coin);
+                        let up = ui.add_enabled(i > 0, egui::Button::new("⏶").small());
+                        if accessible_name(up, egui::WidgetType::Button, &tr_args("a11y-move-up", &[("item", &name)])).clicked() {
+                            moved = Some((i, i - 1));
+                        }
+                        let down = ui.add_enabled(i + 1 < watchlist.len(), egui::Button::new("⏷").small());
+                        if accessible_name(down, egui::WidgetType::Button, &tr_args("a11y-move-down", &[("item", &name)])).clicked() {
+                            moved = Some((i, i + 1));
+                        }
+                        let remove = ui.small_button("🗑");
+                        if accessible_name(remove, egui::WidgetType::Button, &tr_args("a11y-remove", &[("item", &name)])).clicked() {
+                            removed = Some(i);
+                        }
+                    });
+                }
+                if let Some((from, to)) = moved {
+                    watchlist.swap(from, to);
+                    changed = true;
+                }
+                if let Some(i) = removed {
+                    let coin = watchlist.remove(i);
+                    self.settings.favorites.retain(|favorite| *favorite != coin);
+                    changed = true;
+                }
+
+                ui.horizontal(|ui| {
+                    // Identifiant CoinGecko : minuscules, espaces remplacés par des tirets ("shiba-inu")
+                    let coin = self.watchlist_input.trim().to_lowercase().replace(' ', "-");
+                    let valid = !coin.is_empty() && !watchlist.contains(&coin);
+                    let response = ui.add(egui::TextEdit::singleline(&mut self.watchlist_input).hint_text(tr("watchlist-hint")));
+                    let submitted = response.lost_focus() && ui.input(|i| i.key_pressed(egui::Key::Enter));
+                    if (ui.add_enabled(valid, egui::Button::new(tr("watchlist-add"))).clicked() || submitted) && valid {
+                        watchlist.push(coin);
+                        self.watchlist_input.clear();
+                        changed = true;
+                    }
+                });
+
+                if changed {
+                    self.save_settings();
+                }
+            });
+    }
+
+    // Délais et nouvelles tentatives des requêtes, appliqués aux requêtes suivantes
+    #[cfg(not(target_arch = "wasm32"))]
+    fn show_network_settings(&mut self, ui: &mut egui::Ui) {
+        egui::CollapsingHeader::new(tr("network-settings"))
+            .default_open(false)
+            .show(ui, |ui| {
+                let mut policy = self.settings.http;
+                egui::Grid::new("network_settings").num_columns(2).show(ui, |ui| {
+                    ui.label(tr("http-connect-timeout"));
+                    ui.add(egui::DragValue::new(&mut policy.connect_timeout).clamp_range(1..=HttpPolicy::MAX_TIMEOUT).suffix(" s"));
+                    ui.end_row();
+                    ui.label(tr("http-read-timeout"));
+                    ui.add(egui::DragValue::new(&mut policy.read_timeout).clamp_range(1..=HttpPolicy::MAX_TIMEOUT).suffix(" s"));
+                    ui.end_row();
+                    ui.label(tr("http-retries")).on_hover_text(tr("http-retries-hint"));
+                    ui.add(egui::DragValue::new(&mut policy.retries).clamp_range(0..=HttpPolicy::MAX_RETRIES));
+                    ui.end_row();
+                });
+                if policy != self.settings.http {
+                    self.settings.http = policy;
+                    set_http_policy(policy);
+                    self.save_settings();
+                }
+            });
+    }
+
+    // Pack de sons, copie du son vers un deuxième périphérique et sonie des exports
+    fn show_audio_settings(&mut self, ui: &mut egui::Ui) {
+        // Appliqué aux graphiques ouverts ensuite
+        if !self.sound_packs.is_empty() {
+            egui::ComboBox::from_label(tr("sound-pack"))
+                .selected_text(match self.selected_sound_pack {
+                    Some(i) => self.sound_packs[i].name.clone(),
+                    None => tr("sound-pack-none").to_string(),
+                })
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut self.selected_sound_pack, None, tr("sound-pack-none"));
+                    for (i, pack) in self.sound_packs.iter().enumerate() {
+                        ui.selectable_value(&mut self.selected_sound_pack, Some(i), pack.name.clone());
+                    }
+                });
+        }
+
+        // Copie du son vers un deuxième périphérique, prise en compte au prochain graphique ouvert
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let mut mirror = self.settings.mirror_output.clone();
+            let devices = &mut self.output_devices;
+            egui::ComboBox::from_label(tr("mirror-output"))
+                .selected_text(mirror.as_deref().unwrap_or(tr("mirror-off")))
+                .show_ui(ui, |ui| {
+                    ui.selectable_value(&mut mirror, None, tr("mirror-off"));
+                    for name in devices.get_or_insert_with(crate::audio::output_device_names).iter() {
+                        ui.selectable_value(&mut mirror, Some(name.clone()), name);
+                    }
+                    // Un câble virtuel installé après le lancement apparaît après actualisation
+                    if ui.button(tr("mirror-refresh")).clicked() {
+                        *devices = None;
+                    }
+                })
+                .response
+                .on_hover_text(tr("mirror-output-hint"));
+            if mirror != self.settings.mirror_output {
+                self.settings.mirror_output = mirror;
+                self.save_settings();
+            }
+        }
+
+        // Sonie des rendus, des vidéos et de la radio, prise en compte au prochain export
+        let mut loudness = self.settings.export_loudness;
+        ui.horizontal(|ui| {
+            let mut enabled = loudness.is_some();
+            if ui.checkbox(&mut enabled, tr("export-loudness")).on_hover_text(tr("export-loudness-hint")).changed() {
+                loudness = enabled.then_some(DEFAULT_LOUDNESS_TARGET);
+            }
+            if let Some(target) = &mut loudness {
+                ui.add(egui::DragValue::new(target).clamp_range(-30.0..=-6.0).speed(0.5).suffix(" LUFS"));
+            }
+        });
+        if loudness != self.settings.export_loudness {
+            self.settings.export_loudness = loudness;
+            for chart in self.charts.values_mut().chain(self.popped_out.values_mut()) {
+                chart.set_export_loudness(self.settings.export_loudness);
+            }
+            self.save_settings();
+        }
+    }
+
+    // Langue, thème et pack d'images
+    fn show_appearance_settings(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+        let mut language = Language::current();
+        egui::ComboBox::from_label(tr("language"))
+            .selected_text(language.native_name())
+            .show_ui(ui, |ui| {
+                for option in Language::ALL {
+                    ui.selectable_value(&mut language, option, option.native_name());
+                }
+            });
+        language.set();
+
+        let mut theme = self.settings.theme;
+        egui::ComboBox::from_label(tr("theme"))
+            .selected_text(tr(theme.label_key()))
+            .show_ui(ui, |ui| {
+                for option in Theme::ALL {
+                    ui.selectable_value(&mut theme, option, tr(option.label_key()));
+                }
+            });
+        if theme != self.settings.theme {
+            self.settings.theme = theme;
+            theme.apply(ctx);
+            self.save_settings();
+        }
+
+        if self.asset_packs.len() > 1 {
+            let previous_pack = self.selected_pack;
+            egui::ComboBox::from_label(tr("asset-pack"))
+                .selected_text(self.asset_packs[self.selected_pack].name.clone())
+                .show_ui(ui, |ui| {
+                    for (i, pack) in self.asset_packs.iter().enumerate() {
+                        ui.selectable_value(&mut self.selected_pack, i, pack.name.clone());
+                    }
+                });
+            if self.selected_pack != previous_pack {
+                // Les portraits seront rechargés depuis le nouveau pack
+                self.selection_page = SelectionPage::new();
+            }
+        }
+    }
+
+    // Taille de l'interface, police personnalisée, mouvements réduits et sous-titres
+    fn show_accessibility_settings(&mut self, ctx: &egui::Context, ui: &mut egui::Ui) {
+        let response = ui.add(
+            egui::Slider::new(&mut self.settings.ui_scale, Settings::MIN_UI_SCALE..=Settings::MAX_UI_SCALE)
+                .step_by(0.1)
+                .text(tr("ui-scale"))
+        );
+        // On applique le zoom au relâchement pour éviter que le curseur ne bouge sous la souris
+        if response.drag_released() || (response.changed() && !response.dragged()) {
+            ctx.set_zoom_factor(self.settings.ui_scale);
+            self.save_settings();
+        }
+
+        ui.horizontal_wrapped(|ui| {
+            ui.label(tr("font-file"));
+            ui.text_edit_singleline(&mut self.font_path_input);
+            if ui.button(tr("load-font")).clicked() {
+                let path = self.font_path_input.trim().to_string();
+                match apply_custom_font(ctx, Some(&path)) {
+                    Ok(()) => {
+                        self.settings.font_path = Some(path);
+                        self.settings_error = None;
+                        self.save_settings();
+                    },
+                    Err(e) => {
+                        self.settings_error = Some(tr_args("font-failed", &[("error", &e.to_string())]));
+                    },
+                }
+            }
+            if self.settings.font_path.is_some() && ui.button(tr("reset-font")).clicked() {
+                apply_custom_font(ctx, None).ok();
+                self.settings.font_path = None;
+                self.font_path_input.clear();
+                self.save_settings();
+            }
+        });
+
+        let mut reduced = reduce_motion();
+        if ui.checkbox(&mut reduced, tr("reduce-motion")).on_hover_text(tr("reduce-motion-hint")).changed() {
+            self.settings.reduce_motion = Some(reduced);
+            set_reduce_motion(ctx, reduced);
+            self.save_settings();
+        }
+        // Valeur de départ des graphiques ouverts ensuite ; chaque graphique garde son propre réglage
+        if ui.checkbox(&mut self.settings.captions, tr("settings-captions")).on_hover_text(tr("settings-captions-hint")).changed() {
+            self.save_settings();
+        }
+    }
+
+    // Notifications du bureau et, avec la fonctionnalité `tray`, fermeture dans la zone de notification
+    fn show_notification_settings(&mut self, ui: &mut egui::Ui) {
+        if ui.checkbox(&mut self.settings.notifications, tr("notifications")).changed() {
+            crate::notify::set_enabled(self.settings.notifications);
+            self.save_settings();
+        }
+        #[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+        {
+            let close_to_tray = egui::Checkbox::new(&mut self.settings.close_to_tray, tr("close-to-tray"));
+            if ui.add_enabled(self.tray.is_some(), close_to_tray).on_hover_text(tr("close-to-tray-hint")).changed() {
+                self.save_settings();
+            }
+        }
+    }
+}
+
+// Titre de section et ses réglages, séparés de la section suivante
+fn settings_section(ui: &mut egui::Ui, title_key: &'static str, add_contents: impl FnOnce(&mut egui::Ui)) {
+    ui.add_space(8.0);
+    ui.label(egui::RichText::new(tr(title_key)).size(18.0).strong());
+    ui.add_space(4.0);
+    ui.indent(title_key, add_contents);
+    ui.add_space(8.0);
+    ui.separator();
+}
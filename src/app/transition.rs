@@ -0,0 +1,50 @@
+use eframe::egui;
+
+// Sens du passage : vers un graphique ou une autre page, ou retour à l'accueil
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TransitionDirection {
+    Forward,
+    Back,
+}
+
+// Passage d'une page à l'autre : un voile de la couleur du fond se retire en glissant et en
+// s'estompant, vers la gauche en avançant et vers la droite au retour
+pub struct PageTransition {
+    started: f64,
+    direction: TransitionDirection,
+}
+
+impl PageTransition {
+    pub const SECONDS: f64 = 0.3;
+
+    pub fn new(now: f64, direction: TransitionDirection) -> Self {
+        Self { started: now, direction }
+    }
+
+    // Avancement entre 0 et 1, ralenti en fin de course
+    pub fn progress(&self, now: f64) -> f32 {
+        let t = ((now - self.started) / Self::SECONDS).clamp(0.0, 1.0) as f32;
+        1.0 - (1.0 - t).powi(3)
+    }
+
+    pub fn is_done(&self, now: f64) -> bool {
+        now - self.started >= Self::SECONDS
+    }
+
+    // Partie de la fenêtre encore voilée : la nouvelle page entre par la droite en avançant, par la gauche au retour
+    pub fn cover(&self, screen: egui::Rect, now: f64) -> egui::Rect {
+        let revealed = screen.width() * self.progress(now);
+        match self.direction {
+            TransitionDirection::Forward => egui::Rect::from_min_max(screen.min, egui::pos2(screen.right() - revealed, screen.bottom())),
+            TransitionDirection::Back => egui::Rect::from_min_max(egui::pos2(screen.left() + revealed, screen.top()), screen.max),
+        }
+    }
+
+    // Dessine le voile par-dessus la page ; à appeler après celle-ci
+    pub fn paint(&self, ctx: &egui::Context, now: f64) {
+        let fill = ctx.style().visuals.panel_fill.gamma_multiply(1.0 - self.progress(now));
+        let painter = ctx.layer_painter(egui::LayerId::new(egui::Order::Foreground, egui::Id::new("page_transition")));
+        painter.rect_filled(self.cover(ctx.screen_rect(), now), 0.0, fill);
+        ctx.request_repaint();
+    }
+}
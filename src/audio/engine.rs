@@ -0,0 +1,257 @@
+use super::limiter::{limited, LimiterMeter};
+use super::sonifier::NoteEvent;
+use super::synth::{note_source, silence, step_source};
+use crate::error::Result;
+use rodio::{OutputStream, Sink, Source};
+use std::collections::VecDeque;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{mpsc, Arc};
+
+pub type AudioSource = Box<dyn Source<Item = f32> + Send>;
+
+// Ouverture d'une sortie, faite sur le thread du moteur : les flux rodio ne changent pas de thread
+pub type OutputOpener = Box<dyn FnOnce() -> Result<Box<dyn EngineOutput>> + Send>;
+
+// Relevé de l'état des sorties quand aucune commande n'arrive
+#[cfg(not(target_arch = "wasm32"))]
+const POLL_INTERVAL: std::time::Duration = std::time::Duration::from_millis(20);
+
+// Sons gardés d'avance dans chaque sortie ; le reste attend dans le moteur, qui complète la file à
+// chaque relevé, que l'interface dessine des images ou non (fenêtre cachée dans la zone de notification).
+// Dans le navigateur, sans thread pour compléter la file, tout y part d'un coup
+#[cfg(not(target_arch = "wasm32"))]
+const QUEUED_SOURCES: usize = 4;
+#[cfg(target_arch = "wasm32")]
+const QUEUED_SOURCES: usize = usize::MAX;
+
+// Sortie pilotée par le moteur : un sink rodio en vrai, une sortie factice dans les tests sans carte son
+pub trait EngineOutput {
+    fn append(&mut self, source: AudioSource);
+    // Abandonne tout ce qui est en file
+    fn clear(&mut self);
+    fn set_volume(&mut self, volume: f32);
+    // Sons en file, celui qui joue compris
+    fn queued(&self) -> usize;
+}
+
+// Sink rodio et son flux, qui doit rester en vie tant que le sink joue
+pub struct RodioOutput {
+    _stream: OutputStream,
+    sink: Sink,
+}
+
+impl RodioOutput {
+    pub fn new((stream, sink): (OutputStream, Sink)) -> Self {
+        Self { _stream: stream, sink }
+    }
+}
+
+impl EngineOutput for RodioOutput {
+    fn append(&mut self, source: AudioSource) {
+        self.sink.append(source);
+    }
+
+    fn clear(&mut self) {
+        self.sink.stop();
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.sink.set_volume(volume);
+    }
+
+    fn queued(&self) -> usize {
+        self.sink.len()
+    }
+}
+
+pub enum AudioCommand {
+    // Note jouée après ce qui est déjà en file
+    Play(NoteEvent),
+    // Pas de la lecture, voix mélangées à sa durée exacte (voir step_source)
+    Step { voices: Vec<NoteEvent>, step_start: f64, length: Option<f64> },
+    // Silence mis en file pour caler le premier pas sur l'horloge
+    Wait(f64),
+    // Lecture arrêtée : ce qui est en file ne doit pas continuer à jouer
+    Pause,
+    // Saut ailleurs dans la lecture : la file est vidée avant les nouveaux pas
+    Seek,
+    SetVolume(f32),
+    // Sortie supplémentaire qui double la première
+    Mirror(OutputOpener),
+}
+
+// Échecs d'ouverture des sorties, remontés à l'interface
+#[derive(Debug, Clone, PartialEq)]
+pub enum EngineEvent {
+    OutputFailed(String),
+    MirrorFailed(String),
+}
+
+// Son mis en attente dans le moteur, dans l'ordre des commandes
+enum Pending {
+    Note(NoteEvent),
+    Step { voices: Vec<NoteEvent>, step_start: f64, length: Option<f64> },
+    Silence(f64),
+}
+
+// Partagé avec l'interface : nombre de commandes traitées, et sorties vides après la dernière
+#[derive(Default)]
+struct EngineState {
+    processed: AtomicU64,
+    idle: AtomicBool,
+}
+
+// Moteur audio sur son propre thread : l'interface ne touche jamais à rodio, dont les appels peuvent
+// bloquer (un sink arrêté attend la fin de ses sons avant d'en accepter d'autres). Dans le navigateur,
+// sans threads, les commandes sont traitées sur place
+pub struct AudioEngine {
+    #[cfg(not(target_arch = "wasm32"))]
+    commands: mpsc::Sender<AudioCommand>,
+    #[cfg(target_arch = "wasm32")]
+    engine: Engine,
+    events: mpsc::Receiver<EngineEvent>,
+    state: Arc<EngineState>,
+    sent: u64,
+}
+
+impl AudioEngine {
+    pub fn spawn(open: OutputOpener, volume: f32, limiter: LimiterMeter) -> Self {
+        let (event_sender, events) = mpsc::channel();
+        let state = Arc::new(EngineState::default());
+        // Les sorties naissent et meurent sur le thread du moteur
+        let new_engine = move || Engine { outputs: Vec::new(), pending: VecDeque::new(), volume, limiter, events: event_sender };
+
+        #[cfg(not(target_arch = "wasm32"))]
+        {
+            let (commands, receiver) = mpsc::channel();
+            let thread_state = Arc::clone(&state);
+            std::thread::spawn(move || new_engine().run(open, receiver, &thread_state));
+            Self { commands, events, state, sent: 0 }
+        }
+        #[cfg(target_arch = "wasm32")]
+        {
+            let mut engine = new_engine();
+            engine.open(open, EngineEvent::OutputFailed);
+            Self { engine, events, state, sent: 0 }
+        }
+    }
+
+    pub fn send(&mut self, command: AudioCommand) {
+        self.sent += 1;
+        #[cfg(not(target_arch = "wasm32"))]
+        self.commands.send(command).ok();
+        #[cfg(target_arch = "wasm32")]
+        self.engine.process(command, &self.state);
+    }
+
+    // Toutes les commandes envoyées ont été traitées et plus rien ne joue
+    pub fn is_idle(&self) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        let idle = self.state.idle.load(Ordering::SeqCst);
+        // Sans thread pour le relever, l'état des sorties est lu sur place
+        #[cfg(target_arch = "wasm32")]
+        let idle = self.engine.is_idle();
+        idle && self.state.processed.load(Ordering::SeqCst) == self.sent
+    }
+
+    pub fn try_event(&self) -> Option<EngineEvent> {
+        self.events.try_recv().ok()
+    }
+}
+
+struct Engine {
+    outputs: Vec<Box<dyn EngineOutput>>,
+    pending: VecDeque<Pending>,
+    volume: f32,
+    limiter: LimiterMeter,
+    events: mpsc::Sender<EngineEvent>,
+}
+
+impl Engine {
+    // Tourne jusqu'à la disparition de l'AudioEngine, qui emporte le flux audio avec le thread
+    #[cfg(not(target_arch = "wasm32"))]
+    fn run(mut self, open: OutputOpener, commands: mpsc::Receiver<AudioCommand>, state: &EngineState) {
+        self.open(open, EngineEvent::OutputFailed);
+        loop {
+            match commands.recv_timeout(POLL_INTERVAL) {
+                Ok(command) => self.process(command, state),
+                Err(mpsc::RecvTimeoutError::Timeout) => {
+                    self.fill();
+                    state.idle.store(self.is_idle(), Ordering::SeqCst);
+                },
+                Err(mpsc::RecvTimeoutError::Disconnected) => break,
+            }
+        }
+    }
+
+    fn open(&mut self, open: OutputOpener, failed: fn(String) -> EngineEvent) {
+        match open() {
+            Ok(mut output) => {
+                output.set_volume(self.volume);
+                self.outputs.push(output);
+            },
+            Err(e) => {
+                self.events.send(failed(e.to_string())).ok();
+            },
+        }
+    }
+
+    // L'état est publié avant le compte des commandes : l'interface ne voit jamais une commande traitée
+    // avec l'état d'avant
+    fn process(&mut self, command: AudioCommand, state: &EngineState) {
+        self.handle(command);
+        self.fill();
+        state.idle.store(self.is_idle(), Ordering::SeqCst);
+        state.processed.fetch_add(1, Ordering::SeqCst);
+    }
+
+    fn handle(&mut self, command: AudioCommand) {
+        match command {
+            AudioCommand::Play(note) => self.pending.push_back(Pending::Note(note)),
+            AudioCommand::Step { voices, step_start, length } => self.pending.push_back(Pending::Step { voices, step_start, length }),
+            AudioCommand::Wait(seconds) => self.pending.push_back(Pending::Silence(seconds)),
+            AudioCommand::Pause | AudioCommand::Seek => {
+                self.pending.clear();
+                for output in &mut self.outputs {
+                    output.clear();
+                }
+            },
+            AudioCommand::SetVolume(volume) => {
+                self.volume = volume;
+                for output in &mut self.outputs {
+                    output.set_volume(volume);
+                }
+            },
+            AudioCommand::Mirror(open) => self.open(open, EngineEvent::MirrorFailed),
+        }
+    }
+
+    // Complète les sorties avec les sons en attente, sans dépasser QUEUED_SOURCES d'avance ;
+    // sans sortie, ils ne joueront jamais et sont abandonnés
+    fn fill(&mut self) {
+        if self.outputs.is_empty() {
+            self.pending.clear();
+        }
+        while self.outputs.iter().map(|output| output.queued()).max().is_some_and(|queued| queued < QUEUED_SOURCES) {
+            let Some(pending) = self.pending.pop_front() else {
+                break;
+            };
+            match pending {
+                Pending::Note(note) => self.append(|| Box::new(note_source(&note))),
+                Pending::Step { voices, step_start, length } => self.append(|| step_source(&voices, step_start, length)),
+                Pending::Silence(seconds) => self.append(|| Box::new(silence(seconds))),
+            }
+        }
+    }
+
+    // Chaque sortie reçoit sa propre copie de la source, passée par le limiteur
+    fn append(&mut self, source: impl Fn() -> AudioSource) {
+        for output in &mut self.outputs {
+            output.append(Box::new(limited(source(), &self.limiter)));
+        }
+    }
+
+    fn is_idle(&self) -> bool {
+        self.pending.is_empty() && self.outputs.iter().all(|output| output.queued() == 0)
+    }
+}
@@ -0,0 +1,81 @@
+use serde::{Deserialize, Serialize};
+
+// Gamme sur laquelle la voix d'harmonie se cale, avec La pour tonique
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Scale {
+    Chromatic,
+    Major,
+    Minor,
+}
+
+impl Scale {
+    pub const ALL: [Scale; 3] = [Scale::Chromatic, Scale::Major, Scale::Minor];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Scale::Chromatic => "scale-chromatic",
+            Scale::Major => "scale-major",
+            Scale::Minor => "scale-minor",
+        }
+    }
+
+    // Degrés de la gamme, en demi-tons au-dessus de La
+    fn degrees(self) -> &'static [i32] {
+        match self {
+            Scale::Chromatic => &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11],
+            Scale::Major => &[0, 2, 4, 5, 7, 9, 11],
+            Scale::Minor => &[0, 2, 3, 5, 7, 8, 10],
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum HarmonyInterval {
+    Third,
+    Fifth,
+    Octave,
+}
+
+impl HarmonyInterval {
+    pub const ALL: [HarmonyInterval; 3] = [HarmonyInterval::Third, HarmonyInterval::Fifth, HarmonyInterval::Octave];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            HarmonyInterval::Third => "harmony-third",
+            HarmonyInterval::Fifth => "harmony-fifth",
+            HarmonyInterval::Octave => "harmony-octave",
+        }
+    }
+
+    // Écart en degrés de la gamme ; en chromatique, tierce majeure et quinte juste
+    fn steps(self, scale: Scale) -> i32 {
+        match (self, scale) {
+            (HarmonyInterval::Third, Scale::Chromatic) => 4,
+            (HarmonyInterval::Fifth, Scale::Chromatic) => 7,
+            (HarmonyInterval::Octave, Scale::Chromatic) => 12,
+            (HarmonyInterval::Third, _) => 2,
+            (HarmonyInterval::Fifth, _) => 4,
+            (HarmonyInterval::Octave, _) => 7,
+        }
+    }
+}
+
+// Note d'harmonie : la fréquence est ramenée au degré de la gamme le plus proche (par en dessous
+// à égale distance), puis décalée de l'intervalle en restant dans la gamme
+pub fn harmonize(frequency: f32, interval: HarmonyInterval, below: bool, scale: Scale) -> f32 {
+    let degrees = scale.degrees();
+    let len = degrees.len() as i32;
+    // Degré numéro `index` en partant du La 440, en demi-tons
+    let semitones_of = |index: i32| index.div_euclid(len) * 12 + degrees[index.rem_euclid(len) as usize];
+
+    let semitones = (12.0 * (frequency / 440.0).log2()).round() as i32;
+    // Le premier degré de l'octave suivante compte aussi : Sol dièse est plus près du La d'au-dessus
+    let octave = semitones.div_euclid(12) * len;
+    let nearest = (octave..=octave + len)
+        .min_by_key(|&index| ((semitones_of(index) - semitones).abs(), semitones_of(index)))
+        .unwrap_or(octave);
+
+    let steps = interval.steps(scale);
+    let target = if below { nearest - steps } else { nearest + steps };
+    440.0 * 2.0f32.powf(semitones_of(target) as f32 / 12.0)
+}
@@ -0,0 +1,115 @@
+use rodio::Source;
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+// Seuil et largeur du coude : la compression commence en douceur vers -6 dBFS et plafonne à -3 dBFS
+const THRESHOLD_DB: f32 = -3.0;
+const KNEE_DB: f32 = 6.0;
+const RELEASE_SECONDS: f32 = 0.05;
+// En dessous, la réduction est inaudible et ne compte pas comme un déclenchement
+pub const AUDIBLE_REDUCTION_DB: f32 = 0.1;
+
+// Réduction de gain la plus forte (en dB) depuis la dernière lecture, partagée avec l'interface
+#[derive(Clone, Default)]
+pub struct LimiterMeter(Arc<AtomicU32>);
+
+impl LimiterMeter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, reduction_db: f32) {
+        let _ = self.0.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |bits| {
+            (reduction_db > f32::from_bits(bits)).then(|| reduction_db.to_bits())
+        });
+    }
+
+    // Renvoie la réduction maximale puis repart de zéro
+    pub fn take(&self) -> f32 {
+        f32::from_bits(self.0.swap(0, Ordering::Relaxed))
+    }
+}
+
+// Limiteur à coude doux : attaque instantanée sur l'enveloppe crête, relâchement exponentiel
+pub struct Limiter {
+    envelope: f32,
+    release: f32,
+    meter: Option<LimiterMeter>,
+}
+
+impl Limiter {
+    pub fn new(sample_rate: u32, meter: Option<LimiterMeter>) -> Self {
+        Self {
+            envelope: 0.0,
+            release: (-1.0 / (RELEASE_SECONDS * sample_rate as f32)).exp(),
+            meter,
+        }
+    }
+
+    // Courbe statique : niveau de sortie (en dB) pour un niveau d'entrée, jamais au-dessus du seuil
+    fn curve(level_db: f32) -> f32 {
+        let knee_start = THRESHOLD_DB - KNEE_DB / 2.0;
+        if level_db <= knee_start {
+            level_db
+        } else if level_db < THRESHOLD_DB + KNEE_DB / 2.0 {
+            level_db - (level_db - knee_start).powi(2) / (2.0 * KNEE_DB)
+        } else {
+            THRESHOLD_DB
+        }
+    }
+
+    pub fn process(&mut self, sample: f32) -> f32 {
+        let level = sample.abs();
+        self.envelope = if level > self.envelope { level } else { level + self.release * (self.envelope - level) };
+        if self.envelope <= 0.0 {
+            return sample;
+        }
+        let level_db = 20.0 * self.envelope.log10();
+        let reduction_db = level_db - Self::curve(level_db);
+        if reduction_db <= 0.0 {
+            return sample;
+        }
+        if let Some(meter) = &self.meter {
+            meter.record(reduction_db);
+        }
+        sample * 10f32.powf(-reduction_db / 20.0)
+    }
+}
+
+// Source passée par le limiteur, dernier maillon avant la sortie audio
+pub struct Limited<S> {
+    source: S,
+    limiter: Limiter,
+}
+
+pub fn limited<S: Source<Item = f32>>(source: S, meter: &LimiterMeter) -> Limited<S> {
+    let limiter = Limiter::new(source.sample_rate(), Some(meter.clone()));
+    Limited { source, limiter }
+}
+
+impl<S: Source<Item = f32>> Iterator for Limited<S> {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        self.source.next().map(|sample| self.limiter.process(sample))
+    }
+}
+
+impl<S: Source<Item = f32>> Source for Limited<S> {
+    fn current_frame_len(&self) -> Option<usize> {
+        self.source.current_frame_len()
+    }
+
+    fn channels(&self) -> u16 {
+        self.source.channels()
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.source.sample_rate()
+    }
+
+    fn total_duration(&self) -> Option<StdDuration> {
+        self.source.total_duration()
+    }
+}
@@ -0,0 +1,99 @@
+// Sonie intégrée à la manière de l'EBU R128 (ITU-R BS.1770) : pondération K, blocs de 400 ms
+// qui se chevauchent aux trois quarts, porte absolue à -70 LUFS puis porte relative à -10 LU
+
+// Cible par défaut des exports, celle des plateformes d'écoute en ligne
+pub const DEFAULT_LOUDNESS_TARGET: f64 = -16.0;
+// Plafond des crêtes après normalisation (-1 dBFS) : le gain qui rattrape un mois calme ne doit pas
+// faire saturer ses notes les plus fortes
+const PEAK_CEILING: f64 = 0.891;
+const BLOCK_SECONDS: f64 = 0.4;
+const BLOCK_STEP_SECONDS: f64 = 0.1;
+const ABSOLUTE_GATE: f64 = -70.0;
+const RELATIVE_GATE: f64 = -10.0;
+
+// Filtre du second ordre, forme directe I
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    x: [f64; 2],
+    y: [f64; 2],
+}
+
+impl Biquad {
+    fn process(&mut self, input: f64) -> f64 {
+        let output = self.b[0] * input + self.b[1] * self.x[0] + self.b[2] * self.x[1] - self.a[0] * self.y[0] - self.a[1] * self.y[1];
+        self.x = [input, self.x[0]];
+        self.y = [output, self.y[0]];
+        output
+    }
+}
+
+// Pondération K recalculée pour la fréquence d'échantillonnage (les coefficients de la norme sont donnés à 48 kHz) :
+// plateau de +4 dB dans les aigus puis passe-haut vers 38 Hz
+fn k_weighting(sample_rate: u32) -> [Biquad; 2] {
+    let fs = sample_rate as f64;
+
+    let k = (std::f64::consts::PI * 1681.974450955533 / fs).tan();
+    let q = 0.7071752369554196;
+    let vh = 10f64.powf(3.999843853973347 / 20.0);
+    let vb = vh.powf(0.4996667741545416);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = Biquad {
+        b: [(vh + vb * k / q + k * k) / a0, 2.0 * (k * k - vh) / a0, (vh - vb * k / q + k * k) / a0],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        x: [0.0; 2],
+        y: [0.0; 2],
+    };
+
+    let k = (std::f64::consts::PI * 38.13547087602444 / fs).tan();
+    let q = 0.5003270373238773;
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = Biquad {
+        b: [1.0, -2.0, 1.0],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        x: [0.0; 2],
+        y: [0.0; 2],
+    };
+    [shelf, high_pass]
+}
+
+fn block_loudness(mean_square: f64) -> f64 {
+    -0.691 + 10.0 * mean_square.log10()
+}
+
+// Sonie intégrée d'un signal mono, en LUFS ; None pour un signal trop court ou silencieux
+pub fn integrated_loudness(samples: &[f32], sample_rate: u32) -> Option<f64> {
+    let [mut shelf, mut high_pass] = k_weighting(sample_rate);
+    let weighted: Vec<f64> = samples.iter().map(|&sample| high_pass.process(shelf.process(sample as f64))).collect();
+
+    let block_len = (BLOCK_SECONDS * sample_rate as f64) as usize;
+    let step = (BLOCK_STEP_SECONDS * sample_rate as f64) as usize;
+    if block_len == 0 || weighted.len() < block_len {
+        return None;
+    }
+    let blocks: Vec<f64> = (0..=(weighted.len() - block_len) / step)
+        .map(|i| weighted[i * step..i * step + block_len].iter().map(|x| x * x).sum::<f64>() / block_len as f64)
+        .filter(|&power| power > 0.0 && block_loudness(power) > ABSOLUTE_GATE)
+        .collect();
+    if blocks.is_empty() {
+        return None;
+    }
+
+    let relative_gate = block_loudness(blocks.iter().sum::<f64>() / blocks.len() as f64) + RELATIVE_GATE;
+    let gated: Vec<f64> = blocks.into_iter().filter(|&power| block_loudness(power) > relative_gate).collect();
+    Some(block_loudness(gated.iter().sum::<f64>() / gated.len() as f64))
+}
+
+// Ramène le signal à la sonie visée, sans que ses crêtes dépassent -1 dBFS ; renvoie le gain appliqué
+// (1 quand le signal est silencieux)
+pub fn normalize_loudness(samples: &mut [f32], sample_rate: u32, target_lufs: f64) -> f64 {
+    let Some(loudness) = integrated_loudness(samples, sample_rate) else {
+        return 1.0;
+    };
+    let peak = samples.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs())) as f64;
+    let gain = 10f64.powf((target_lufs - loudness) / 20.0).min(PEAK_CEILING / peak);
+    for sample in samples.iter_mut() {
+        *sample = (*sample as f64 * gain) as f32;
+    }
+    gain
+}
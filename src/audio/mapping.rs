@@ -0,0 +1,80 @@
+use crate::data::{drawdown_series, DailyPrice};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum ViewMode {
+    Price,
+    Drawdown,
+}
+
+pub fn change_to_frequency(price_change: f64) -> f32 {
+    let base_freq = 440.0f32;
+    if price_change > 0.0 {
+        base_freq / (1.0 + (price_change.abs() / 2.0) as f32)
+    } else {
+        base_freq * (1.0 + (price_change.abs() / 2.0) as f32)
+    }
+}
+
+// Hauteur ramenée entre `min` et `max` : le La de référence reste en place (ou devient le centre de la
+// plage quand elle ne le contient pas), les écarts modérés sont gardés tels quels et, passé la moitié de
+// la place disponible, les extrêmes sont comprimés en douceur vers les bornes sans jamais les dépasser.
+// L'ordre des notes est conservé : deux chutes de tailles différentes restent distinctes
+pub fn normalize_frequency(frequency: f32, min: f32, max: f32) -> f32 {
+    const REFERENCE: f32 = 440.0;
+    const KNEE: f32 = 0.5;
+
+    let (min, max) = (min.min(max), min.max(max));
+    let center = if (min..=max).contains(&REFERENCE) { REFERENCE } else { (min * max).sqrt() };
+    let octaves = (frequency / REFERENCE).log2();
+    let room = if octaves >= 0.0 { (max / center).log2() } else { (center / min).log2() };
+    if room <= 0.0 || !octaves.is_finite() {
+        return center;
+    }
+    let x = octaves.abs() / room;
+    let y = if x <= KNEE { x } else { KNEE + (1.0 - KNEE) * ((x - KNEE) / (1.0 - KNEE)).tanh() };
+    center * 2.0f32.powf(y.copysign(octaves) * room)
+}
+
+// Volume du jour rapporté à la médiane de la période : le double sonne une octave plus haut,
+// à deux octaves au plus du La
+pub fn volume_to_frequency(volume: f64, median_volume: f64) -> f32 {
+    let octaves = (volume / median_volume).log2().clamp(-2.0, 2.0);
+    440.0 * 2.0f32.powf(if octaves.is_finite() { octaves as f32 } else { 0.0 })
+}
+
+// Amplitude de la journée (plus haut - plus bas, en % du prix) : 220 Hz pour une journée immobile,
+// une octave de plus tous les 5 %
+pub fn range_to_frequency(range_percent: f64) -> f32 {
+    220.0 * 2.0f32.powf((range_percent / 5.0).min(4.0) as f32)
+}
+
+// Plus le creux est profond, plus la note est grave (-10% = une octave plus bas)
+pub fn drawdown_to_frequency(drawdown: f64) -> f32 {
+    440.0 * 2.0f32.powf((drawdown / 10.0) as f32)
+}
+
+// Voix du sentiment, deux octaves sous le prix : 110 Hz en peur extrême, 440 Hz en avidité extrême
+pub fn sentiment_to_frequency(score: f64) -> f32 {
+    110.0 * 2.0f32.powf((score / 50.0) as f32)
+}
+
+// En dessous (en %), deux plateformes sont considérées alignées
+pub const SPREAD_DEAD_ZONE: f64 = 0.05;
+
+// Écart entre deux plateformes : silence dans la zone morte, puis un battement de plus en plus
+// rapide entre deux notes désaccordées (0.1 % = 2 Hz, 1 % et plus = 20 Hz, un grésillement)
+pub fn spread_to_beat_frequency(spread_percent: f64) -> Option<f32> {
+    const BEAT_PER_PERCENT: f64 = 20.0;
+    const MAX_BEAT: f64 = 20.0;
+    let spread = spread_percent.abs();
+    (spread >= SPREAD_DEAD_ZONE).then(|| (spread * BEAT_PER_PERCENT).min(MAX_BEAT) as f32)
+}
+
+// Valeurs tracées et sonifiées selon le mode d'affichage
+pub fn series_values(view_mode: ViewMode, daily_prices: &[DailyPrice]) -> Vec<f64> {
+    match view_mode {
+        ViewMode::Price => daily_prices.iter().map(|p| p.price).collect(),
+        ViewMode::Drawdown => drawdown_series(daily_prices),
+    }
+}
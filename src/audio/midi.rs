@@ -0,0 +1,84 @@
+use super::sonifier::NoteEvent;
+use crate::error::Result;
+use midly::num::{u15, u24, u28, u4, u7};
+use midly::{Format, Header, MetaMessage, MidiMessage, Smf, Timing, TrackEvent, TrackEventKind};
+use std::path::Path;
+
+const TICKS_PER_BEAT: u16 = 480;
+// 120 BPM : une noire dure 500 ms
+const MICROSECONDS_PER_BEAT: u32 = 500_000;
+
+// Note MIDI la plus proche d'une fréquence (69 = La 440 Hz)
+fn frequency_to_key(frequency: f32) -> u7 {
+    let key = 69.0 + 12.0 * (frequency / 440.0).log2();
+    u7::new(key.round().clamp(0.0, 127.0) as u8)
+}
+
+// Nom anglo-saxon de la note la plus proche (A4 pour 440 Hz), pour les sous-titres
+pub fn note_name(frequency: f32) -> String {
+    const NAMES: [&str; 12] = ["C", "C#", "D", "D#", "E", "F", "F#", "G", "G#", "A", "A#", "B"];
+    let key = frequency_to_key(frequency).as_int() as i32;
+    format!("{}{}", NAMES[key as usize % 12], key / 12 - 1)
+}
+
+fn seconds_to_ticks(seconds: f64) -> u32 {
+    let ticks_per_second = TICKS_PER_BEAT as f64 * 1_000_000.0 / MICROSECONDS_PER_BEAT as f64;
+    (seconds * ticks_per_second).round() as u32
+}
+
+// Écrit les notes jouées dans un fichier MIDI à une piste
+pub fn write_notes_midi(notes: &[NoteEvent], path: &Path) -> Result<()> {
+    // (tick, note relâchée ?, touche, vélocité) ; les relâchements passent avant les attaques au même instant
+    let mut events: Vec<(u32, bool, u7, u7)> = Vec::new();
+    for note in notes {
+        let start = seconds_to_ticks(note.start);
+        let end = start + seconds_to_ticks(note.duration);
+        let velocity = u7::new((note.velocity.clamp(0.0, 1.0) * 127.0).round() as u8);
+        match note.arpeggio {
+            // Arpège : les touches se succèdent en boucle sur toute la durée de la note
+            Some(note_seconds) if !note.frequencies.is_empty() => {
+                let length = seconds_to_ticks(note_seconds).max(1);
+                for (i, tick) in (start..end).step_by(length as usize).enumerate() {
+                    let key = frequency_to_key(note.frequencies[i % note.frequencies.len()]);
+                    events.push((tick, false, key, velocity));
+                    events.push(((tick + length).min(end), true, key, u7::new(0)));
+                }
+            },
+            _ => {
+                for frequency in &note.frequencies {
+                    let key = frequency_to_key(*frequency);
+                    events.push((start, false, key, velocity));
+                    events.push((end, true, key, u7::new(0)));
+                }
+            },
+        }
+    }
+    events.sort_by_key(|(tick, is_off, _, _)| (*tick, !*is_off));
+
+    let mut track = vec![TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Meta(MetaMessage::Tempo(u24::new(MICROSECONDS_PER_BEAT))),
+    }];
+    let mut last_tick = 0;
+    for (tick, is_off, key, vel) in events {
+        let message = if is_off {
+            MidiMessage::NoteOff { key, vel }
+        } else {
+            MidiMessage::NoteOn { key, vel }
+        };
+        track.push(TrackEvent {
+            delta: u28::new(tick - last_tick),
+            kind: TrackEventKind::Midi { channel: u4::new(0), message },
+        });
+        last_tick = tick;
+    }
+    track.push(TrackEvent {
+        delta: u28::new(0),
+        kind: TrackEventKind::Meta(MetaMessage::EndOfTrack),
+    });
+
+    let mut smf = Smf::new(Header::new(Format::SingleTrack, Timing::Metrical(u15::new(TICKS_PER_BEAT))));
+    smf.tracks.push(track);
+    smf.save(path)?;
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// Moteur audio sur son thread, synthèse des notes, horloge de lecture, packs de sons, texture du carnet d'ordres, limiteur, normalisation de la sonie, export MIDI ou en liste de notes (CSV, JSON) et correspondance entre mouvements de prix et fréquences
+pub mod engine;
+pub mod harmony;
+pub mod limiter;
+pub mod loudness;
+pub mod mapping;
+pub mod midi;
+pub mod notelist;
+pub mod samples;
+pub mod scheduler;
+pub mod sonifier;
+pub mod synth;
+pub mod texture;
+
+pub use engine::{AudioCommand, AudioEngine, AudioSource, EngineEvent, EngineOutput, OutputOpener, RodioOutput};
+pub use harmony::{harmonize, HarmonyInterval, Scale};
+pub use limiter::{limited, Limiter, LimiterMeter, AUDIBLE_REDUCTION_DB};
+pub use loudness::{integrated_loudness, normalize_loudness, DEFAULT_LOUDNESS_TARGET};
+pub use mapping::{normalize_frequency, sentiment_to_frequency, series_values, spread_to_beat_frequency, ViewMode, SPREAD_DEAD_ZONE};
+pub use midi::{note_name, write_notes_midi};
+pub use notelist::{note_rows, notes_to_csv, write_note_list, NoteRow};
+pub use samples::{Sample, SoundPack};
+pub use scheduler::StepScheduler;
+pub use sonifier::{preview_voice, Ending, NoteEvent, NoteLength, Sonifier, SoundOptions, Timbre, INTRADAY_SPEED};
+pub use synth::{
+    alarm_beeps, mix_notes, note_source, open_mirror_output, open_sound_output, output_device_names, silence, step_source,
+    write_notes_wav, ToneSource, AUDIO_SAMPLE_RATE, NOTE_DURATION_MS, STEP_SECONDS,
+};
+pub use texture::{book_texture, ducking_gain, spread_beating, wall_burst, PinkNoise};
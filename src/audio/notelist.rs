@@ -0,0 +1,66 @@
+use super::midi::note_name;
+use super::sonifier::{percent_change, NoteEvent};
+use crate::data::DailyPrice;
+use crate::error::Result;
+use serde::Serialize;
+use std::path::Path;
+
+// Une ligne de la liste des notes : le jour atteint, son mouvement et la note qui le joue
+#[derive(Debug, Clone, PartialEq, Serialize)]
+pub struct NoteRow {
+    pub date: String,
+    pub price: f64,
+    // Variation depuis la veille, en %
+    pub change: f64,
+    // Première fréquence de la note (la mélodie, avant l'arpège ou le reste de l'accord), en Hz
+    pub frequency: f32,
+    pub note: String,
+    pub velocity: f32,
+    // Début et durée, en secondes depuis le début de la lecture
+    pub start: f64,
+    pub duration: f64,
+}
+
+// Associe chaque note de `Sonifier::sonify` au jour qu'elle atteint ; les voix ajoutées (harmonie,
+// écho, cadence) ne correspondent à aucun jour et restent hors de la liste
+pub fn note_rows(daily_prices: &[DailyPrice], notes: &[NoteEvent]) -> Vec<NoteRow> {
+    daily_prices
+        .windows(2)
+        .zip(notes)
+        .map(|(pair, note)| {
+            let frequency = note.frequencies.first().copied().unwrap_or(0.0);
+            NoteRow {
+                date: pair[1].date.clone(),
+                price: pair[1].price,
+                change: percent_change(pair[0].price, pair[1].price),
+                frequency,
+                note: if frequency > 0.0 { note_name(frequency) } else { String::new() },
+                velocity: note.velocity,
+                start: note.start,
+                duration: note.duration,
+            }
+        })
+        .collect()
+}
+
+pub fn notes_to_csv(rows: &[NoteRow]) -> String {
+    let mut csv = String::from("date,price,change,frequency,note,velocity,start,duration\n");
+    for row in rows {
+        csv.push_str(&format!(
+            "{},{},{:.4},{:.2},{},{:.3},{:.3},{:.3}\n",
+            row.date, row.price, row.change, row.frequency, row.note, row.velocity, row.start, row.duration
+        ));
+    }
+    csv
+}
+
+// Format choisi d'après l'extension : JSON pour .json, CSV sinon
+pub fn write_note_list(rows: &[NoteRow], path: &Path) -> Result<()> {
+    let content = if path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("json")) {
+        serde_json::to_string_pretty(rows)?
+    } else {
+        notes_to_csv(rows)
+    };
+    std::fs::write(path, content)?;
+    Ok(())
+}
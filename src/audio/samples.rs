@@ -0,0 +1,168 @@
+use super::sonifier::{NoteEvent, Timbre};
+use super::synth::AUDIO_SAMPLE_RATE;
+use crate::error::Result;
+use crate::i18n::tr_args;
+use serde::Deserialize;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+// Échantillon d'un pack de sons, déjà converti en mono à AUDIO_SAMPLE_RATE
+#[derive(Debug, PartialEq)]
+pub struct Sample {
+    pub name: String,
+    pub samples: Vec<f32>,
+}
+
+impl Sample {
+    // Lit un WAV (entier ou flottant, n'importe quelle fréquence) : canaux moyennés, rééchantillonnage linéaire
+    pub fn load(path: &Path) -> Result<Self> {
+        let mut reader = hound::WavReader::open(path)?;
+        let spec = reader.spec();
+        let interleaved: Vec<f32> = match spec.sample_format {
+            hound::SampleFormat::Float => reader.samples::<f32>().collect::<std::result::Result<_, _>>()?,
+            hound::SampleFormat::Int => {
+                let scale = (1i64 << (spec.bits_per_sample - 1)) as f32;
+                reader
+                    .samples::<i32>()
+                    .map(|sample| sample.map(|value| value as f32 / scale))
+                    .collect::<std::result::Result<_, _>>()?
+            },
+        };
+        let channels = spec.channels.max(1) as usize;
+        let mono: Vec<f32> = interleaved
+            .chunks(channels)
+            .map(|frame| frame.iter().sum::<f32>() / channels as f32)
+            .collect();
+        Ok(Self {
+            name: path.file_name().map(|name| name.to_string_lossy().into_owned()).unwrap_or_default(),
+            samples: resample(&mono, spec.sample_rate, AUDIO_SAMPLE_RATE),
+        })
+    }
+
+    pub fn seconds(&self) -> f64 {
+        self.samples.len() as f64 / AUDIO_SAMPLE_RATE as f64
+    }
+}
+
+fn resample(samples: &[f32], from: u32, to: u32) -> Vec<f32> {
+    if from == to || samples.is_empty() || from == 0 {
+        return samples.to_vec();
+    }
+    let ratio = from as f64 / to as f64;
+    let len = (samples.len() as f64 / ratio) as usize;
+    (0..len)
+        .map(|n| {
+            let position = n as f64 * ratio;
+            let index = position as usize;
+            let next = samples.get(index + 1).copied().unwrap_or(samples[index]);
+            let fraction = (position - index as f64) as f32;
+            samples[index] + (next - samples[index]) * fraction
+        })
+        .collect()
+}
+
+// Un son du manifeste : simple fichier, ou fichier réservé aux variations d'au moins `min_change` %
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ManifestSample {
+    File(String),
+    Tier { file: String, min_change: f64 },
+}
+
+impl ManifestSample {
+    fn file_and_threshold(&self) -> (&str, f64) {
+        match self {
+            ManifestSample::File(file) => (file, 0.0),
+            ManifestSample::Tier { file, min_change } => (file, min_change.abs()),
+        }
+    }
+}
+
+// Manifeste d'un pack de sons (assets/sounds/<nom>/manifest.json)
+#[derive(Debug, Deserialize)]
+struct SoundManifest {
+    name: Option<String>,
+    // Sans synthèse, seuls les échantillons jouent la mélodie ; sinon ils s'y superposent
+    #[serde(default)]
+    replace_synth: bool,
+    #[serde(default)]
+    up: Vec<ManifestSample>,
+    #[serde(default)]
+    down: Vec<ManifestSample>,
+}
+
+// Pack de sons : des échantillons pour les jours de hausse et de baisse, par paliers de variation
+#[derive(Debug)]
+pub struct SoundPack {
+    pub name: String,
+    pub replace_synth: bool,
+    // (variation minimale en %, échantillon), triés par seuil croissant
+    up: Vec<(f64, Arc<Sample>)>,
+    down: Vec<(f64, Arc<Sample>)>,
+}
+
+impl SoundPack {
+    pub fn load(dir: &Path) -> Result<Self> {
+        let manifest: SoundManifest = serde_json::from_str(&std::fs::read_to_string(dir.join("manifest.json"))?)?;
+        let load_tiers = |entries: &[ManifestSample]| -> Result<Vec<(f64, Arc<Sample>)>> {
+            let mut tiers = entries
+                .iter()
+                .map(|entry| {
+                    let (file, threshold) = entry.file_and_threshold();
+                    Ok((threshold, Arc::new(Sample::load(&dir.join(file))?)))
+                })
+                .collect::<Result<Vec<_>>>()?;
+            tiers.sort_by(|a, b| a.0.total_cmp(&b.0));
+            Ok(tiers)
+        };
+        Ok(Self {
+            name: manifest.name.unwrap_or_else(|| {
+                dir.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default()
+            }),
+            replace_synth: manifest.replace_synth,
+            up: load_tiers(&manifest.up)?,
+            down: load_tiers(&manifest.down)?,
+        })
+    }
+
+    // Échantillon du palier le plus haut atteint par la variation ; None si aucun ne correspond
+    pub fn sample_for(&self, price_change: f64) -> Option<&Arc<Sample>> {
+        let tiers = if price_change >= 0.0 { &self.up } else { &self.down };
+        tiers.iter().rev().find(|(threshold, _)| price_change.abs() >= *threshold).map(|(_, sample)| sample)
+    }
+
+    // Note jouant l'échantillon au rythme de la note de synthèse, coupée à la fin du pas
+    pub fn sample_note(&self, note: &NoteEvent, price_change: f64) -> Option<NoteEvent> {
+        let sample = self.sample_for(price_change)?;
+        Some(NoteEvent {
+            duration: note.duration.min(sample.seconds()),
+            frequencies: Vec::new(),
+            fade_out: 0.0,
+            arpeggio: None,
+            timbre: Timbre::Sine,
+            sample: Some(sample.clone()),
+            ..note.clone()
+        })
+    }
+
+    pub fn discover() -> Vec<Arc<Self>> {
+        let mut packs = Vec::new();
+        if let Ok(entries) = std::fs::read_dir(Path::new("assets").join("sounds")) {
+            let mut dirs: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.join("manifest.json").is_file())
+                .collect();
+            dirs.sort();
+            for dir in dirs {
+                match Self::load(&dir) {
+                    Ok(pack) => packs.push(Arc::new(pack)),
+                    Err(e) => println!("{}", tr_args("log-sound-pack-failed", &[
+                        ("path", &dir.display().to_string()),
+                        ("error", &e.to_string()),
+                    ])),
+                }
+            }
+        }
+        packs
+    }
+}
@@ -0,0 +1,41 @@
+// Horloge de lecture à pas fixe, calée sur l'horloge murale : le pas `n` tombe à
+// origin + (n - first_step) * step_seconds, quelles que soient la cadence d'affichage et la latence audio.
+// Une frame en retard ne décale donc pas les pas suivants
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct StepScheduler {
+    origin: f64,
+    first_step: usize,
+    step_seconds: f64,
+}
+
+impl StepScheduler {
+    // Le pas `step` tombe à `at`, les suivants tous les `step_seconds`
+    pub fn new(step: usize, at: f64, step_seconds: f64) -> Self {
+        Self { origin: at, first_step: step, step_seconds }
+    }
+
+    pub fn step_seconds(&self) -> f64 {
+        self.step_seconds
+    }
+
+    pub fn step_time(&self, step: usize) -> f64 {
+        self.origin + (step as f64 - self.first_step as f64) * self.step_seconds
+    }
+
+    // Pas à jouer à `now`, à partir de `next_step` : après un blocage de plusieurs pas (fenêtre masquée),
+    // seul le plus récent est rendu, pour que la lecture rattrape l'horloge au lieu d'enchaîner les notes
+    pub fn due_step(&self, now: f64, next_step: usize) -> Option<usize> {
+        if now < self.step_time(next_step) {
+            return None;
+        }
+        let elapsed = ((now - self.origin) / self.step_seconds).floor() as usize;
+        Some((self.first_step + elapsed).max(next_step))
+    }
+
+    // Changement de vitesse : `next_step` garde son échéance, les suivants prennent le nouveau rythme
+    pub fn retime(&mut self, next_step: usize, step_seconds: f64) {
+        self.origin = self.step_time(next_step);
+        self.first_step = next_step;
+        self.step_seconds = step_seconds;
+    }
+}
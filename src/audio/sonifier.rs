@@ -0,0 +1,496 @@
+use super::harmony::{harmonize, HarmonyInterval, Scale};
+use super::mapping::{
+    change_to_frequency, drawdown_to_frequency, normalize_frequency, range_to_frequency, sentiment_to_frequency, volume_to_frequency,
+    ViewMode,
+};
+use super::samples::Sample;
+use super::synth::{NOTE_DURATION_MS, STEP_SECONDS};
+use crate::data::{drawdown_series, DailyPrice};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+// Bornes de l'audible : les mouvements extrêmes ne produisent ni infrasons ni ultrasons
+pub const MIN_FREQUENCY: f32 = 20.0;
+pub const MAX_FREQUENCY: f32 = 20_000.0;
+// Vélocité d'un jour sans variation, qui monte jusqu'à 1.0 pour une variation de FULL_VELOCITY_CHANGE %
+pub const MIN_VELOCITY: f32 = 0.5;
+const FULL_VELOCITY_CHANGE: f64 = 10.0;
+const RESOLUTION_CHORD: [f32; 3] = [440.0, 554.37, 659.25];
+const MINOR_CHORD: [f32; 3] = [440.0, 523.25, 659.25];
+// Traîne de la fin de lecture, en durées de note
+const ENDING_TAIL: f64 = 1.5;
+const CADENCE_VELOCITY: f32 = 0.8;
+// Note la plus courte des durées variables, en part de la durée de note
+const MIN_LENGTH_RATIO: f64 = 0.2;
+// Notes de l'arpège par pas
+const ARPEGGIO_NOTES_PER_STEP: f64 = 16.0;
+// La voix d'harmonie reste en retrait de la mélodie
+const HARMONY_VELOCITY_RATIO: f32 = 0.7;
+// Écho de la veille : part de la durée de note qu'il occupe avant la note du jour, et sa vélocité
+const ECHO_RATIO: f64 = 0.15;
+const ECHO_VELOCITY: f32 = 0.35;
+// Sous-basse des baisses prolongées : mi grave et son octave (les petits haut-parleurs n'ont que
+// l'octave), vélocité à l'entrée puis gagnée chaque jour de baisse en plus, jusqu'à 1
+const SUB_BASS_CHORD: [f32; 2] = [41.2, 82.41];
+const SUB_BASS_VELOCITY: f32 = 0.4;
+const SUB_BASS_SWELL: f32 = 0.15;
+// Extinction au premier jour de hausse, en durées de note
+const SUB_BASS_RELEASE: f64 = 0.5;
+// Glissando sans fin des tendances : jours de même sens d'affilée avant qu'il prenne la mélodie, et
+// variation (en %) qui fait glisser d'une octave entière, au plus une octave par pas
+const SHEPARD_TREND_DAYS: usize = 3;
+const SHEPARD_PERCENT_PER_OCTAVE: f64 = 12.0;
+// Aperçu au survol : note du jour raccourcie et jouée à mi-voix
+const PREVIEW_SECONDS: f64 = 0.4;
+const PREVIEW_VELOCITY_RATIO: f32 = 0.5;
+// Vue sur 24 h (un point toutes les 5 minutes) : dix points par seconde, la journée tient en une demi-minute
+pub const INTRADAY_SPEED: f64 = 20.0;
+
+// Note (ou accord) jouée lors du passage d'un jour au suivant
+#[derive(Debug, Clone, PartialEq)]
+pub struct NoteEvent {
+    pub start: f64,
+    pub duration: f64,
+    pub frequencies: Vec<f32>,
+    // Entre MIN_VELOCITY et 1.0
+    pub velocity: f32,
+    // Fondu sur les dernières secondes de la note, 0 pour une note qui s'arrête net
+    pub fade_out: f64,
+    // Avec une durée (en secondes), les fréquences sont jouées l'une après l'autre en boucle au lieu d'ensemble
+    pub arpeggio: Option<f64>,
+    pub timbre: Timbre,
+    // Échantillon d'un pack de sons joué à la place des fréquences
+    pub sample: Option<Arc<Sample>>,
+}
+
+// Couleur du son d'une note
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timbre {
+    // Sinusoïde pure
+    Sine,
+    // Harmoniques impaires, son creux proche de la clarinette
+    Hollow,
+    // Ton de Shepard qui glisse de la première fréquence à la dernière : il paraît monter ou descendre
+    // sans fin sans jamais quitter le médium
+    Shepard,
+}
+
+// Durée des notes selon l'ampleur du mouvement, sans toucher au rythme des pas
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum NoteLength {
+    Fixed,
+    // Les grands mouvements tiennent, les petits sont piqués
+    LongerForBigMoves,
+    // L'inverse : un jour calme tient, un jour agité claque
+    ShorterForBigMoves,
+}
+
+impl NoteLength {
+    pub const ALL: [NoteLength; 3] = [NoteLength::Fixed, NoteLength::LongerForBigMoves, NoteLength::ShorterForBigMoves];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            NoteLength::Fixed => "note-length-fixed",
+            NoteLength::LongerForBigMoves => "note-length-longer",
+            NoteLength::ShorterForBigMoves => "note-length-shorter",
+        }
+    }
+
+    // Part de la durée de note jouée, entre MIN_LENGTH_RATIO et 1 : une note ne déborde jamais sur le pas suivant
+    pub fn ratio(self, price_change: f64) -> f64 {
+        let intensity = (price_change.abs() / FULL_VELOCITY_CHANGE).min(1.0);
+        match self {
+            NoteLength::Fixed => 1.0,
+            NoteLength::LongerForBigMoves => MIN_LENGTH_RATIO + (1.0 - MIN_LENGTH_RATIO) * intensity,
+            NoteLength::ShorterForBigMoves => 1.0 - (1.0 - MIN_LENGTH_RATIO) * intensity,
+        }
+    }
+}
+
+// Manière dont se termine la lecture
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum Ending {
+    // La dernière note s'arrête comme les autres
+    Cut,
+    // La dernière note se prolonge en s'éteignant
+    FadeOut,
+    // Un accord de La conclut la période, majeur si elle a fini en hausse, mineur sinon
+    Cadence,
+}
+
+impl Ending {
+    pub const ALL: [Ending; 3] = [Ending::Cut, Ending::FadeOut, Ending::Cadence];
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            Ending::Cut => "ending-cut",
+            Ending::FadeOut => "ending-fade-out",
+            Ending::Cadence => "ending-cadence",
+        }
+    }
+}
+
+// Façon de jouer les notes, choisie sur le graphique et retenue dans les préférences
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SoundOptions {
+    pub note_length: NoteLength,
+    // Chaque pas devient un arpège : variation du prix, volume et amplitude du jour
+    pub arpeggio: bool,
+    // Deuxième voix qui double la mélodie à cet intervalle, calée sur la gamme
+    pub harmony: Option<HarmonyInterval>,
+    pub harmony_below: bool,
+    pub scale: Scale,
+    // La note de la veille est rejouée doucement juste avant celle du jour
+    pub echo: bool,
+    // Les samedis et dimanches sont joués avec un timbre creux et grisés sur le graphique
+    pub weekend_timbre: bool,
+    // Atténuation des couches de fond sous chaque note (0 à 1) et durée de leur retour, en secondes
+    pub duck_depth: f32,
+    pub duck_release: f64,
+    pub ending: Ending,
+    // Sous-basse qui gonfle après `sub_bass_days` jours de baisse d'affilée et s'éteint au premier jour de hausse
+    pub sub_bass: bool,
+    pub sub_bass_days: usize,
+    // Plage des hauteurs (en Hz) dans laquelle toutes les notes sont ramenées, hors sous-basse
+    pub min_frequency: f32,
+    pub max_frequency: f32,
+    // Dans les tendances longues, la mélodie devient un ton de Shepard qui glisse toujours dans le même sens
+    pub shepard: bool,
+}
+
+impl Default for SoundOptions {
+    fn default() -> Self {
+        Self {
+            note_length: NoteLength::Fixed,
+            arpeggio: false,
+            harmony: None,
+            harmony_below: false,
+            scale: Scale::Major,
+            echo: false,
+            weekend_timbre: false,
+            duck_depth: 0.5,
+            duck_release: 0.3,
+            ending: Ending::Cut,
+            sub_bass: false,
+            sub_bass_days: 3,
+            min_frequency: 110.0,
+            max_frequency: 1760.0,
+            shepard: false,
+        }
+    }
+}
+
+// Transforme une série de prix en notes, sans dépendance à l'interface ni à la sortie audio
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Sonifier {
+    pub view_mode: ViewMode,
+    pub step_seconds: f64,
+    pub note_seconds: f64,
+    pub options: SoundOptions,
+}
+
+impl Sonifier {
+    pub fn new(view_mode: ViewMode) -> Self {
+        Self {
+            view_mode,
+            step_seconds: STEP_SECONDS,
+            note_seconds: NOTE_DURATION_MS as f64 / 1000.0,
+            options: SoundOptions::default(),
+        }
+    }
+
+    pub fn with_options(mut self, options: SoundOptions) -> Self {
+        self.options = options;
+        self
+    }
+
+    pub fn with_note_length(mut self, note_length: NoteLength) -> Self {
+        self.options.note_length = note_length;
+        self
+    }
+
+    pub fn with_ending(mut self, ending: Ending) -> Self {
+        self.options.ending = ending;
+        self
+    }
+
+    // Accélère (ou ralentit) le rythme et la durée des notes
+    pub fn with_speed(mut self, speed: f64) -> Self {
+        self.step_seconds /= speed;
+        self.note_seconds /= speed;
+        self
+    }
+
+    // Une note par transition entre deux jours consécutifs, ramenée dans la plage des hauteurs
+    // (sauf les glissandos, qui restent dans le médium)
+    pub fn sonify(&self, daily_prices: &[DailyPrice]) -> Vec<NoteEvent> {
+        crate::profile_function!();
+        let mut notes = self.melody(daily_prices);
+        for note in notes.iter_mut().filter(|note| note.timbre != Timbre::Shepard) {
+            for frequency in &mut note.frequencies {
+                *frequency = self.audible(*frequency);
+            }
+        }
+        notes
+    }
+
+    // Mélodie aux hauteurs brutes, avant la plage : l'harmonie part de ces hauteurs pour que la plage ne
+    // soit appliquée qu'une fois à chaque voix
+    fn melody(&self, daily_prices: &[DailyPrice]) -> Vec<NoteEvent> {
+        let drawdowns = match self.view_mode {
+            ViewMode::Price => Vec::new(),
+            ViewMode::Drawdown => drawdown_series(daily_prices),
+        };
+
+        let median_volume = self.options.arpeggio.then(|| median_volume(daily_prices)).flatten();
+        let glides = self.shepard_glides(daily_prices);
+
+        let mut notes: Vec<NoteEvent> = daily_prices
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| {
+                let price_change = percent_change(pair[0].price, pair[1].price);
+                let mut frequencies = match self.view_mode {
+                    ViewMode::Price => vec![change_to_frequency(price_change)],
+                    // Retour au sommet : accord majeur de résolution
+                    ViewMode::Drawdown if drawdowns[index] < 0.0 && drawdowns[index + 1] >= 0.0 => {
+                        RESOLUTION_CHORD.to_vec()
+                    },
+                    ViewMode::Drawdown => vec![drawdown_to_frequency(drawdowns[index + 1])],
+                };
+                // Avec l'écho, la note du jour laisse la place à celle de la veille en début de pas
+                let echo_seconds = if self.options.echo && index > 0 { self.note_seconds * ECHO_RATIO } else { 0.0 };
+                let start = index as f64 * self.step_seconds + echo_seconds;
+                let duration = (self.note_seconds * self.options.note_length.ratio(price_change)).min(self.note_seconds - echo_seconds);
+                // Dans une tendance, le glissando remplace la note et son arpège ; il reste dans le médium sans
+                // passer par la plage des hauteurs
+                if let Some((from, to)) = glides[index] {
+                    return NoteEvent {
+                        start,
+                        duration,
+                        frequencies: vec![from, to],
+                        velocity: velocity_for_change(price_change),
+                        fade_out: 0.0,
+                        arpeggio: None,
+                        timbre: Timbre::Shepard,
+                        sample: None,
+                    };
+                }
+                // Arpège du jour atteint : la note du prix puis celles des autres mesures disponibles
+                let arpeggio = self.options.arpeggio.then(|| {
+                    let day = &pair[1];
+                    if let (Some(volume), Some(median)) = (day.volume, median_volume) {
+                        frequencies.push(volume_to_frequency(volume, median));
+                    }
+                    if day.range.is_some() {
+                        frequencies.push(range_to_frequency(day.range_percent()));
+                    }
+                    self.step_seconds / ARPEGGIO_NOTES_PER_STEP
+                });
+                NoteEvent {
+                    start,
+                    duration,
+                    frequencies,
+                    velocity: velocity_for_change(price_change),
+                    fade_out: 0.0,
+                    arpeggio,
+                    timbre: if self.options.weekend_timbre && pair[1].is_weekend() { Timbre::Hollow } else { Timbre::Sine },
+                    sample: None,
+                }
+            })
+            .collect();
+        if let Some(last) = notes.last_mut().filter(|_| self.options.ending == Ending::FadeOut) {
+            last.fade_out = self.note_seconds * ENDING_TAIL;
+            last.duration += last.fade_out;
+        }
+        notes
+    }
+
+    // Accord de conclusion (fin Ending::Cadence), un pas après la dernière note ; il porte lui-même le fondu
+    pub fn cadence(&self, daily_prices: &[DailyPrice]) -> Option<NoteEvent> {
+        let (first, last) = (daily_prices.first()?, daily_prices.last()?);
+        if self.options.ending != Ending::Cadence || daily_prices.len() < 2 {
+            return None;
+        }
+        let chord = if last.price >= first.price { RESOLUTION_CHORD } else { MINOR_CHORD };
+        let fade_out = self.note_seconds * ENDING_TAIL;
+        Some(NoteEvent {
+            start: (daily_prices.len() - 1) as f64 * self.step_seconds,
+            duration: self.note_seconds + fade_out,
+            frequencies: chord.to_vec(),
+            velocity: CADENCE_VELOCITY,
+            fade_out,
+            arpeggio: None,
+            timbre: Timbre::Sine,
+            sample: None,
+        })
+    }
+
+    // Voix d'harmonie : chaque note de la mélodie (arpèges compris) transposée de l'intervalle choisi,
+    // un peu moins fort, puis ramenée dans la plage ; vide sans harmonie
+    pub fn harmony(&self, daily_prices: &[DailyPrice]) -> Vec<NoteEvent> {
+        let Some(interval) = self.options.harmony else {
+            return Vec::new();
+        };
+        self.melody(daily_prices)
+            .iter()
+            .map(|note| NoteEvent {
+                frequencies: note
+                    .frequencies
+                    .iter()
+                    .map(|frequency| self.audible(harmonize(*frequency, interval, self.options.harmony_below, self.options.scale)))
+                    .collect(),
+                velocity: note.velocity * HARMONY_VELOCITY_RATIO,
+                ..note.clone()
+            })
+            .collect()
+    }
+
+    // Écho : la hauteur de la note précédente rejouée brièvement et doucement juste avant chaque note,
+    // ce qui fait entendre l'intervalle d'un jour à l'autre comme un motif de deux notes ; vide sans écho
+    pub fn echoes(&self, notes: &[NoteEvent]) -> Vec<NoteEvent> {
+        if !self.options.echo {
+            return Vec::new();
+        }
+        let echo_seconds = self.note_seconds * ECHO_RATIO;
+        notes
+            .windows(2)
+            .map(|pair| NoteEvent {
+                start: pair[1].start - echo_seconds,
+                duration: echo_seconds,
+                // Seule la note du prix est reprise, sans les notes d'arpège
+                frequencies: pair[0].frequencies.iter().take(1).copied().collect(),
+                velocity: ECHO_VELOCITY,
+                fade_out: 0.0,
+                arpeggio: None,
+                timbre: pair[0].timbre,
+                sample: None,
+            })
+            .collect()
+    }
+
+    // Sous-basse, au rythme des notes du prix : tenue sur chaque pas à partir du `sub_bass_days`-ième jour
+    // de baisse consécutif, de plus en plus fort, puis une courte extinction au premier jour sans baisse ;
+    // None ailleurs, et partout sans l'option
+    pub fn sub_bass(&self, daily_prices: &[DailyPrice]) -> Vec<Option<NoteEvent>> {
+        let threshold = self.options.sub_bass_days.max(1);
+        let mut streak = 0;
+        daily_prices
+            .windows(2)
+            .enumerate()
+            .map(|(index, pair)| {
+                let previous_streak = streak;
+                streak = if pair[1].price < pair[0].price { streak + 1 } else { 0 };
+                let start = index as f64 * self.step_seconds;
+                let note = |duration: f64, velocity: f32, fade_out: f64| NoteEvent {
+                    start,
+                    duration,
+                    frequencies: SUB_BASS_CHORD.to_vec(),
+                    velocity,
+                    fade_out,
+                    arpeggio: None,
+                    timbre: Timbre::Sine,
+                    sample: None,
+                };
+                let velocity = |streak: usize| (SUB_BASS_VELOCITY + SUB_BASS_SWELL * (streak - threshold) as f32).min(1.0);
+                if !self.options.sub_bass {
+                    None
+                } else if streak >= threshold {
+                    Some(note(self.note_seconds, velocity(streak), 0.0))
+                } else if previous_streak >= threshold {
+                    let release = self.note_seconds * SUB_BASS_RELEASE;
+                    Some(note(release, velocity(previous_streak), release))
+                } else {
+                    None
+                }
+            })
+            .collect()
+    }
+
+    // Glissando de chaque pas (de, vers, en Hz), à partir du SHEPARD_TREND_DAYS-ième jour de même sens
+    // d'affilée ; None ailleurs, et partout sans l'option. La hauteur avance de pas en pas sans revenir
+    // en arrière, et comme la hausse du prix fait descendre les notes, une hausse prolongée descend sans fin
+    fn shepard_glides(&self, daily_prices: &[DailyPrice]) -> Vec<Option<(f32, f32)>> {
+        let mut streak = 0i64;
+        // En octaves au-dessus du La 440 ; seule la place dans l'octave s'entend
+        let mut position = 0.0f64;
+        daily_prices
+            .windows(2)
+            .map(|pair| {
+                let price_change = percent_change(pair[0].price, pair[1].price);
+                streak = match price_change.partial_cmp(&0.0) {
+                    Some(std::cmp::Ordering::Greater) => streak.max(0) + 1,
+                    Some(std::cmp::Ordering::Less) => streak.min(0) - 1,
+                    _ => 0,
+                };
+                if !self.options.shepard || streak.unsigned_abs() < SHEPARD_TREND_DAYS as u64 {
+                    return None;
+                }
+                let from = position - position.floor();
+                position = from - (price_change / SHEPARD_PERCENT_PER_OCTAVE).clamp(-1.0, 1.0);
+                let hertz = |octaves: f64| (440.0 * 2.0f64.powf(octaves)) as f32;
+                Some((hertz(from), hertz(position)))
+            })
+            .collect()
+    }
+
+    // Hauteur jouée : ramenée dans la plage choisie, et jamais hors de l'audible
+    fn audible(&self, frequency: f32) -> f32 {
+        if frequency.is_nan() {
+            return 440.0;
+        }
+        normalize_frequency(frequency, self.options.min_frequency, self.options.max_frequency).clamp(MIN_FREQUENCY, MAX_FREQUENCY)
+    }
+
+    // Voix du sentiment, au même rythme que les notes du prix : le score du jour atteint, à faible
+    // vélocité pour rester en retrait ; None les jours sans score
+    pub fn sonify_sentiment(&self, scores: &[Option<f64>]) -> Vec<Option<NoteEvent>> {
+        scores
+            .iter()
+            .skip(1)
+            .enumerate()
+            .map(|(index, score)| {
+                score.map(|score| NoteEvent {
+                    start: index as f64 * self.step_seconds,
+                    duration: self.note_seconds,
+                    frequencies: vec![self.audible(sentiment_to_frequency(score))],
+                    velocity: MIN_VELOCITY,
+                    fade_out: 0.0,
+                    arpeggio: None,
+                    timbre: Timbre::Sine,
+                    sample: None,
+                })
+            })
+            .collect()
+    }
+}
+
+// Variation en pourcentage, nulle si le prix de départ ne permet pas de la calculer
+pub fn percent_change(from: f64, to: f64) -> f64 {
+    let change = (to - from) / from * 100.0;
+    if change.is_finite() { change } else { 0.0 }
+}
+
+// Volume médian des jours qui en ont un, référence des notes de volume de l'arpège
+fn median_volume(daily_prices: &[DailyPrice]) -> Option<f64> {
+    let mut volumes: Vec<f64> = daily_prices.iter().filter_map(|day| day.volume).filter(|volume| *volume > 0.0).collect();
+    volumes.sort_by(f64::total_cmp);
+    volumes.get(volumes.len() / 2).copied()
+}
+
+pub fn velocity_for_change(price_change: f64) -> f32 {
+    let intensity = (price_change.abs() / FULL_VELOCITY_CHANGE).min(1.0) as f32;
+    MIN_VELOCITY + (1.0 - MIN_VELOCITY) * intensity
+}
+
+// Voix d'un jour telle que la lecture la joue, raccourcie et adoucie pour l'aperçu au survol du graphique
+pub fn preview_voice(voice: &NoteEvent) -> NoteEvent {
+    let duration = voice.duration.min(PREVIEW_SECONDS);
+    NoteEvent {
+        duration,
+        velocity: voice.velocity * PREVIEW_VELOCITY_RATIO,
+        fade_out: duration / 2.0,
+        ..voice.clone()
+    }
+}
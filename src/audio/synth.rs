@@ -0,0 +1,435 @@
+use super::limiter::Limiter;
+use super::loudness::normalize_loudness;
+use super::samples::Sample;
+use super::sonifier::{NoteEvent, Timbre};
+use crate::error::Result;
+#[cfg(feature = "jack")]
+use crate::i18n::tr_args;
+use rodio::{OutputStream, OutputStreamHandle, Sink, Source};
+use std::path::Path;
+use std::sync::Arc;
+use std::time::Duration as StdDuration;
+
+pub const NOTE_DURATION_MS: u64 = 2000;
+// Amplitude d'une note à pleine vélocité
+pub const NOTE_AMPLITUDE: f32 = 0.25;
+// Intervalle entre deux jours de la lecture
+pub const STEP_SECONDS: f64 = 2.0;
+pub const AUDIO_SAMPLE_RATE: u32 = 44100;
+// Plus grave composante d'un ton de Shepard
+const MIN_AUDIBLE: f32 = 20.0;
+
+// Somme de sinusoïdes (une note ou un accord), normalisée par le nombre de voix
+pub struct ToneSource {
+    frequencies: Vec<f32>,
+    sample_rate: u32,
+    position: u64,
+    // Fondu linéaire vers le silence : (premier échantillon, longueur en échantillons)
+    fade_out: Option<(u64, u64)>,
+    // Longueur (en échantillons) de chaque note d'un arpège ; None joue les fréquences ensemble
+    arpeggio: Option<u64>,
+    timbre: Timbre,
+}
+
+impl ToneSource {
+    pub fn new(frequencies: Vec<f32>, sample_rate: u32) -> Self {
+        Self {
+            frequencies,
+            sample_rate,
+            position: 0,
+            fade_out: None,
+            arpeggio: None,
+            timbre: Timbre::Sine,
+        }
+    }
+
+    pub fn with_timbre(mut self, timbre: Timbre) -> Self {
+        self.timbre = timbre;
+        self
+    }
+
+    // Joue les fréquences l'une après l'autre, `note_seconds` chacune, en boucle
+    pub fn with_arpeggio(mut self, note_seconds: f64) -> Self {
+        self.arpeggio = Some(((note_seconds * self.sample_rate as f64) as u64).max(1));
+        self
+    }
+
+    // S'éteint en `length` secondes à partir de `start` secondes, puis reste silencieuse
+    pub fn with_fade_out(mut self, start: f64, length: f64) -> Self {
+        let to_samples = |seconds: f64| (seconds * self.sample_rate as f64) as u64;
+        self.fade_out = Some((to_samples(start), to_samples(length).max(1)));
+        self
+    }
+
+    fn gain(&self) -> f32 {
+        fade_gain(self.fade_out, self.position)
+    }
+
+    // Échantillon d'une voix à l'instant t, entre -1 et 1
+    fn wave(&self, frequency: f32, t: f32) -> f32 {
+        let phase = 2.0 * std::f32::consts::PI * frequency * t;
+        match self.timbre {
+            // Le ton de Shepard a sa propre source (voir ShepardSource) ; ici, la seule octave de la note
+            Timbre::Sine | Timbre::Shepard => phase.sin(),
+            Timbre::Hollow => {
+                // Harmoniques 1, 3 et 5 en 1/n, sans dépasser la moitié de la fréquence d'échantillonnage
+                let nyquist = self.sample_rate as f32 / 2.0;
+                let (sum, norm) = [1.0f32, 3.0, 5.0]
+                    .into_iter()
+                    .filter(|n| n * frequency < nyquist || *n == 1.0)
+                    .fold((0.0, 0.0), |(sum, norm), n| (sum + (n * phase).sin() / n, norm + 1.0 / n));
+                sum / norm
+            },
+        }
+    }
+}
+
+impl Iterator for ToneSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let t = self.position as f32 / self.sample_rate as f32;
+        let gain = self.gain();
+        let position = self.position;
+        self.position += 1;
+        if let Some(length) = self.arpeggio.filter(|_| !self.frequencies.is_empty()) {
+            let frequency = self.frequencies[(position / length) as usize % self.frequencies.len()];
+            // Attaque et relâchement de quelques millisecondes : pas de clic au changement de note
+            let offset = position % length;
+            let ramp = (length / 4).min(self.sample_rate as u64 / 200).max(1);
+            let envelope = (offset.min(length - 1 - offset) as f32 / ramp as f32).min(1.0);
+            return Some(gain * envelope * self.wave(frequency, t));
+        }
+        let voices = self.frequencies.len().max(1) as f32;
+        let sum: f32 = self.frequencies.iter().map(|f| self.wave(*f, t)).sum();
+        Some(gain * sum / voices)
+    }
+}
+
+impl Source for ToneSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<StdDuration> {
+        None
+    }
+}
+
+// Gain d'un fondu linéaire vers le silence : (premier échantillon, longueur en échantillons)
+fn fade_gain(fade_out: Option<(u64, u64)>, position: u64) -> f32 {
+    match fade_out {
+        Some((start, length)) if position > start => 1.0 - ((position - start) as f32 / length as f32).min(1.0),
+        _ => 1.0,
+    }
+}
+
+// Ton de Shepard : la même note sur toutes les octaves audibles, pondérées par une cloche fixe centrée
+// sur le La 440. En glissant d'une hauteur à une autre (glissando de Risset), les octaves se déplacent
+// ensemble sous la cloche : celles qui sortent d'un côté s'effacent pendant que d'autres entrent de
+// l'autre, et la note semble monter (ou descendre) sans fin tout en restant dans le médium
+pub struct ShepardSource {
+    // Hauteur de départ, en octaves au-dessus du La 440, et glissement par échantillon
+    start: f32,
+    slope: f32,
+    // Phase de chaque octave, intégrée échantillon par échantillon puisque la fréquence glisse
+    phases: Vec<f32>,
+    sample_rate: u32,
+    position: u64,
+    fade_out: Option<(u64, u64)>,
+}
+
+impl ShepardSource {
+    // Largeur de la cloche, en octaves : à deux octaves du centre, une composante ne pèse plus que 13 %
+    const SPREAD: f32 = 1.0;
+    // Octaves jouées de part et d'autre de la note, de quoi couvrir l'audible
+    const OCTAVES: i32 = 5;
+
+    // Glisse de `from` à `to` (en Hz) en `seconds` secondes ; avec from == to, un ton de Shepard fixe
+    pub fn new(from: f32, to: f32, seconds: f64, sample_rate: u32) -> Self {
+        let samples = (seconds * sample_rate as f64).max(1.0) as f32;
+        let start = (from / 440.0).log2();
+        Self {
+            start,
+            slope: ((to / 440.0).log2() - start) / samples,
+            phases: vec![0.0; (2 * Self::OCTAVES + 1) as usize],
+            sample_rate,
+            position: 0,
+            fade_out: None,
+        }
+    }
+
+    pub fn with_fade_out(mut self, start: f64, length: f64) -> Self {
+        let to_samples = |seconds: f64| (seconds * self.sample_rate as f64) as u64;
+        self.fade_out = Some((to_samples(start), to_samples(length).max(1)));
+        self
+    }
+}
+
+impl Iterator for ShepardSource {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        let pitch = self.start + self.slope * self.position as f32;
+        // Seule la place de la note dans l'octave compte : les composantes sont les mêmes une octave plus loin
+        let pitch = pitch - pitch.floor();
+        let nyquist = self.sample_rate as f32 / 2.0;
+        let (mut sum, mut norm) = (0.0, 0.0);
+        for (phase, octave) in self.phases.iter_mut().zip(-Self::OCTAVES..=Self::OCTAVES) {
+            let octaves = pitch + octave as f32;
+            let frequency = 440.0 * 2.0f32.powf(octaves);
+            if !(MIN_AUDIBLE..nyquist).contains(&frequency) {
+                continue;
+            }
+            let weight = (-0.5 * (octaves / Self::SPREAD).powi(2)).exp();
+            *phase = (*phase + std::f32::consts::TAU * frequency / self.sample_rate as f32) % std::f32::consts::TAU;
+            sum += weight * phase.sin();
+            norm += weight;
+        }
+        let gain = fade_gain(self.fade_out, self.position);
+        self.position += 1;
+        Some(if norm > 0.0 { gain * sum / norm.sqrt().max(1.0) } else { 0.0 })
+    }
+}
+
+impl Source for ShepardSource {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        self.sample_rate
+    }
+
+    fn total_duration(&self) -> Option<StdDuration> {
+        None
+    }
+}
+
+// Voix d'une note : synthèse, ton de Shepard, ou échantillon d'un pack de sons lu jusqu'au bout
+enum NoteVoice {
+    Tone(ToneSource),
+    Shepard(ShepardSource),
+    Sample(Arc<Sample>, usize),
+}
+
+impl Iterator for NoteVoice {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        match self {
+            NoteVoice::Tone(tone) => tone.next(),
+            NoteVoice::Shepard(shepard) => shepard.next(),
+            NoteVoice::Sample(sample, position) => {
+                let value = sample.samples.get(*position).copied();
+                *position += 1;
+                value
+            },
+        }
+    }
+}
+
+impl Source for NoteVoice {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        1
+    }
+
+    fn sample_rate(&self) -> u32 {
+        AUDIO_SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<StdDuration> {
+        None
+    }
+}
+
+fn note_voice(note: &NoteEvent) -> NoteVoice {
+    match &note.sample {
+        Some(sample) => NoteVoice::Sample(sample.clone(), 0),
+        None if note.timbre == Timbre::Shepard => NoteVoice::Shepard(note_shepard(note)),
+        None => NoteVoice::Tone(note_tone(note)),
+    }
+}
+
+// Glissando de la première fréquence de la note à la dernière, sur toute sa durée hors fondu
+fn note_shepard(note: &NoteEvent) -> ShepardSource {
+    let from = note.frequencies.first().copied().unwrap_or(440.0);
+    let to = note.frequencies.last().copied().unwrap_or(from);
+    let shepard = ShepardSource::new(from, to, note.duration - note.fade_out, AUDIO_SAMPLE_RATE);
+    if note.fade_out > 0.0 {
+        shepard.with_fade_out(note.duration - note.fade_out, note.fade_out)
+    } else {
+        shepard
+    }
+}
+
+fn note_tone(note: &NoteEvent) -> ToneSource {
+    let mut tone = ToneSource::new(note.frequencies.clone(), AUDIO_SAMPLE_RATE).with_timbre(note.timbre);
+    if let Some(note_seconds) = note.arpeggio {
+        tone = tone.with_arpeggio(note_seconds);
+    }
+    if note.fade_out > 0.0 {
+        tone.with_fade_out(note.duration - note.fade_out, note.fade_out)
+    } else {
+        tone
+    }
+}
+
+// Note (ou accord) jouée à chaque pas de la lecture
+pub fn note_source(note: &NoteEvent) -> impl Source<Item = f32> + Send {
+    crate::profile_function!();
+    note_voice(note)
+        .take_duration(StdDuration::from_secs_f64(note.duration))
+        .amplify(NOTE_AMPLITUDE * note.velocity)
+}
+
+// Voix d'un pas de la lecture mélangées, chacune retardée jusqu'à son départ dans le pas (`step_start`,
+// en secondes depuis le début de la série). Avec `length`, le pas dure exactement ce temps, complété de
+// silence ou coupé : mis bout à bout dans un sink, les pas s'enchaînent alors sans trou ni chevauchement
+pub fn step_source(voices: &[NoteEvent], step_start: f64, length: Option<f64>) -> Box<dyn Source<Item = f32> + Send> {
+    crate::profile_function!();
+    let mut mixed: Box<dyn Source<Item = f32> + Send> = Box::new(silence(length.unwrap_or(0.0)));
+    for voice in voices {
+        let delay = StdDuration::from_secs_f64((voice.start - step_start).max(0.0));
+        mixed = Box::new(mixed.mix(note_source(voice).delay(delay)));
+    }
+    match length {
+        Some(length) => Box::new(mixed.take_duration(StdDuration::from_secs_f64(length))),
+        None => mixed,
+    }
+}
+
+pub fn silence(seconds: f64) -> impl Source<Item = f32> + Send {
+    rodio::source::Zero::<f32>::new(1, AUDIO_SAMPLE_RATE).take_duration(StdDuration::from_secs_f64(seconds.max(0.0)))
+}
+
+// Sortie audio par défaut (ou JACK, voir open_stream) ; le flux doit rester en vie tant que le sink joue
+pub fn open_sound_output(volume: f32) -> Result<(OutputStream, Sink)> {
+    let (stream, stream_handle) = open_stream()?;
+    let sink = Sink::try_new(&stream_handle)?;
+    sink.set_volume(volume);
+    Ok((stream, sink))
+}
+
+// Deuxième sortie qui double la première, par exemple un câble virtuel capté par OBS
+pub fn open_mirror_output(device_name: &str, volume: f32) -> Result<(OutputStream, Sink)> {
+    use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+    let device = rodio::cpal::default_host()
+        .output_devices()
+        .map_err(|_| rodio::StreamError::NoDevice)?
+        .find(|device| device.name().is_ok_and(|name| name == device_name))
+        .ok_or(rodio::StreamError::NoDevice)?;
+    let (stream, stream_handle) = OutputStream::try_from_device(&device)?;
+    let sink = Sink::try_new(&stream_handle)?;
+    sink.set_volume(volume);
+    Ok((stream, sink))
+}
+
+// Noms des périphériques de sortie, pour choisir celui de open_mirror_output
+pub fn output_device_names() -> Vec<String> {
+    use rodio::cpal::traits::{DeviceTrait, HostTrait};
+
+    rodio::cpal::default_host()
+        .output_devices()
+        .map(|devices| devices.filter_map(|device| device.name().ok()).collect())
+        .unwrap_or_default()
+}
+
+// Avec la fonctionnalité « jack », le son passe par le serveur JACK quand il tourne et retombe sur
+// le périphérique par défaut sinon
+fn open_stream() -> Result<(OutputStream, OutputStreamHandle)> {
+    #[cfg(feature = "jack")]
+    match open_jack_stream() {
+        Ok(stream) => return Ok(stream),
+        Err(e) => println!("{}", tr_args("log-jack-unavailable", &[("error", &e.to_string())])),
+    }
+    Ok(OutputStream::try_default()?)
+}
+
+// Client JACK « cpal_client_out », relié automatiquement aux sorties système et libre d'être rebranché
+#[cfg(feature = "jack")]
+fn open_jack_stream() -> Result<(OutputStream, OutputStreamHandle)> {
+    use rodio::cpal::traits::HostTrait;
+    use rodio::StreamError;
+
+    let host = rodio::cpal::host_from_id(rodio::cpal::HostId::Jack).map_err(|_| StreamError::NoDevice)?;
+    let device = host.default_output_device().ok_or(StreamError::NoDevice)?;
+    Ok(OutputStream::try_from_device(&device)?)
+}
+
+// Alarme des alertes de prix : bips aigus alternés, bien distincts des notes de la lecture
+pub fn alarm_beeps() -> impl Iterator<Item = impl Source<Item = f32> + Send> {
+    const BEEP: StdDuration = StdDuration::from_millis(140);
+    const GAP: StdDuration = StdDuration::from_millis(60);
+    [1320.0, 990.0, 1320.0, 990.0, 1320.0, 990.0]
+        .into_iter()
+        .enumerate()
+        .map(|(i, frequency)| {
+            ToneSource::new(vec![frequency], AUDIO_SAMPLE_RATE)
+                .take_duration(BEEP)
+                .delay(if i == 0 { StdDuration::ZERO } else { GAP })
+                .amplify(NOTE_AMPLITUDE)
+        })
+}
+
+// Synthétise les notes jouées (même timbre que la lecture) en échantillons mono à AUDIO_SAMPLE_RATE
+pub fn mix_notes(notes: &[NoteEvent], duration: f64) -> Vec<f32> {
+    let sample_rate = AUDIO_SAMPLE_RATE as f64;
+    let mut samples = vec![0.0f32; (duration * sample_rate) as usize];
+
+    for note in notes {
+        let offset = (note.start * sample_rate) as usize;
+        let note_samples = (note.duration * sample_rate) as usize;
+        let amplitude = NOTE_AMPLITUDE * note.velocity;
+        let tone = note_voice(note).take(note_samples);
+        for (n, value) in tone.enumerate() {
+            let Some(sample) = samples.get_mut(offset + n) else {
+                break;
+            };
+            *sample += amplitude * value;
+        }
+    }
+    // Même dernier maillon que la lecture : les notes qui se chevauchent ne saturent pas
+    let mut limiter = Limiter::new(AUDIO_SAMPLE_RATE, None);
+    for sample in &mut samples {
+        *sample = limiter.process(*sample);
+    }
+    samples
+}
+
+// Avec `loudness`, le fichier est normalisé à cette sonie (en LUFS) pour que tous les exports s'écoutent au même niveau
+pub fn write_notes_wav(notes: &[NoteEvent], duration: f64, loudness: Option<f64>, path: &Path) -> Result<()> {
+    let mut samples = mix_notes(notes, duration);
+    if let Some(target) = loudness {
+        normalize_loudness(&mut samples, AUDIO_SAMPLE_RATE, target);
+    }
+    let spec = hound::WavSpec {
+        channels: 1,
+        sample_rate: AUDIO_SAMPLE_RATE,
+        bits_per_sample: 16,
+        sample_format: hound::SampleFormat::Int,
+    };
+    let mut writer = hound::WavWriter::create(path, spec)?;
+    for sample in samples {
+        writer.write_sample((sample.clamp(-1.0, 1.0) * i16::MAX as f32) as i16)?;
+    }
+    writer.finalize()?;
+    Ok(())
+}
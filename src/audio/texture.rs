@@ -0,0 +1,107 @@
+use super::mapping::spread_to_beat_frequency;
+use super::synth::{ToneSource, AUDIO_SAMPLE_RATE};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use rodio::Source;
+use std::time::Duration as StdDuration;
+
+// Volume du souffle de fond, discret sous la mélodie
+const TEXTURE_AMPLITUDE: f32 = 0.04;
+const WALL_AMPLITUDE: f32 = 0.15;
+const WALL_DURATION: StdDuration = StdDuration::from_millis(250);
+const SPREAD_AMPLITUDE: f32 = 0.08;
+
+// Gain des couches de fond (carnet d'ordres, écart entre plateformes) autour d'une note de la mélodie :
+// abaissé de `depth` jusqu'à la fin de la note, puis remonté linéairement en `release` secondes
+pub fn ducking_gain(now: f64, note_end: f64, depth: f32, release: f64) -> f32 {
+    let reduced = 1.0 - depth.clamp(0.0, 1.0);
+    if now < note_end {
+        return reduced;
+    }
+    let progress = if release > 0.0 { ((now - note_end) / release).min(1.0) as f32 } else { 1.0 };
+    reduced + (1.0 - reduced) * progress
+}
+
+// Bruit rose stéréo (filtre de Paul Kellet), placé entre les deux canaux par `pan`
+// (-1 tout à gauche, 1 tout à droite)
+pub struct PinkNoise {
+    rng: StdRng,
+    state: [f32; 3],
+    gains: (f32, f32),
+    // Échantillon droit en attente : les canaux sont entrelacés
+    pending: Option<f32>,
+}
+
+impl PinkNoise {
+    pub fn new(pan: f32) -> Self {
+        // Panoramique à puissance constante
+        let pan = pan.clamp(-1.0, 1.0);
+        let left = ((1.0 - pan) / 2.0).sqrt();
+        let right = ((1.0 + pan) / 2.0).sqrt();
+        Self {
+            rng: StdRng::from_entropy(),
+            state: [0.0; 3],
+            gains: (left, right),
+            pending: None,
+        }
+    }
+}
+
+impl Iterator for PinkNoise {
+    type Item = f32;
+
+    fn next(&mut self) -> Option<f32> {
+        if let Some(right) = self.pending.take() {
+            return Some(right);
+        }
+        let white: f32 = self.rng.gen_range(-1.0..1.0);
+        self.state[0] = 0.99765 * self.state[0] + white * 0.0990460;
+        self.state[1] = 0.96300 * self.state[1] + white * 0.2965164;
+        self.state[2] = 0.57000 * self.state[2] + white * 1.0526913;
+        let pink = (self.state.iter().sum::<f32>() + white * 0.1848) * 0.25;
+        self.pending = Some(pink * self.gains.1);
+        Some(pink * self.gains.0)
+    }
+}
+
+impl Source for PinkNoise {
+    fn current_frame_len(&self) -> Option<usize> {
+        None
+    }
+
+    fn channels(&self) -> u16 {
+        2
+    }
+
+    fn sample_rate(&self) -> u32 {
+        AUDIO_SAMPLE_RATE
+    }
+
+    fn total_duration(&self) -> Option<StdDuration> {
+        None
+    }
+}
+
+// Texture du carnet d'ordres pendant `duration` : le souffle penche à gauche quand les achats
+// dominent, à droite quand ce sont les ventes
+pub fn book_texture(imbalance: f64, duration: StdDuration) -> impl Source<Item = f32> + Send {
+    PinkNoise::new(-imbalance as f32)
+        .take_duration(duration)
+        .amplify(TEXTURE_AMPLITUDE * (0.5 + 0.5 * imbalance.abs() as f32))
+}
+
+// Bouffée brève et plus forte, du côté du mur qui vient d'apparaître (achats à gauche, ventes à droite)
+pub fn wall_burst(bid: bool) -> impl Source<Item = f32> + Send {
+    PinkNoise::new(if bid { -1.0 } else { 1.0 })
+        .take_duration(WALL_DURATION)
+        .amplify(WALL_AMPLITUDE)
+}
+
+// Deux La légèrement désaccordés, dont le battement trahit l'écart de prix entre deux plateformes ;
+// None quand les prix sont alignés
+pub fn spread_beating(spread_percent: f64, duration: StdDuration) -> Option<impl Source<Item = f32> + Send> {
+    let beat = spread_to_beat_frequency(spread_percent)?;
+    Some(ToneSource::new(vec![440.0, 440.0 + beat], AUDIO_SAMPLE_RATE)
+        .take_duration(duration)
+        .amplify(SPREAD_AMPLITUDE))
+}
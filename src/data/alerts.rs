@@ -0,0 +1,156 @@
+use super::coins::{coin_display_name, CoinId};
+use super::models::{format_price, ChartData};
+use crate::i18n::tr_args;
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum AlertCondition {
+    // Seuils de prix, dans la devise de la règle
+    Above(f64),
+    Below(f64),
+    // Variation sur 24 h, en pourcentage
+    RisesPercent(f64),
+    DropsPercent(f64),
+}
+
+impl AlertCondition {
+    pub fn value(&self) -> f64 {
+        match *self {
+            AlertCondition::Above(value)
+            | AlertCondition::Below(value)
+            | AlertCondition::RisesPercent(value)
+            | AlertCondition::DropsPercent(value) => value,
+        }
+    }
+
+    pub fn value_mut(&mut self) -> &mut f64 {
+        match self {
+            AlertCondition::Above(value)
+            | AlertCondition::Below(value)
+            | AlertCondition::RisesPercent(value)
+            | AlertCondition::DropsPercent(value) => value,
+        }
+    }
+
+    // Les quatre conditions avec la même valeur, pour changer de type sans la perdre
+    pub fn variants(value: f64) -> [AlertCondition; 4] {
+        [
+            AlertCondition::Above(value),
+            AlertCondition::Below(value),
+            AlertCondition::RisesPercent(value),
+            AlertCondition::DropsPercent(value),
+        ]
+    }
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            AlertCondition::Above(_) => "alert-kind-above",
+            AlertCondition::Below(_) => "alert-kind-below",
+            AlertCondition::RisesPercent(_) => "alert-kind-rises",
+            AlertCondition::DropsPercent(_) => "alert-kind-drops",
+        }
+    }
+
+    pub fn is_percent(&self) -> bool {
+        matches!(self, AlertCondition::RisesPercent(_) | AlertCondition::DropsPercent(_))
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct AlertRule {
+    pub coin: CoinId,
+    pub currency: String,
+    pub condition: AlertCondition,
+}
+
+impl AlertRule {
+    pub fn is_met(&self, data: &ChartData) -> bool {
+        match self.condition {
+            AlertCondition::Above(price) => data.latest_price >= price,
+            AlertCondition::Below(price) => data.latest_price <= price,
+            AlertCondition::RisesPercent(percent) => data.change_24h >= percent,
+            AlertCondition::DropsPercent(percent) => data.change_24h <= -percent,
+        }
+    }
+
+    // Seuil formaté pour l'affichage : prix dans la devise ou pourcentage
+    fn threshold(&self) -> String {
+        if self.condition.is_percent() {
+            format!("{:.1}%", self.condition.value())
+        } else {
+            format_price(self.condition.value(), &self.currency)
+        }
+    }
+
+    // Règle telle qu'affichée dans la liste des alertes
+    pub fn describe(&self) -> String {
+        let key = match self.condition {
+            AlertCondition::Above(_) => "alert-rule-above",
+            AlertCondition::Below(_) => "alert-rule-below",
+            AlertCondition::RisesPercent(_) => "alert-rule-rises",
+            AlertCondition::DropsPercent(_) => "alert-rule-drops",
+        };
+        tr_args(key, &[("coin", &coin_display_name(&self.coin)), ("threshold", &self.threshold())])
+    }
+
+    // Message du bandeau quand la règle se déclenche
+    pub fn message(&self, data: &ChartData) -> String {
+        tr_args("alert-triggered", &[
+            ("rule", &self.describe()),
+            ("price", &format_price(data.latest_price, &data.currency)),
+            ("change", &format!("{:+.1}", data.change_24h)),
+        ])
+    }
+}
+
+// Suit l'état des règles d'un relevé à l'autre : une règle ne se déclenche qu'en passant de fausse
+// à vraie, puis se réarme quand elle redevient fausse (une règle déjà vraie au premier relevé sonne une fois)
+pub struct AlertTracker {
+    rules: Vec<AlertRule>,
+    met: Vec<bool>,
+}
+
+impl AlertTracker {
+    pub fn new() -> Self {
+        Self {
+            rules: Vec::new(),
+            met: Vec::new(),
+        }
+    }
+
+    // Remplace les règles suivies ; renvoie true si elles ont changé, leur état repart alors de zéro
+    pub fn set_rules(&mut self, rules: &[AlertRule]) -> bool {
+        if self.rules == rules {
+            return false;
+        }
+        self.rules = rules.to_vec();
+        self.met = vec![false; rules.len()];
+        true
+    }
+
+    pub fn rules(&self) -> &[AlertRule] {
+        &self.rules
+    }
+
+    // Confronte un relevé aux règles de sa crypto et de sa devise, renvoie celles qui viennent de se déclencher
+    pub fn observe(&mut self, data: &ChartData) -> Vec<AlertRule> {
+        let mut triggered = Vec::new();
+        for (rule, met) in self.rules.iter().zip(self.met.iter_mut()) {
+            if rule.coin != data.coin || rule.currency != data.currency {
+                continue;
+            }
+            let now_met = rule.is_met(data);
+            if now_met && !*met {
+                triggered.push(rule.clone());
+            }
+            *met = now_met;
+        }
+        triggered
+    }
+}
+
+impl Default for AlertTracker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
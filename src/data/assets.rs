@@ -0,0 +1,156 @@
+use crate::error::Result;
+use crate::i18n::tr_args;
+use serde::Deserialize;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+// Images du pack par défaut intégrées au binaire ; un fichier du même nom dans assets/ reste prioritaire
+const EMBEDDED_ASSETS: &[(&str, &[u8])] = &[
+    ("bull1.png", include_bytes!("../../assets/bull1.png")),
+    ("bull2.png", include_bytes!("../../assets/bull2.png")),
+    ("bull3.png", include_bytes!("../../assets/bull3.png")),
+    ("bull4.png", include_bytes!("../../assets/bull4.png")),
+    ("bull5.png", include_bytes!("../../assets/bull5.png")),
+    ("bull6.png", include_bytes!("../../assets/bull6.png")),
+    ("bull7.png", include_bytes!("../../assets/bull7.png")),
+    ("bear1.png", include_bytes!("../../assets/bear1.png")),
+    ("bear2.png", include_bytes!("../../assets/bear2.png")),
+    ("bear3.png", include_bytes!("../../assets/bear3.png")),
+    ("bear4.png", include_bytes!("../../assets/bear4.png")),
+    ("vitalik.png", include_bytes!("../../assets/vitalik.png")),
+    ("satoshi.png", include_bytes!("../../assets/satoshi.png")),
+    ("david_xrp.png", include_bytes!("../../assets/david_xrp.png")),
+];
+
+// Lit une image depuis le disque, avec repli sur la copie intégrée pour le pack par défaut
+pub fn read_asset(path: &Path) -> std::io::Result<Cow<'static, [u8]>> {
+    match std::fs::read(path) {
+        Ok(bytes) => Ok(Cow::Owned(bytes)),
+        Err(e) => {
+            let embedded = (path.parent() == Some(Path::new("assets")))
+                .then(|| path.file_name()?.to_str())
+                .flatten()
+                .and_then(|name| EMBEDDED_ASSETS.iter().find(|(file, _)| *file == name));
+            match embedded {
+                Some((_, bytes)) => Ok(Cow::Borrowed(*bytes)),
+                None => Err(e),
+            }
+        },
+    }
+}
+
+// Une image de réaction : simple fichier, ou planche de sprites avec sa cadence
+#[derive(Debug, Deserialize)]
+#[serde(untagged)]
+enum ManifestImage {
+    File(String),
+    Sprite { file: String, frames: u32, fps: f32 },
+}
+
+// Manifeste d'un pack d'images (assets/packs/<nom>/manifest.json)
+#[derive(Debug, Deserialize)]
+struct AssetManifest {
+    name: Option<String>,
+    #[serde(default)]
+    bull: Vec<ManifestImage>,
+    #[serde(default)]
+    bear: Vec<ManifestImage>,
+    #[serde(default)]
+    portraits: HashMap<String, String>,
+}
+
+#[derive(Clone)]
+pub struct ReactionImage {
+    pub path: PathBuf,
+    // Nombre d'images et cadence si le fichier est une planche de sprites horizontale
+    pub sprite: Option<(u32, f32)>,
+}
+
+impl ReactionImage {
+    fn from_manifest(dir: &Path, entry: &ManifestImage) -> Self {
+        match entry {
+            ManifestImage::File(file) => Self { path: dir.join(file), sprite: None },
+            ManifestImage::Sprite { file, frames, fps } => Self {
+                path: dir.join(file),
+                sprite: Some((*frames, *fps)),
+            },
+        }
+    }
+}
+
+#[derive(Clone)]
+pub struct AssetPack {
+    pub name: String,
+    pub bull_images: Vec<ReactionImage>,
+    pub bear_images: Vec<ReactionImage>,
+    pub portraits: HashMap<String, PathBuf>,
+}
+
+impl AssetPack {
+    fn default_pack() -> Self {
+        let assets = Path::new("assets");
+        Self {
+            name: "Default".to_string(),
+            bull_images: (1..=7)
+                .map(|i| ReactionImage { path: assets.join(format!("bull{}.png", i)), sprite: None })
+                .collect(),
+            bear_images: (1..=4)
+                .map(|i| ReactionImage { path: assets.join(format!("bear{}.png", i)), sprite: None })
+                .collect(),
+            portraits: HashMap::from([
+                ("ethereum".to_string(), assets.join("vitalik.png")),
+                ("bitcoin".to_string(), assets.join("satoshi.png")),
+                ("ripple".to_string(), assets.join("david_xrp.png")),
+            ]),
+        }
+    }
+
+    // Les entrées absentes du manifeste reprennent celles du pack par défaut
+    pub fn load(dir: &Path) -> Result<Self> {
+        let manifest: AssetManifest = serde_json::from_str(
+            &std::fs::read_to_string(dir.join("manifest.json"))?
+        )?;
+        let mut pack = Self::default_pack();
+
+        pack.name = manifest.name.unwrap_or_else(|| {
+            dir.file_name().map(|n| n.to_string_lossy().into_owned()).unwrap_or_default()
+        });
+        if !manifest.bull.is_empty() {
+            pack.bull_images = manifest.bull.iter().map(|e| ReactionImage::from_manifest(dir, e)).collect();
+        }
+        if !manifest.bear.is_empty() {
+            pack.bear_images = manifest.bear.iter().map(|e| ReactionImage::from_manifest(dir, e)).collect();
+        }
+        for (coin, file) in manifest.portraits {
+            pack.portraits.insert(coin, dir.join(file));
+        }
+        Ok(pack)
+    }
+
+    // Les packs héritent des portraits du pack par défaut : None pour une crypto sans portrait
+    pub fn portrait(&self, coin: &str) -> Option<PathBuf> {
+        self.portraits.get(coin).cloned()
+    }
+
+    pub fn discover() -> Vec<Self> {
+        let mut packs = vec![Self::default_pack()];
+        if let Ok(entries) = std::fs::read_dir(Path::new("assets").join("packs")) {
+            let mut dirs: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| path.join("manifest.json").is_file())
+                .collect();
+            dirs.sort();
+            for dir in dirs {
+                match Self::load(&dir) {
+                    Ok(pack) => packs.push(pack),
+                    Err(e) => println!("{}", tr_args("log-pack-failed", &[
+                        ("path", &dir.display().to_string()),
+                        ("error", &e.to_string()),
+                    ])),
+                }
+            }
+        }
+        packs
+    }
+}
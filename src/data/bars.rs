@@ -0,0 +1,86 @@
+use serde::{Deserialize, Serialize};
+
+// Longueur des barres du mode direct : une note par barre terminée, quel que soit le rythme des relevés
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum BarLength {
+    OneSecond,
+    FiveSeconds,
+    OneMinute,
+}
+
+impl BarLength {
+    pub const ALL: [BarLength; 3] = [BarLength::OneSecond, BarLength::FiveSeconds, BarLength::OneMinute];
+
+    pub fn seconds(self) -> f64 {
+        match self {
+            BarLength::OneSecond => 1.0,
+            BarLength::FiveSeconds => 5.0,
+            BarLength::OneMinute => 60.0,
+        }
+    }
+
+    pub fn label_key(self) -> &'static str {
+        match self {
+            BarLength::OneSecond => "bar-1s",
+            BarLength::FiveSeconds => "bar-5s",
+            BarLength::OneMinute => "bar-1m",
+        }
+    }
+}
+
+// Barre de prix : ouverture, extrêmes et clôture des relevés d'un intervalle
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Bar {
+    // Début de l'intervalle, en secondes
+    pub start: f64,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub ticks: usize,
+}
+
+// Regroupe les relevés en barres alignées sur des multiples de leur longueur ; un intervalle sans
+// relevé ne produit pas de barre
+pub struct BarAggregator {
+    length: BarLength,
+    current: Option<Bar>,
+}
+
+impl BarAggregator {
+    pub fn new(length: BarLength) -> Self {
+        Self { length, current: None }
+    }
+
+    pub fn length(&self) -> BarLength {
+        self.length
+    }
+
+    // Change la longueur des barres ; la barre en cours est abandonnée
+    pub fn set_length(&mut self, length: BarLength) {
+        if length != self.length {
+            self.length = length;
+            self.current = None;
+        }
+    }
+
+    pub fn current(&self) -> Option<&Bar> {
+        self.current.as_ref()
+    }
+
+    // Ajoute un relevé ; renvoie la barre précédente quand celui-ci en ouvre une nouvelle
+    pub fn push(&mut self, time: f64, price: f64) -> Option<Bar> {
+        let seconds = self.length.seconds();
+        let start = (time / seconds).floor() * seconds;
+        match &mut self.current {
+            Some(bar) if bar.start == start => {
+                bar.high = bar.high.max(price);
+                bar.low = bar.low.min(price);
+                bar.close = price;
+                bar.ticks += 1;
+                None
+            },
+            _ => self.current.replace(Bar { start, open: price, high: price, low: price, close: price, ticks: 1 }),
+        }
+    }
+}
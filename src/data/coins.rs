@@ -0,0 +1,45 @@
+use super::portfolio::PORTFOLIO_ID;
+use crate::i18n::tr;
+use eframe::egui::Color32;
+
+// Identifiant CoinGecko d'une crypto ("bitcoin", "ethereum"...)
+pub type CoinId = String;
+
+// Métadonnées des cryptos connues : nom, symbole et couleur de la marque
+pub struct CoinMeta {
+    pub id: &'static str,
+    pub name: &'static str,
+    pub symbol: &'static str,
+    pub accent: Color32,
+    pub tagline_key: &'static str,
+}
+
+pub const COINS: &[CoinMeta] = &[
+    CoinMeta { id: "bitcoin", name: "Bitcoin", symbol: "BTC", accent: Color32::from_rgb(247, 147, 26), tagline_key: "tagline-bitcoin" },
+    CoinMeta { id: "ethereum", name: "Ethereum", symbol: "ETH", accent: Color32::from_rgb(140, 110, 230), tagline_key: "tagline-ethereum" },
+    CoinMeta { id: "ripple", name: "Ripple", symbol: "XRP", accent: Color32::from_rgb(0, 153, 204), tagline_key: "tagline-ripple" },
+    CoinMeta { id: "solana", name: "Solana", symbol: "SOL", accent: Color32::from_rgb(153, 69, 255), tagline_key: "" },
+    CoinMeta { id: "cardano", name: "Cardano", symbol: "ADA", accent: Color32::from_rgb(40, 90, 220), tagline_key: "" },
+    CoinMeta { id: "dogecoin", name: "Dogecoin", symbol: "DOGE", accent: Color32::from_rgb(194, 166, 51), tagline_key: "" },
+];
+
+// Couleur utilisée pour les cryptos absentes de la table
+pub const DEFAULT_ACCENT: Color32 = Color32::from_rgb(255, 215, 0);
+
+pub fn coin_meta(coin: &str) -> Option<&'static CoinMeta> {
+    COINS.iter().find(|meta| meta.id == coin)
+}
+
+pub fn coin_display_name(coin: &str) -> String {
+    if coin == PORTFOLIO_ID {
+        return tr("portfolio").to_string();
+    }
+    match coin_meta(coin) {
+        Some(meta) => format!("{} ({})", meta.name, meta.symbol),
+        None => coin.to_string(),
+    }
+}
+
+pub fn coin_accent(coin: &str) -> Color32 {
+    coin_meta(coin).map_or(DEFAULT_ACCENT, |meta| meta.accent)
+}
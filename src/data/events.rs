@@ -0,0 +1,96 @@
+use super::coins::CoinId;
+use super::models::{day_index, DailyPrice};
+use crate::error::Result;
+use crate::i18n::tr_args;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+
+// Événement marquant du calendrier crypto, affiché comme repère sur la frise du graphique
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct MarketEvent {
+    // Jour de l'événement (AAAA-MM-JJ)
+    pub date: String,
+    pub title: String,
+    // Cryptos concernées, toutes si la liste est vide (krach général, faillite d'une plateforme...)
+    #[serde(default)]
+    pub coins: Vec<CoinId>,
+}
+
+impl MarketEvent {
+    pub fn concerns(&self, coin: &str) -> bool {
+        self.coins.is_empty() || self.coins.iter().any(|c| c == coin)
+    }
+}
+
+// Halvings, mises à jour majeures et décisions sur les ETF, fournis avec l'application
+const BUILTIN_EVENTS: &[(&str, &[&str], &str)] = &[
+    ("2012-11-28", &["bitcoin"], "First Bitcoin halving"),
+    ("2016-07-09", &["bitcoin"], "Second Bitcoin halving"),
+    ("2016-07-20", &["ethereum"], "DAO hard fork"),
+    ("2017-08-01", &["bitcoin"], "Bitcoin Cash fork"),
+    ("2017-08-24", &["bitcoin"], "SegWit activation"),
+    ("2019-02-28", &["ethereum"], "Constantinople upgrade"),
+    ("2020-03-12", &[], "Black Thursday crash"),
+    ("2020-05-11", &["bitcoin"], "Third Bitcoin halving"),
+    ("2021-08-05", &["ethereum"], "London upgrade (EIP-1559)"),
+    ("2021-11-14", &["bitcoin"], "Taproot activation"),
+    ("2022-05-09", &[], "Terra/UST collapse"),
+    ("2022-09-15", &["ethereum"], "The Merge (proof of stake)"),
+    ("2022-11-11", &[], "FTX files for bankruptcy"),
+    ("2023-04-12", &["ethereum"], "Shapella upgrade (staking withdrawals)"),
+    ("2023-07-13", &["ripple"], "XRP ruled not a security on exchanges"),
+    ("2024-01-10", &["bitcoin"], "US spot Bitcoin ETFs approved"),
+    ("2024-03-13", &["ethereum"], "Dencun upgrade"),
+    ("2024-04-20", &["bitcoin"], "Fourth Bitcoin halving"),
+    ("2024-05-23", &["ethereum"], "US spot Ether ETFs approved"),
+    ("2024-07-23", &["ethereum"], "US spot Ether ETFs start trading"),
+    ("2025-05-07", &["ethereum"], "Pectra upgrade"),
+];
+
+pub fn builtin_events() -> Vec<MarketEvent> {
+    BUILTIN_EVENTS
+        .iter()
+        .map(|(date, coins, title)| MarketEvent {
+            date: date.to_string(),
+            title: title.to_string(),
+            coins: coins.iter().map(|coin| coin.to_string()).collect(),
+        })
+        .collect()
+}
+
+// Calendrier personnel, à côté des préférences : une liste JSON d'événements au même format
+fn user_events_path() -> PathBuf {
+    dirs::config_dir()
+        .map(|dir| dir.join("crypto-price-sonifier"))
+        .unwrap_or_default()
+        .join("events.json")
+}
+
+fn load_user_events() -> Result<Vec<MarketEvent>> {
+    let path = user_events_path();
+    if !path.exists() {
+        return Ok(Vec::new());
+    }
+    Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+}
+
+// Événements fournis et personnels ; un calendrier personnel illisible est signalé sans masquer les autres
+pub fn load_events() -> Vec<MarketEvent> {
+    let mut events = builtin_events();
+    match load_user_events() {
+        Ok(user_events) => events.extend(user_events),
+        Err(e) => println!("{}", tr_args("log-events-failed", &[("error", &e.to_string())])),
+    }
+    events
+}
+
+// Repères de la crypto dans la période chargée : indice du jour et titre, dans l'ordre chronologique
+pub fn event_markers(events: &[MarketEvent], coin: &str, prices: &[DailyPrice]) -> Vec<(usize, String)> {
+    let mut markers: Vec<(usize, String)> = events
+        .iter()
+        .filter(|event| event.concerns(coin))
+        .filter_map(|event| Some((day_index(prices, &event.date)?, event.title.clone())))
+        .collect();
+    markers.sort_by_key(|(index, _)| *index);
+    markers
+}
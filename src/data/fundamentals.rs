@@ -0,0 +1,68 @@
+use super::http::get_text;
+use crate::error::Result;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Montants par devise ; CoinGecko met null quand une valeur manque
+type Amounts = HashMap<String, Option<f64>>;
+
+// Chiffres clés d'une crypto pour le bandeau du graphique, dans la devise demandée
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct CoinFundamentals {
+    pub market_cap: Option<f64>,
+    pub market_cap_rank: Option<u32>,
+    pub circulating_supply: Option<f64>,
+    pub high_24h: Option<f64>,
+    pub low_24h: Option<f64>,
+    pub ath: Option<f64>,
+    // Écart du prix actuel au plus haut historique, en % (négatif sous le record)
+    pub ath_change: Option<f64>,
+}
+
+#[derive(Deserialize)]
+struct CoinDetails {
+    market_cap_rank: Option<u32>,
+    market_data: Option<MarketData>,
+}
+
+#[derive(Deserialize)]
+struct MarketData {
+    #[serde(default)]
+    market_cap: Amounts,
+    circulating_supply: Option<f64>,
+    #[serde(default)]
+    high_24h: Amounts,
+    #[serde(default)]
+    low_24h: Amounts,
+    #[serde(default)]
+    ath: Amounts,
+    #[serde(default)]
+    ath_change_percentage: Amounts,
+}
+
+// Réponse de /coins/{id} : seuls les chiffres de marché dans `currency` sont gardés
+pub fn parse_coin_details(body: &str, currency: &str) -> Result<CoinFundamentals> {
+    let details: CoinDetails = serde_json::from_str(body)?;
+    let Some(market) = details.market_data else {
+        return Ok(CoinFundamentals { market_cap_rank: details.market_cap_rank, ..CoinFundamentals::default() });
+    };
+    let in_currency = |amounts: &Amounts| amounts.get(currency).copied().flatten();
+    Ok(CoinFundamentals {
+        market_cap: in_currency(&market.market_cap),
+        market_cap_rank: details.market_cap_rank,
+        circulating_supply: market.circulating_supply,
+        high_24h: in_currency(&market.high_24h),
+        low_24h: in_currency(&market.low_24h),
+        ath: in_currency(&market.ath),
+        ath_change: in_currency(&market.ath_change_percentage),
+    })
+}
+
+pub async fn fetch_coin_fundamentals_async(coin: &str, currency: &str) -> Result<CoinFundamentals> {
+    let url = format!(
+        "https://api.coingecko.com/api/v3/coins/{}?localization=false&tickers=false&community_data=false&developer_data=false",
+        coin
+    );
+    let body = get_text(|client| client.get(&url)).await?;
+    parse_coin_details(&body, currency)
+}
@@ -0,0 +1,158 @@
+use crate::error::Result;
+use crate::task::CancelToken;
+use serde::{Deserialize, Serialize};
+use std::future::Future;
+use std::sync::{Mutex, RwLock};
+
+// Délais et nouvelles tentatives des requêtes HTTP, réglables dans les préférences
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct HttpPolicy {
+    // Établissement de la connexion, en secondes
+    pub connect_timeout: u64,
+    // Réponse complète, corps compris (reqwest 0.11 n'a pas de délai de lecture séparé), en secondes
+    pub read_timeout: u64,
+    // Nouvelles tentatives après un délai dépassé, une connexion refusée ou une erreur 5xx/429
+    pub retries: u32,
+}
+
+impl HttpPolicy {
+    pub const DEFAULT: HttpPolicy = HttpPolicy { connect_timeout: 10, read_timeout: 30, retries: 2 };
+    pub const MAX_TIMEOUT: u64 = 300;
+    pub const MAX_RETRIES: u32 = 5;
+
+    // Attente avant la tentative `attempt` (1 pour la première reprise) : 0,5 s, 1 s, 2 s...
+    pub fn backoff(attempt: u32) -> std::time::Duration {
+        std::time::Duration::from_millis(500 << attempt.saturating_sub(1).min(6))
+    }
+
+    // Les erreurs passagères méritent une nouvelle tentative ; un 404 ou une réponse illisible, non
+    pub fn is_transient(error: &reqwest::Error) -> bool {
+        #[cfg(not(target_arch = "wasm32"))]
+        if error.is_connect() {
+            return true;
+        }
+        error.is_timeout()
+            || error
+                .status()
+                .is_some_and(|status| status.is_server_error() || status == reqwest::StatusCode::TOO_MANY_REQUESTS)
+    }
+}
+
+impl Default for HttpPolicy {
+    fn default() -> Self {
+        Self::DEFAULT
+    }
+}
+
+static POLICY: RwLock<HttpPolicy> = RwLock::new(HttpPolicy::DEFAULT);
+// Client partagé par toutes les requêtes (et son pool de connexions), reconstruit quand la politique change
+static CLIENT: Mutex<Option<reqwest::Client>> = Mutex::new(None);
+
+pub fn http_policy() -> HttpPolicy {
+    POLICY.read().map_or(HttpPolicy::DEFAULT, |policy| *policy)
+}
+
+pub fn set_http_policy(policy: HttpPolicy) {
+    if let Ok(mut current) = POLICY.write() {
+        *current = policy;
+    }
+    if let Ok(mut client) = CLIENT.lock() {
+        *client = None;
+    }
+}
+
+// Les délais ne s'appliquent qu'en natif : le client du navigateur passe par fetch(), sans délai configurable
+pub fn client_builder(policy: &HttpPolicy) -> reqwest::ClientBuilder {
+    let builder = reqwest::Client::builder().user_agent("Mozilla/5.0");
+    #[cfg(not(target_arch = "wasm32"))]
+    let builder = builder
+        .connect_timeout(std::time::Duration::from_secs(policy.connect_timeout))
+        .timeout(std::time::Duration::from_secs(policy.read_timeout));
+    #[cfg(target_arch = "wasm32")]
+    let _ = policy;
+    builder
+}
+
+pub fn http_client() -> reqwest::Client {
+    let build = || client_builder(&http_policy()).build().unwrap_or_default();
+    match CLIENT.lock() {
+        Ok(mut client) => client.get_or_insert_with(build).clone(),
+        Err(_) => build(),
+    }
+}
+
+// Corps texte d'une requête GET construite par `request` sur le client partagé, avec les nouvelles tentatives
+pub async fn get_text(request: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder) -> Result<String> {
+    get_text_cancellable(request, &CancelToken::new()).await
+}
+
+// Même requête, abandonnée avec Error::Cancelled dès que `cancel` est levé, avant chaque tentative et
+// pendant l'attente qui la précède
+pub async fn get_text_cancellable(
+    request: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder,
+    cancel: &CancelToken,
+) -> Result<String> {
+    let client = http_client();
+    with_retries(
+        || {
+            let request = request(&client);
+            async move { request.send().await?.error_for_status()?.text().await }
+        },
+        cancel,
+    )
+    .await
+}
+
+pub async fn get_bytes(request: impl Fn(&reqwest::Client) -> reqwest::RequestBuilder) -> Result<Vec<u8>> {
+    let client = http_client();
+    with_retries(
+        || {
+            let request = request(&client);
+            async move { Ok(request.send().await?.error_for_status()?.bytes().await?.to_vec()) }
+        },
+        &CancelToken::new(),
+    )
+    .await
+}
+
+async fn with_retries<T, F, Fut>(attempt: F, cancel: &CancelToken) -> Result<T>
+where
+    F: Fn() -> Fut,
+    Fut: Future<Output = reqwest::Result<T>>,
+{
+    // Le navigateur n'a pas de minuterie tokio pour attendre entre deux tentatives : une seule y est faite
+    #[cfg(not(target_arch = "wasm32"))]
+    let retries = http_policy().retries;
+    #[cfg(target_arch = "wasm32")]
+    let retries = 0;
+    let mut tries = 0;
+    loop {
+        cancel.check()?;
+        match attempt().await {
+            Err(e) if tries < retries && HttpPolicy::is_transient(&e) => {
+                tries += 1;
+                cancellable_sleep(HttpPolicy::backoff(tries), cancel).await?;
+            },
+            result => return Ok(result?),
+        }
+    }
+}
+
+// Attente entre deux tentatives, relevant le jeton à intervalles courts pour s'arrêter sans attendre la fin
+#[cfg(not(target_arch = "wasm32"))]
+async fn cancellable_sleep(duration: std::time::Duration, cancel: &CancelToken) -> Result<()> {
+    const CHECK_INTERVAL: std::time::Duration = std::time::Duration::from_millis(50);
+    let deadline = tokio::time::Instant::now() + duration;
+    while tokio::time::Instant::now() < deadline {
+        cancel.check()?;
+        tokio::time::sleep(CHECK_INTERVAL.min(deadline - tokio::time::Instant::now())).await;
+    }
+    cancel.check()
+}
+
+// Jamais appelée dans le navigateur, où aucune requête n'est reprise
+#[cfg(target_arch = "wasm32")]
+async fn cancellable_sleep(_duration: std::time::Duration, cancel: &CancelToken) -> Result<()> {
+    cancel.check()
+}
@@ -0,0 +1,87 @@
+use super::models::{ChartData, MarketChart};
+use super::provider::{chart_data_from_prices, intraday_chart_data};
+use crate::error::{Error, Result};
+use chrono::{DateTime, NaiveDate, NaiveDateTime};
+
+// Noms de colonnes reconnus dans l'en-tête, en minuscules
+const DATE_COLUMNS: [&str; 5] = ["date", "time", "timestamp", "day", "snapped_at"];
+const PRICE_COLUMNS: [&str; 5] = ["price", "close", "adj close", "value", "open"];
+const VOLUME_COLUMNS: [&str; 2] = ["volume", "total_volume"];
+
+// Historique de prix d'un fichier CSV quelconque (export de plateforme, tableur, liste des notes de
+// l'application) : une date et un prix par ligne, un volume en option. Sans en-tête reconnu, les
+// colonnes sont lues dans cet ordre. Plusieurs points par jour sur deux jours au plus donnent la vue
+// sur 24 h, sinon un prix par jour
+pub fn parse_price_csv(coin: &str, currency: &str, body: &str) -> Result<ChartData> {
+    let mut lines = body.lines().map(str::trim).filter(|line| !line.is_empty()).peekable();
+    let separator = match lines.peek() {
+        Some(line) if line.contains('\t') => '\t',
+        Some(line) if line.contains(';') && !line.contains(',') => ';',
+        _ => ',',
+    };
+    let split = |line: &str| -> Vec<String> { line.split(separator).map(|cell| cell.trim().trim_matches('"').to_string()).collect() };
+
+    let (mut date_column, mut price_column, mut volume_column) = (0, 1, Some(2));
+    if let Some(header) = lines.peek().map(|line| split(line)) {
+        let find = |names: &[&str]| header.iter().position(|cell| names.contains(&cell.to_lowercase().as_str()));
+        if let (Some(date), Some(price)) = (find(&DATE_COLUMNS), find(&PRICE_COLUMNS)) {
+            (date_column, price_column, volume_column) = (date, price, find(&VOLUME_COLUMNS));
+            lines.next();
+        }
+    }
+
+    let mut prices = Vec::new();
+    let mut total_volumes = Vec::new();
+    for (number, line) in lines.enumerate() {
+        let cells = split(line);
+        let timestamp = cells.get(date_column).and_then(|cell| parse_timestamp_ms(cell));
+        let price = cells.get(price_column).and_then(|cell| parse_number(cell));
+        match (timestamp, price) {
+            (Some(timestamp), Some(price)) => {
+                prices.push((timestamp, price));
+                if let Some(volume) = volume_column.and_then(|column| parse_number(cells.get(column)?)) {
+                    total_volumes.push((timestamp, volume));
+                }
+            },
+            // Une première ligne illisible est un en-tête inconnu ; plus loin, le fichier est invalide
+            _ if number == 0 && prices.is_empty() => continue,
+            _ => return Err(Error::InvalidCsv(line.to_string())),
+        }
+    }
+    if prices.is_empty() {
+        return Err(Error::NoData(coin.to_string()));
+    }
+    prices.sort_by(|a, b| a.0.total_cmp(&b.0));
+    total_volumes.sort_by(|a, b| a.0.total_cmp(&b.0));
+
+    let span_days = (prices[prices.len() - 1].0 - prices[0].0) / 86_400_000.0;
+    let response = MarketChart { prices, total_volumes };
+    if span_days <= 2.0 && response.prices.len() as f64 > span_days.ceil() + 1.0 {
+        intraday_chart_data(coin, currency, response)
+    } else {
+        chart_data_from_prices(coin, currency, response)
+    }
+}
+
+// Nombre fini : « NaN » et « inf » sont lisibles comme f64 mais ne sont pas des prix
+fn parse_number(cell: &str) -> Option<f64> {
+    cell.parse::<f64>().ok().filter(|number| number.is_finite())
+}
+
+// Date ISO (avec ou sans heure) ou horodatage Unix, en secondes ou en millisecondes. Un horodatage
+// hors des dates que chrono sait représenter rend la ligne illisible
+fn parse_timestamp_ms(cell: &str) -> Option<f64> {
+    if let Ok(number) = cell.parse::<f64>() {
+        // Avant 5138 en secondes, après 1973 en millisecondes
+        let timestamp = if number < 1e11 { number * 1000.0 } else { number };
+        return Some(timestamp).filter(|timestamp| timestamp.is_finite() && DateTime::from_timestamp_millis(*timestamp as i64).is_some());
+    }
+    if let Ok(date) = DateTime::parse_from_rfc3339(cell) {
+        return Some(date.timestamp_millis() as f64);
+    }
+    ["%Y-%m-%d %H:%M:%S", "%Y-%m-%d %H:%M", "%Y-%m-%d %H:%M:%S UTC"]
+        .iter()
+        .find_map(|format| NaiveDateTime::parse_from_str(cell, format).ok())
+        .or_else(|| NaiveDate::parse_from_str(cell, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0))
+        .map(|date| date.and_utc().timestamp_millis() as f64)
+}
@@ -0,0 +1,193 @@
+use super::http::get_text;
+use super::models::{ChartData, DailyPrice, MarketChart};
+use super::provider::{chart_data_from_prices, fetch_market_chart_async, intraday_chart_data, PriceProvider, DEFAULT_CURRENCY, INTRADAY_DAYS};
+#[cfg(target_arch = "wasm32")]
+use super::provider::LocalFuture;
+use super::spread::{spread_symbol, Exchange};
+use crate::error::{Error, Result};
+use crate::i18n::tr;
+use chrono::{Duration, Utc};
+use std::collections::BTreeMap;
+
+// Écart entre la source la plus haute et la plus basse (en % de la médiane) au-delà duquel un
+// intervalle est marqué comme douteux
+pub const DISAGREEMENT_PERCENT: f64 = 1.0;
+// Sur 24 h, les points de chaque source sont ramenés au multiple de 5 minutes précédent pour se correspondre
+const INTRADAY_STEP_MS: f64 = 300_000.0;
+// Coinbase ne renvoie pas plus de 300 bougies par requête
+const COINBASE_MAX_CANDLES: i64 = 300;
+
+// Indice multi-plateformes : la même paire chez CoinGecko, Binance et Coinbase, avec le prix médian
+// de chaque intervalle ; une source en panne est ignorée tant qu'il en reste une
+pub struct IndexProvider;
+
+impl PriceProvider for IndexProvider {
+    fn name(&self) -> String {
+        tr("index-provider").to_string()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn fetch_chart_data(&self, coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+        crate::task::runtime()?.block_on(fetch_index_chart_data_async(coin, days, currency))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn fetch_chart_data(&self, _coin: &str, _days: i64, _currency: &str) -> Result<ChartData> {
+        Err(Error::Unsupported("blocking requests"))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn fetch_chart_data_async<'a>(&'a self, coin: &'a str, days: i64, currency: &'a str) -> LocalFuture<'a, Result<ChartData>> {
+        Box::pin(fetch_index_chart_data_async(coin, days, currency))
+    }
+}
+
+// Les plateformes cotent en dollars (USDT pour Binance) : dans une autre devise, seul CoinGecko répond
+pub async fn fetch_index_chart_data_async(coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+    let intraday = days <= INTRADAY_DAYS;
+    let mut responses = vec![fetch_market_chart_async(coin, days, currency).await];
+    if let Some(symbol) = spread_symbol(coin).filter(|_| currency == DEFAULT_CURRENCY) {
+        for exchange in Exchange::ALL {
+            responses.push(fetch_exchange_history_async(exchange, &symbol, days).await);
+        }
+    }
+
+    let mut series = Vec::new();
+    let mut last_error = None;
+    for response in responses {
+        let chart = response.and_then(|mut response| {
+            if intraday {
+                align_timestamps(&mut response, INTRADAY_STEP_MS);
+                intraday_chart_data(coin, currency, response)
+            } else {
+                chart_data_from_prices(coin, currency, response)
+            }
+        });
+        match chart {
+            Ok(chart) => series.push(chart),
+            Err(e) => last_error = Some(e),
+        }
+    }
+    match (series.is_empty(), last_error) {
+        (true, Some(e)) => Err(e),
+        _ => median_index(coin, currency, &series),
+    }
+}
+
+// Historique d'une plateforme au même format que CoinGecko : prix d'ouverture de chaque bougie,
+// journalière ou de 5 minutes sur 24 h
+async fn fetch_exchange_history_async(exchange: Exchange, symbol: &str, days: i64) -> Result<MarketChart> {
+    let intraday = days <= INTRADAY_DAYS;
+    let end = Utc::now();
+    let url = match exchange {
+        Exchange::Binance => {
+            let (interval, limit) = if intraday { ("5m", 289) } else { ("1d", (days + 1).min(1000)) };
+            format!(
+                "https://data-api.binance.vision/api/v3/klines?symbol={}USDT&interval={}&startTime={}&limit={}",
+                symbol,
+                interval,
+                (end - Duration::days(days)).timestamp_millis(),
+                limit
+            )
+        },
+        Exchange::Coinbase => {
+            // Au-delà de 300 jours, seule la fin de la période vient de Coinbase
+            let (granularity, start) = if intraday {
+                (300, end - Duration::days(days))
+            } else {
+                (86_400, end - Duration::days(days.min(COINBASE_MAX_CANDLES - 1)))
+            };
+            format!(
+                "https://api.exchange.coinbase.com/products/{}-USD/candles?granularity={}&start={}&end={}",
+                symbol,
+                granularity,
+                start.to_rfc3339(),
+                end.to_rfc3339()
+            )
+        },
+    };
+    let body = get_text(|client| client.get(&url)).await?;
+    match exchange {
+        Exchange::Binance => parse_klines(&body),
+        Exchange::Coinbase => parse_candles(&body),
+    }
+}
+
+// Bougies Binance : [ouverture en ms, "ouverture", "plus haut", "plus bas", "clôture", ...]
+pub fn parse_klines(body: &str) -> Result<MarketChart> {
+    let rows: Vec<Vec<serde_json::Value>> = serde_json::from_str(body)?;
+    let prices = rows
+        .iter()
+        .filter_map(|row| {
+            let time = row.first()?.as_f64()?;
+            let open = row.get(1)?.as_str()?.parse().ok()?;
+            Some((time, open))
+        })
+        .collect();
+    Ok(MarketChart { prices, total_volumes: Vec::new() })
+}
+
+// Bougies Coinbase : [début en s, plus bas, plus haut, ouverture, clôture, volume], les plus récentes d'abord
+pub fn parse_candles(body: &str) -> Result<MarketChart> {
+    let rows: Vec<Vec<f64>> = serde_json::from_str(body)?;
+    let mut prices: Vec<(f64, f64)> = rows
+        .iter()
+        .filter_map(|row| Some((*row.first()? * 1000.0, *row.get(3)?)))
+        .collect();
+    prices.sort_by(|a, b| a.0.total_cmp(&b.0));
+    Ok(MarketChart { prices, total_volumes: Vec::new() })
+}
+
+fn align_timestamps(response: &mut MarketChart, step_ms: f64) {
+    for (timestamp, _) in response.prices.iter_mut().chain(response.total_volumes.iter_mut()) {
+        *timestamp = (*timestamp / step_ms).floor() * step_ms;
+    }
+}
+
+// Médiane des sources pour chaque intervalle présent chez au moins l'une d'elles ; volume et
+// extrêmes viennent de la première source qui les donne
+pub fn median_index(coin: &str, currency: &str, series: &[ChartData]) -> Result<ChartData> {
+    let mut intervals: BTreeMap<&str, Vec<&DailyPrice>> = BTreeMap::new();
+    for chart in series {
+        for day in &chart.daily_prices {
+            intervals.entry(day.date.as_str()).or_default().push(day);
+        }
+    }
+    if intervals.is_empty() {
+        return Err(Error::NoData(coin.to_string()));
+    }
+
+    let daily_prices = intervals
+        .into_values()
+        .map(|sources| {
+            let prices: Vec<f64> = sources.iter().map(|day| day.price).collect();
+            let price = median(&prices);
+            let (low, high) = prices.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(low, high), p| (low.min(*p), high.max(*p)));
+            DailyPrice {
+                price,
+                volume: sources.iter().find_map(|day| day.volume),
+                range: sources.iter().find_map(|day| day.range),
+                source_spread: (prices.len() > 1 && price > 0.0).then(|| (high - low) / price * 100.0),
+                ..DailyPrice::new(sources[0].date.clone(), price)
+            }
+        })
+        .collect();
+
+    let latest: Vec<f64> = series.iter().map(|chart| chart.latest_price).collect();
+    let changes: Vec<f64> = series.iter().map(|chart| chart.change_24h).collect();
+    Ok(ChartData {
+        coin: coin.to_string(),
+        currency: currency.to_string(),
+        daily_prices,
+        latest_price: median(&latest),
+        change_24h: median(&changes),
+    })
+}
+
+// Médiane d'une liste non vide ; moyenne des deux valeurs centrales pour un nombre pair
+fn median(values: &[f64]) -> f64 {
+    let mut sorted = values.to_vec();
+    sorted.sort_by(f64::total_cmp);
+    let mid = sorted.len() / 2;
+    if sorted.len().is_multiple_of(2) { (sorted[mid - 1] + sorted[mid]) / 2.0 } else { sorted[mid] }
+}
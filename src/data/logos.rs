@@ -0,0 +1,47 @@
+use super::http::get_bytes;
+use crate::error::Result;
+use serde::Deserialize;
+use std::path::PathBuf;
+
+#[derive(Deserialize)]
+struct CoinImages {
+    large: String,
+}
+
+#[derive(Deserialize)]
+struct CoinDetails {
+    image: CoinImages,
+}
+
+// Logos déjà téléchargés, un fichier par crypto
+fn cache_path(coin: &str) -> PathBuf {
+    dirs::cache_dir()
+        .map(|dir| dir.join("crypto-price-sonifier"))
+        .unwrap_or_default()
+        .join("logos")
+        .join(coin)
+}
+
+// Logo officiel de la crypto (PNG ou JPEG encodé), depuis le cache disque ou depuis CoinGecko
+pub async fn load_logo_async(coin: String) -> Result<Vec<u8>> {
+    let path = cache_path(&coin);
+    if let Ok(bytes) = std::fs::read(&path) {
+        return Ok(bytes);
+    }
+
+    let url = format!(
+        "https://api.coingecko.com/api/v3/coins/{}?localization=false&tickers=false&market_data=false&community_data=false&developer_data=false",
+        coin,
+    );
+    let details: CoinDetails = serde_json::from_slice(&get_bytes(|client| client.get(&url)).await?)?;
+    let bytes = get_bytes(|client| client.get(&details.image.large)).await?;
+
+    // Pas de système de fichiers dans le navigateur : le logo sera retéléchargé à la prochaine visite
+    if !cfg!(target_arch = "wasm32") {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).ok();
+        }
+        std::fs::write(&path, &bytes).ok();
+    }
+    Ok(bytes)
+}
@@ -0,0 +1,106 @@
+use super::http::get_text;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Nombre de cryptos de la page des plus grosses capitalisations
+pub const TOP_COINS: usize = 100;
+
+// Entrée de /coins/markets ; les champs manquent parfois pour les cryptos peu échangées
+#[derive(Debug, Clone, Deserialize)]
+pub struct MarketCoin {
+    pub id: String,
+    pub symbol: String,
+    pub name: String,
+    pub current_price: Option<f64>,
+    pub market_cap: Option<f64>,
+    pub market_cap_rank: Option<u32>,
+    pub price_change_percentage_24h: Option<f64>,
+    pub sparkline_in_7d: Option<Sparkline>,
+}
+
+// Prix horaires des 7 derniers jours
+#[derive(Debug, Clone, Deserialize)]
+pub struct Sparkline {
+    pub price: Vec<f64>,
+}
+
+// Vue d'ensemble du marché (/global), dans la devise demandée
+#[derive(Debug, Clone, PartialEq)]
+pub struct GlobalMarket {
+    pub total_market_cap: f64,
+    pub total_volume: f64,
+    // Part de Bitcoin dans la capitalisation totale, en %
+    pub btc_dominance: f64,
+    // Variation de la capitalisation totale sur 24 h, en %
+    pub market_cap_change_24h: f64,
+}
+
+#[derive(Deserialize)]
+struct GlobalResponse {
+    data: GlobalData,
+}
+
+#[derive(Deserialize)]
+struct GlobalData {
+    total_market_cap: HashMap<String, f64>,
+    total_volume: HashMap<String, f64>,
+    market_cap_percentage: HashMap<String, f64>,
+    #[serde(default)]
+    market_cap_change_percentage_24h_usd: f64,
+}
+
+// Les montants sont donnés dans toutes les devises : on garde celle de l'application
+pub fn parse_global(body: &str, currency: &str) -> Result<GlobalMarket> {
+    let response: GlobalResponse = serde_json::from_str(body)?;
+    let data = response.data;
+    let in_currency = |amounts: &HashMap<String, f64>| {
+        amounts.get(currency).copied().ok_or_else(|| Error::NoData(currency.to_string()))
+    };
+    Ok(GlobalMarket {
+        total_market_cap: in_currency(&data.total_market_cap)?,
+        total_volume: in_currency(&data.total_volume)?,
+        btc_dominance: data.market_cap_percentage.get("btc").copied().unwrap_or(0.0),
+        market_cap_change_24h: data.market_cap_change_percentage_24h_usd,
+    })
+}
+
+pub async fn fetch_global_market_async(currency: &str) -> Result<GlobalMarket> {
+    let body = get_text(|client| client.get("https://api.coingecko.com/api/v3/global")).await?;
+    parse_global(&body, currency)
+}
+
+// Plus fortes hausses et plus fortes baisses sur 24 h, `count` de chaque côté ; les cryptos sans
+// variation connue sont ignorées
+pub fn top_movers(coins: &[MarketCoin], count: usize) -> (Vec<&MarketCoin>, Vec<&MarketCoin>) {
+    let mut sorted: Vec<(&MarketCoin, f64)> = coins
+        .iter()
+        .filter_map(|coin| Some((coin, coin.price_change_percentage_24h?)))
+        .collect();
+    sorted.sort_by(|a, b| b.1.total_cmp(&a.1));
+    let gainers = sorted.iter().take(count).filter(|(_, change)| *change > 0.0).map(|(coin, _)| *coin).collect();
+    let losers = sorted.iter().rev().take(count).filter(|(_, change)| *change < 0.0).map(|(coin, _)| *coin).collect();
+    (gainers, losers)
+}
+
+// Montant abrégé (1.2T USD, 350.4B USD...), les montants complets seraient illisibles
+pub fn format_compact(amount: f64, currency: &str) -> String {
+    let (scaled, suffix) = match amount {
+        a if a >= 1e12 => (a / 1e12, "T"),
+        a if a >= 1e9 => (a / 1e9, "B"),
+        a if a >= 1e6 => (a / 1e6, "M"),
+        a => (a, ""),
+    };
+    format!("{:.1}{} {}", scaled, suffix, currency.to_uppercase())
+}
+
+// Cryptos classées par capitalisation décroissante, avec leur courbe sur 7 jours
+pub async fn fetch_top_coins_async(currency: &str, count: usize) -> Result<Vec<MarketCoin>> {
+    let url = format!(
+        "https://api.coingecko.com/api/v3/coins/markets?vs_currency={}&order=market_cap_desc&per_page={}&page=1&sparkline=true",
+        currency,
+        count,
+    );
+    let body = get_text(|client| client.get(&url)).await?;
+    Ok(serde_json::from_str(&body)?)
+}
@@ -0,0 +1,144 @@
+use super::models::{ChartData, DailyPrice};
+use super::provider::{FetchStage, PriceProvider};
+use crate::error::{Error, Result};
+use crate::i18n::tr;
+use crate::task::CancelToken;
+use chrono::{Duration, NaiveDate, Utc};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+
+// Formes de séries synthétiques, pour tester l'interface et le son sans réseau
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MockScenario {
+    Sine,
+    RandomWalk,
+    Crash,
+}
+
+impl MockScenario {
+    pub const ALL: [MockScenario; 3] = [MockScenario::Sine, MockScenario::RandomWalk, MockScenario::Crash];
+
+    pub fn label_key(&self) -> &'static str {
+        match self {
+            MockScenario::Sine => "mock-sine",
+            MockScenario::RandomWalk => "mock-random-walk",
+            MockScenario::Crash => "mock-crash",
+        }
+    }
+}
+
+// Série déterministe : la même graine et la même date de fin donnent toujours les mêmes prix
+pub struct MockProvider {
+    pub scenario: MockScenario,
+    pub seed: u64,
+    pub end: NaiveDate,
+}
+
+impl MockProvider {
+    const BASE_PRICE: f64 = 100.0;
+    // Période de la sinusoïde, en jours
+    const SINE_PERIOD: f64 = 14.0;
+    // Variation journalière maximale de la marche aléatoire, en %
+    const MAX_DAILY_MOVE: f64 = 4.0;
+
+    pub fn new(scenario: MockScenario) -> Self {
+        Self {
+            scenario,
+            seed: 42,
+            end: Utc::now().date_naive(),
+        }
+    }
+
+    fn prices(&self, coin: &str, count: usize) -> Vec<f64> {
+        // Chaque crypto a sa propre série pour que le tableau de bord ne montre pas quatre fois la même courbe
+        let seed = coin.bytes().fold(self.seed, |seed, byte| seed.wrapping_mul(31).wrapping_add(byte as u64));
+        let mut rng = StdRng::seed_from_u64(seed);
+
+        match self.scenario {
+            MockScenario::Sine => {
+                let phase = rng.gen_range(0.0..std::f64::consts::TAU);
+                (0..count)
+                    .map(|day| {
+                        let angle = day as f64 / Self::SINE_PERIOD * std::f64::consts::TAU + phase;
+                        Self::BASE_PRICE * (1.0 + 0.1 * angle.sin())
+                    })
+                    .collect()
+            },
+            MockScenario::RandomWalk => {
+                let mut price = Self::BASE_PRICE;
+                (0..count)
+                    .map(|_| {
+                        let current = price;
+                        price *= 1.0 + rng.gen_range(-Self::MAX_DAILY_MOVE..Self::MAX_DAILY_MOVE) / 100.0;
+                        current
+                    })
+                    .collect()
+            },
+            MockScenario::Crash => {
+                // Lente hausse, chute d'environ 60% en trois jours puis rebond partiel
+                let crash_day = count * 2 / 3;
+                let mut price = Self::BASE_PRICE;
+                (0..count)
+                    .map(|day| {
+                        let current = price;
+                        let change = match day {
+                            d if d < crash_day => 1.0 + rng.gen_range(0.0..1.5),
+                            d if d < crash_day + 3 => -25.0 - rng.gen_range(0.0..5.0),
+                            _ => rng.gen_range(-1.0..3.0),
+                        };
+                        price *= 1.0 + change / 100.0;
+                        current
+                    })
+                    .collect()
+            },
+        }
+    }
+}
+
+impl PriceProvider for MockProvider {
+    fn name(&self) -> String {
+        tr(self.scenario.label_key()).to_string()
+    }
+
+    fn fetch_chart_data(&self, coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+        if days < 1 {
+            return Err(Error::NoData(coin.to_string()));
+        }
+
+        let count = days as usize + 1;
+        let start = self.end - Duration::days(days);
+        let daily_prices: Vec<DailyPrice> = self.prices(coin, count)
+            .into_iter()
+            .enumerate()
+            .map(|(day, price)| DailyPrice::new((start + Duration::days(day as i64)).format("%Y-%m-%d").to_string(), price))
+            .collect();
+
+        let latest_price = daily_prices[count - 1].price;
+        let previous_price = daily_prices[count - 2].price;
+        Ok(ChartData {
+            coin: coin.to_string(),
+            currency: currency.to_string(),
+            daily_prices,
+            latest_price,
+            change_24h: (latest_price - previous_price) / previous_price * 100.0,
+        })
+    }
+
+    // Mêmes étapes qu'une source distante, pour exercer l'écran d'attente et l'annulation entre deux étapes
+    fn fetch_chart_data_reporting(
+        &self,
+        coin: &str,
+        days: i64,
+        currency: &str,
+        progress: &(dyn Fn(FetchStage) + Sync),
+        cancel: &CancelToken,
+    ) -> Result<ChartData> {
+        cancel.check()?;
+        let data = self.fetch_chart_data(coin, days, currency)?;
+        progress(FetchStage::Received);
+        cancel.check()?;
+        progress(FetchStage::Parsing);
+        cancel.check()?;
+        Ok(data)
+    }
+}
@@ -0,0 +1,58 @@
+// Modèles, sources des prix (CoinGecko, indice multi-plateformes, plugins ou séries synthétiques), statistiques, alertes de prix, portefeuille, import de fichiers CSV, recherche de cryptos, classement par capitalisation et vue d'ensemble du marché, client HTTP partagé (délais et nouvelles tentatives), carnets d'ordres, écarts entre plateformes, barres du mode direct, chiffres clés, métadonnées et logos des cryptos, packs d'images
+pub mod alerts;
+pub mod assets;
+pub mod bars;
+pub mod coins;
+pub mod events;
+pub mod fundamentals;
+pub mod http;
+pub mod import;
+pub mod index;
+pub mod logos;
+pub mod markets;
+pub mod mock;
+pub mod models;
+pub mod news;
+pub mod orderbook;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod plugin;
+pub mod portfolio;
+pub mod provider;
+pub mod search;
+pub mod sentiment;
+pub mod spread;
+pub mod stats;
+
+pub use alerts::{AlertCondition, AlertRule, AlertTracker};
+pub use assets::{read_asset, AssetPack, ReactionImage};
+pub use bars::{Bar, BarAggregator, BarLength};
+pub use coins::{coin_accent, coin_display_name, coin_meta, CoinId, CoinMeta, COINS};
+pub use events::{builtin_events, event_markers, load_events, MarketEvent};
+pub use fundamentals::{fetch_coin_fundamentals_async, parse_coin_details, CoinFundamentals};
+pub use http::{client_builder, http_client, http_policy, set_http_policy, HttpPolicy};
+pub use import::parse_price_csv;
+pub use index::{fetch_index_chart_data_async, median_index, parse_candles, parse_klines, IndexProvider, DISAGREEMENT_PERCENT};
+pub use logos::load_logo_async;
+pub use markets::{
+    fetch_global_market_async, fetch_top_coins_async, format_compact, parse_global, top_movers, GlobalMarket, MarketCoin, Sparkline, TOP_COINS,
+};
+pub use mock::{MockProvider, MockScenario};
+pub use models::{day_index, format_price, weekend_runs, ChartData, DailyPrice};
+pub use news::{fetch_headlines_async, parse_headlines, Headline};
+pub use orderbook::{fetch_order_book_async, order_book_symbol, parse_depth, BookSide, OrderBook, Wall};
+#[cfg(not(target_arch = "wasm32"))]
+pub use plugin::{discover_plugins, PluginProvider};
+pub use portfolio::{portfolio_series, Holding, PORTFOLIO_ID};
+#[cfg(not(target_arch = "wasm32"))]
+pub use provider::fetch_chart_data;
+pub use provider::{fetch_chart_data_async, CoinGeckoProvider, DateRange, FetchStage, PriceProvider, DEFAULT_CURRENCY, DEFAULT_DAYS, INTRADAY_DAYS};
+pub use search::{load_coin_list_async, search_coins, CoinListing};
+pub use sentiment::{align_sentiment, fetch_sentiment_async, parse_fear_greed, sentiment_label_key, SentimentPoint};
+pub use spread::{fetch_exchange_price_async, parse_ticker, spread_percent, spread_symbol, Exchange};
+pub use stats::{drawdown_series, PriceStats};
+
+// Pas de bibliothèques dynamiques dans le navigateur
+#[cfg(target_arch = "wasm32")]
+pub fn discover_plugins() -> Vec<std::sync::Arc<dyn PriceProvider>> {
+    Vec::new()
+}
@@ -0,0 +1,117 @@
+use super::index::DISAGREEMENT_PERCENT;
+use chrono::{Datelike, NaiveDate, Weekday};
+use serde::{Deserialize, Serialize};
+
+#[derive(Debug, Deserialize)]
+pub struct MarketChart {
+    pub prices: Vec<(f64, f64)>,
+    // Volume échangé sur 24 h glissantes, aux mêmes horodatages ; absent chez certains plugins
+    #[serde(default)]
+    pub total_volumes: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DailyPrice {
+    pub date: String,
+    pub price: f64,
+    // Volume échangé dans la journée, quand la source le donne
+    #[serde(default)]
+    pub volume: Option<f64>,
+    // (plus bas, plus haut) de la journée, quand la source donne plusieurs points par jour
+    #[serde(default)]
+    pub range: Option<(f64, f64)>,
+    // Écart entre les sources de l'indice multi-plateformes (en % de la médiane), quand il y en a plusieurs
+    #[serde(default)]
+    pub source_spread: Option<f64>,
+}
+
+impl DailyPrice {
+    pub fn new(date: String, price: f64) -> Self {
+        Self { date, price, volume: None, range: None, source_spread: None }
+    }
+
+    // Amplitude de la journée en % du prix, 0 sans plus haut ni plus bas
+    pub fn range_percent(&self) -> f64 {
+        match self.range {
+            Some((low, high)) if self.price > 0.0 => (high - low) / self.price * 100.0,
+            _ => 0.0,
+        }
+    }
+
+    // Point de la vue sur 24 h : la date porte aussi l'heure (« AAAA-MM-JJ HH:MM », en UTC)
+    pub fn is_intraday(&self) -> bool {
+        self.date.len() > 10
+    }
+
+    // Libellé court pour les axes et l'en-tête : JJ/MM, ou HH:MM sur 24 h
+    pub fn short_label(&self) -> String {
+        match (self.date.get(11..16), self.date.get(5..7), self.date.get(8..10)) {
+            (Some(time), _, _) => time.to_string(),
+            (None, Some(month), Some(day)) => format!("{}/{}", day, month),
+            _ => self.date.clone(),
+        }
+    }
+
+    // Sources de l'indice en désaccord marqué sur cet intervalle
+    pub fn sources_disagree(&self) -> bool {
+        self.source_spread.is_some_and(|spread| spread >= DISAGREEMENT_PERCENT)
+    }
+
+    // Samedi ou dimanche ; faux pour une date illisible
+    pub fn is_weekend(&self) -> bool {
+        self.date
+            .get(..10)
+            .and_then(|date| NaiveDate::parse_from_str(date, "%Y-%m-%d").ok())
+            .is_some_and(|date| matches!(date.weekday(), Weekday::Sat | Weekday::Sun))
+    }
+}
+
+#[derive(Clone, Serialize, Deserialize)]
+pub struct ChartData {
+    pub coin: String,
+    pub currency: String,
+    pub daily_prices: Vec<DailyPrice>,
+    pub latest_price: f64,
+    pub change_24h: f64,
+}
+
+impl ChartData {
+    pub fn is_intraday(&self) -> bool {
+        self.daily_prices.first().is_some_and(DailyPrice::is_intraday)
+    }
+}
+
+// Week-ends de la série, en intervalles de jours consécutifs (premier, dernier)
+pub fn weekend_runs(prices: &[DailyPrice]) -> Vec<(usize, usize)> {
+    let mut runs: Vec<(usize, usize)> = Vec::new();
+    for (index, _) in prices.iter().enumerate().filter(|(_, price)| price.is_weekend()) {
+        match runs.last_mut() {
+            Some((_, last)) if *last + 1 == index => *last = index,
+            _ => runs.push((index, index)),
+        }
+    }
+    runs
+}
+
+// Premier jour de la série à partir d'une date (publication, événement), pour entendre la réaction
+// du marché ; None si la date tombe hors de la période chargée
+pub fn day_index(prices: &[DailyPrice], date: &str) -> Option<usize> {
+    if prices.last().is_none_or(|last| last.date.as_str() < date) {
+        return None;
+    }
+    if prices.first().is_some_and(|first| first.date.as_str() > date) {
+        return None;
+    }
+    prices.iter().position(|price| price.date.as_str() >= date)
+}
+
+// Prix avec le symbole de la devise, ou son code pour les devises moins courantes
+pub fn format_price(price: f64, currency: &str) -> String {
+    match currency {
+        "usd" => format!("${:.2}", price),
+        "eur" => format!("{:.2} €", price),
+        "gbp" => format!("£{:.2}", price),
+        "jpy" => format!("¥{:.0}", price),
+        _ => format!("{:.2} {}", price, currency.to_uppercase()),
+    }
+}
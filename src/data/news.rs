@@ -0,0 +1,51 @@
+use super::coins::coin_meta;
+use super::http::get_text;
+use crate::error::Result;
+use chrono::DateTime;
+
+// Au-delà, le panneau deviendrait illisible et les titres les plus anciens sont rarement dans la période
+const MAX_HEADLINES: usize = 50;
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct Headline {
+    pub title: String,
+    pub link: String,
+    pub source: Option<String>,
+    // Jour de publication au format des séries de prix (AAAA-MM-JJ)
+    pub date: String,
+}
+
+// Extrait les titres d'un flux RSS, les plus récents en premier ; les entrées sans date lisible sont ignorées
+pub fn parse_headlines(xml: &str) -> Result<Vec<Headline>> {
+    let document = roxmltree::Document::parse(xml)?;
+    let mut headlines: Vec<(DateTime<chrono::FixedOffset>, Headline)> = document
+        .descendants()
+        .filter(|node| node.has_tag_name("item"))
+        .filter_map(|item| {
+            let child = |name: &str| item.children().find(|node| node.has_tag_name(name)).and_then(|node| node.text()).map(str::trim);
+            let published = DateTime::parse_from_rfc2822(child("pubDate")?).ok()?;
+            let headline = Headline {
+                title: child("title")?.to_string(),
+                link: child("link").unwrap_or_default().to_string(),
+                source: child("source").map(str::to_string),
+                date: published.date_naive().format("%Y-%m-%d").to_string(),
+            };
+            Some((published, headline))
+        })
+        .collect();
+    headlines.sort_by_key(|(published, _)| std::cmp::Reverse(*published));
+    Ok(headlines.into_iter().take(MAX_HEADLINES).map(|(_, headline)| headline).collect())
+}
+
+// Titres récents de Google News pour la crypto (pas de clé d'API, contrairement à CryptoPanic)
+pub async fn fetch_headlines_async(coin: &str) -> Result<Vec<Headline>> {
+    let name = coin_meta(coin).map_or_else(|| coin.replace('-', " "), |meta| meta.name.to_string());
+    let query = format!("{} crypto", name);
+    let body = get_text(|client| {
+        client
+            .get("https://news.google.com/rss/search")
+            .query(&[("q", query.as_str()), ("hl", "en-US"), ("gl", "US"), ("ceid", "US:en")])
+    })
+    .await?;
+    parse_headlines(&body)
+}
@@ -0,0 +1,96 @@
+use super::coins::coin_meta;
+use super::http::get_text;
+use crate::error::{Error, Result};
+use serde::Deserialize;
+
+// Taille d'un « mur » : un niveau au moins WALL_FACTOR fois plus gros que la médiane du carnet
+const WALL_FACTOR: f64 = 8.0;
+
+// Carnet d'ordres : niveaux (prix, quantité), du meilleur au moins bon prix
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OrderBook {
+    pub bids: Vec<(f64, f64)>,
+    pub asks: Vec<(f64, f64)>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BookSide {
+    Bid,
+    Ask,
+}
+
+// Gros ordre apparu depuis le relevé précédent
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Wall {
+    pub side: BookSide,
+    pub price: f64,
+    pub size: f64,
+}
+
+// Réponse de l'API Binance : prix et quantités sont des chaînes
+#[derive(Deserialize)]
+struct DepthResponse {
+    bids: Vec<(String, String)>,
+    asks: Vec<(String, String)>,
+}
+
+fn parse_levels(levels: Vec<(String, String)>) -> Vec<(f64, f64)> {
+    levels
+        .into_iter()
+        .filter_map(|(price, size)| Some((price.parse().ok()?, size.parse().ok()?)))
+        .collect()
+}
+
+pub fn parse_depth(body: &str) -> Result<OrderBook> {
+    let response: DepthResponse = serde_json::from_str(body)?;
+    Ok(OrderBook { bids: parse_levels(response.bids), asks: parse_levels(response.asks) })
+}
+
+// Paire Binance de la crypto, cotée en USDT ; None pour les cryptos dont le symbole est inconnu
+pub fn order_book_symbol(coin: &str) -> Option<String> {
+    coin_meta(coin).map(|meta| format!("{}USDT", meta.symbol))
+}
+
+// Cent meilleurs niveaux de chaque côté, sur le miroir public des données de marché Binance (sans clé)
+pub async fn fetch_order_book_async(symbol: &str) -> Result<OrderBook> {
+    let url = format!("https://data-api.binance.vision/api/v3/depth?symbol={}&limit=100", symbol);
+    let body = get_text(|client| client.get(&url)).await?;
+    let book = parse_depth(&body)?;
+    if book.bids.is_empty() || book.asks.is_empty() {
+        return Err(Error::NoData(symbol.to_string()));
+    }
+    Ok(book)
+}
+
+impl OrderBook {
+    // Déséquilibre en valeur, de -1 (que des ventes) à 1 (que des achats)
+    pub fn imbalance(&self) -> f64 {
+        let value = |levels: &[(f64, f64)]| levels.iter().map(|(price, size)| price * size).sum::<f64>();
+        let (bids, asks) = (value(&self.bids), value(&self.asks));
+        if bids + asks > 0.0 { (bids - asks) / (bids + asks) } else { 0.0 }
+    }
+
+    // Niveaux anormalement gros absents (ou bien plus petits) au relevé précédent
+    pub fn new_walls(&self, previous: &OrderBook) -> Vec<Wall> {
+        let mut sizes: Vec<f64> = self.bids.iter().chain(&self.asks).map(|(_, size)| *size).collect();
+        if sizes.is_empty() {
+            return Vec::new();
+        }
+        sizes.sort_by(f64::total_cmp);
+        let threshold = sizes[sizes.len() / 2] * WALL_FACTOR;
+
+        let sides = [(BookSide::Bid, &self.bids, &previous.bids), (BookSide::Ask, &self.asks, &previous.asks)];
+        sides
+            .into_iter()
+            .flat_map(|(side, levels, before)| {
+                levels
+                    .iter()
+                    .filter(move |(price, size)| {
+                        let earlier = before.iter().find(|(p, _)| p == price).map_or(0.0, |(_, s)| *s);
+                        *size >= threshold && earlier < threshold
+                    })
+                    .map(move |(price, size)| Wall { side, price: *price, size: *size })
+            })
+            .collect()
+    }
+}
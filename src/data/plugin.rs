@@ -0,0 +1,154 @@
+use super::models::{ChartData, MarketChart};
+use super::provider::{chart_data_from_prices, intraday_chart_data, FetchStage, PriceProvider, INTRADAY_DAYS};
+use crate::error::{Error, Result};
+use crate::i18n::tr_args;
+use crate::task::CancelToken;
+use libloading::Library;
+use serde::Deserialize;
+use std::ffi::{c_char, CStr, CString};
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+// Version de l'interface C des plugins, à incrémenter à chaque changement incompatible
+pub const PLUGIN_API_VERSION: u32 = 1;
+
+// Dossier des plugins, à côté des préférences
+pub fn plugins_dir() -> PathBuf {
+    dirs::config_dir()
+        .map(|dir| dir.join("crypto-price-sonifier"))
+        .unwrap_or_default()
+        .join("plugins")
+}
+
+type ApiVersionFn = unsafe extern "C" fn() -> u32;
+type NameFn = unsafe extern "C" fn() -> *const c_char;
+type FetchFn = unsafe extern "C" fn(coin: *const c_char, days: i64, currency: *const c_char) -> *mut c_char;
+type FreeFn = unsafe extern "C" fn(response: *mut c_char);
+
+// Réponse JSON d'un plugin : les points bruts au format CoinGecko, ou un message d'erreur
+#[derive(Deserialize)]
+#[serde(untagged)]
+enum PluginResponse {
+    Prices(MarketChart),
+    Error { error: String },
+}
+
+// Source de prix chargée depuis une bibliothèque dynamique du dossier plugins. Les chargements
+// tournent sur plusieurs threads (graphique affiché, préchargement) : les appels au plugin passent un
+// par un, qui n'a donc pas à être réentrant
+pub struct PluginProvider {
+    name: String,
+    library: Library,
+    calls: Mutex<()>,
+}
+
+impl PluginProvider {
+    pub fn load(path: &Path) -> Result<Self> {
+        // Sûreté : on fait confiance aux bibliothèques que l'utilisateur a placées dans le dossier plugins
+        unsafe {
+            let library = Library::new(path).map_err(plugin_error)?;
+            let api_version = library.get::<ApiVersionFn>(b"sonifier_plugin_api_version").map_err(plugin_error)?();
+            if api_version != PLUGIN_API_VERSION {
+                return Err(Error::Plugin(format!(
+                    "{} uses plugin API version {}, expected {}",
+                    path.display(),
+                    api_version,
+                    PLUGIN_API_VERSION,
+                )));
+            }
+            let name = library.get::<NameFn>(b"sonifier_plugin_name").map_err(plugin_error)?();
+            if name.is_null() {
+                return Err(Error::Plugin(format!("{} has no name", path.display())));
+            }
+            let name = CStr::from_ptr(name).to_string_lossy().into_owned();
+            // Les deux fonctions utilisées par fetch_chart_data doivent exister dès le chargement
+            library.get::<FetchFn>(b"sonifier_plugin_fetch").map_err(plugin_error)?;
+            library.get::<FreeFn>(b"sonifier_plugin_free").map_err(plugin_error)?;
+            Ok(Self { name, library, calls: Mutex::new(()) })
+        }
+    }
+
+    // Réponse JSON brute du plugin
+    fn call_fetch(&self, coin: &str, days: i64, currency: &str) -> Result<String> {
+        let coin_arg = CString::new(coin).map_err(|e| Error::Plugin(e.to_string()))?;
+        let currency_arg = CString::new(currency).map_err(|e| Error::Plugin(e.to_string()))?;
+
+        // Un appel qui a paniqué laisse le verrou empoisonné sans rien corrompre côté Rust
+        let _call = self.calls.lock().unwrap_or_else(|poisoned| poisoned.into_inner());
+        // Sûreté : les symboles ont été vérifiés au chargement et la réponse est rendue au plugin pour être libérée
+        unsafe {
+            let fetch = self.library.get::<FetchFn>(b"sonifier_plugin_fetch").map_err(plugin_error)?;
+            let free = self.library.get::<FreeFn>(b"sonifier_plugin_free").map_err(plugin_error)?;
+            let response = fetch(coin_arg.as_ptr(), days, currency_arg.as_ptr());
+            if response.is_null() {
+                return Err(Error::Plugin(format!("{} returned no data", self.name)));
+            }
+            let body = CStr::from_ptr(response).to_string_lossy().into_owned();
+            free(response);
+            Ok(body)
+        }
+    }
+
+    fn parse(&self, coin: &str, days: i64, currency: &str, body: &str) -> Result<ChartData> {
+        match serde_json::from_str(body)? {
+            PluginResponse::Prices(prices) if days <= INTRADAY_DAYS => intraday_chart_data(coin, currency, prices),
+            PluginResponse::Prices(prices) => chart_data_from_prices(coin, currency, prices),
+            PluginResponse::Error { error } => Err(Error::Plugin(format!("{}: {}", self.name, error))),
+        }
+    }
+}
+
+impl PriceProvider for PluginProvider {
+    fn name(&self) -> String {
+        self.name.clone()
+    }
+
+    fn fetch_chart_data(&self, coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+        self.parse(coin, days, currency, &self.call_fetch(coin, days, currency)?)
+    }
+
+    // L'appel au plugin ne peut pas être interrompu : l'annulation est relevée avant et après
+    fn fetch_chart_data_reporting(
+        &self,
+        coin: &str,
+        days: i64,
+        currency: &str,
+        progress: &(dyn Fn(FetchStage) + Sync),
+        cancel: &CancelToken,
+    ) -> Result<ChartData> {
+        cancel.check()?;
+        let body = self.call_fetch(coin, days, currency)?;
+        cancel.check()?;
+        progress(FetchStage::Received);
+        progress(FetchStage::Parsing);
+        self.parse(coin, days, currency, &body)
+    }
+}
+
+fn plugin_error(error: libloading::Error) -> Error {
+    Error::Plugin(error.to_string())
+}
+
+// Charge les bibliothèques (.so, .dylib, .dll) du dossier plugins ; celles qui échouent sont ignorées
+pub fn discover_plugins() -> Vec<Arc<dyn PriceProvider>> {
+    let Ok(entries) = std::fs::read_dir(plugins_dir()) else {
+        return Vec::new();
+    };
+    let mut paths: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|e| e.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == std::env::consts::DLL_EXTENSION))
+        .collect();
+    paths.sort();
+
+    let mut plugins: Vec<Arc<dyn PriceProvider>> = Vec::new();
+    for path in paths {
+        match PluginProvider::load(&path) {
+            Ok(plugin) => plugins.push(Arc::new(plugin)),
+            Err(e) => println!("{}", tr_args("log-plugin-failed", &[
+                ("path", &path.display().to_string()),
+                ("error", &e.to_string()),
+            ])),
+        }
+    }
+    plugins
+}
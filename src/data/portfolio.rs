@@ -0,0 +1,66 @@
+use super::coins::CoinId;
+use super::models::{ChartData, DailyPrice};
+use crate::error::{Error, Result};
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+// Identifiant réservé au graphique du portefeuille, qui n'est pas une crypto CoinGecko
+pub const PORTFOLIO_ID: &str = "portfolio";
+
+// Quantité détenue d'une crypto
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Holding {
+    pub coin: CoinId,
+    pub amount: f64,
+}
+
+// Valeur totale du portefeuille jour par jour : somme des prix pondérés par les quantités détenues.
+// Seuls les jours présents dans toutes les séries sont gardés
+pub fn portfolio_series(holdings: &[Holding], series: &[ChartData]) -> Result<ChartData> {
+    let mut weighted = Vec::with_capacity(holdings.len());
+    for holding in holdings.iter().filter(|holding| holding.amount > 0.0) {
+        let data = series
+            .iter()
+            .find(|data| data.coin == holding.coin)
+            .ok_or_else(|| Error::NoData(holding.coin.clone()))?;
+        let prices: HashMap<&str, f64> = data.daily_prices.iter().map(|p| (p.date.as_str(), p.price)).collect();
+        weighted.push((holding.amount, data, prices));
+    }
+    let Some((_, first, _)) = weighted.first() else {
+        return Err(Error::NoData(PORTFOLIO_ID.to_string()));
+    };
+
+    let daily_prices: Vec<DailyPrice> = first.daily_prices
+        .iter()
+        .filter_map(|day| {
+            let total = weighted
+                .iter()
+                .map(|(amount, _, prices)| prices.get(day.date.as_str()).map(|price| amount * price))
+                .sum::<Option<f64>>()?;
+            Some(DailyPrice::new(day.date.clone(), total))
+        })
+        .collect();
+    if daily_prices.is_empty() {
+        return Err(Error::NoData(PORTFOLIO_ID.to_string()));
+    }
+
+    // Valeur actuelle et valeur 24 h plus tôt, retrouvée à partir de la variation de chaque crypto
+    let latest_price: f64 = weighted.iter().map(|(amount, data, _)| amount * data.latest_price).sum();
+    let price_24h_ago: f64 = weighted
+        .iter()
+        .map(|(amount, data, _)| amount * data.latest_price / (1.0 + data.change_24h / 100.0))
+        .sum();
+    let change_24h = if price_24h_ago > 0.0 {
+        (latest_price - price_24h_ago) / price_24h_ago * 100.0
+    } else {
+        0.0
+    };
+
+    Ok(ChartData {
+        coin: PORTFOLIO_ID.to_string(),
+        currency: first.currency.clone(),
+        daily_prices,
+        latest_price,
+        change_24h,
+    })
+}
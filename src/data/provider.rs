@@ -0,0 +1,326 @@
+use super::http::get_text_cancellable;
+use super::models::{ChartData, DailyPrice, MarketChart};
+use crate::error::{Error, Result};
+use crate::task::CancelToken;
+use chrono::{DateTime, Duration, NaiveDate, NaiveDateTime, Utc};
+use serde::{Deserialize, Serialize};
+
+pub const DEFAULT_DAYS: i64 = 30;
+pub const DEFAULT_CURRENCY: &str = "usd";
+// Période de la vue « Dernières 24 h » : CoinGecko y renvoie un point toutes les 5 minutes, gardés tels quels
+pub const INTRADAY_DAYS: i64 = 1;
+
+#[cfg(target_arch = "wasm32")]
+pub type LocalFuture<'a, T> = std::pin::Pin<Box<dyn std::future::Future<Output = T> + 'a>>;
+
+// Étapes d'un chargement, dans l'ordre, pour l'écran d'attente
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FetchStage {
+    Requesting,
+    Received,
+    Parsing,
+    Ready,
+}
+
+impl FetchStage {
+    pub fn label_key(self) -> &'static str {
+        match self {
+            FetchStage::Requesting => "progress-requesting",
+            FetchStage::Received => "progress-received",
+            FetchStage::Parsing => "progress-parsing",
+            FetchStage::Ready => "progress-ready",
+        }
+    }
+
+    // Part de la barre de progression remplie une fois l'étape atteinte ; l'attente du réseau domine
+    pub fn fraction(self) -> f32 {
+        match self {
+            FetchStage::Requesting => 0.1,
+            FetchStage::Received => 0.6,
+            FetchStage::Parsing => 0.8,
+            FetchStage::Ready => 1.0,
+        }
+    }
+}
+
+// Période fixe, en horodatages Unix (secondes, UTC) : la même série d'un jour à l'autre, contrairement
+// aux `days` derniers jours
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct DateRange {
+    pub start: i64,
+    pub end: i64,
+}
+
+impl DateRange {
+    // Du début de la première période de la série à la fin de la dernière
+    pub fn of_prices(daily_prices: &[DailyPrice]) -> Option<Self> {
+        let (start, _) = period_bounds(&daily_prices.first()?.date)?;
+        let (_, end) = period_bounds(&daily_prices.last()?.date)?;
+        Some(Self { start, end })
+    }
+
+    // Jours couverts, au moins un
+    pub fn days(&self) -> i64 {
+        ((self.end - self.start + 86_399) / 86_400).max(1)
+    }
+
+    // Jours à demander à une source qui ne connaît que les `days` derniers jours pour couvrir le début
+    pub fn days_back(&self) -> i64 {
+        ((Utc::now().timestamp() - self.start) / 86_400 + 1).max(1)
+    }
+
+    pub fn is_intraday(&self) -> bool {
+        self.end - self.start <= INTRADAY_DAYS * 86_400
+    }
+
+    // Ne garde que les périodes qui chevauchent la plage
+    pub fn trim(&self, mut data: ChartData) -> Result<ChartData> {
+        data.daily_prices
+            .retain(|day| period_bounds(&day.date).is_some_and(|(start, end)| end >= self.start && start <= self.end));
+        if data.daily_prices.is_empty() {
+            return Err(Error::NoData(data.coin));
+        }
+        Ok(data)
+    }
+}
+
+// Premier et dernier instant d'une période de la série : une journée, ou une minute sur 24 h
+fn period_bounds(date: &str) -> Option<(i64, i64)> {
+    if let Ok(minute) = NaiveDateTime::parse_from_str(date, "%Y-%m-%d %H:%M") {
+        let start = minute.and_utc().timestamp();
+        return Some((start, start + 59));
+    }
+    let start = NaiveDate::parse_from_str(date, "%Y-%m-%d").ok()?.and_hms_opt(0, 0, 0)?.and_utc().timestamp();
+    Some((start, start + 86_399))
+}
+
+// Source des séries de prix, appelée depuis un thread d'arrière-plan
+pub trait PriceProvider: Send + Sync {
+    fn name(&self) -> String;
+
+    fn fetch_chart_data(&self, coin: &str, days: i64, currency: &str) -> Result<ChartData>;
+
+    // Même chargement en signalant les étapes intermédiaires, abandonné avec Error::Cancelled entre deux
+    // étapes une fois `cancel` levé ; les sources qui n'en ont pas gardent celle-ci
+    fn fetch_chart_data_reporting(
+        &self,
+        coin: &str,
+        days: i64,
+        currency: &str,
+        _progress: &(dyn Fn(FetchStage) + Sync),
+        cancel: &CancelToken,
+    ) -> Result<ChartData> {
+        cancel.check()?;
+        let data = self.fetch_chart_data(coin, days, currency)?;
+        cancel.check()?;
+        Ok(data)
+    }
+
+    // Série d'une période fixe ; les sources qui n'en ont pas l'équivalent chargent assez de derniers
+    // jours pour couvrir son début, puis la recoupent
+    fn fetch_chart_range_reporting(
+        &self,
+        coin: &str,
+        range: DateRange,
+        currency: &str,
+        progress: &(dyn Fn(FetchStage) + Sync),
+        cancel: &CancelToken,
+    ) -> Result<ChartData> {
+        range.trim(self.fetch_chart_data_reporting(coin, range.days_back(), currency, progress, cancel)?)
+    }
+
+    // Le navigateur interdit les requêtes bloquantes : les sources distantes redéfinissent cette version
+    #[cfg(target_arch = "wasm32")]
+    fn fetch_chart_data_async<'a>(&'a self, coin: &'a str, days: i64, currency: &'a str) -> LocalFuture<'a, Result<ChartData>> {
+        Box::pin(std::future::ready(self.fetch_chart_data(coin, days, currency)))
+    }
+}
+
+pub struct CoinGeckoProvider;
+
+impl PriceProvider for CoinGeckoProvider {
+    fn name(&self) -> String {
+        "CoinGecko".to_string()
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn fetch_chart_data(&self, coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+        fetch_chart_data(coin, days, currency)
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    fn fetch_chart_data_reporting(
+        &self,
+        coin: &str,
+        days: i64,
+        currency: &str,
+        progress: &(dyn Fn(FetchStage) + Sync),
+        cancel: &CancelToken,
+    ) -> Result<ChartData> {
+        crate::task::runtime()?.block_on(fetch_chart_data_reporting_async(coin, days, currency, progress, cancel))
+    }
+
+    // L'API CoinGecko prend directement une plage : pas de jours en trop à télécharger
+    #[cfg(not(target_arch = "wasm32"))]
+    fn fetch_chart_range_reporting(
+        &self,
+        coin: &str,
+        range: DateRange,
+        currency: &str,
+        progress: &(dyn Fn(FetchStage) + Sync),
+        cancel: &CancelToken,
+    ) -> Result<ChartData> {
+        crate::task::runtime()?.block_on(fetch_chart_range_reporting_async(coin, range, currency, progress, cancel))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn fetch_chart_data(&self, _coin: &str, _days: i64, _currency: &str) -> Result<ChartData> {
+        Err(Error::Unsupported("blocking requests"))
+    }
+
+    #[cfg(target_arch = "wasm32")]
+    fn fetch_chart_data_async<'a>(&'a self, coin: &'a str, days: i64, currency: &'a str) -> LocalFuture<'a, Result<ChartData>> {
+        Box::pin(fetch_chart_data_async(coin, days, currency))
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+pub fn fetch_chart_data(coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+    crate::task::runtime()?.block_on(fetch_chart_data_async(coin, days, currency))
+}
+
+// Prix journaliers des `days` derniers jours depuis l'API CoinGecko, dans la devise demandée ; sur
+// INTRADAY_DAYS, les points restent à la minute
+pub async fn fetch_chart_data_async(coin: &str, days: i64, currency: &str) -> Result<ChartData> {
+    fetch_chart_data_reporting_async(coin, days, currency, &|_| {}, &CancelToken::new()).await
+}
+
+// Même chargement en signalant la réception de la réponse puis sa lecture ; une fois `cancel` levé,
+// il s'arrête avant la prochaine tentative ou la prochaine étape
+pub async fn fetch_chart_data_reporting_async(
+    coin: &str,
+    days: i64,
+    currency: &str,
+    progress: &(dyn Fn(FetchStage) + Sync),
+    cancel: &CancelToken,
+) -> Result<ChartData> {
+    let end = Utc::now();
+    let range = DateRange { start: (end - Duration::days(days)).timestamp(), end: end.timestamp() };
+    let body = fetch_market_chart_body_async(coin, range, currency, cancel).await?;
+    cancel.check()?;
+    progress(FetchStage::Received);
+    progress(FetchStage::Parsing);
+    let response = serde_json::from_str(&body)?;
+    if days <= INTRADAY_DAYS {
+        intraday_chart_data(coin, currency, response)
+    } else {
+        chart_data_from_prices(coin, currency, response)
+    }
+}
+
+// Même chargement sur une période fixe, recoupée aux périodes entières qu'elle couvre
+pub async fn fetch_chart_range_reporting_async(
+    coin: &str,
+    range: DateRange,
+    currency: &str,
+    progress: &(dyn Fn(FetchStage) + Sync),
+    cancel: &CancelToken,
+) -> Result<ChartData> {
+    let body = fetch_market_chart_body_async(coin, range, currency, cancel).await?;
+    cancel.check()?;
+    progress(FetchStage::Received);
+    progress(FetchStage::Parsing);
+    let response = serde_json::from_str(&body)?;
+    range.trim(if range.is_intraday() {
+        intraday_chart_data(coin, currency, response)?
+    } else {
+        chart_data_from_prices(coin, currency, response)?
+    })
+}
+
+// Points bruts de CoinGecko sur les `days` derniers jours, avant regroupement
+pub async fn fetch_market_chart_async(coin: &str, days: i64, currency: &str) -> Result<MarketChart> {
+    let end = Utc::now();
+    let range = DateRange { start: (end - Duration::days(days)).timestamp(), end: end.timestamp() };
+    Ok(serde_json::from_str(&fetch_market_chart_body_async(coin, range, currency, &CancelToken::new()).await?)?)
+}
+
+async fn fetch_market_chart_body_async(coin: &str, range: DateRange, currency: &str, cancel: &CancelToken) -> Result<String> {
+    let url = format!(
+        "https://api.coingecko.com/api/v3/coins/{}/market_chart/range?vs_currency={}&from={}&to={}",
+        coin,
+        currency,
+        range.start,
+        range.end
+    );
+
+    get_text_cancellable(|client| client.get(&url), cancel).await
+}
+
+// Regroupe les points bruts (horodatage en ms, prix) par jour, au format renvoyé par CoinGecko
+pub fn chart_data_from_prices(coin: &str, currency: &str, response: MarketChart) -> Result<ChartData> {
+    group_prices(coin, currency, response, "%Y-%m-%d")
+}
+
+// Même regroupement à la minute, pour la vue sur 24 h
+pub fn intraday_chart_data(coin: &str, currency: &str, response: MarketChart) -> Result<ChartData> {
+    group_prices(coin, currency, response, "%Y-%m-%d %H:%M")
+}
+
+// Regroupe les points par période, identifiée par le format de date `period` (en UTC)
+fn group_prices(coin: &str, currency: &str, response: MarketChart, period: &str) -> Result<ChartData> {
+    if response.prices.is_empty() {
+        return Err(Error::NoData(coin.to_string()));
+    }
+
+    // Dernier prix connu et variation sur 24h à partir des points bruts
+    let (latest_timestamp, latest_price) = response.prices.last().copied().unwrap_or((0.0, 0.0));
+    let price_24h_ago = response.prices
+        .iter()
+        .rev()
+        .find(|(timestamp, _)| *timestamp <= latest_timestamp - 86_400_000.0)
+        .map(|(_, price)| *price)
+        .unwrap_or(latest_price);
+    let change_24h = if price_24h_ago > 0.0 {
+        (latest_price - price_24h_ago) / price_24h_ago * 100.0
+    } else {
+        0.0
+    };
+
+    // Les horodatages viennent aussi des plugins et des fichiers importés : hors des dates représentables, erreur
+    let period_of = |timestamp: f64| {
+        DateTime::<Utc>::from_timestamp((timestamp / 1000.0) as i64, 0)
+            .filter(|_| timestamp.is_finite())
+            .map(|date| date.format(period).to_string())
+            .ok_or(Error::InvalidTimestamp(timestamp))
+    };
+
+    // Premier prix de chaque période ; avec plusieurs points, on en garde aussi le plus bas et le plus haut
+    let mut daily_prices: Vec<DailyPrice> = Vec::new();
+    for (timestamp, price) in response.prices {
+        let date = period_of(timestamp)?;
+        match daily_prices.last_mut() {
+            Some(day) if day.date == date => {
+                let (low, high) = day.range.unwrap_or((day.price, day.price));
+                day.range = Some((low.min(price), high.max(price)));
+            },
+            _ => daily_prices.push(DailyPrice::new(date, price)),
+        }
+    }
+
+    // Dernier volume sur 24 h relevé dans chaque période
+    for (timestamp, volume) in response.total_volumes {
+        let date = period_of(timestamp)?;
+        if let Some(day) = daily_prices.iter_mut().rev().find(|day| day.date == date) {
+            day.volume = Some(volume);
+        }
+    }
+
+    Ok(ChartData {
+        coin: coin.to_string(),
+        currency: currency.to_string(),
+        daily_prices,
+        latest_price,
+        change_24h,
+    })
+}
@@ -0,0 +1,104 @@
+use super::http::get_text;
+use crate::error::Result;
+use serde::{Deserialize, Serialize};
+use std::path::PathBuf;
+use std::time::Duration;
+
+// Au-delà, la liste en cache est retéléchargée (elle reste utilisée si CoinGecko ne répond pas)
+const CACHE_MAX_AGE: Duration = Duration::from_secs(24 * 60 * 60);
+
+// Entrée de /coins/list : toutes les cryptos connues de CoinGecko
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct CoinListing {
+    pub id: String,
+    pub symbol: String,
+    pub name: String,
+}
+
+fn cache_path() -> PathBuf {
+    dirs::cache_dir()
+        .map(|dir| dir.join("crypto-price-sonifier"))
+        .unwrap_or_default()
+        .join("coins_list.json")
+}
+
+// Liste en cache et son ancienneté, None si absente ou illisible (toujours le cas dans le navigateur)
+fn read_cache() -> Option<(Vec<CoinListing>, Duration)> {
+    let path = cache_path();
+    let age = std::fs::metadata(&path).ok()?.modified().ok()?.elapsed().unwrap_or_default();
+    let list = serde_json::from_str(&std::fs::read_to_string(&path).ok()?).ok()?;
+    Some((list, age))
+}
+
+fn write_cache(list: &[CoinListing]) -> Result<()> {
+    let path = cache_path();
+    if let Some(dir) = path.parent() {
+        std::fs::create_dir_all(dir)?;
+    }
+    std::fs::write(path, serde_json::to_string(list)?)?;
+    Ok(())
+}
+
+async fn fetch_coin_list_async() -> Result<Vec<CoinListing>> {
+    let body = get_text(|client| client.get("https://api.coingecko.com/api/v3/coins/list")).await?;
+    Ok(serde_json::from_str(&body)?)
+}
+
+// Liste des cryptos depuis le cache s'il est récent, sinon depuis CoinGecko
+pub async fn load_coin_list_async() -> Result<Vec<CoinListing>> {
+    let cached = read_cache();
+    if let Some((list, age)) = &cached {
+        if *age < CACHE_MAX_AGE {
+            return Ok(list.clone());
+        }
+    }
+    match fetch_coin_list_async().await {
+        Ok(list) => {
+            if !cfg!(target_arch = "wasm32") {
+                write_cache(&list).ok();
+            }
+            Ok(list)
+        },
+        Err(e) => cached.map(|(list, _)| list).ok_or(e),
+    }
+}
+
+// Note de correspondance d'un texte avec la recherche, plus basse = meilleure ; None si les lettres
+// de la recherche n'y apparaissent pas dans l'ordre
+fn match_score(query: &str, text: &str) -> Option<usize> {
+    if text == query {
+        return Some(0);
+    }
+    if text.starts_with(query) {
+        return Some(1 + text.len() - query.len());
+    }
+    if let Some(position) = text.find(query) {
+        return Some(100 + position);
+    }
+    // Recherche approximative : lettres dans l'ordre, pénalisée par les trous entre elles
+    let mut gaps = 0;
+    let mut chars = text.chars();
+    for wanted in query.chars() {
+        gaps += chars.by_ref().position(|c| c == wanted)?;
+    }
+    Some(1000 + gaps)
+}
+
+// Meilleures correspondances sur le symbole et le nom, sans tenir compte de la casse
+pub fn search_coins<'a>(list: &'a [CoinListing], query: &str, limit: usize) -> Vec<&'a CoinListing> {
+    let query = query.trim().to_lowercase();
+    if query.is_empty() {
+        return Vec::new();
+    }
+    let mut matches: Vec<(usize, &CoinListing)> = list
+        .iter()
+        .filter_map(|coin| {
+            let symbol = match_score(&query, &coin.symbol.to_lowercase());
+            let name = match_score(&query, &coin.name.to_lowercase());
+            Some((symbol.into_iter().chain(name).min()?, coin))
+        })
+        .collect();
+    // À note égale, les noms courts passent devant ("Bitcoin" avant "Bitcoin Cash")
+    matches.sort_by_key(|(score, coin)| (*score, coin.name.len()));
+    matches.into_iter().take(limit).map(|(_, coin)| coin).collect()
+}
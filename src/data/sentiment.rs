@@ -0,0 +1,63 @@
+use super::http::get_text;
+use super::models::DailyPrice;
+use crate::error::Result;
+use chrono::DateTime;
+use serde::Deserialize;
+use std::collections::HashMap;
+
+// Score de sentiment d'un jour, de 0 (peur extrême) à 100 (avidité extrême)
+#[derive(Debug, Clone, PartialEq)]
+pub struct SentimentPoint {
+    pub date: String,
+    pub score: f64,
+}
+
+// Réponse de l'indice Fear & Greed d'alternative.me : valeurs et horodatages sont des chaînes
+#[derive(Deserialize)]
+struct FearGreedResponse {
+    data: Vec<FearGreedEntry>,
+}
+
+#[derive(Deserialize)]
+struct FearGreedEntry {
+    value: String,
+    timestamp: String,
+}
+
+// Relit l'historique de l'indice, les entrées illisibles sont ignorées
+pub fn parse_fear_greed(body: &str) -> Result<Vec<SentimentPoint>> {
+    let response: FearGreedResponse = serde_json::from_str(body)?;
+    Ok(response.data
+        .into_iter()
+        .filter_map(|entry| {
+            let score = entry.value.parse::<f64>().ok()?;
+            let date = DateTime::from_timestamp(entry.timestamp.parse().ok()?, 0)?;
+            Some(SentimentPoint { date: date.format("%Y-%m-%d").to_string(), score })
+        })
+        .collect())
+}
+
+// Indice Fear & Greed des derniers jours. Il porte sur l'ensemble du marché (sans clé d'API,
+// contrairement aux scores par crypto de LunarCrush ou Santiment)
+pub async fn fetch_sentiment_async(days: usize) -> Result<Vec<SentimentPoint>> {
+    let url = format!("https://api.alternative.me/fng/?limit={}&format=json", days + 1);
+    let body = get_text(|client| client.get(&url)).await?;
+    parse_fear_greed(&body)
+}
+
+// Score de chaque jour de la série, None pour les jours sans relevé (l'indice démarre en 2018)
+pub fn align_sentiment(prices: &[DailyPrice], points: &[SentimentPoint]) -> Vec<Option<f64>> {
+    let scores: HashMap<&str, f64> = points.iter().map(|point| (point.date.as_str(), point.score)).collect();
+    prices.iter().map(|price| scores.get(price.date.as_str()).copied()).collect()
+}
+
+// Clé de traduction de la catégorie, avec les seuils d'alternative.me
+pub fn sentiment_label_key(score: f64) -> &'static str {
+    match score {
+        s if s < 25.0 => "sentiment-extreme-fear",
+        s if s < 47.0 => "sentiment-fear",
+        s if s < 55.0 => "sentiment-neutral",
+        s if s < 76.0 => "sentiment-greed",
+        _ => "sentiment-extreme-greed",
+    }
+}
@@ -0,0 +1,57 @@
+use super::coins::coin_meta;
+use super::http::get_text;
+use crate::error::Result;
+use serde::Deserialize;
+
+// Plateformes comparées : le miroir public de Binance (en USDT) et Coinbase (en USD)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Exchange {
+    Binance,
+    Coinbase,
+}
+
+impl Exchange {
+    pub const ALL: [Exchange; 2] = [Exchange::Binance, Exchange::Coinbase];
+
+    pub fn name(self) -> &'static str {
+        match self {
+            Exchange::Binance => "Binance",
+            Exchange::Coinbase => "Coinbase",
+        }
+    }
+
+    fn ticker_url(self, symbol: &str) -> String {
+        match self {
+            Exchange::Binance => format!("https://data-api.binance.vision/api/v3/ticker/price?symbol={}USDT", symbol),
+            Exchange::Coinbase => format!("https://api.exchange.coinbase.com/products/{}-USD/ticker", symbol),
+        }
+    }
+}
+
+// Les deux API renvoient le dernier prix sous forme de chaîne, dans un champ « price »
+#[derive(Deserialize)]
+struct TickerResponse {
+    price: String,
+}
+
+pub fn parse_ticker(body: &str) -> Result<f64> {
+    let response: TickerResponse = serde_json::from_str(body)?;
+    // Le contenu de la chaîne est lui-même un nombre JSON
+    Ok(serde_json::from_str(&response.price)?)
+}
+
+// Symbole commun aux deux plateformes ; None pour les cryptos dont le symbole est inconnu
+pub fn spread_symbol(coin: &str) -> Option<String> {
+    coin_meta(coin).map(|meta| meta.symbol.to_string())
+}
+
+pub async fn fetch_exchange_price_async(exchange: Exchange, symbol: &str) -> Result<f64> {
+    let body = get_text(|client| client.get(exchange.ticker_url(symbol))).await?;
+    parse_ticker(&body)
+}
+
+// Écart relatif (en %) du premier prix par rapport au second, rapporté à leur moyenne
+pub fn spread_percent(a: f64, b: f64) -> f64 {
+    let mid = (a + b) / 2.0;
+    if mid > 0.0 { (a - b) / mid * 100.0 } else { 0.0 }
+}
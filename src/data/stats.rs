@@ -0,0 +1,74 @@
+use super::models::DailyPrice;
+
+// Statistiques descriptives de la période chargée
+pub struct PriceStats {
+    pub min: f64,
+    pub max: f64,
+    pub mean: f64,
+    pub median: f64,
+    pub total_return: f64,
+    pub max_drawdown: f64,
+    pub annualized_volatility: f64,
+}
+
+impl PriceStats {
+    pub fn compute(daily_prices: &[DailyPrice]) -> Option<Self> {
+        let prices: Vec<f64> = daily_prices.iter().map(|p| p.price).collect();
+        let first = *prices.first()?;
+        let last = *prices.last()?;
+
+        let min = prices.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = prices.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let mean = prices.iter().sum::<f64>() / prices.len() as f64;
+
+        let mut sorted = prices.clone();
+        sorted.sort_by(|a, b| a.total_cmp(b));
+        let mid = sorted.len() / 2;
+        let median = if sorted.len().is_multiple_of(2) {
+            (sorted[mid - 1] + sorted[mid]) / 2.0
+        } else {
+            sorted[mid]
+        };
+
+        // Plus forte baisse depuis un sommet précédent
+        let mut peak = first;
+        let mut max_drawdown = 0.0f64;
+        for &price in &prices {
+            peak = peak.max(price);
+            max_drawdown = max_drawdown.max((peak - price) / peak * 100.0);
+        }
+
+        // Volatilité des rendements logarithmiques journaliers, annualisée sur 365 jours (marché 24/7)
+        let log_returns: Vec<f64> = prices.windows(2).map(|w| (w[1] / w[0]).ln()).collect();
+        let annualized_volatility = if log_returns.len() > 1 {
+            let mean_return = log_returns.iter().sum::<f64>() / log_returns.len() as f64;
+            let variance = log_returns.iter().map(|r| (r - mean_return).powi(2)).sum::<f64>()
+                / (log_returns.len() - 1) as f64;
+            variance.sqrt() * 365.0f64.sqrt() * 100.0
+        } else {
+            0.0
+        };
+
+        Some(Self {
+            min,
+            max,
+            mean,
+            median,
+            total_return: (last - first) / first * 100.0,
+            max_drawdown,
+            annualized_volatility,
+        })
+    }
+}
+
+// Écart en % par rapport au plus haut atteint jusque-là (toujours <= 0)
+pub fn drawdown_series(daily_prices: &[DailyPrice]) -> Vec<f64> {
+    let mut peak = f64::NEG_INFINITY;
+    daily_prices
+        .iter()
+        .map(|p| {
+            peak = peak.max(p.price);
+            (p.price - peak) / peak * 100.0
+        })
+        .collect()
+}
@@ -0,0 +1,60 @@
+use thiserror::Error;
+
+// Erreurs de l'application, affichées telles quelles dans les boîtes de dialogue
+#[derive(Debug, Error)]
+pub enum Error {
+    #[error("network request failed: {0}")]
+    Network(#[from] reqwest::Error),
+    #[error("could not parse the response: {0}")]
+    Parse(#[from] serde_json::Error),
+    #[error("could not read the news feed: {0}")]
+    Feed(#[from] roxmltree::Error),
+    #[error("no price data available for {0}")]
+    NoData(String),
+    #[error("no audio output device: {0}")]
+    AudioOutput(#[from] rodio::StreamError),
+    #[error("could not start audio playback: {0}")]
+    AudioPlayback(#[from] rodio::PlayError),
+    #[error("could not decode image: {0}")]
+    Image(#[from] image::ImageError),
+    #[error("image contains no frames")]
+    EmptyImage,
+    #[error("could not write audio file: {0}")]
+    Wav(#[from] hound::Error),
+    #[error("{0}")]
+    Io(#[from] std::io::Error),
+    #[error("{0}")]
+    Export(String),
+    #[error("plugin error: {0}")]
+    Plugin(String),
+    #[error("{0} are not supported in this build")]
+    Unsupported(&'static str),
+    #[error("background task failed: {0}")]
+    Task(String),
+    #[error("audio stream failed: {0}")]
+    Stream(String),
+    #[error("invalid recipe: {0}")]
+    InvalidRecipe(String),
+    #[error("unreadable row in the price file: {0}")]
+    InvalidCsv(String),
+    #[error("timestamp out of range: {0}")]
+    InvalidTimestamp(f64),
+    #[error("system tray unavailable: {0}")]
+    Tray(String),
+    #[error("cancelled")]
+    Cancelled,
+}
+
+impl Error {
+    // Clé de traduction du titre de la boîte de dialogue
+    pub fn title_key(&self) -> &'static str {
+        match self {
+            Error::Network(_) | Error::NoData(_) => "error-network",
+            Error::Parse(_) | Error::Feed(_) | Error::InvalidCsv(_) | Error::InvalidTimestamp(_) => "error-parse",
+            Error::AudioOutput(_) | Error::AudioPlayback(_) => "error-audio",
+            _ => "error-generic",
+        }
+    }
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,762 @@
+use std::sync::atomic::{AtomicU8, Ordering};
+
+// Langue de l'interface, partagée par toute l'application
+static LANGUAGE: AtomicU8 = AtomicU8::new(0);
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum Language {
+    English,
+    French,
+}
+
+impl Language {
+    pub const ALL: [Language; 2] = [Language::English, Language::French];
+
+    pub fn current() -> Self {
+        match LANGUAGE.load(Ordering::Relaxed) {
+            1 => Language::French,
+            _ => Language::English,
+        }
+    }
+
+    pub fn set(self) {
+        LANGUAGE.store(self as u8, Ordering::Relaxed);
+    }
+
+    // Langue par défaut déduite de l'environnement (LANG=fr_FR.UTF-8...)
+    pub fn from_env() -> Self {
+        match std::env::var("LANG") {
+            Ok(lang) if lang.starts_with("fr") => Language::French,
+            _ => Language::English,
+        }
+    }
+
+    pub fn native_name(&self) -> &'static str {
+        match self {
+            Language::English => "English",
+            Language::French => "Français",
+        }
+    }
+
+    fn bundle(&self) -> &'static [(&'static str, &'static str)] {
+        match self {
+            Language::English => EN_STRINGS,
+            Language::French => FR_STRINGS,
+        }
+    }
+}
+
+const EN_STRINGS: &[(&str, &str)] = &[
+    ("app-title", "Crypto Price Sonifier"),
+    ("app-description", "Experience cryptocurrency price movements through sound and visuals.\nWatch and listen as the market evolves over the last 30 days."),
+    ("choose-side", "Choose Your Side"),
+    ("tagline-ethereum", "Smart contracts pioneer"),
+    ("tagline-bitcoin", "Digital gold & store of value"),
+    ("tagline-ripple", "Global payments solution"),
+    ("click-hint", "Click on an icon to start the price sonification"),
+    ("open-dashboard", "📊 Multi-chart dashboard"),
+    ("asset-pack", "Asset pack"),
+    ("sound-pack", "Sound pack"),
+    ("sound-pack-none", "None (synth only)"),
+    ("debug-menu", "Debug"),
+    ("data-source", "Price data source"),
+    ("index-provider", "Index (median of exchanges)"),
+    ("index-disagreement", "⚠ Sources disagree by {spread}%"),
+    ("source-live", "Live data"),
+    ("mock-sine", "Mock: sine wave"),
+    ("mock-random-walk", "Mock: random walk"),
+    ("mock-crash", "Mock: crash"),
+    ("theme", "Theme"),
+    ("theme-dark", "Dark"),
+    ("theme-light", "Light"),
+    ("theme-high-contrast", "High contrast"),
+    ("reduce-motion", "Reduce motion"),
+    ("reduce-motion-hint", "Keeps the bull and bear images still and turns off particles. Follows the system setting until changed here."),
+    ("particles-reduced-motion", "Off while reduce motion is on"),
+    ("orderbook-layer", "📚 Order book"),
+    ("orderbook-hint", "Plays the coin's current Binance order book under the melody as soft noise: it leans left when buyers dominate, right when sellers do, with a short burst when a large order appears"),
+    ("orderbook-loading", "Reading the order book..."),
+    ("orderbook-failed", "Order book unavailable"),
+    ("orderbook-status", "Order book: {bids}% bids"),
+    ("orderbook-wall", " · new {side} wall at {price}"),
+    ("orderbook-bid-wall", "buy"),
+    ("orderbook-ask-wall", "sell"),
+    ("spread-layer", "⚖ Spread"),
+    ("spread-hint", "Compares the coin's current price on Binance and Coinbase: silent while they agree, then two detuned tones that beat faster as the gap widens"),
+    ("spread-loading", "Reading both exchanges..."),
+    ("spread-failed", "Exchange prices unavailable"),
+    ("spread-status", "Binance {binance} · Coinbase {coinbase} · spread {spread}%"),
+    ("spread-aligned", "Binance {binance} · Coinbase {coinbase} · aligned"),
+    ("live-layer", "📡 Live"),
+    ("live-hint", "Follows the coin's current price on Binance and plays one note per completed bar, whatever the pace of trading: the note compares the bar's close with the previous one, like a day of the replay"),
+    ("continue-live", "Then live"),
+    ("continue-live-hint", "When the replay reaches the last point, the live layer takes over from the last price and keeps playing one note per bar"),
+    ("live-loading", "Waiting for the first price..."),
+    ("live-failed", "Live price unavailable"),
+    ("live-status", "{price} · {bars} bars"),
+    ("bar-1s", "1 s"),
+    ("bar-5s", "5 s"),
+    ("bar-1m", "1 min"),
+    ("mirror-output", "Duplicate audio to"),
+    ("mirror-output-hint", "Also plays the charts on a second device, such as a virtual cable picked up by OBS, while you keep listening on the default one. Applies to the next chart opened."),
+    ("mirror-off", "Nothing"),
+    ("mirror-refresh", "⟳ Refresh devices"),
+    ("mirror-unavailable", "⚠ Audio copy unavailable"),
+    ("captions", "Captions"),
+    ("captions-hint", "Describes each note as it plays; recordings keep the captions as a subtitle track (MP4) or an .srt file (GIF)"),
+    ("note-log", "📜 Note log"),
+    ("note-log-hint", "Adds a line per played note (day, change, note, duration), a readable transcript of the sonification"),
+    ("hover-preview", "Hover preview"),
+    ("hover-preview-hint", "While paused, resting the pointer on a day plays its note quietly, so you can scrub the chart by ear"),
+    ("note-log-empty", "Played notes will appear here"),
+    ("sound-options", "🎼 Sound"),
+    ("note-length", "Note length"),
+    ("note-length-hint", "Lets each note's length carry the size of the day's move; the pace of the days stays the same"),
+    ("note-length-fixed", "Same for every day"),
+    ("note-length-longer", "Longer for big moves"),
+    ("note-length-shorter", "Shorter for big moves"),
+    ("arpeggio", "Arpeggio"),
+    ("arpeggio-hint", "Each day becomes a fast broken chord: the price note, then the day's volume (higher than usual = higher note) and its high-low range (wider = higher); days without volume or range data play the price note alone"),
+    ("echo", "Echo of the previous day"),
+    ("echo-hint", "Quietly replays the previous day's note just before each new one, so the step between two days is heard as a two-note motif"),
+    ("weekend-timbre", "Weekend timbre"),
+    ("weekend-timbre-hint", "Saturdays and Sundays are played with a hollow, clarinet-like sound and shaded on the chart, so the weekly cycle can be heard and seen"),
+    ("ducking", "Ducking"),
+    ("ducking-hint", "Lowers the background layers (order book, spread) while each note plays, then brings them back, so the price notes stay clear over the ambience"),
+    ("ducking-depth", "Depth"),
+    ("ducking-release", "Release"),
+    ("harmony", "Harmony"),
+    ("harmony-hint", "Adds a second, softer voice that follows the melody at a fixed interval, kept inside the chosen scale"),
+    ("harmony-off", "Off"),
+    ("harmony-third", "Third"),
+    ("harmony-fifth", "Fifth"),
+    ("harmony-octave", "Octave"),
+    ("harmony-below", "Below the melody"),
+    ("scale", "Scale (in A)"),
+    ("scale-chromatic", "Chromatic"),
+    ("scale-major", "Major"),
+    ("scale-minor", "Minor"),
+    ("pitch-range", "Pitch range"),
+    ("pitch-range-hint", "Every note is brought inside this range: small moves keep their pitch, extreme ones are squeezed towards the edges instead of turning shrill or inaudible"),
+    ("pitch-range-low", "Lowest"),
+    ("pitch-range-high", "Highest"),
+    ("sub-bass", "Sub-bass in downtrends"),
+    ("sub-bass-hint", "A deep rumble swells in once the price has fallen several days in a row, grows with every further red day and dies away on the first green day"),
+    ("sub-bass-days", "Red days before it starts"),
+    ("shepard", "Endless glide in trends"),
+    ("shepard-hint", "After three days moving the same way, the melody becomes a Shepard tone that keeps gliding in one direction without ever leaving the midrange, however long the run lasts"),
+    ("ending", "Ending"),
+    ("ending-cut", "Stop"),
+    ("ending-fade-out", "Fade out"),
+    ("ending-cadence", "Final chord"),
+    ("ending-hint", "How playback ends: the last note stops like the others, fades away, or resolves on an A chord, major if the period closed up and minor if it closed down"),
+    ("limiter", "LIM"),
+    ("limiter-hint", "Output limiter: lights up when loud peaks (stacked notes, chords) are being softened to avoid clipping"),
+    ("caption-step", "Day {day}: {change}, note {note}, {loudness}"),
+    ("caption-louder", "louder"),
+    ("caption-softer", "softer"),
+    ("caption-steady", "same volume"),
+    ("range", "History"),
+    ("range-days", "{days} days"),
+    ("range-24h", "Last 24 hours"),
+    ("prefetch", "Preload charts in the background"),
+    ("notifications", "Desktop notifications for finished recordings and alerts"),
+    ("export-loudness", "Normalize exported audio"),
+    ("export-loudness-hint", "Brings rendered files, recorded videos and the radio stream to the same perceived loudness (EBU R128), so a quiet sideways month and a violent crash play at comparable levels"),
+    ("progress-requesting", "Requesting prices from {source}"),
+    ("progress-received", "Response received"),
+    ("progress-parsing", "Reading the price history"),
+    ("progress-ready", "Ready"),
+    ("network-settings", "Network"),
+    ("http-connect-timeout", "Connection timeout"),
+    ("http-read-timeout", "Response timeout"),
+    ("http-retries", "Retries"),
+    ("http-retries-hint", "Extra attempts after a timeout, a refused connection or a server error (5xx, 429), waiting 0.5 s, 1 s, 2 s... in between"),
+    ("log-profiler", "Profiler listening on {address}, connect with puffin_viewer"),
+    ("log-profiler-failed", "Could not start the profiler server: {error}"),
+    ("log-tray-failed", "Could not add the tray icon: {error}"),
+    ("log-play-failed", "Couldn't load {coin} data: {error}"),
+    ("tray-sonify", "Sonify {coin} ({range})"),
+    ("tray-dashboard", "Open dashboard"),
+    ("tray-show", "Show window"),
+    ("tray-hide", "Minimize to tray"),
+    ("tray-quit", "Quit"),
+    ("close-to-tray", "Minimize to tray when closing"),
+    ("close-to-tray-hint", "Closing the window keeps the app running in the tray, live audio included; use Quit in the tray menu to exit"),
+    ("language", "Language"),
+    ("loading", "Fetching {coin} price data"),
+    ("dashboard-loading", "Fetching {coin}..."),
+    ("back-home", "← Back to Home"),
+    ("dock-chart", "← Back to main window"),
+    ("pop-out", "Pop out"),
+    ("pop-out-hint", "Open this chart in its own window, for example on a second monitor"),
+    ("mini-ticker", "Mini ticker"),
+    ("mini-ticker-hint", "Shrink the window to a small always-on-top ticker with this coin's curve and price"),
+    ("ticker-close", "Back to the full window (Esc or double-click)"),
+    ("ticker-sound", "Sound"),
+    ("ticker-sound-hint", "Quietly play a note for each live price bar"),
+    ("ticker-live", "live"),
+    ("presentation-hint", "F11: presentation mode"),
+    ("save-chart", "💾 Save chart image"),
+    ("export-full-width", "Full size ({width} px)"),
+    ("export-width-hint", "The image is captured from the window, so it is at most as wide as the chart on screen. Enlarge the window or use presentation mode (F11) for a larger image"),
+    ("play", "▶ Play"),
+    ("pause", "⏸ Pause"),
+    ("volume", "Volume"),
+    ("record", "⏺ Record"),
+    ("stop-recording", "⏹ Stop recording"),
+    ("recording", "Recording..."),
+    ("saved", "Saved {path}"),
+    ("export-failed", "Export failed: {error}"),
+    ("recording-failed", "Recording failed: {error}"),
+    ("recording-encoding", "Encoding the recording... {percent}%"),
+    ("export-notes", "🎵 Export notes"),
+    ("export-notes-hint", "Saves the note list (date, price, change, pitch, velocity, duration) as CSV or JSON in exports/"),
+    ("save-session", "💾 Save session"),
+    ("session-save-failed", "Could not save the session: {error}"),
+    ("open-session", "Open session"),
+    ("choose-session", "Choose..."),
+    ("no-sessions", "No saved sessions"),
+    ("share-recipe", "🔗 Share recipe"),
+    ("share-recipe-hint", "Copy the coin, range, currency, preset and speed so someone else can replay this sonification"),
+    ("recipe-copied", "Recipe copied to the clipboard and saved to {path}"),
+    ("recipe-save-failed", "Could not save the recipe: {error}"),
+    ("recipe-hint", "Paste a recipe..."),
+    ("open-recipe", "Play recipe"),
+    ("saved-recipes", "Saved recipes"),
+    ("choose-recipe", "Choose..."),
+    ("no-recipes", "No saved recipes"),
+    ("event-markers", "Events"),
+    ("log-events-failed", "Could not read the personal event calendar: {error}"),
+    ("sentiment-voice", "🗣 Sentiment"),
+    ("sentiment-hint", "Add a second, lower voice following the market's Fear & Greed index: the higher it sings, the greedier the market"),
+    ("sentiment-loading", "Loading sentiment..."),
+    ("sentiment-failed", "Sentiment unavailable"),
+    ("sentiment-missing", "No sentiment for this day"),
+    ("sentiment-score", "Sentiment {score}/100 · {label}"),
+    ("sentiment-extreme-fear", "Extreme fear"),
+    ("sentiment-fear", "Fear"),
+    ("sentiment-neutral", "Neutral"),
+    ("sentiment-greed", "Greed"),
+    ("sentiment-extreme-greed", "Extreme greed"),
+    ("playback-day", "Day {day}/{total}"),
+    ("playback-shortcuts", "Space: play/pause · ←/→: previous/next day · Home/End: first/last day · Backspace: back"),
+    ("a11y-playback-progress", "Day {day} of {total}, {date}: {price}, {change} since start"),
+    ("a11y-plot", "Price chart: hover or drag to explore, double-click to reset the zoom"),
+    ("a11y-minimap", "Overview of the whole range: click or drag to move the zoomed area"),
+    ("a11y-move-up", "Move {item} up"),
+    ("a11y-move-down", "Move {item} down"),
+    ("a11y-remove", "Remove {item}"),
+    ("a11y-holding", "Amount of {coin} held"),
+    ("a11y-alert-threshold", "Alert threshold"),
+    ("a11y-open-article", "Open the article: {title}"),
+    ("news", "📰 News"),
+    ("news-title", "Headlines"),
+    ("news-loading", "Loading headlines..."),
+    ("news-empty", "No recent headlines"),
+    ("news-jump-hint", "Play from this day to hear the market's reaction"),
+    ("news-out-of-range", "Published outside the loaded range"),
+    ("alerts", "🔔 Price alerts"),
+    ("alert-no-rules", "No alerts yet"),
+    ("alert-add", "Add alert"),
+    ("alert-kind-above", "Price above"),
+    ("alert-kind-below", "Price below"),
+    ("alert-kind-rises", "Up in 24h by"),
+    ("alert-kind-drops", "Down in 24h by"),
+    ("alert-rule-above", "{coin} above {threshold}"),
+    ("alert-rule-below", "{coin} below {threshold}"),
+    ("alert-rule-rises", "{coin} up {threshold} in 24h"),
+    ("alert-rule-drops", "{coin} down {threshold} in 24h"),
+    ("alert-triggered", "{rule}: now {price} ({change}% in 24h)"),
+    ("alert-dismiss", "Dismiss"),
+    ("alert-dismiss-all", "Dismiss all"),
+    ("log-alert-failed", "Alert check failed for {coin}: {error}"),
+    ("log-logo-failed", "Could not load the logo of {coin}: {error}"),
+    ("log-notification-failed", "Could not show the desktop notification: {error}"),
+    ("notification-alert", "Price alert"),
+    ("notification-recording-done", "Recording finished"),
+    ("notification-render-done", "{coin} {days}d render finished"),
+    ("portfolio", "My portfolio"),
+    ("portfolio-header", "💼 Portfolio"),
+    ("portfolio-listen", "🎧 Hear my portfolio"),
+    ("portfolio-progress", "Loaded {done} of {total} coins"),
+    ("watchlist", "👁 Watchlist"),
+    ("watchlist-hint", "CoinGecko id, e.g. solana"),
+    ("watchlist-add", "Add"),
+    ("watchlist-empty", "Your watchlist is empty: add coins below"),
+    ("pin-favorite", "Pin to the top"),
+    ("unpin-favorite", "Unpin"),
+    ("search-hint", "🔍 Search any coin by name or symbol"),
+    ("search-loading", "Loading the coin list..."),
+    ("search-failed", "Coin list unavailable: {error}"),
+    ("search-no-results", "No matching coin"),
+    ("recent", "Recently viewed:"),
+    ("open-browse", "🏆 Top 100 by market cap"),
+    ("browse-title", "Top {count} coins by market cap"),
+    ("browse-loading", "Loading market data..."),
+    ("browse-coin", "Coin"),
+    ("browse-price", "Price"),
+    ("browse-change", "24h"),
+    ("browse-market-cap", "Market cap"),
+    ("browse-7d", "Last 7 days"),
+    ("browse-hint", "Click to sonify this coin"),
+    ("open-overview", "🌍 Market overview"),
+    ("open-settings", "⚙ Settings"),
+    ("settings-title", "Settings"),
+    ("settings-live-hint", "Changes apply and are saved right away. Ctrl+, opens this page from anywhere."),
+    ("settings-data", "Data"),
+    ("settings-audio", "Audio"),
+    ("settings-appearance", "Appearance"),
+    ("settings-accessibility", "Accessibility"),
+    ("settings-notifications", "Notifications"),
+    ("settings-captions", "Captions in new charts"),
+    ("settings-captions-hint", "Show playback captions in the charts opened from now on; each open chart keeps its own setting"),
+    ("overview-title", "Market overview"),
+    ("overview-market-cap", "Total market cap"),
+    ("overview-market-cap-change", "24h change"),
+    ("overview-btc-dominance", "BTC dominance"),
+    ("overview-volume", "24h volume"),
+    ("overview-gainers", "📈 Top gainers"),
+    ("overview-losers", "📉 Top losers"),
+    ("overview-none", "None today"),
+    ("overview-sonify", "🔊 Sonify"),
+    ("overview-sonify-hint", "Opens the {coin} chart and listens to it"),
+    ("particles", "Particles"),
+    ("mode-price", "Price"),
+    ("mode-drawdown", "Drawdown"),
+    ("view-chart", "Chart"),
+    ("view-calendar", "Calendar"),
+    ("statistics", "Statistics"),
+    ("stat-min", "Min"),
+    ("stat-max", "Max"),
+    ("stat-mean", "Mean"),
+    ("stat-median", "Median"),
+    ("stat-total-return", "Total return"),
+    ("stat-max-drawdown", "Max drawdown"),
+    ("stat-volatility", "Annualized volatility"),
+    ("change-24h", "24h"),
+    ("since-start", "since start"),
+    ("fundamentals-loading", "Loading key figures..."),
+    ("fundamentals-failed", "Key figures unavailable"),
+    ("fundamentals-rank", "Rank"),
+    ("fundamentals-market-cap", "Market cap"),
+    ("fundamentals-supply", "Circulating"),
+    ("fundamentals-high-24h", "24h high"),
+    ("fundamentals-low-24h", "24h low"),
+    ("fundamentals-ath", "ATH"),
+    ("plot-price-label", "day {date}\nprice({currency}) = {value}"),
+    ("plot-drawdown-label", "day {date}\ndrawdown = {value}%"),
+    ("weekday-1", "Mon"),
+    ("weekday-2", "Tue"),
+    ("weekday-3", "Wed"),
+    ("weekday-4", "Thu"),
+    ("weekday-5", "Fri"),
+    ("weekday-6", "Sat"),
+    ("weekday-7", "Sun"),
+    ("log-image-failed", "Could not load image {path}: {error}"),
+    ("log-pack-failed", "Could not load asset pack {path}: {error}"),
+    ("log-sound-pack-failed", "Could not load sound pack {path}: {error}"),
+    ("log-plugin-failed", "Could not load plugin {path}: {error}"),
+    ("images-missing", "⚠ {count} image(s) could not be loaded"),
+    ("no-audio", "🔇 No audio output, visual-only mode"),
+    ("log-no-audio", "No audio output available, playing without sound: {error}"),
+    ("log-jack-unavailable", "JACK server unavailable, using the default audio device: {error}"),
+    ("log-mirror-failed", "Could not open the mirror output \"{device}\": {error}"),
+    ("log-radio-http", "Radio on the air at http://localhost:{port}/"),
+    ("log-radio-icecast", "Connecting to Icecast at {server}"),
+    ("log-radio-listener", "New listener: {peer}"),
+    ("log-radio-listener-failed", "Could not serve listener {peer}: {error}"),
+    ("log-radio-icecast-failed", "Could not connect to Icecast, retrying in {seconds} s: {error}"),
+    ("log-radio-fetch-failed", "Could not read the price, retrying in a minute: {error}"),
+    ("log-radio-playing", "Playing {coin} live, one note every {seconds} s"),
+    ("log-render-fetching", "Fetching {coin} prices for the last {days} days..."),
+    ("log-render-audio", "Wrote {count} notes to {path}"),
+    ("log-render-midi", "Wrote MIDI to {path}"),
+    ("log-render-notes", "Wrote the note list to {path}"),
+    ("ui-scale", "UI scale"),
+    ("font-file", "Font file (TTF/OTF)"),
+    ("load-font", "Load font"),
+    ("reset-font", "Default font"),
+    ("font-failed", "Could not load font: {error}"),
+    ("settings-save-failed", "Could not save settings: {error}"),
+    ("error-network", "Network error"),
+    ("error-parse", "Invalid data"),
+    ("error-audio", "Audio error"),
+    ("error-generic", "Error"),
+    ("retry", "Retry"),
+    ("dismiss", "Dismiss"),
+    ("fetch-failed", "Couldn't load {coin} data"),
+    ("fetch-failed-back", "Back"),
+    ("drop-hint", "Drop a price CSV or a saved session to open it"),
+    ("nav-back", "Back"),
+    ("nav-back-hint", "Back (Alt+←)"),
+    ("nav-forward", "Forward"),
+    ("nav-forward-hint", "Forward (Alt+→)"),
+];
+
+const FR_STRINGS: &[(&str, &str)] = &[
+    ("app-title", "Crypto Price Sonifier"),
+    ("app-description", "Vivez les mouvements des cryptomonnaies en son et en images.\nRegardez et écoutez l'évolution du marché sur les 30 derniers jours."),
+    ("choose-side", "Choisissez votre camp"),
+    ("tagline-ethereum", "Pionnier des smart contracts"),
+    ("tagline-bitcoin", "L'or numérique, réserve de valeur"),
+    ("tagline-ripple", "Solution de paiements mondiale"),
+    ("click-hint", "Cliquez sur une icône pour lancer la sonification des prix"),
+    ("open-dashboard", "📊 Tableau de bord multi-graphiques"),
+    ("asset-pack", "Pack d'images"),
+    ("sound-pack", "Pack de sons"),
+    ("sound-pack-none", "Aucun (synthèse seule)"),
+    ("debug-menu", "Débogage"),
+    ("data-source", "Source des prix"),
+    ("index-provider", "Indice (médiane des plateformes)"),
+    ("index-disagreement", "⚠ Sources en désaccord : {spread} %"),
+    ("source-live", "Données réelles"),
+    ("mock-sine", "Simulation : sinusoïde"),
+    ("mock-random-walk", "Simulation : marche aléatoire"),
+    ("mock-crash", "Simulation : krach"),
+    ("theme", "Thème"),
+    ("theme-dark", "Sombre"),
+    ("theme-light", "Clair"),
+    ("theme-high-contrast", "Contraste élevé"),
+    ("reduce-motion", "Réduire les animations"),
+    ("reduce-motion-hint", "Les images de taureau et d'ours restent immobiles et les particules sont coupées. Suit le réglage du système tant qu'il n'est pas modifié ici."),
+    ("particles-reduced-motion", "Coupées tant que les animations sont réduites"),
+    ("orderbook-layer", "📚 Carnet d'ordres"),
+    ("orderbook-hint", "Joue le carnet d'ordres Binance actuel de la crypto sous la mélodie, en souffle discret : il penche à gauche quand les acheteurs dominent, à droite quand ce sont les vendeurs, avec une bouffée quand un gros ordre apparaît"),
+    ("orderbook-loading", "Lecture du carnet d'ordres..."),
+    ("orderbook-failed", "Carnet d'ordres indisponible"),
+    ("orderbook-status", "Carnet : {bids} % d'achats"),
+    ("orderbook-wall", " · nouveau mur {side} à {price}"),
+    ("orderbook-bid-wall", "d'achat"),
+    ("orderbook-ask-wall", "de vente"),
+    ("spread-layer", "⚖ Écart"),
+    ("spread-hint", "Compare le prix actuel de la crypto sur Binance et Coinbase : silence tant qu'ils concordent, puis deux notes désaccordées qui battent de plus en plus vite quand l'écart se creuse"),
+    ("spread-loading", "Lecture des deux plateformes..."),
+    ("spread-failed", "Prix des plateformes indisponibles"),
+    ("spread-status", "Binance {binance} · Coinbase {coinbase} · écart {spread} %"),
+    ("spread-aligned", "Binance {binance} · Coinbase {coinbase} · alignés"),
+    ("live-layer", "📡 Direct"),
+    ("live-hint", "Suit le prix actuel de la crypto sur Binance et joue une note par barre terminée, quel que soit le rythme des échanges : la note compare la clôture de la barre à la précédente, comme un jour de la lecture"),
+    ("continue-live", "Puis en direct"),
+    ("continue-live-hint", "Quand la lecture atteint le dernier point, le mode direct prend le relais depuis le dernier prix et continue à jouer une note par barre"),
+    ("live-loading", "En attente du premier prix..."),
+    ("live-failed", "Prix en direct indisponible"),
+    ("live-status", "{price} · {bars} barres"),
+    ("bar-1s", "1 s"),
+    ("bar-5s", "5 s"),
+    ("bar-1m", "1 min"),
+    ("mirror-output", "Copier le son vers"),
+    ("mirror-output-hint", "Joue aussi les graphiques sur un deuxième périphérique, par exemple un câble virtuel capté par OBS, sans couper l'écoute sur la sortie par défaut. Pris en compte au prochain graphique ouvert."),
+    ("mirror-off", "Aucun"),
+    ("mirror-refresh", "⟳ Actualiser la liste"),
+    ("mirror-unavailable", "⚠ Copie du son indisponible"),
+    ("captions", "Sous-titres"),
+    ("captions-hint", "Décrit chaque note jouée ; les enregistrements gardent les sous-titres en piste (MP4) ou dans un fichier .srt (GIF)"),
+    ("note-log", "📜 Journal"),
+    ("note-log-hint", "Ajoute une ligne par note jouée (jour, variation, note, durée), une transcription lisible de la sonification"),
+    ("hover-preview", "Aperçu au survol"),
+    ("hover-preview-hint", "À l'arrêt, poser le pointeur sur un jour joue doucement sa note : le graphique s'explore à l'oreille"),
+    ("note-log-empty", "Les notes jouées s'afficheront ici"),
+    ("sound-options", "🎼 Son"),
+    ("note-length", "Durée des notes"),
+    ("note-length-hint", "La durée de chaque note porte l'ampleur du mouvement du jour ; le rythme des jours ne change pas"),
+    ("note-length-fixed", "Identique chaque jour"),
+    ("note-length-longer", "Plus longue pour les grands mouvements"),
+    ("note-length-shorter", "Plus courte pour les grands mouvements"),
+    ("arpeggio", "Arpège"),
+    ("arpeggio-hint", "Chaque jour devient un accord brisé rapide : la note du prix, puis celle du volume du jour (plus haut que d'habitude = plus aigu) et celle de son amplitude entre plus haut et plus bas (plus large = plus aigu) ; les jours sans volume ni amplitude jouent la note du prix seule"),
+    ("echo", "Écho de la veille"),
+    ("echo-hint", "Rejoue doucement la note de la veille juste avant chaque nouvelle note : l'écart entre deux jours s'entend comme un motif de deux notes"),
+    ("weekend-timbre", "Timbre du week-end"),
+    ("weekend-timbre-hint", "Les samedis et dimanches sont joués avec un son creux, proche de la clarinette, et grisés sur le graphique : le cycle de la semaine s'entend et se voit"),
+    ("ducking", "Atténuation du fond"),
+    ("ducking-hint", "Baisse les couches de fond (carnet d'ordres, écart) pendant chaque note puis les fait revenir : les notes du prix restent nettes par-dessus l'ambiance"),
+    ("ducking-depth", "Profondeur"),
+    ("ducking-release", "Retour"),
+    ("harmony", "Harmonie"),
+    ("harmony-hint", "Ajoute une seconde voix, plus douce, qui suit la mélodie à intervalle fixe en restant dans la gamme choisie"),
+    ("harmony-off", "Désactivée"),
+    ("harmony-third", "Tierce"),
+    ("harmony-fifth", "Quinte"),
+    ("harmony-octave", "Octave"),
+    ("harmony-below", "Sous la mélodie"),
+    ("scale", "Gamme (en la)"),
+    ("scale-chromatic", "Chromatique"),
+    ("scale-major", "Majeure"),
+    ("scale-minor", "Mineure"),
+    ("pitch-range", "Plage des hauteurs"),
+    ("pitch-range-hint", "Toutes les notes sont ramenées dans cette plage : les petits mouvements gardent leur hauteur, les extrêmes sont resserrés vers les bords au lieu de devenir stridents ou inaudibles"),
+    ("pitch-range-low", "Plus grave"),
+    ("pitch-range-high", "Plus aiguë"),
+    ("sub-bass", "Sous-basse dans les baisses"),
+    ("sub-bass-hint", "Un grondement grave monte quand le prix a baissé plusieurs jours d'affilée, grossit à chaque nouveau jour rouge et s'éteint au premier jour vert"),
+    ("sub-bass-days", "Jours rouges avant le départ"),
+    ("shepard", "Glissando sans fin dans les tendances"),
+    ("shepard-hint", "Après trois jours dans le même sens, la mélodie devient un ton de Shepard qui glisse toujours dans la même direction sans jamais quitter le médium, quelle que soit la durée du mouvement"),
+    ("ending", "Fin"),
+    ("ending-cut", "Arrêt net"),
+    ("ending-fade-out", "Fondu"),
+    ("ending-cadence", "Accord final"),
+    ("ending-hint", "Manière dont se termine la lecture : la dernière note s'arrête comme les autres, s'éteint en fondu, ou se résout sur un accord de La, majeur si la période a fini en hausse, mineur si elle a fini en baisse"),
+    ("limiter", "LIM"),
+    ("limiter-hint", "Limiteur de sortie : s'allume quand des crêtes trop fortes (notes superposées, accords) sont adoucies pour éviter la saturation"),
+    ("caption-step", "Jour {day} : {change}, note {note}, {loudness}"),
+    ("caption-louder", "plus fort"),
+    ("caption-softer", "moins fort"),
+    ("caption-steady", "même volume"),
+    ("range", "Historique"),
+    ("range-days", "{days} jours"),
+    ("range-24h", "Dernières 24 heures"),
+    ("prefetch", "Précharger les graphiques en arrière-plan"),
+    ("notifications", "Notifications du bureau (enregistrements terminés, alertes)"),
+    ("export-loudness", "Normaliser le son exporté"),
+    ("export-loudness-hint", "Amène les fichiers rendus, les vidéos enregistrées et le flux radio à la même sonie perçue (EBU R128) : un mois calme et un krach violent s'écoutent à des niveaux comparables"),
+    ("progress-requesting", "Demande des prix à {source}"),
+    ("progress-received", "Réponse reçue"),
+    ("progress-parsing", "Lecture de l'historique des prix"),
+    ("progress-ready", "Prêt"),
+    ("network-settings", "Réseau"),
+    ("http-connect-timeout", "Délai de connexion"),
+    ("http-read-timeout", "Délai de réponse"),
+    ("http-retries", "Nouvelles tentatives"),
+    ("http-retries-hint", "Tentatives supplémentaires après un délai dépassé, une connexion refusée ou une erreur du serveur (5xx, 429), avec une attente de 0,5 s, 1 s, 2 s... entre chacune"),
+    ("log-profiler", "Profileur à l'écoute sur {address}, à ouvrir avec puffin_viewer"),
+    ("log-profiler-failed", "Impossible de démarrer le serveur du profileur: {error}"),
+    ("log-tray-failed", "Impossible d'ajouter l'icône de notification: {error}"),
+    ("log-play-failed", "Impossible de charger les données de {coin}: {error}"),
+    ("tray-sonify", "Sonifier {coin} ({range})"),
+    ("tray-dashboard", "Ouvrir le tableau de bord"),
+    ("tray-show", "Afficher la fenêtre"),
+    ("tray-hide", "Réduire dans la zone de notification"),
+    ("tray-quit", "Quitter"),
+    ("close-to-tray", "Réduire dans la zone de notification à la fermeture"),
+    ("close-to-tray-hint", "Fermer la fenêtre laisse l'application tourner dans la zone de notification, son du direct compris ; Quitter, dans le menu de l'icône, la ferme vraiment"),
+    ("language", "Langue"),
+    ("loading", "Récupération des prix de {coin}"),
+    ("dashboard-loading", "Récupération de {coin}..."),
+    ("back-home", "← Retour à l'accueil"),
+    ("dock-chart", "← Retour à la fenêtre principale"),
+    ("pop-out", "Détacher"),
+    ("pop-out-hint", "Ouvrir ce graphique dans sa propre fenêtre, par exemple sur un second écran"),
+    ("mini-ticker", "Mini bandeau"),
+    ("mini-ticker-hint", "Réduire la fenêtre à un petit bandeau toujours au premier plan avec la courbe et le prix de cette crypto"),
+    ("ticker-close", "Revenir à la fenêtre complète (Échap ou double-clic)"),
+    ("ticker-sound", "Son"),
+    ("ticker-sound-hint", "Jouer discrètement une note pour chaque barre de prix en direct"),
+    ("ticker-live", "direct"),
+    ("presentation-hint", "F11 : mode présentation"),
+    ("save-chart", "💾 Enregistrer l'image"),
+    ("export-full-width", "Taille réelle ({width} px)"),
+    ("export-width-hint", "L'image est capturée dans la fenêtre : elle ne dépasse pas la largeur du graphique à l'écran. Agrandissez la fenêtre ou passez en mode présentation (F11) pour une image plus grande"),
+    ("play", "▶ Lecture"),
+    ("pause", "⏸ Pause"),
+    ("volume", "Volume"),
+    ("record", "⏺ Enregistrer"),
+    ("stop-recording", "⏹ Arrêter l'enregistrement"),
+    ("recording", "Enregistrement..."),
+    ("saved", "Enregistré : {path}"),
+    ("export-failed", "Échec de l'export : {error}"),
+    ("recording-failed", "Échec de l'enregistrement : {error}"),
+    ("recording-encoding", "Encodage de l'enregistrement... {percent} %"),
+    ("export-notes", "🎵 Exporter les notes"),
+    ("export-notes-hint", "Enregistre la liste des notes (date, prix, variation, hauteur, vélocité, durée) en CSV ou en JSON dans exports/"),
+    ("save-session", "💾 Sauvegarder la session"),
+    ("session-save-failed", "Impossible de sauvegarder la session : {error}"),
+    ("open-session", "Ouvrir une session"),
+    ("choose-session", "Choisir..."),
+    ("no-sessions", "Aucune session sauvegardée"),
+    ("share-recipe", "🔗 Partager la recette"),
+    ("share-recipe-hint", "Copie la crypto, la période, la devise, le preset et la vitesse pour qu'un autre puisse rejouer cette sonification"),
+    ("recipe-copied", "Recette copiée dans le presse-papiers et enregistrée dans {path}"),
+    ("recipe-save-failed", "Impossible d'enregistrer la recette : {error}"),
+    ("recipe-hint", "Coller une recette..."),
+    ("open-recipe", "Jouer la recette"),
+    ("saved-recipes", "Recettes enregistrées"),
+    ("choose-recipe", "Choisir..."),
+    ("no-recipes", "Aucune recette enregistrée"),
+    ("event-markers", "Événements"),
+    ("log-events-failed", "Impossible de lire le calendrier d'événements personnel: {error}"),
+    ("sentiment-voice", "🗣 Sentiment"),
+    ("sentiment-hint", "Ajoute une deuxième voix, plus grave, qui suit l'indice Fear & Greed du marché : plus elle est aiguë, plus le marché est avide"),
+    ("sentiment-loading", "Chargement du sentiment..."),
+    ("sentiment-failed", "Sentiment indisponible"),
+    ("sentiment-missing", "Pas de sentiment pour ce jour"),
+    ("sentiment-score", "Sentiment {score}/100 · {label}"),
+    ("sentiment-extreme-fear", "Peur extrême"),
+    ("sentiment-fear", "Peur"),
+    ("sentiment-neutral", "Neutre"),
+    ("sentiment-greed", "Avidité"),
+    ("sentiment-extreme-greed", "Avidité extrême"),
+    ("playback-day", "Jour {day}/{total}"),
+    ("playback-shortcuts", "Espace : lecture/pause · ←/→ : jour précédent/suivant · Début/Fin : premier/dernier jour · Retour arrière : retour"),
+    ("a11y-playback-progress", "Jour {day} sur {total}, {date} : {price}, {change} depuis le début"),
+    ("a11y-plot", "Graphique des prix : survoler ou glisser pour explorer, double-cliquer pour réinitialiser le zoom"),
+    ("a11y-minimap", "Vue d'ensemble de la période : cliquer ou glisser pour déplacer la zone zoomée"),
+    ("a11y-move-up", "Monter {item}"),
+    ("a11y-move-down", "Descendre {item}"),
+    ("a11y-remove", "Retirer {item}"),
+    ("a11y-holding", "Quantité de {coin} détenue"),
+    ("a11y-alert-threshold", "Seuil de l'alerte"),
+    ("a11y-open-article", "Ouvrir l'article : {title}"),
+    ("news", "📰 Actualités"),
+    ("news-title", "Gros titres"),
+    ("news-loading", "Chargement des titres..."),
+    ("news-empty", "Aucun titre récent"),
+    ("news-jump-hint", "Lire à partir de ce jour pour entendre la réaction du marché"),
+    ("news-out-of-range", "Publié hors de la période chargée"),
+    ("alerts", "🔔 Alertes de prix"),
+    ("alert-no-rules", "Aucune alerte pour l'instant"),
+    ("alert-add", "Ajouter l'alerte"),
+    ("alert-kind-above", "Prix au-dessus de"),
+    ("alert-kind-below", "Prix en dessous de"),
+    ("alert-kind-rises", "Hausse sur 24 h de"),
+    ("alert-kind-drops", "Baisse sur 24 h de"),
+    ("alert-rule-above", "{coin} au-dessus de {threshold}"),
+    ("alert-rule-below", "{coin} en dessous de {threshold}"),
+    ("alert-rule-rises", "{coin} en hausse de {threshold} sur 24 h"),
+    ("alert-rule-drops", "{coin} en baisse de {threshold} sur 24 h"),
+    ("alert-triggered", "{rule} : maintenant {price} ({change} % sur 24 h)"),
+    ("alert-dismiss", "Fermer"),
+    ("alert-dismiss-all", "Tout fermer"),
+    ("log-alert-failed", "Échec de la vérification des alertes pour {coin}: {error}"),
+    ("log-logo-failed", "Impossible de charger le logo de {coin}: {error}"),
+    ("log-notification-failed", "Impossible d'afficher la notification du bureau: {error}"),
+    ("notification-alert", "Alerte de prix"),
+    ("notification-recording-done", "Enregistrement terminé"),
+    ("notification-render-done", "Rendu {coin} {days} j terminé"),
+    ("portfolio", "Mon portefeuille"),
+    ("portfolio-header", "💼 Portefeuille"),
+    ("portfolio-listen", "🎧 Écouter mon portefeuille"),
+    ("portfolio-progress", "{done} cryptos chargées sur {total}"),
+    ("watchlist", "👁 Liste de suivi"),
+    ("watchlist-hint", "Identifiant CoinGecko, ex. solana"),
+    ("watchlist-add", "Ajouter"),
+    ("watchlist-empty", "Votre liste de suivi est vide : ajoutez des cryptos ci-dessous"),
+    ("pin-favorite", "Épingler en tête"),
+    ("unpin-favorite", "Désépingler"),
+    ("search-hint", "🔍 Rechercher une crypto par nom ou symbole"),
+    ("search-loading", "Chargement de la liste des cryptos..."),
+    ("search-failed", "Liste des cryptos indisponible : {error}"),
+    ("search-no-results", "Aucune crypto correspondante"),
+    ("recent", "Consultées récemment :"),
+    ("open-browse", "🏆 Top 100 par capitalisation"),
+    ("browse-title", "Les {count} plus grosses capitalisations"),
+    ("browse-loading", "Chargement des données de marché..."),
+    ("browse-coin", "Crypto"),
+    ("browse-price", "Prix"),
+    ("browse-change", "24 h"),
+    ("browse-market-cap", "Capitalisation"),
+    ("browse-7d", "7 derniers jours"),
+    ("browse-hint", "Cliquer pour sonifier cette crypto"),
+    ("open-overview", "🌍 Vue d'ensemble du marché"),
+    ("open-settings", "⚙ Préférences"),
+    ("settings-title", "Préférences"),
+    ("settings-live-hint", "Les changements s'appliquent et s'enregistrent aussitôt. Ctrl+, ouvre cette page depuis n'importe où."),
+    ("settings-data", "Données"),
+    ("settings-audio", "Audio"),
+    ("settings-appearance", "Apparence"),
+    ("settings-accessibility", "Accessibilité"),
+    ("settings-notifications", "Notifications"),
+    ("settings-captions", "Sous-titres dans les nouveaux graphiques"),
+    ("settings-captions-hint", "Afficher les sous-titres de lecture dans les graphiques ouverts désormais ; chaque graphique ouvert garde son propre réglage"),
+    ("overview-title", "Vue d'ensemble du marché"),
+    ("overview-market-cap", "Capitalisation totale"),
+    ("overview-market-cap-change", "Variation sur 24 h"),
+    ("overview-btc-dominance", "Dominance de Bitcoin"),
+    ("overview-volume", "Volume sur 24 h"),
+    ("overview-gainers", "📈 Plus fortes hausses"),
+    ("overview-losers", "📉 Plus fortes baisses"),
+    ("overview-none", "Aucune aujourd'hui"),
+    ("overview-sonify", "🔊 Sonifier"),
+    ("overview-sonify-hint", "Ouvre le graphique de {coin} et l'écoute"),
+    ("particles", "Particules"),
+    ("mode-price", "Prix"),
+    ("mode-drawdown", "Drawdown"),
+    ("view-chart", "Graphique"),
+    ("view-calendar", "Calendrier"),
+    ("statistics", "Statistiques"),
+    ("stat-min", "Min"),
+    ("stat-max", "Max"),
+    ("stat-mean", "Moyenne"),
+    ("stat-median", "Médiane"),
+    ("stat-total-return", "Rendement total"),
+    ("stat-max-drawdown", "Drawdown maximal"),
+    ("stat-volatility", "Volatilité annualisée"),
+    ("change-24h", "24h"),
+    ("since-start", "depuis le début"),
+    ("fundamentals-loading", "Chargement des chiffres clés..."),
+    ("fundamentals-failed", "Chiffres clés indisponibles"),
+    ("fundamentals-rank", "Rang"),
+    ("fundamentals-market-cap", "Capitalisation"),
+    ("fundamentals-supply", "En circulation"),
+    ("fundamentals-high-24h", "Haut 24 h"),
+    ("fundamentals-low-24h", "Bas 24 h"),
+    ("fundamentals-ath", "Record"),
+    ("plot-price-label", "jour {date}\nprix({currency}) = {value}"),
+    ("plot-drawdown-label", "jour {date}\ndrawdown = {value}%"),
+    ("weekday-1", "Lun"),
+    ("weekday-2", "Mar"),
+    ("weekday-3", "Mer"),
+    ("weekday-4", "Jeu"),
+    ("weekday-5", "Ven"),
+    ("weekday-6", "Sam"),
+    ("weekday-7", "Dim"),
+    ("log-image-failed", "Impossible de charger l'image {path}: {error}"),
+    ("log-pack-failed", "Impossible de charger le pack {path}: {error}"),
+    ("log-sound-pack-failed", "Impossible de charger le pack de sons {path}: {error}"),
+    ("log-plugin-failed", "Impossible de charger le plugin {path}: {error}"),
+    ("images-missing", "⚠ {count} image(s) n'ont pas pu être chargée(s)"),
+    ("no-audio", "🔇 Pas de sortie audio, mode visuel uniquement"),
+    ("log-no-audio", "Aucune sortie audio disponible, lecture sans son: {error}"),
+    ("log-jack-unavailable", "Serveur JACK indisponible, sortie audio par défaut: {error}"),
+    ("log-mirror-failed", "Impossible d'ouvrir la sortie de copie « {device} »: {error}"),
+    ("log-radio-http", "Radio à l'antenne sur http://localhost:{port}/"),
+    ("log-radio-icecast", "Connexion à Icecast sur {server}"),
+    ("log-radio-listener", "Nouvel auditeur: {peer}"),
+    ("log-radio-listener-failed", "Impossible de servir l'auditeur {peer}: {error}"),
+    ("log-radio-icecast-failed", "Impossible de se connecter à Icecast, nouvel essai dans {seconds} s: {error}"),
+    ("log-radio-fetch-failed", "Impossible de relever le prix, nouvel essai dans une minute: {error}"),
+    ("log-radio-playing", "Lecture de {coin} en direct, une note toutes les {seconds} s"),
+    ("log-render-fetching", "Récupération des prix de {coin} sur les {days} derniers jours..."),
+    ("log-render-audio", "{count} notes écrites dans {path}"),
+    ("log-render-midi", "MIDI écrit dans {path}"),
+    ("log-render-notes", "Liste des notes écrite dans {path}"),
+    ("ui-scale", "Taille de l'interface"),
+    ("font-file", "Police (TTF/OTF)"),
+    ("load-font", "Charger la police"),
+    ("reset-font", "Police par défaut"),
+    ("font-failed", "Impossible de charger la police : {error}"),
+    ("settings-save-failed", "Impossible d'enregistrer les réglages : {error}"),
+    ("error-network", "Erreur réseau"),
+    ("error-parse", "Données invalides"),
+    ("error-audio", "Erreur audio"),
+    ("error-generic", "Erreur"),
+    ("retry", "Réessayer"),
+    ("dismiss", "Fermer"),
+    ("fetch-failed", "Impossible de charger les données de {coin}"),
+    ("fetch-failed-back", "Retour"),
+    ("drop-hint", "Déposez un historique de prix en CSV ou une session sauvegardée pour l'ouvrir"),
+    ("nav-back", "Précédent"),
+    ("nav-back-hint", "Page précédente (Alt+←)"),
+    ("nav-forward", "Suivant"),
+    ("nav-forward-hint", "Page suivante (Alt+→)"),
+];
+
+// Traduction d'une clé dans la langue courante, avec repli sur l'anglais
+pub fn tr(key: &'static str) -> &'static str {
+    let lookup = |bundle: &'static [(&'static str, &'static str)]| {
+        bundle.iter().find(|(k, _)| *k == key).map(|(_, v)| *v)
+    };
+    lookup(Language::current().bundle())
+        .or_else(|| lookup(EN_STRINGS))
+        .unwrap_or(key)
+}
+
+// Traduction avec remplacement des paramètres {nom}
+pub fn tr_args<S: AsRef<str>>(key: &'static str, args: &[(&str, S)]) -> String {
+    let mut text = tr(key).to_string();
+    for (name, value) in args {
+        text = text.replace(&format!("{{{}}}", name), value.as_ref());
+    }
+    text
+}
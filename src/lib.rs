@@ -0,0 +1,16 @@
+// Sonification des prix des cryptomonnaies : données, synthèse audio et interface egui
+pub mod app;
+pub mod audio;
+pub mod data;
+pub mod error;
+pub mod i18n;
+pub mod notify;
+pub mod profiling;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod radio;
+#[cfg(not(target_arch = "wasm32"))]
+pub mod render;
+pub mod task;
+#[cfg(all(feature = "tray", not(target_arch = "wasm32")))]
+pub mod tray;
+pub mod ui;
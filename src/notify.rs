@@ -0,0 +1,41 @@
+// Notifications du bureau (fin d'un rendu ou d'un enregistrement, alerte de prix), visibles même
+// quand la fenêtre est réduite. Sans effet dans le navigateur
+use std::sync::atomic::{AtomicBool, Ordering};
+
+static ENABLED: AtomicBool = AtomicBool::new(true);
+
+// Reflète la préférence de l'utilisateur, appliquée à toutes les notifications suivantes
+pub fn set_enabled(enabled: bool) {
+    ENABLED.store(enabled, Ordering::Relaxed);
+}
+
+pub fn is_enabled() -> bool {
+    ENABLED.load(Ordering::Relaxed)
+}
+
+// Envoie la notification et attend qu'elle soit remise (ligne de commande, qui quitte juste après)
+pub fn notify_and_wait(summary: &str, body: &str) {
+    if !is_enabled() {
+        return;
+    }
+    #[cfg(not(target_arch = "wasm32"))]
+    if let Err(e) = notify_rust::Notification::new()
+        .appname("Crypto Price Sonifier")
+        .summary(summary)
+        .body(body)
+        .show()
+    {
+        println!("{}", crate::i18n::tr_args("log-notification-failed", &[("error", &e.to_string())]));
+    }
+    #[cfg(target_arch = "wasm32")]
+    let _ = (summary, body);
+}
+
+// Le service de notifications peut tarder à répondre : l'interface ne l'attend pas
+pub fn notify(summary: &str, body: &str) {
+    if !is_enabled() {
+        return;
+    }
+    let (summary, body) = (summary.to_string(), body.to_string());
+    crate::task::spawn_background(move || notify_and_wait(&summary, &body));
+}
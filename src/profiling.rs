@@ -0,0 +1,43 @@
+// Mesures de performance pour puffin_viewer, compilées seulement avec la fonctionnalité `profiling` :
+// sans elle, les macros ne génèrent aucun code
+#[cfg(feature = "profiling")]
+use crate::i18n::tr_args;
+
+// Mesure la durée du bloc englobant sous le nom donné
+#[macro_export]
+macro_rules! profile_scope {
+    ($name:expr) => {
+        #[cfg(feature = "profiling")]
+        puffin::profile_scope!($name);
+    };
+}
+
+// Mesure la durée de la fonction englobante, nommée automatiquement
+#[macro_export]
+macro_rules! profile_function {
+    () => {
+        #[cfg(feature = "profiling")]
+        puffin::profile_function!();
+    };
+}
+
+// Démarre le serveur que puffin_viewer interroge (127.0.0.1:8585) et active les mesures
+#[cfg(all(feature = "profiling", not(target_arch = "wasm32")))]
+pub fn start_server() {
+    let address = format!("127.0.0.1:{}", puffin_http::DEFAULT_PORT);
+    match puffin_http::Server::new(&address) {
+        Ok(server) => {
+            println!("{}", tr_args("log-profiler", &[("address", &address)]));
+            // Le serveur doit vivre aussi longtemps que l'application
+            std::mem::forget(server);
+            puffin::set_scopes_on(true);
+        },
+        Err(e) => println!("{}", tr_args("log-profiler-failed", &[("error", &e.to_string())])),
+    }
+}
+
+// Clôt la frame en cours pour que puffin_viewer affiche les mesures frame par frame
+pub fn new_frame() {
+    #[cfg(feature = "profiling")]
+    puffin::GlobalProfiler::lock().new_frame();
+}
@@ -0,0 +1,266 @@
+use crate::audio::{mix_notes, normalize_loudness, Limiter, Sonifier, AUDIO_SAMPLE_RATE};
+use crate::data::{coin_display_name, fetch_exchange_price_async, spread_symbol, Bar, BarAggregator, BarLength, Exchange};
+use crate::error::{Error, Result};
+use crate::i18n::tr_args;
+use crate::task::runtime;
+use crate::ui::live::bar_voices;
+use std::collections::VecDeque;
+use std::io::{Read, Write};
+use std::net::{TcpListener, TcpStream};
+use std::process::{Child, Command, Stdio};
+use std::sync::mpsc::{self, Receiver, SyncSender};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Le son part par paquets d'une demi-seconde, au rythme du temps réel
+const CHUNK_SECONDS: f64 = 0.5;
+// Relevés du prix, comme le mode direct de la fenêtre
+const POLL_SECONDS: f64 = 0.5;
+const BAR_LENGTH: BarLength = BarLength::FiveSeconds;
+// Attente avant de relever à nouveau le prix après un échec
+const RETRY_SECONDS: u64 = 60;
+// Reconnexion à Icecast : attente doublée à chaque échec, remise à zéro après une minute de diffusion
+const ICECAST_MIN_BACKOFF_SECONDS: u64 = 5;
+const ICECAST_MAX_BACKOFF_SECONDS: u64 = 300;
+const ICECAST_STABLE_SECONDS: u64 = 60;
+// Un auditeur a quelques secondes pour envoyer sa requête
+const REQUEST_TIMEOUT_SECONDS: u64 = 5;
+// Paquets en attente par encodeur (4 s de son) : au-delà, l'auditeur ne suit plus et il est retiré
+const QUEUED_CHUNKS: usize = 8;
+// Prix de la barre de référence de la normalisation ; seule la variation compte, ici nulle
+const REFERENCE_PRICE: f64 = 100.0;
+
+pub enum RadioOutput {
+    // Serveur HTTP intégré : chaque auditeur reçoit son propre flux OGG
+    Http(u16),
+    // Flux poussé vers un point de montage Icecast (icecast://source:motdepasse@hôte:port/montage)
+    Icecast(String),
+}
+
+// Encodeur ffmpeg : PCM flottant sur l'entrée standard, OGG Vorbis en sortie. Les paquets passent par
+// une file bornée vidée par un thread, pour que la diffusion ne bloque jamais sur un auditeur lent
+struct Encoder {
+    child: Child,
+    queue: SyncSender<Arc<[u8]>>,
+}
+
+impl Encoder {
+    fn spawn(output: &[&str], stdout: Stdio) -> Result<Self> {
+        let mut child = Command::new("ffmpeg")
+            .args(["-loglevel", "error", "-f", "f32le", "-ar", &AUDIO_SAMPLE_RATE.to_string(), "-ac", "1", "-i", "pipe:0"])
+            .args(["-c:a", "libvorbis", "-q:a", "4", "-f", "ogg"])
+            .args(output)
+            .stdin(Stdio::piped())
+            .stdout(stdout)
+            .spawn()
+            .map_err(|e| Error::Stream(format!("ffmpeg not available ({})", e)))?;
+        let mut input = child.stdin.take().ok_or_else(|| Error::Stream("ffmpeg input unavailable".to_string()))?;
+        let (queue, chunks) = mpsc::sync_channel::<Arc<[u8]>>(QUEUED_CHUNKS);
+        // S'arrête quand ffmpeg se ferme ou quand l'encodeur est retiré
+        std::thread::spawn(move || {
+            for chunk in chunks {
+                if input.write_all(&chunk).is_err() {
+                    break;
+                }
+            }
+        });
+        Ok(Self { child, queue })
+    }
+}
+
+impl Drop for Encoder {
+    fn drop(&mut self) {
+        let _ = self.child.kill();
+        let _ = self.child.wait();
+    }
+}
+
+// Un auditeur : l'en-tête HTTP puis la sortie de son encodeur, jusqu'à ce qu'il se déconnecte.
+// Chacun a son encodeur, car un flux OGG pris en cours de route n'a pas les en-têtes Vorbis
+fn serve_listener(mut stream: TcpStream) -> Result<Encoder> {
+    // La requête n'est pas analysée : toute URL reçoit la radio
+    stream.set_read_timeout(Some(Duration::from_secs(REQUEST_TIMEOUT_SECONDS)))?;
+    let mut request = [0u8; 1024];
+    let _ = stream.read(&mut request)?;
+    stream.write_all(b"HTTP/1.0 200 OK\r\nContent-Type: audio/ogg\r\nCache-Control: no-cache\r\n\r\n")?;
+    let mut encoder = Encoder::spawn(&["pipe:1"], Stdio::piped())?;
+    let mut output = encoder.child.stdout.take().ok_or_else(|| Error::Stream("ffmpeg output unavailable".to_string()))?;
+    std::thread::spawn(move || {
+        let _ = std::io::copy(&mut output, &mut stream);
+    });
+    Ok(encoder)
+}
+
+// Chaque connexion est servie sur son propre thread : un client muet ne retient pas les suivants
+fn accept_listeners(listener: TcpListener, encoders: Arc<Mutex<Vec<Encoder>>>) {
+    for stream in listener.incoming().flatten() {
+        let encoders = encoders.clone();
+        std::thread::spawn(move || {
+            let peer = stream.peer_addr().map(|addr| addr.to_string()).unwrap_or_default();
+            match serve_listener(stream) {
+                Ok(encoder) => {
+                    println!("{}", tr_args("log-radio-listener", &[("peer", &peer)]));
+                    if let Ok(mut encoders) = encoders.lock() {
+                        encoders.push(encoder);
+                    }
+                },
+                Err(e) => println!("{}", tr_args("log-radio-listener-failed", &[("peer", &peer), ("error", &e.to_string())])),
+            }
+        });
+    }
+}
+
+// Envoie les échantillons à tous les encodeurs sans attendre ; ceux dont l'auditeur est parti ou ne
+// suit plus (file pleine) sont retirés
+fn broadcast(encoders: &Mutex<Vec<Encoder>>, samples: &[f32]) {
+    let bytes: Arc<[u8]> = samples.iter().flat_map(|sample| sample.clamp(-1.0, 1.0).to_le_bytes()).collect();
+    if let Ok(mut encoders) = encoders.lock() {
+        encoders.retain(|encoder| encoder.queue.try_send(bytes.clone()).is_ok());
+    }
+}
+
+// Relève le prix en continu sur un thread à part, pour que le flux ne s'arrête pas pendant une requête ;
+// chaque prix arrive avec son heure de relevé, en secondes depuis `started`
+fn poll_prices(symbol: String, started: Instant) -> Receiver<(f64, f64)> {
+    let (sender, prices) = mpsc::channel();
+    std::thread::spawn(move || loop {
+        let price = runtime().and_then(|runtime| runtime.block_on(fetch_exchange_price_async(Exchange::Binance, &symbol)));
+        match price {
+            Ok(price) => {
+                if sender.send((started.elapsed().as_secs_f64(), price)).is_err() {
+                    return;
+                }
+                std::thread::sleep(Duration::from_secs_f64(POLL_SECONDS));
+            },
+            Err(e) => {
+                println!("{}", tr_args("log-radio-fetch-failed", &[("error", &e.to_string())]));
+                std::thread::sleep(Duration::from_secs(RETRY_SECONDS));
+            },
+        }
+    });
+    prices
+}
+
+// Source Icecast unique, reconnectée avec une attente croissante si ffmpeg ou le serveur la perd
+struct IcecastSource {
+    url: String,
+    backoff: Duration,
+    next_connect: Instant,
+    connected_at: Option<Instant>,
+}
+
+impl IcecastSource {
+    fn new(url: String) -> Self {
+        Self {
+            url,
+            backoff: Duration::from_secs(ICECAST_MIN_BACKOFF_SECONDS),
+            next_connect: Instant::now(),
+            connected_at: None,
+        }
+    }
+
+    fn keep_connected(&mut self, encoders: &Mutex<Vec<Encoder>>) {
+        let connected = encoders.lock().is_ok_and(|encoders| !encoders.is_empty());
+        if connected {
+            if self.connected_at.is_some_and(|at| at.elapsed() >= Duration::from_secs(ICECAST_STABLE_SECONDS)) {
+                self.backoff = Duration::from_secs(ICECAST_MIN_BACKOFF_SECONDS);
+            }
+            return;
+        }
+        if Instant::now() < self.next_connect {
+            return;
+        }
+        // Sans le mot de passe de la source
+        let server = self.url.rsplit('@').next().unwrap_or(&self.url);
+        println!("{}", tr_args("log-radio-icecast", &[("server", server)]));
+        self.next_connect = Instant::now() + self.backoff;
+        let retry = self.backoff.as_secs().to_string();
+        self.backoff = (self.backoff * 2).min(Duration::from_secs(ICECAST_MAX_BACKOFF_SECONDS));
+        match Encoder::spawn(&["-content_type", "audio/ogg", &self.url], Stdio::null()) {
+            Ok(encoder) => {
+                self.connected_at = Some(Instant::now());
+                if let Ok(mut encoders) = encoders.lock() {
+                    encoders.push(encoder);
+                }
+            },
+            Err(e) => println!("{}", tr_args("log-radio-icecast-failed", &[("error", &e.to_string()), ("seconds", &retry)])),
+        }
+    }
+}
+
+// Gain fixe de la normalisation, appliqué à toutes les barres : une barre sans variation, la plus
+// courante sur cinq secondes, sonne à la sonie visée sur toute sa durée. Les fortes variations restent
+// plus fortes que les petites, comme le veut leur vélocité, et le limiteur du flux tient leurs crêtes
+fn loudness_gain(sonifier: Sonifier, target: f64) -> f64 {
+    let bar = Bar { start: 0.0, open: REFERENCE_PRICE, high: REFERENCE_PRICE, low: REFERENCE_PRICE, close: REFERENCE_PRICE, ticks: 1 };
+    let notes = bar_voices(sonifier, REFERENCE_PRICE, &bar, BAR_LENGTH);
+    let mut samples = mix_notes(&notes, BAR_LENGTH.seconds());
+    normalize_loudness(&mut samples, AUDIO_SAMPLE_RATE, target)
+}
+
+// Radio crypto sans fenêtre, menée par le mode direct : le prix actuel sur Binance est regroupé en
+// barres, et chaque barre terminée joue une note, diffusée en temps réel
+pub fn radio(coin: &str, sonifier: Sonifier, loudness: Option<f64>, output: RadioOutput) -> Result<()> {
+    let symbol = spread_symbol(coin).ok_or_else(|| Error::NoData(coin.to_string()))?;
+    let encoders = Arc::new(Mutex::new(Vec::new()));
+    let mut icecast = match output {
+        RadioOutput::Http(port) => {
+            let listener = TcpListener::bind(("0.0.0.0", port))?;
+            let encoders = encoders.clone();
+            std::thread::spawn(move || accept_listeners(listener, encoders));
+            println!("{}", tr_args("log-radio-http", &[("port", &port.to_string())]));
+            None
+        },
+        RadioOutput::Icecast(url) => Some(IcecastSource::new(url)),
+    };
+    println!("{}", tr_args("log-radio-playing", &[
+        ("coin", &coin_display_name(coin)),
+        ("seconds", &BAR_LENGTH.seconds().to_string()),
+    ]));
+
+    let started = Instant::now();
+    let prices = poll_prices(symbol, started);
+    let mut aggregator = BarAggregator::new(BAR_LENGTH);
+    let mut previous_close: Option<f64> = None;
+    let gain = loudness.map_or(1.0, |target| loudness_gain(sonifier, target)) as f32;
+    // Dernier maillon, comme la lecture : garde son état d'un paquet à l'autre
+    let mut limiter = Limiter::new(AUDIO_SAMPLE_RATE, None);
+    // Son à venir : les notes des barres y sont mélangées, et chaque paquet en est retiré
+    let mut upcoming: VecDeque<f32> = VecDeque::new();
+    let chunk_len = (CHUNK_SECONDS * AUDIO_SAMPLE_RATE as f64) as usize;
+    for chunk_index in 1.. {
+        for (time, price) in prices.try_iter() {
+            let Some(bar) = aggregator.push(time, price) else {
+                continue;
+            };
+            // Mélodie et harmonie ; l'écho, la sous-basse et la cadence demandent une série de jours
+            let notes = previous_close.map(|previous| bar_voices(sonifier, previous, &bar, BAR_LENGTH)).unwrap_or_default();
+            previous_close = Some(bar.close);
+            if notes.is_empty() {
+                continue;
+            }
+            let duration = notes.iter().map(|note| note.start + note.duration).fold(0.0, f64::max);
+            let samples = mix_notes(&notes, duration);
+            if upcoming.len() < samples.len() {
+                upcoming.resize(samples.len(), 0.0);
+            }
+            for (slot, sample) in upcoming.iter_mut().zip(samples) {
+                *slot += sample * gain;
+            }
+        }
+
+        if let Some(icecast) = &mut icecast {
+            icecast.keep_connected(&encoders);
+        }
+        let end = upcoming.len().min(chunk_len);
+        let mut chunk: Vec<f32> = upcoming.drain(..end).collect();
+        chunk.resize(chunk_len, 0.0);
+        for sample in &mut chunk {
+            *sample = limiter.process(*sample);
+        }
+        broadcast(&encoders, &chunk);
+        let due = started + Duration::from_secs_f64(chunk_index as f64 * CHUNK_SECONDS);
+        std::thread::sleep(due.saturating_duration_since(Instant::now()));
+    }
+    Ok(())
+}
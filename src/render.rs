@@ -0,0 +1,53 @@
+use crate::audio::{self, Sonifier, INTRADAY_SPEED};
+use crate::data::{coin_display_name, fetch_chart_data};
+use crate::error::Result;
+use crate::i18n::tr_args;
+use crate::notify;
+use std::path::Path;
+
+// Fichiers écrits par `render` : l'audio, et éventuellement le MIDI et la liste des notes
+pub struct RenderOutputs<'a> {
+    pub audio: &'a Path,
+    pub midi: Option<&'a Path>,
+    pub notes: Option<&'a Path>,
+}
+
+// Sonification sans fenêtre : récupère les prix et écrit les fichiers demandés
+pub fn render(coin: &str, days: i64, currency: &str, sonifier: Sonifier, loudness: Option<f64>, outputs: RenderOutputs) -> Result<()> {
+    let out = outputs.audio;
+    println!("{}", tr_args("log-render-fetching", &[("coin", &coin_display_name(coin)), ("days", &days.to_string())]));
+    let data = fetch_chart_data(coin, days, currency)?;
+
+    // Même rythme que la lecture dans l'interface : une note par jour, ou dix par seconde sur 24 h
+    let sonifier = if data.is_intraday() { sonifier.with_speed(INTRADAY_SPEED) } else { sonifier };
+    let mut notes = sonifier.sonify(&data.daily_prices);
+    let rows = audio::note_rows(&data.daily_prices, &notes);
+    let harmony = sonifier.harmony(&data.daily_prices);
+    let echoes = sonifier.echoes(&notes);
+    notes.extend(harmony);
+    notes.extend(echoes);
+    notes.extend(sonifier.sub_bass(&data.daily_prices).into_iter().flatten());
+    notes.extend(sonifier.cadence(&data.daily_prices));
+    // Fin de la note qui se termine le plus tard, les voix ajoutées ne suivant pas l'ordre des pas
+    let duration = notes.iter().map(|note| note.start + note.duration).fold(0.0, f64::max);
+
+    audio::write_notes_wav(&notes, duration, loudness, out)?;
+    println!("{}", tr_args("log-render-audio", &[("count", &notes.len().to_string()), ("path", &out.display().to_string())]));
+
+    if let Some(midi) = outputs.midi {
+        audio::write_notes_midi(&notes, midi)?;
+        println!("{}", tr_args("log-render-midi", &[("path", &midi.display().to_string())]));
+    }
+
+    if let Some(note_list) = outputs.notes {
+        audio::write_note_list(&rows, note_list)?;
+        println!("{}", tr_args("log-render-notes", &[("path", &note_list.display().to_string())]));
+    }
+
+    // Un long rendu tourne souvent dans un terminal en arrière-plan : on prévient quand il est prêt
+    notify::notify_and_wait(
+        &tr_args("notification-render-done", &[("coin", &coin_display_name(coin)), ("days", &days.to_string())]),
+        &out.display().to_string(),
+    );
+    Ok(())
+}
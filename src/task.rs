@@ -0,0 +1,274 @@
+use crate::data::{ChartData, CoinId, DateRange, FetchStage, PriceProvider};
+use crate::error::{Error, Result};
+use crate::i18n::{tr, tr_args};
+use eframe::egui;
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::future::Future;
+use std::sync::{mpsc, Arc};
+use tokio::sync::mpsc::{unbounded_channel, UnboundedReceiver, UnboundedSender};
+
+// Jeton d'annulation partagé avec une tâche en arrière-plan : une fois levé, la tâche s'arrête
+// dès que possible et son résultat n'est jamais transmis
+#[derive(Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+
+    // Point d'arrêt entre deux étapes : Error::Cancelled une fois le jeton levé
+    pub fn check(&self) -> Result<()> {
+        if self.is_cancelled() {
+            Err(Error::Cancelled)
+        } else {
+            Ok(())
+        }
+    }
+}
+
+// Runtime tokio partagé par toutes les requêtes, démarré au premier besoin
+#[cfg(not(target_arch = "wasm32"))]
+pub fn runtime() -> Result<&'static tokio::runtime::Runtime> {
+    static RUNTIME: std::sync::OnceLock<tokio::runtime::Runtime> = std::sync::OnceLock::new();
+    if let Some(runtime) = RUNTIME.get() {
+        return Ok(runtime);
+    }
+    let runtime = tokio::runtime::Runtime::new()?;
+    Ok(RUNTIME.get_or_init(|| runtime))
+}
+
+// Travail en arrière-plan : un thread en natif, exécuté sur place dans le navigateur (pas de threads en WebAssembly)
+pub fn spawn_background<F: FnOnce() + Send + 'static>(work: F) {
+    #[cfg(not(target_arch = "wasm32"))]
+    std::thread::spawn(work);
+    #[cfg(target_arch = "wasm32")]
+    work();
+}
+
+// Requête ponctuelle hors FetchPipeline (liste des cryptos, classement...) : le résultat arrive par le canal renvoyé
+#[cfg(not(target_arch = "wasm32"))]
+pub fn spawn_request<T, F>(ctx: &egui::Context, request: F) -> mpsc::Receiver<Result<T>>
+where
+    T: Send + 'static,
+    F: Future<Output = Result<T>> + Send + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let ctx = ctx.clone();
+    let send = move |result| {
+        tx.send(result).ok();
+        ctx.request_repaint();
+    };
+    match runtime() {
+        Ok(runtime) => {
+            runtime.spawn(async move { send(request.await) });
+        },
+        Err(e) => send(Err(e)),
+    }
+    rx
+}
+
+#[cfg(target_arch = "wasm32")]
+pub fn spawn_request<T, F>(ctx: &egui::Context, request: F) -> mpsc::Receiver<Result<T>>
+where
+    T: 'static,
+    F: Future<Output = Result<T>> + 'static,
+{
+    let (tx, rx) = mpsc::channel();
+    let ctx = ctx.clone();
+    wasm_bindgen_futures::spawn_local(async move {
+        tx.send(request.await).ok();
+        ctx.request_repaint();
+    });
+    rx
+}
+
+// Identifiant d'une requête, unique au sein de son FetchPipeline
+pub type RequestId = u64;
+
+pub struct FetchRequest {
+    pub provider: Arc<dyn PriceProvider>,
+    pub coin: CoinId,
+    pub days: i64,
+    pub currency: String,
+    // Période fixe (recette) à la place des `days` derniers jours
+    pub range: Option<DateRange>,
+}
+
+// Messages envoyés par les tâches de chargement, lus dans update
+pub enum FetchEvent {
+    Progress { id: RequestId, stage: FetchStage, message: String },
+    Done { id: RequestId, coin: CoinId, result: Result<ChartData> },
+}
+
+// Requête en cours : son jeton, relevé par la source entre ses étapes et avant chaque nouvelle
+// tentative, et en natif la tâche tokio interrompue à l'annulation
+struct PendingFetch {
+    cancel: CancelToken,
+    #[cfg(not(target_arch = "wasm32"))]
+    task: Option<tokio::task::JoinHandle<()>>,
+}
+
+impl PendingFetch {
+    fn cancel(self) {
+        self.cancel.cancel();
+        #[cfg(not(target_arch = "wasm32"))]
+        if let Some(task) = self.task {
+            task.abort();
+        }
+    }
+}
+
+// Chargements concurrents sur des tâches tokio (spawn_local dans le navigateur) ; les réponses
+// arrivent par un canal non borné et celles des requêtes annulées sont écartées
+pub struct FetchPipeline {
+    next_id: RequestId,
+    pending: HashMap<RequestId, PendingFetch>,
+    sender: UnboundedSender<FetchEvent>,
+    receiver: UnboundedReceiver<FetchEvent>,
+}
+
+impl FetchPipeline {
+    pub fn new() -> Self {
+        let (sender, receiver) = unbounded_channel();
+        Self {
+            next_id: 0,
+            pending: HashMap::new(),
+            sender,
+            receiver,
+        }
+    }
+
+    pub fn fetch(&mut self, ctx: &egui::Context, request: FetchRequest) -> RequestId {
+        let id = self.next_id;
+        self.next_id += 1;
+        let cancel = CancelToken::new();
+        let token = cancel.clone();
+
+        let sender = self.sender.clone();
+        let ctx = ctx.clone();
+        let send = {
+            let cancel = cancel.clone();
+            move |event: FetchEvent| {
+                if !cancel.is_cancelled() {
+                    sender.send(event).ok();
+                    ctx.request_repaint();
+                }
+            }
+        };
+        send(FetchEvent::Progress {
+            id,
+            stage: FetchStage::Requesting,
+            message: tr_args(FetchStage::Requesting.label_key(), &[("source", &request.provider.name())]),
+        });
+        // Étapes suivantes, signalées par la source puis par la tâche une fois la série prête
+        let progress = {
+            let send = send.clone();
+            move |stage: FetchStage| send(FetchEvent::Progress { id, stage, message: tr(stage.label_key()).to_string() })
+        };
+
+        // Interrompre la tâche tokio abandonne l'attente, mais pas le thread bloquant de la source : elle
+        // reçoit le jeton pour s'arrêter d'elle-même avant sa prochaine étape
+        #[cfg(not(target_arch = "wasm32"))]
+        let task = match runtime() {
+            Ok(runtime) => Some(runtime.spawn(async move {
+                let FetchRequest { provider, coin, days, currency, range } = request;
+                let fetch_coin = coin.clone();
+                // Les sources sont synchrones (plugins, séries synthétiques) : on les isole du pool async
+                let source_progress = progress.clone();
+                let fetch = tokio::task::spawn_blocking(move || {
+                    crate::profile_scope!("fetch_chart_data");
+                    match range {
+                        Some(range) => provider.fetch_chart_range_reporting(&fetch_coin, range, &currency, &source_progress, &token),
+                        None => provider.fetch_chart_data_reporting(&fetch_coin, days, &currency, &source_progress, &token),
+                    }
+                });
+                let result = fetch.await.unwrap_or_else(|e| Err(Error::Task(e.to_string())));
+                if result.is_ok() {
+                    progress(FetchStage::Ready);
+                }
+                send(FetchEvent::Done { id, coin, result });
+            })),
+            Err(e) => {
+                send(FetchEvent::Done { id, coin: request.coin, result: Err(e) });
+                None
+            },
+        };
+        #[cfg(not(target_arch = "wasm32"))]
+        self.pending.insert(id, PendingFetch { cancel, task });
+
+        #[cfg(target_arch = "wasm32")]
+        self.pending.insert(id, PendingFetch { cancel });
+        #[cfg(target_arch = "wasm32")]
+        wasm_bindgen_futures::spawn_local(async move {
+            // Annulée avant même d'avoir démarré : rien à demander
+            if token.is_cancelled() {
+                return;
+            }
+            let FetchRequest { provider, coin, days, currency, range } = request;
+            let days = range.map_or(days, |range| range.days_back());
+            let result = provider.fetch_chart_data_async(&coin, days, &currency).await;
+            let result = match range {
+                Some(range) => result.and_then(|data| range.trim(data)),
+                None => result,
+            };
+            if result.is_ok() {
+                progress(FetchStage::Ready);
+            }
+            send(FetchEvent::Done { id, coin, result });
+        });
+
+        id
+    }
+
+    pub fn is_pending(&self, id: RequestId) -> bool {
+        self.pending.contains_key(&id)
+    }
+
+    // La source s'arrête avant sa prochaine étape ou nouvelle tentative, et sa réponse ne sera jamais lue
+    pub fn cancel(&mut self, id: RequestId) {
+        if let Some(pending) = self.pending.remove(&id) {
+            pending.cancel();
+        }
+    }
+
+    pub fn cancel_all(&mut self) {
+        for (_, pending) in self.pending.drain() {
+            pending.cancel();
+        }
+    }
+
+    // Messages arrivés depuis le dernier appel, sans ceux des requêtes annulées
+    pub fn poll(&mut self) -> Vec<FetchEvent> {
+        let mut events = Vec::new();
+        while let Ok(event) = self.receiver.try_recv() {
+            match &event {
+                FetchEvent::Progress { id, .. } if self.pending.contains_key(id) => events.push(event),
+                FetchEvent::Done { id, .. } if self.pending.remove(id).is_some() => events.push(event),
+                _ => {},
+            }
+        }
+        events
+    }
+}
+
+impl Default for FetchPipeline {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Drop for FetchPipeline {
+    fn drop(&mut self) {
+        self.cancel_all();
+    }
+}
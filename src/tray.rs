@@ -0,0 +1,131 @@
+// Icône de la zone de notification (fonctionnalité `tray`) : un menu pour sonifier une crypto suivie,
+// ouvrir le tableau de bord ou cacher la fenêtre pendant que le mode direct continue de jouer
+use crate::app::Settings;
+use crate::data::{coin_meta, CoinId};
+use crate::error::{Error, Result};
+use crate::i18n::{tr, tr_args};
+use eframe::egui;
+use std::sync::mpsc;
+use tray_icon::menu::{Menu, MenuEvent, MenuItem, PredefinedMenuItem};
+use tray_icon::{Icon, TrayIcon, TrayIconBuilder};
+
+const ICON_SIZE: u32 = 32;
+
+#[derive(Debug, Clone, PartialEq)]
+pub enum TrayCommand {
+    Sonify(CoinId),
+    OpenDashboard,
+    ShowWindow,
+    HideWindow,
+    Quit,
+}
+
+// Les choix du menu arrivent par un canal et réveillent l'interface, même fenêtre cachée
+pub struct Tray {
+    commands: Vec<TrayCommand>,
+    receiver: mpsc::Receiver<usize>,
+    // Absente sous Linux : elle vit dans le fil GTK qui fait tourner son menu
+    _icon: Option<TrayIcon>,
+}
+
+impl Tray {
+    // Menu figé au lancement : les cryptos suivies à cet instant, sur la période des préférences
+    pub fn new(ctx: &egui::Context, watchlist: &[CoinId], days: i64) -> Result<Self> {
+        let range = Settings::range_label(days);
+        let mut entries: Vec<(String, TrayCommand)> = watchlist
+            .iter()
+            .map(|coin| {
+                let symbol = coin_meta(coin).map_or(coin.as_str(), |meta| meta.symbol);
+                (tr_args("tray-sonify", &[("coin", symbol), ("range", range.as_str())]), TrayCommand::Sonify(coin.clone()))
+            })
+            .collect();
+        entries.extend([
+            (tr("tray-dashboard").to_string(), TrayCommand::OpenDashboard),
+            (tr("tray-show").to_string(), TrayCommand::ShowWindow),
+            (tr("tray-hide").to_string(), TrayCommand::HideWindow),
+            (tr("tray-quit").to_string(), TrayCommand::Quit),
+        ]);
+        let labels: Vec<String> = entries.iter().map(|(label, _)| label.clone()).collect();
+        let commands = entries.into_iter().map(|(_, command)| command).collect();
+        let separator = watchlist.len();
+
+        let (sender, receiver) = mpsc::channel();
+        let ctx = ctx.clone();
+        MenuEvent::set_event_handler(Some(move |event: MenuEvent| {
+            if let Ok(index) = event.id.0.parse() {
+                let _ = sender.send(index);
+                ctx.request_repaint();
+            }
+        }));
+
+        Ok(Self { commands, receiver, _icon: start_icon(labels, separator)? })
+    }
+
+    pub fn poll(&self) -> Option<TrayCommand> {
+        let index = self.receiver.try_recv().ok()?;
+        self.commands.get(index).cloned()
+    }
+}
+
+// GTK doit tourner dans son propre fil, que la boucle d'événements de la fenêtre n'anime pas
+#[cfg(target_os = "linux")]
+fn start_icon(labels: Vec<String>, separator: usize) -> Result<Option<TrayIcon>> {
+    let (ready, started) = mpsc::channel();
+    std::thread::spawn(move || {
+        if let Err(e) = gtk::init() {
+            let _ = ready.send(Err(tray_error(e)));
+            return;
+        }
+        match build_icon(&labels, separator) {
+            Ok(_icon) => {
+                let _ = ready.send(Ok(()));
+                gtk::main();
+            },
+            Err(e) => {
+                let _ = ready.send(Err(e));
+            },
+        }
+    });
+    started.recv().map_err(tray_error)??;
+    Ok(None)
+}
+
+// Ailleurs, l'icône est créée dans le fil de la fenêtre, dont la boucle d'événements anime le menu
+#[cfg(not(target_os = "linux"))]
+fn start_icon(labels: Vec<String>, separator: usize) -> Result<Option<TrayIcon>> {
+    build_icon(&labels, separator).map(Some)
+}
+
+// Les entrées sont identifiées par leur position, pour retrouver la commande hors du fil du menu
+fn build_icon(labels: &[String], separator: usize) -> Result<TrayIcon> {
+    let menu = Menu::new();
+    for (index, label) in labels.iter().enumerate() {
+        if index == separator && index > 0 {
+            menu.append(&PredefinedMenuItem::separator()).map_err(tray_error)?;
+        }
+        menu.append(&MenuItem::with_id(index.to_string(), label, true, None)).map_err(tray_error)?;
+    }
+    TrayIconBuilder::new()
+        .with_menu(Box::new(menu))
+        .with_tooltip(tr("app-title"))
+        .with_icon(icon()?)
+        .build()
+        .map_err(tray_error)
+}
+
+// Disque doré, la couleur du titre de l'application, au bord adouci
+fn icon() -> Result<Icon> {
+    let center = (ICON_SIZE as f32 - 1.0) / 2.0;
+    let rgba = (0..ICON_SIZE * ICON_SIZE)
+        .flat_map(|i| {
+            let (x, y) = ((i % ICON_SIZE) as f32 - center, (i / ICON_SIZE) as f32 - center);
+            let alpha = ((center - x.hypot(y)).clamp(0.0, 1.0) * 255.0) as u8;
+            [255, 215, 0, alpha]
+        })
+        .collect();
+    Icon::from_rgba(rgba, ICON_SIZE, ICON_SIZE).map_err(tray_error)
+}
+
+fn tray_error(error: impl std::fmt::Display) -> Error {
+    Error::Tray(error.to_string())
+}
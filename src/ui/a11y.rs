@@ -0,0 +1,31 @@
+// Accessibilité : egui expose ses widgets aux lecteurs d'écran via AccessKit, mais les boutons-icônes,
+// les images cliquables et les zones dessinées à la main n'ont pas de nom lisible sans ces aides
+use eframe::egui::{self, accesskit, WidgetInfo, WidgetType};
+
+// Nom lu par les lecteurs d'écran à la place du texte affiché (icône seule, image, case dessinée)
+pub fn accessible_name(response: egui::Response, typ: WidgetType, name: &str) -> egui::Response {
+    response.widget_info(|| WidgetInfo::labeled(typ, name));
+    response
+}
+
+// Texte lu automatiquement à chaque changement, sans que l'élément ait le focus (région « live »)
+pub fn announce(ui: &egui::Ui, response: &egui::Response, text: &str) {
+    response.widget_info(|| WidgetInfo::labeled(WidgetType::Label, text));
+    ui.ctx().accesskit_node_builder(response.id, |builder| builder.set_live(accesskit::Live::Polite));
+}
+
+// Raccourci de page, ignoré tant qu'un widget a le focus (Échap le relâche) pour ne pas voler la
+// touche à un champ de saisie ou au bouton sélectionné
+pub fn shortcut_pressed(ctx: &egui::Context, key: egui::Key) -> bool {
+    !ctx.wants_keyboard_input() && ctx.input(|i| i.key_pressed(key))
+}
+
+// Contour du focus clavier pour les widgets sans cadre ou dessinés à la main ; les autres le
+// reçoivent du style « active » (voir Theme::visuals)
+pub fn focus_outline(response: egui::Response) -> egui::Response {
+    if response.has_focus() {
+        let stroke = response.ctx.style().visuals.widgets.active.bg_stroke;
+        response.ctx.layer_painter(response.layer_id).rect_stroke(response.rect.expand(2.0), 4.0, stroke);
+    }
+    response
+}
@@ -0,0 +1,116 @@
+use super::a11y::{focus_outline, shortcut_pressed};
+use super::widgets::{secondary_text_color, sparkline, ChartPalette};
+use crate::data::{coin_accent, format_compact, format_price, fetch_top_coins_async, CoinId, MarketCoin, TOP_COINS};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui::{self, Color32};
+use std::sync::mpsc;
+
+const SPARKLINE_SIZE: egui::Vec2 = egui::vec2(120.0, 28.0);
+
+// Les 100 plus grosses capitalisations de CoinGecko ; un clic sur une ligne lance sa sonification
+pub struct BrowsePage {
+    currency: String,
+    request: Option<mpsc::Receiver<Result<Vec<MarketCoin>>>>,
+    coins: Vec<MarketCoin>,
+    error: Option<String>,
+    pub should_return_home: bool,
+}
+
+impl BrowsePage {
+    pub fn new(currency: &str) -> Self {
+        Self {
+            currency: currency.to_string(),
+            request: None,
+            coins: Vec::new(),
+            error: None,
+            should_return_home: false,
+        }
+    }
+
+    fn receive_coins(&mut self, ctx: &egui::Context) {
+        if self.coins.is_empty() && self.error.is_none() && self.request.is_none() {
+            let currency = self.currency.clone();
+            self.request = Some(spawn_request(ctx, async move { fetch_top_coins_async(&currency, TOP_COINS).await }));
+        }
+        let Some(request) = &self.request else {
+            return;
+        };
+        if let Ok(result) = request.try_recv() {
+            self.request = None;
+            match result {
+                Ok(coins) => self.coins = coins,
+                Err(e) => self.error = Some(e.to_string()),
+            }
+        }
+    }
+
+    // Renvoie l'identifiant et le nom de la crypto cliquée
+    pub fn update(&mut self, ctx: &egui::Context) -> Option<(CoinId, String)> {
+        crate::profile_function!();
+        self.receive_coins(ctx);
+
+        let mut chosen = None;
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button(
+                    egui::RichText::new(tr("back-home"))
+                        .size(16.0)
+                        .color(Color32::from_rgb(255, 215, 0))
+                ).clicked() || shortcut_pressed(ctx, egui::Key::Backspace) {
+                    self.should_return_home = true;
+                }
+                ui.separator();
+                ui.heading(tr_args("browse-title", &[("count", &TOP_COINS.to_string())]));
+            });
+            ui.add_space(8.0);
+
+            if let Some(error) = &self.error {
+                ui.colored_label(Color32::from_rgb(255, 88, 88), error);
+                if ui.button(tr("retry")).clicked() {
+                    self.error = None;
+                }
+                return;
+            }
+            if self.coins.is_empty() {
+                ui.label(tr("browse-loading"));
+                return;
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                egui::Grid::new("top_coins").striped(true).spacing([16.0, 6.0]).show(ui, |ui| {
+                    for header in ["#", tr("browse-coin"), tr("browse-price"), tr("browse-change"), tr("browse-market-cap"), tr("browse-7d")] {
+                        ui.label(egui::RichText::new(header).strong());
+                    }
+                    ui.end_row();
+
+                    for coin in &self.coins {
+                        ui.label(egui::RichText::new(coin.market_cap_rank.map_or("-".to_string(), |rank| rank.to_string()))
+                            .color(secondary_text_color(ui)));
+                        let name = egui::RichText::new(format!("{} ({})", coin.name, coin.symbol.to_uppercase()))
+                            .strong()
+                            .color(coin_accent(&coin.id));
+                        if focus_outline(ui.add(egui::Button::new(name).frame(false))).on_hover_text(tr("browse-hint")).clicked() {
+                            chosen = Some((coin.id.clone(), coin.name.clone()));
+                        }
+                        ui.label(coin.current_price.map_or("-".to_string(), |price| format_price(price, &self.currency)));
+                        match coin.price_change_percentage_24h {
+                            Some(change) => {
+                                let color = ChartPalette::current().change_color(change);
+                                ui.colored_label(color, format!("{:+.2}%", change));
+                            },
+                            None => {
+                                ui.label("-");
+                            },
+                        }
+                        ui.label(coin.market_cap.map_or("-".to_string(), |cap| format_compact(cap, &self.currency)));
+                        sparkline(ui, coin.sparkline_in_7d.as_ref().map_or(&[], |sparkline| &sparkline.price), SPARKLINE_SIZE);
+                        ui.end_row();
+                    }
+                });
+            });
+        });
+        chosen
+    }
+}
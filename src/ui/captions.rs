@@ -0,0 +1,55 @@
+use crate::audio::{note_name, NoteEvent};
+use crate::data::DailyPrice;
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use std::path::Path;
+
+// En dessous, deux notes sont jouées au même volume
+const VELOCITY_TOLERANCE: f32 = 0.02;
+
+// Sous-titre d'un pas de la lecture, pour suivre la sonification sans l'entendre :
+// « Day 14: +3.1%, note C5, louder »
+pub fn step_caption(day: usize, price_change: f64, note: &NoteEvent, previous: Option<&NoteEvent>) -> String {
+    let notes = note.frequencies.iter().map(|frequency| note_name(*frequency)).collect::<Vec<_>>().join(" ");
+    let loudness = match previous.map(|previous| note.velocity - previous.velocity) {
+        Some(delta) if delta > VELOCITY_TOLERANCE => "caption-louder",
+        Some(delta) if delta < -VELOCITY_TOLERANCE => "caption-softer",
+        _ => "caption-steady",
+    };
+    tr_args("caption-step", &[
+        ("day", day.to_string()),
+        ("change", format!("{:+.1}%", price_change)),
+        ("note", notes),
+        ("loudness", tr(loudness).to_string()),
+    ])
+}
+
+// Ligne du journal des notes, compacte et sans traduction : « 14/03  +2.3%  E5  0.8s »
+pub fn note_log_line(day: &DailyPrice, price_change: f64, note: &NoteEvent) -> String {
+    let notes = note.frequencies.iter().map(|frequency| note_name(*frequency)).collect::<Vec<_>>().join(" ");
+    format!("{}  {:+.1}%  {}  {:.1}s", day.short_label(), price_change, notes, note.duration)
+}
+
+// Horodatage SRT : 00:01:02,345
+fn srt_timestamp(seconds: f64) -> String {
+    let millis = (seconds.max(0.0) * 1000.0).round() as u64;
+    format!("{:02}:{:02}:{:02},{:03}", millis / 3_600_000, millis / 60_000 % 60, millis / 1000 % 60, millis % 1000)
+}
+
+// Sous-titres au format SRT ; chacun reste affiché jusqu'au suivant, le dernier jusqu'à `end`
+pub fn captions_to_srt(captions: &[(f64, String)], end: f64) -> String {
+    captions
+        .iter()
+        .enumerate()
+        .map(|(i, (start, text))| {
+            let until = captions.get(i + 1).map_or(end, |(next, _)| *next).max(*start);
+            format!("{}\n{} --> {}\n{}\n", i + 1, srt_timestamp(*start), srt_timestamp(until), text)
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+pub fn write_captions_srt(captions: &[(f64, String)], end: f64, path: &Path) -> Result<()> {
+    std::fs::write(path, captions_to_srt(captions, end))?;
+    Ok(())
+}
@@ -0,0 +1,1716 @@
+use super::a11y::{accessible_name, announce, focus_outline, shortcut_pressed};
+use super::captions::{note_log_line, step_caption};
+use super::export::{captured_width, save_chart_png, PNG_WIDTHS, save_note_list, RecordingEvent, RecordingFormat, SessionRecorder};
+use super::live::LiveLayer;
+use super::fundamentals::FundamentalsBand;
+use super::news::NewsPanel;
+use super::orderbook::OrderBookLayer;
+use super::spread::SpreadLayer;
+use super::recipe::{save_recipe, Recipe};
+use super::sentiment::SentimentVoice;
+use super::session::{save_session, SavedSession};
+use super::textures::{
+    decode_reaction_image, placeholder_texture, AnimatedTexture, DecodedImage, ImageSequencer, TextureCache,
+    visual_seed,
+};
+use super::widgets::{reduce_motion, secondary_text_color, AnimatedImage, ChartPalette, ParticleSystem};
+use crate::audio::{
+    self, preview_voice, AudioCommand, AudioEngine, Ending, EngineEvent, EngineOutput, HarmonyInterval, LimiterMeter, NoteEvent,
+    NoteLength, OutputOpener, RodioOutput, Scale, SoundOptions, SoundPack, Sonifier, StepScheduler, ViewMode, AUDIBLE_REDUCTION_DB,
+    INTRADAY_SPEED,
+};
+use crate::audio::sonifier::{MAX_FREQUENCY, MIN_FREQUENCY};
+use crate::data::{
+    coin_accent, coin_display_name, event_markers, format_price, load_events, order_book_symbol, spread_symbol, AssetPack,
+    weekend_runs, ChartData, DailyPrice, DateRange, PriceStats, PORTFOLIO_ID,
+};
+use crate::i18n::{tr, tr_args};
+use crate::notify;
+use crate::task::spawn_background;
+use chrono::{Datelike, Duration};
+use eframe::egui::{self, Color32, Image};
+use egui_plot::{Line, LineStyle, Plot, PlotBounds, PlotPoints, Polygon};
+use serde::{Deserialize, Serialize};
+use std::collections::VecDeque;
+use std::path::PathBuf;
+use std::sync::{mpsc, Arc};
+use std::time::Duration as StdDuration;
+
+const MINIMAP_HEIGHT: f32 = 50.0;
+const REACTION_IMAGE_SIZE: [f32; 2] = [400.0, 400.0];
+// Au-delà, une frame est considérée comme une reprise après une pause de rendu
+const MAX_FRAME_SECONDS: f32 = 0.1;
+// Intervalle de vérification de la fin d'une note ou du décodage des images
+const POLL_SECONDS: f64 = 0.05;
+// Durée d'apparition du point d'un nouveau jour
+const POINT_SECONDS: f32 = 0.5;
+// Durée d'allumage du voyant du limiteur après un déclenchement
+const LIMITER_HOLD_SECONDS: f64 = 0.5;
+// Journal des notes : lignes gardées (les plus anciennes disparaissent) et hauteur du panneau
+const NOTE_LOG_LINES: usize = 500;
+const NOTE_LOG_HEIGHT: f32 = 90.0;
+// Temps de pause du pointeur sur un jour avant son aperçu sonore, pour ne pas jouer chaque jour balayé
+const HOVER_PREVIEW_DELAY: f64 = 0.15;
+
+// Image décodée en arrière-plan : (taureau ?, index dans le pack, chemin, résultat)
+type DecodedReaction = (bool, usize, PathBuf, std::result::Result<DecodedImage, String>);
+
+// Garde le min et le max de chaque paquet de points visibles pour les longues séries
+fn decimate_min_max(points: &[[f64; 2]], x_range: (f64, f64), max_buckets: usize) -> Vec<[f64; 2]> {
+    // Un point de marge de chaque côté pour que les lignes sortent proprement de la vue
+    let start = points.iter().position(|p| p[0] >= x_range.0).unwrap_or(points.len()).saturating_sub(1);
+    let end = points.iter().rposition(|p| p[0] <= x_range.1).map_or(0, |i| (i + 2).min(points.len()));
+    if start >= end {
+        return Vec::new();
+    }
+    let visible = &points[start..end];
+    if visible.len() <= max_buckets.max(1) * 2 {
+        return visible.to_vec();
+    }
+
+    let bucket_size = visible.len().div_ceil(max_buckets);
+    let mut decimated = Vec::with_capacity(max_buckets * 2);
+    for bucket in visible.chunks(bucket_size) {
+        let min = bucket.iter().min_by(|a, b| a[1].total_cmp(&b[1])).unwrap();
+        let max = bucket.iter().max_by(|a, b| a[1].total_cmp(&b[1])).unwrap();
+        // On conserve l'ordre chronologique des deux extrêmes
+        if min[0] <= max[0] {
+            decimated.push(*min);
+            if max[0] != min[0] {
+                decimated.push(*max);
+            }
+        } else {
+            decimated.push(*max);
+            decimated.push(*min);
+        }
+    }
+    decimated
+}
+
+type Polylines = Vec<Vec<[f64; 2]>>;
+
+// Regroupe les segments consécutifs de même sens en polylignes (vertes en hausse, rouges en baisse)
+fn colored_runs(points: &[[f64; 2]]) -> (Polylines, Polylines) {
+    let mut green_runs = Vec::new();
+    let mut red_runs = Vec::new();
+    let mut run: Vec<[f64; 2]> = Vec::new();
+    let mut run_is_up = true;
+
+    for window in points.windows(2) {
+        let is_up = window[0][1] <= window[1][1];
+        if !run.is_empty() && is_up != run_is_up {
+            let finished = std::mem::take(&mut run);
+            if run_is_up { green_runs.push(finished) } else { red_runs.push(finished) }
+        }
+        if run.is_empty() {
+            run.push(window[0]);
+            run_is_up = is_up;
+        }
+        run.push(window[1]);
+    }
+    if !run.is_empty() {
+        if run_is_up { green_runs.push(run) } else { red_runs.push(run) }
+    }
+    (green_runs, red_runs)
+}
+
+// Série affichée et polylignes déjà découpées, recalculées seulement quand la lecture ou la vue change
+struct PlotCache {
+    view_mode: ViewMode,
+    values: Arc<[f64]>,
+    // Position de lecture, vue horizontale et nombre de paquets ayant servi au découpage
+    segments_key: Option<(usize, (f64, f64), usize)>,
+    points: Vec<[f64; 2]>,
+    green_segments: Polylines,
+    red_segments: Polylines,
+}
+
+// Voix de toute la série, calculées une fois pour un réglage de son et une vitesse donnés : chaque pas
+// y prend les siennes au lieu de tout resonifier
+struct VoiceCache {
+    sonifier: Sonifier,
+    with_sentiment: bool,
+    notes: Vec<NoteEvent>,
+    harmony: Vec<NoteEvent>,
+    echoes: Vec<NoteEvent>,
+    sub_bass: Vec<Option<NoteEvent>>,
+    sentiment: Vec<Option<NoteEvent>>,
+    cadence: Option<NoteEvent>,
+}
+
+#[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum Visualization {
+    Chart,
+    Calendar,
+}
+
+pub struct ChartApp {
+    coin: String,
+    currency: String,
+    accent: Color32,
+    latest_price: f64,
+    change_24h: f64,
+    // Partagée avec les formateurs du graphique sans recopie à chaque frame
+    daily_prices: Arc<[DailyPrice]>,
+    plot_cache: Option<PlotCache>,
+    voice_cache: Option<VoiceCache>,
+    view_mode: ViewMode,
+    visualization: Visualization,
+    view_x: (f64, f64),
+    pending_view: Option<(f64, f64)>,
+    stats: Option<PriceStats>,
+    current_index: usize,
+    // Notes de la lecture, jouées sur le thread du moteur ; audio_error donne la raison d'un mode muet
+    audio: AudioEngine,
+    audio_error: Option<String>,
+    // Échec de la copie du son sur un deuxième périphérique (câble virtuel pour le streaming)
+    mirror_device: Option<String>,
+    mirror_error: Option<String>,
+    // Réduction de gain du limiteur en sortie, relevée à chaque frame pour son voyant
+    limiter: LimiterMeter,
+    limiter_lit_until: f64,
+    // Fin de la note en cours (horloge egui) et gain des couches de fond, atténuées pour la laisser passer
+    note_until: f64,
+    layer_gain: f32,
+    // Échéances des pas de lecture (horloge egui), None à l'arrêt
+    scheduler: Option<StepScheduler>,
+    // Premier pas dont l'audio n'est pas encore dans les sinks
+    queued_until: usize,
+    // Multiplicateur de vitesse de lecture (2.0 = deux fois plus rapide)
+    speed: f64,
+    playing: bool,
+    volume: f32,
+    bull_textures: Vec<Option<AnimatedTexture>>,
+    bear_textures: Vec<Option<AnimatedTexture>>,
+    image_receiver: mpsc::Receiver<DecodedReaction>,
+    texture_cache: TextureCache,
+    image_errors: Vec<String>,
+    current_texture_index: usize,
+    image_animation: AnimatedImage,
+    point_progress: f32,
+    // Échéance du dernier pas joué, dont découle l'apparition du point plutôt que du nombre de frames ;
+    // None la fait partir de la prochaine frame
+    step_started_at: Option<f64>,
+    pub should_return_home: bool,
+    // Demande d'ouverture dans une fenêtre à part, traitée par MainApp
+    pub should_pop_out: bool,
+    // Demande de passage au bandeau compact, traitée par MainApp
+    pub should_open_ticker: bool,
+    // Affiché dans sa propre fenêtre : le retour ramène le graphique dans la fenêtre principale
+    popped_out: bool,
+    // Ordre des images et tirages des particules, les mêmes pour une crypto et une période données
+    image_sequencer: ImageSequencer,
+    presentation_mode: bool,
+    plot_rect: Option<egui::Rect>,
+    // Largeur de l'image exportée ; sans largeur, celle de la capture
+    export_width: Option<u32>,
+    export_requested: bool,
+    export_status: Option<String>,
+    particles: ParticleSystem,
+    recording_format: RecordingFormat,
+    recorder: Option<SessionRecorder>,
+    // Enregistrement terminé en cours d'encodage
+    encoding: Option<mpsc::Receiver<RecordingEvent>>,
+    // Sonie visée de la bande son des vidéos exportées, en LUFS
+    export_loudness: Option<f64>,
+    // Panneau des actualités, None quand il est fermé
+    news: Option<NewsPanel>,
+    // Chiffres clés de la crypto (aucun pour un portefeuille)
+    fundamentals: Option<FundamentalsBand>,
+    // Événements du calendrier crypto dans la période : indice du jour et titre
+    events: Vec<(usize, String)>,
+    show_events: bool,
+    // Journal des notes jouées, une ligne par pas ; il sert aussi de transcription lisible
+    note_log: VecDeque<String>,
+    show_note_log: bool,
+    // Aperçu sonore du jour survolé, à l'arrêt : jour sous le pointeur avec l'instant où il y est
+    // arrivé, et dernier jour joué
+    hover_preview: bool,
+    hovered_day: Option<(usize, f64)>,
+    previewed_day: Option<usize>,
+    // Voix du sentiment du marché, None quand elle est coupée
+    sentiment: Option<SentimentVoice>,
+    // Texture du carnet d'ordres actuel, None quand elle est coupée
+    order_book: Option<OrderBookLayer>,
+    spread: Option<SpreadLayer>,
+    live: Option<LiveLayer>,
+    // Mode hybride : le direct prend le relais à la fin de la lecture, une seule fois par lecture
+    continue_live: bool,
+    live_continued: bool,
+    // Sous-titres décrivant chaque note, pour suivre la lecture sans le son
+    captions: bool,
+    sound: SoundOptions,
+    // Échantillons joués avec (ou à la place de) la note de chaque jour
+    sound_pack: Option<Arc<SoundPack>>,
+    caption: Option<String>,
+}
+
+impl ChartApp {
+    pub fn new_from_data(
+        data: ChartData,
+        asset_pack: AssetPack,
+        view_mode: ViewMode,
+        volume: f32,
+        speed: f64,
+        playing: bool,
+        texture_cache: TextureCache,
+    ) -> Self {
+        // Sans sortie audio (CI, certaines VM), le graphique s'ouvre quand même en mode muet : le moteur
+        // le signale par un EngineEvent, relevé dans receive_audio_events
+        let limiter = LimiterMeter::new();
+        let open: OutputOpener = Box::new(move || Ok(Box::new(RodioOutput::new(audio::open_sound_output(volume)?)) as Box<dyn EngineOutput>));
+        let audio = AudioEngine::spawn(open, volume, limiter.clone());
+
+        let events = event_markers(&load_events(), &data.coin, &data.daily_prices);
+        let visual_seed = visual_seed(&data.coin, &data.daily_prices);
+        let fundamentals = (data.coin != PORTFOLIO_ID).then(|| FundamentalsBand::new(&data.coin, &data.currency));
+
+        Self {
+            accent: coin_accent(&data.coin),
+            coin: data.coin,
+            currency: data.currency,
+            latest_price: data.latest_price,
+            change_24h: data.change_24h,
+            stats: PriceStats::compute(&data.daily_prices),
+            daily_prices: data.daily_prices.into(),
+            plot_cache: None,
+            voice_cache: None,
+            view_mode,
+            visualization: Visualization::Chart,
+            view_x: (f64::NEG_INFINITY, f64::INFINITY),
+            pending_view: None,
+            current_index: 0,
+            audio,
+            audio_error: None,
+            mirror_device: None,
+            mirror_error: None,
+            limiter,
+            limiter_lit_until: f64::NEG_INFINITY,
+            note_until: f64::NEG_INFINITY,
+            layer_gain: 1.0,
+            scheduler: None,
+            queued_until: 0,
+            speed,
+            playing,
+            volume,
+            bull_textures: asset_pack.bull_images.iter().map(|r| texture_cache.get(&r.path)).collect(),
+            bear_textures: asset_pack.bear_images.iter().map(|r| texture_cache.get(&r.path)).collect(),
+            image_receiver: Self::spawn_image_decoding(&asset_pack, &texture_cache),
+            texture_cache,
+            image_errors: Vec::new(),
+            current_texture_index: 0,
+            image_animation: AnimatedImage::new(),
+            point_progress: 0.0,
+            step_started_at: None,
+            should_return_home: false,
+            should_pop_out: false,
+            should_open_ticker: false,
+            popped_out: false,
+            image_sequencer: ImageSequencer::new(asset_pack.bull_images.len(), asset_pack.bear_images.len(), visual_seed),
+            presentation_mode: false,
+            plot_rect: None,
+            export_width: None,
+            export_requested: false,
+            export_status: None,
+            particles: ParticleSystem::new(visual_seed),
+            recording_format: RecordingFormat::Gif,
+            recorder: None,
+            encoding: None,
+            export_loudness: None,
+            news: None,
+            fundamentals,
+            events,
+            show_events: true,
+            note_log: VecDeque::new(),
+            show_note_log: false,
+            hover_preview: false,
+            hovered_day: None,
+            previewed_day: None,
+            sentiment: None,
+            order_book: None,
+            spread: None,
+            live: None,
+            continue_live: false,
+            live_continued: false,
+            captions: false,
+            sound: SoundOptions::default(),
+            sound_pack: None,
+            caption: None,
+        }
+    }
+
+    // Rouvre une session sauvegardée, en pause à la position enregistrée
+    pub fn from_session(session: SavedSession, asset_pack: AssetPack, texture_cache: TextureCache) -> Self {
+        let mut chart = Self::new_from_data(
+            session.data,
+            asset_pack,
+            session.view_mode,
+            session.volume,
+            session.speed,
+            false,
+            texture_cache,
+        );
+        chart.visualization = session.visualization;
+        chart.seek(session.position);
+        chart.pending_view = session.view_x;
+        chart
+    }
+
+    // État courant du graphique, de quoi le rouvrir à l'identique
+    pub fn to_session(&self) -> SavedSession {
+        let data = ChartData {
+            coin: self.coin.clone(),
+            currency: self.currency.clone(),
+            daily_prices: self.daily_prices.to_vec(),
+            latest_price: self.latest_price,
+            change_24h: self.change_24h,
+        };
+        let view_x = Some(self.view_x).filter(|(min, max)| min.is_finite() && max.is_finite());
+        SavedSession::new(data, self.view_mode, self.visualization, self.current_index, self.speed, self.volume, view_x)
+    }
+
+    // Recette reproduisant ce graphique, la période étant retrouvée à partir des dates de la série.
+    // None pour le portefeuille, propre à chaque utilisateur
+    pub fn to_recipe(&self) -> Option<Recipe> {
+        if self.coin == PORTFOLIO_ID {
+            return None;
+        }
+        let range = DateRange::of_prices(&self.daily_prices)?;
+        Some(Recipe::new(self.coin.clone(), range, self.currency.clone(), self.view_mode, self.speed, self.sound))
+    }
+
+    // Décode chaque image du pack absente du cache sur son propre thread pour ne pas bloquer les premières images
+    fn spawn_image_decoding(asset_pack: &AssetPack, texture_cache: &TextureCache) -> mpsc::Receiver<DecodedReaction> {
+        let (tx, rx) = mpsc::channel();
+        let reactions = asset_pack.bull_images
+            .iter()
+            .enumerate()
+            .map(|(i, reaction)| (true, i, reaction.clone()))
+            .chain(asset_pack.bear_images.iter().enumerate().map(|(i, reaction)| (false, i, reaction.clone())))
+            .filter(|(_, _, reaction)| texture_cache.get(&reaction.path).is_none());
+
+        for (bullish, index, reaction) in reactions {
+            let tx = tx.clone();
+            spawn_background(move || {
+                let result = decode_reaction_image(&reaction, REACTION_IMAGE_SIZE).map_err(|e| {
+                    tr_args("log-image-failed", &[
+                        ("path", &reaction.path.display().to_string()),
+                        ("error", &e.to_string()),
+                    ])
+                });
+                tx.send((bullish, index, reaction.path, result)).ok();
+            });
+        }
+        rx
+    }
+
+    // Envoie au GPU les images décodées depuis la dernière frame
+    fn receive_decoded_images(&mut self, ctx: &egui::Context) {
+        crate::profile_function!();
+        let now = ctx.input(|i| i.time);
+        while let Ok((bullish, index, path, result)) = self.image_receiver.try_recv() {
+            let texture = match result {
+                Ok(decoded) => {
+                    let texture = decoded.upload(ctx, now);
+                    self.texture_cache.insert(path, texture.clone());
+                    texture
+                },
+                Err(message) => {
+                    // Image illisible : une flèche de remplacement et un avertissement, sans interrompre la lecture
+                    println!("{}", message);
+                    self.image_errors.push(message);
+                    let (name, color) = if bullish {
+                        (format!("placeholder_bull_{}", index), ChartPalette::current().up)
+                    } else {
+                        (format!("placeholder_bear_{}", index), ChartPalette::current().down)
+                    };
+                    placeholder_texture(ctx, &name, color, bullish, now)
+                },
+            };
+            let textures = if bullish { &mut self.bull_textures } else { &mut self.bear_textures };
+            textures[index] = Some(texture);
+        }
+    }
+
+    fn set_presentation_mode(&mut self, ctx: &egui::Context, enabled: bool) {
+        self.presentation_mode = enabled;
+        ctx.send_viewport_cmd(egui::ViewportCommand::Fullscreen(enabled));
+    }
+
+    // Bandeau : nom de la crypto, dernier prix, variation 24h et prix à la position de lecture
+    fn show_ticker_header(&self, ui: &mut egui::Ui) {
+        let Some(current) = self.daily_prices.get(self.current_index) else {
+            return;
+        };
+        let change_color = |change: f64| ChartPalette::current().change_color(change);
+        let first_price = self.daily_prices[0].price;
+        let change_since_start = (current.price - first_price) / first_price * 100.0;
+
+        ui.horizontal_wrapped(|ui| {
+            if let Some(logo) = self.texture_cache.logo(ui.ctx(), &self.coin) {
+                ui.add(Image::new(&logo).fit_to_exact_size(egui::vec2(24.0, 24.0)).rounding(12.0));
+            }
+            ui.label(
+                egui::RichText::new(coin_display_name(&self.coin))
+                    .size(20.0)
+                    .strong()
+                    .color(self.accent)
+            );
+            ui.separator();
+            ui.label(egui::RichText::new(format_price(self.latest_price, &self.currency)).size(18.0).strong());
+            ui.label(
+                egui::RichText::new(format!("{:+.2}% {}", self.change_24h, tr("change-24h")))
+                    .size(14.0)
+                    .color(change_color(self.change_24h))
+            );
+            ui.separator();
+            ui.label(
+                egui::RichText::new(current.short_label())
+                    .size(14.0)
+                    .color(secondary_text_color(ui))
+            );
+            ui.label(egui::RichText::new(format_price(current.price, &self.currency)).size(18.0).strong());
+            ui.label(
+                egui::RichText::new(format!("{:+.2}% {}", change_since_start, tr("since-start")))
+                    .size(14.0)
+                    .color(change_color(change_since_start))
+            );
+
+            // Progression de la lecture, annoncée par les lecteurs d'écran à chaque nouveau jour
+            let day = (self.current_index + 1).to_string();
+            let total = self.daily_prices.len().to_string();
+            let progress = ui.label(
+                egui::RichText::new(tr_args("playback-day", &[("day", &day), ("total", &total)]))
+                    .size(14.0)
+                    .color(secondary_text_color(ui))
+            );
+            announce(ui, &progress, &tr_args("a11y-playback-progress", &[
+                ("day", &day),
+                ("total", &total),
+                ("date", &current.date),
+                ("price", &format_price(current.price, &self.currency)),
+                ("change", &format!("{:+.2}%", change_since_start)),
+            ]));
+        });
+    }
+
+    // Vue d'ensemble de la série sous le graphique, le rectangle indique la zone zoomée
+    fn show_minimap(&mut self, ui: &mut egui::Ui, values: &[f64], full_range: (f64, f64)) {
+        let (rect, response) = ui.allocate_exact_size(
+            egui::vec2(ui.available_width(), MINIMAP_HEIGHT),
+            egui::Sense::click_and_drag(),
+        );
+        let response = accessible_name(response, egui::WidgetType::Other, tr("a11y-minimap"));
+        let painter = ui.painter_at(rect);
+        painter.rect_filled(rect, 4.0, Color32::from_rgb(30, 30, 30));
+
+        let min = values.iter().copied().fold(f64::INFINITY, f64::min);
+        let max = values.iter().copied().fold(f64::NEG_INFINITY, f64::max);
+        let y_span = (max - min).max(f64::EPSILON);
+        let to_screen_x = |x: f64| {
+            rect.left() + ((x - full_range.0) / (full_range.1 - full_range.0)) as f32 * rect.width()
+        };
+        let to_screen = |day: usize, value: f64| {
+            egui::pos2(
+                to_screen_x(day as f64 * 2.0),
+                rect.bottom() - 4.0 - ((value - min) / y_span) as f32 * (rect.height() - 8.0),
+            )
+        };
+
+        let all_points: Vec<egui::Pos2> = values.iter().enumerate().map(|(day, v)| to_screen(day, *v)).collect();
+        let played = (self.current_index + 1).min(all_points.len());
+        painter.add(egui::Shape::line(all_points.clone(), egui::Stroke::new(1.0, Color32::GRAY)));
+        painter.add(egui::Shape::line(
+            all_points[..played].to_vec(),
+            egui::Stroke::new(1.5, Color32::from_rgb(255, 215, 0)),
+        ));
+
+        let viewport = egui::Rect::from_x_y_ranges(
+            to_screen_x(self.view_x.0).max(rect.left())..=to_screen_x(self.view_x.1).min(rect.right()),
+            rect.y_range(),
+        );
+        painter.rect_filled(viewport, 2.0, Color32::from_white_alpha(25));
+        painter.rect_stroke(viewport, 2.0, egui::Stroke::new(1.0, Color32::WHITE));
+
+        // Cliquer ou glisser recentre la vue en gardant la même largeur de zoom
+        if response.clicked() || response.dragged() {
+            if let Some(pointer) = response.interact_pointer_pos() {
+                let t = ((pointer.x - rect.left()) / rect.width()) as f64;
+                let center = full_range.0 + t * (full_range.1 - full_range.0);
+                let half_width = (self.view_x.1 - self.view_x.0) / 2.0;
+                self.pending_view = Some((center - half_width, center + half_width));
+            }
+        }
+    }
+
+    // Commandes de lecture au clavier : Espace, flèches gauche/droite, Début/Fin et Retour arrière
+    fn handle_shortcuts(&mut self, ctx: &egui::Context) {
+        if shortcut_pressed(ctx, egui::Key::Space) {
+            self.playing = !self.playing;
+        }
+        if shortcut_pressed(ctx, egui::Key::ArrowRight) {
+            self.seek(self.current_index + 1);
+        }
+        if shortcut_pressed(ctx, egui::Key::ArrowLeft) {
+            self.seek(self.current_index.saturating_sub(1));
+        }
+        if shortcut_pressed(ctx, egui::Key::Home) {
+            self.seek(0);
+        }
+        if shortcut_pressed(ctx, egui::Key::End) {
+            self.seek(self.daily_prices.len() - 1);
+        }
+        if shortcut_pressed(ctx, egui::Key::Backspace) && !self.popped_out {
+            self.should_return_home = true;
+        }
+    }
+
+    // Journal défilant des notes jouées, calé sur la dernière ligne
+    fn show_note_log_panel(&self, ui: &mut egui::Ui) {
+        egui::Frame::group(ui.style()).show(ui, |ui| {
+            egui::ScrollArea::vertical()
+                .max_height(NOTE_LOG_HEIGHT)
+                .auto_shrink([false, true])
+                .stick_to_bottom(true)
+                .show(ui, |ui| {
+                    if self.note_log.is_empty() {
+                        ui.label(egui::RichText::new(tr("note-log-empty")).size(12.0).color(secondary_text_color(ui)));
+                    }
+                    for line in &self.note_log {
+                        ui.label(egui::RichText::new(line).monospace().size(12.0));
+                    }
+                });
+        });
+    }
+
+    // À l'arrêt, joue doucement la note du jour survolé une fois que le pointeur s'y est posé
+    fn preview_hovered_day(&mut self, ctx: &egui::Context, day: Option<usize>) {
+        if !self.hover_preview || self.playing {
+            self.hovered_day = None;
+            self.previewed_day = None;
+            return;
+        }
+        let now = ctx.input(|i| i.time);
+        let since = match (day, self.hovered_day) {
+            (Some(day), Some((hovered, since))) if day == hovered => since,
+            (Some(day), _) => {
+                self.hovered_day = Some((day, now));
+                now
+            },
+            (None, _) => {
+                self.hovered_day = None;
+                self.previewed_day = None;
+                return;
+            },
+        };
+        let Some(day) = day.filter(|day| self.previewed_day != Some(*day)) else {
+            return;
+        };
+        if now - since < HOVER_PREVIEW_DELAY {
+            ctx.request_repaint_after(StdDuration::from_secs_f64(HOVER_PREVIEW_DELAY - (now - since)));
+            return;
+        }
+        self.previewed_day = Some(day);
+        // Voix du pas qui atteint ce jour, tirées du cache comme à la lecture ; le premier jour n'en a pas
+        let Some((cache, index)) = self.voice_cache.as_ref().zip(day.checked_sub(1)).filter(|(cache, index)| *index < cache.notes.len()) else {
+            return;
+        };
+        let step_start = cache.notes[index].start;
+        let voices = self.day_voices(cache, index).iter().map(preview_voice).collect();
+        // Le balayage coupe l'aperçu précédent plutôt que de les enchaîner
+        self.audio.send(AudioCommand::Seek);
+        self.audio.send(AudioCommand::Step { voices, step_start, length: None });
+    }
+
+    // Saute directement à un jour donné
+    fn seek(&mut self, index: usize) {
+        self.audio.send(AudioCommand::Seek);
+        self.current_index = index.min(self.daily_prices.len() - 1);
+        self.scheduler = None;
+        self.live_continued = false;
+        self.point_progress = 0.0;
+        self.step_started_at = None;
+        self.caption = None;
+        self.note_log.clear();
+    }
+
+    // Graphique quitté mais gardé dans l'historique : la lecture s'arrête là où elle en était
+    pub fn suspend(&mut self) {
+        self.playing = false;
+        self.scheduler = None;
+        self.stop_recording();
+        self.audio.send(AudioCommand::Pause);
+    }
+
+    // Grille mensuelle des rendements journaliers, un clic positionne la lecture sur le jour
+    fn show_calendar(&mut self, ui: &mut egui::Ui) -> egui::Rect {
+        const LABEL_WIDTH: f32 = 60.0;
+        const MAX_CELL: f32 = 90.0;
+        const FULL_COLOR_PERCENT: f32 = 10.0;
+        let palette = ChartPalette::current();
+
+        let mut clicked_day = None;
+        let cell = ((ui.available_width() - LABEL_WIDTH) / 7.0).min(MAX_CELL);
+
+        let response = ui.vertical(|ui| {
+            ui.horizontal(|ui| {
+                ui.add_space(LABEL_WIDTH);
+                for weekday in ["weekday-1", "weekday-2", "weekday-3", "weekday-4", "weekday-5", "weekday-6", "weekday-7"].map(tr) {
+                    ui.add_sized([cell, 20.0], egui::Label::new(
+                        egui::RichText::new(weekday).color(Color32::GRAY)
+                    ));
+                }
+            });
+
+            let dates: Vec<chrono::NaiveDate> = self.daily_prices
+                .iter()
+                .filter_map(|p| chrono::NaiveDate::parse_from_str(&p.date, "%Y-%m-%d").ok())
+                .collect();
+            let Some(first) = dates.first().copied() else {
+                return;
+            };
+
+            // On commence la grille au lundi de la première semaine
+            let grid_start = first - Duration::days(first.weekday().num_days_from_monday() as i64);
+            let mut week_start = grid_start;
+            let mut last_month = None;
+
+            while week_start <= *dates.last().unwrap() {
+                ui.horizontal(|ui| {
+                    let month = week_start.format("%b %Y").to_string();
+                    let label = if last_month.as_ref() != Some(&month) {
+                        last_month = Some(month.clone());
+                        month
+                    } else {
+                        String::new()
+                    };
+                    ui.add_sized([LABEL_WIDTH, cell], egui::Label::new(
+                        egui::RichText::new(label).size(12.0).color(secondary_text_color(ui))
+                    ));
+
+                    for offset in 0..7 {
+                        let date = week_start + Duration::days(offset);
+                        let (rect, response) = ui.allocate_exact_size(egui::vec2(cell, cell), egui::Sense::click());
+                        let Some(index) = dates.iter().position(|d| *d == date) else {
+                            continue;
+                        };
+
+                        let daily_return = (index > 0).then(|| {
+                            let previous = self.daily_prices[index - 1].price;
+                            (self.daily_prices[index].price - previous) / previous * 100.0
+                        });
+                        let intensity = daily_return
+                            .map(|r| (r.abs() as f32 / FULL_COLOR_PERCENT).clamp(0.15, 1.0))
+                            .unwrap_or(0.0);
+                        let fill = match daily_return {
+                            Some(r) => palette.change_color(r).gamma_multiply(intensity),
+                            None => Color32::from_gray(60),
+                        };
+
+                        let painter = ui.painter();
+                        painter.rect_filled(rect.shrink(2.0), 4.0, fill);
+                        if index == self.current_index {
+                            painter.rect_stroke(rect.shrink(1.0), 4.0, egui::Stroke::new(2.0, Color32::from_rgb(255, 215, 0)));
+                        }
+                        painter.text(
+                            rect.left_top() + egui::vec2(6.0, 4.0),
+                            egui::Align2::LEFT_TOP,
+                            date.format("%d").to_string(),
+                            egui::FontId::proportional(12.0),
+                            Color32::WHITE,
+                        );
+
+                        let tooltip = match daily_return {
+                            Some(r) => format!("{}\n{:+.2}%", self.daily_prices[index].date, r),
+                            None => self.daily_prices[index].date.clone(),
+                        };
+                        let response = focus_outline(accessible_name(response, egui::WidgetType::Button, &tooltip.replace('\n', ", ")));
+                        if response.on_hover_text(tooltip).clicked() {
+                            clicked_day = Some(index);
+                        }
+                    }
+                });
+                week_start += Duration::days(7);
+            }
+        }).response;
+
+        if let Some(index) = clicked_day {
+            self.seek(index);
+        }
+        response.rect
+    }
+
+    // Lecture arrivée au dernier jour et dernière note (ou accord de conclusion) éteinte
+    pub fn playback_finished(&self) -> bool {
+        self.playing && self.current_index >= self.daily_prices.len() - 1 && self.audio.is_idle()
+    }
+
+    pub fn set_popped_out(&mut self, popped_out: bool) {
+        self.popped_out = popped_out;
+    }
+
+    pub fn coin(&self) -> &str {
+        &self.coin
+    }
+
+    pub fn currency(&self) -> &str {
+        &self.currency
+    }
+
+    pub fn daily_prices(&self) -> &[DailyPrice] {
+        &self.daily_prices
+    }
+
+    pub fn view_mode(&self) -> ViewMode {
+        self.view_mode
+    }
+
+    pub fn volume(&self) -> f32 {
+        self.volume
+    }
+
+    pub fn with_captions(mut self, enabled: bool) -> Self {
+        self.captions = enabled;
+        self
+    }
+
+    // Pris en compte au prochain enregistrement
+    pub fn set_export_loudness(&mut self, loudness: Option<f64>) {
+        self.export_loudness = loudness;
+    }
+
+    // Double la sortie audio sur le périphérique nommé ; en cas d'échec la lecture continue sur la sortie principale
+    pub fn with_mirror_output(mut self, device_name: Option<&str>) -> Self {
+        if let Some(name) = device_name {
+            let (name, volume) = (name.to_string(), self.volume);
+            self.mirror_device = Some(name.clone());
+            self.audio.send(AudioCommand::Mirror(Box::new(move || {
+                Ok(Box::new(RodioOutput::new(audio::open_mirror_output(&name, volume)?)) as Box<dyn EngineOutput>)
+            })));
+        }
+        self
+    }
+
+    // Échecs d'ouverture des sorties, signalés par le moteur audio une fois sur son thread
+    fn receive_audio_events(&mut self) {
+        while let Some(event) = self.audio.try_event() {
+            let message = match event {
+                EngineEvent::OutputFailed(error) => {
+                    let message = tr_args("log-no-audio", &[("error", &error)]);
+                    self.audio_error = Some(message.clone());
+                    message
+                },
+                EngineEvent::MirrorFailed(error) => {
+                    let device = self.mirror_device.as_deref().unwrap_or_default();
+                    let message = tr_args("log-mirror-failed", &[("device", device), ("error", &error)]);
+                    self.mirror_error = Some(message.clone());
+                    message
+                },
+            };
+            println!("{}", message);
+        }
+    }
+
+    // Réglages de la manière de jouer les notes, regroupés dans un menu
+    fn show_sound_options(&mut self, ui: &mut egui::Ui) {
+        ui.label(egui::RichText::new(tr("note-length")).strong()).on_hover_text(tr("note-length-hint"));
+        for note_length in NoteLength::ALL {
+            ui.radio_value(&mut self.sound.note_length, note_length, tr(note_length.label_key()));
+        }
+        ui.separator();
+        ui.checkbox(&mut self.sound.arpeggio, tr("arpeggio")).on_hover_text(tr("arpeggio-hint"));
+        ui.checkbox(&mut self.sound.echo, tr("echo")).on_hover_text(tr("echo-hint"));
+        ui.checkbox(&mut self.sound.weekend_timbre, tr("weekend-timbre")).on_hover_text(tr("weekend-timbre-hint"));
+        ui.separator();
+        ui.label(egui::RichText::new(tr("harmony")).strong()).on_hover_text(tr("harmony-hint"));
+        ui.radio_value(&mut self.sound.harmony, None, tr("harmony-off"));
+        for interval in HarmonyInterval::ALL {
+            ui.radio_value(&mut self.sound.harmony, Some(interval), tr(interval.label_key()));
+        }
+        ui.add_enabled_ui(self.sound.harmony.is_some(), |ui| {
+            ui.checkbox(&mut self.sound.harmony_below, tr("harmony-below"));
+            ui.label(tr("scale"));
+            for scale in Scale::ALL {
+                ui.radio_value(&mut self.sound.scale, scale, tr(scale.label_key()));
+            }
+        });
+        ui.separator();
+        ui.label(egui::RichText::new(tr("ducking")).strong()).on_hover_text(tr("ducking-hint"));
+        ui.add(egui::Slider::new(&mut self.sound.duck_depth, 0.0..=1.0).text(tr("ducking-depth")));
+        ui.add(egui::Slider::new(&mut self.sound.duck_release, 0.05..=2.0).suffix(" s").text(tr("ducking-release")));
+        ui.separator();
+        ui.label(egui::RichText::new(tr("pitch-range")).strong()).on_hover_text(tr("pitch-range-hint"));
+        ui.add(
+            egui::Slider::new(&mut self.sound.min_frequency, MIN_FREQUENCY..=self.sound.max_frequency)
+                .logarithmic(true)
+                .max_decimals(0)
+                .suffix(" Hz")
+                .text(tr("pitch-range-low")),
+        );
+        ui.add(
+            egui::Slider::new(&mut self.sound.max_frequency, self.sound.min_frequency..=MAX_FREQUENCY)
+                .logarithmic(true)
+                .max_decimals(0)
+                .suffix(" Hz")
+                .text(tr("pitch-range-high")),
+        );
+        ui.separator();
+        ui.checkbox(&mut self.sound.sub_bass, tr("sub-bass")).on_hover_text(tr("sub-bass-hint"));
+        ui.add_enabled(self.sound.sub_bass, egui::Slider::new(&mut self.sound.sub_bass_days, 2..=10).text(tr("sub-bass-days")));
+        ui.checkbox(&mut self.sound.shepard, tr("shepard")).on_hover_text(tr("shepard-hint"));
+        ui.separator();
+        ui.label(egui::RichText::new(tr("ending")).strong()).on_hover_text(tr("ending-hint"));
+        for ending in Ending::ALL {
+            ui.radio_value(&mut self.sound.ending, ending, tr(ending.label_key()));
+        }
+    }
+
+    fn set_layer_volume(&self) {
+        if let Some(order_book) = &self.order_book {
+            order_book.set_volume(self.volume * self.layer_gain);
+        }
+        if let Some(spread) = &self.spread {
+            spread.set_volume(self.volume * self.layer_gain);
+        }
+    }
+
+    pub fn captions(&self) -> bool {
+        self.captions
+    }
+
+    pub fn with_sound_options(mut self, sound: SoundOptions) -> Self {
+        self.sound = sound;
+        self
+    }
+
+    pub fn with_sound_pack(mut self, sound_pack: Option<Arc<SoundPack>>) -> Self {
+        self.sound_pack = sound_pack;
+        self
+    }
+
+    pub fn sound_options(&self) -> SoundOptions {
+        self.sound
+    }
+
+    // Met à jour le cache du graphique si la série, la position de lecture ou la vue ont changé
+    fn refresh_plot_cache(&mut self, max_buckets: usize) -> &PlotCache {
+        if self.plot_cache.as_ref().is_some_and(|cache| cache.view_mode != self.view_mode) {
+            self.plot_cache = None;
+        }
+        let cache = self.plot_cache.get_or_insert_with(|| PlotCache {
+            view_mode: self.view_mode,
+            values: audio::series_values(self.view_mode, &self.daily_prices).into(),
+            segments_key: None,
+            points: Vec::new(),
+            green_segments: Vec::new(),
+            red_segments: Vec::new(),
+        });
+
+        let key = (self.current_index, self.view_x, max_buckets);
+        if cache.segments_key != Some(key) {
+            let current_data: Vec<[f64; 2]> = cache.values[..=self.current_index.min(cache.values.len() - 1)]
+                .iter()
+                .enumerate()
+                .map(|(day, value)| {
+                    let day = day as f64 * 2.0;
+                    [day, *value]
+                })
+                .collect();
+
+            // Niveau de détail : au plus un paquet min/max par pixel de la zone visible
+            cache.points = decimate_min_max(&current_data, self.view_x, max_buckets);
+            (cache.green_segments, cache.red_segments) = colored_runs(&cache.points);
+            cache.segments_key = Some(key);
+        }
+        cache
+    }
+
+    // Rythme et durée des notes ajustés à la vitesse de lecture
+    fn sonifier(&self) -> Sonifier {
+        let sonifier = Sonifier::new(self.view_mode).with_speed(self.speed).with_options(self.sound);
+        if self.daily_prices.first().is_some_and(DailyPrice::is_intraday) {
+            sonifier.with_speed(INTRADAY_SPEED)
+        } else {
+            sonifier
+        }
+    }
+
+    // Recalcule les voix de la série quand la vue, la vitesse, les réglages de son ou le sentiment changent ;
+    // renvoie true dans ce cas
+    fn refresh_voice_cache(&mut self, sonifier: &Sonifier) -> bool {
+        let scores = self.sentiment.as_ref().and_then(SentimentVoice::scores);
+        if self.voice_cache.as_ref().is_some_and(|cache| cache.sonifier == *sonifier && cache.with_sentiment == scores.is_some()) {
+            return false;
+        }
+        let notes = sonifier.sonify(&self.daily_prices);
+        self.voice_cache = Some(VoiceCache {
+            sonifier: *sonifier,
+            with_sentiment: scores.is_some(),
+            harmony: sonifier.harmony(&self.daily_prices),
+            echoes: sonifier.echoes(&notes),
+            sub_bass: sonifier.sub_bass(&self.daily_prices),
+            sentiment: scores.map(|scores| sonifier.sonify_sentiment(scores)).unwrap_or_default(),
+            cadence: sonifier.cadence(&self.daily_prices),
+            notes,
+        });
+        true
+    }
+
+    // Mélodie (ou échantillon) et harmonie d'un pas, les voix propres au jour atteint
+    fn day_voices(&self, cache: &VoiceCache, index: usize) -> Vec<NoteEvent> {
+        let price_change = (self.daily_prices[index + 1].price - self.daily_prices[index].price) / self.daily_prices[index].price * 100.0;
+        let note = cache.notes[index].clone();
+        let harmony_note = cache.harmony.get(index).cloned();
+        let sample_note = self.sound_pack.as_ref().and_then(|pack| pack.sample_note(&note, price_change));
+        // Un pack qui remplace la synthèse ne laisse jouer que son échantillon, quand il en a un pour ce jour
+        let replaced = sample_note.is_some() && self.sound_pack.as_ref().is_some_and(|pack| pack.replace_synth);
+        (!replaced).then_some(note).into_iter().chain(sample_note).chain(harmony_note).collect()
+    }
+
+    // Voix d'un pas : mélodie (ou échantillon), harmonie, écho, sentiment et sous-basse, puis l'accord de conclusion
+    // au dernier pas
+    fn step_voices(&self, index: usize) -> (Vec<NoteEvent>, Option<NoteEvent>) {
+        let Some(cache) = &self.voice_cache else {
+            return (Vec::new(), None);
+        };
+        let echo = index.checked_sub(1).and_then(|previous| cache.echoes.get(previous).cloned());
+        let sentiment_note = cache.sentiment.get(index).cloned().flatten();
+        let sub_bass = cache.sub_bass.get(index).cloned().flatten();
+        let cadence = cache.cadence.clone().filter(|_| index + 2 == self.daily_prices.len());
+        let mut voices = self.day_voices(cache, index);
+        voices.extend(echo.into_iter().chain(sentiment_note).chain(sub_bass));
+        (voices, cadence)
+    }
+
+    // Confie au moteur audio tous les pas restants, chacun à sa durée exacte : il les enchaîne lui-même,
+    // sans attendre les frames, et la lecture continue fenêtre cachée. Des sinks vides (début de lecture)
+    // sont recalés sur l'horloge par un silence, et les pas dont l'échéance est passée ne sont pas joués
+    fn queue_steps(&mut self, sonifier: &Sonifier, now: f64) {
+        let Some(scheduler) = self.scheduler else {
+            return;
+        };
+        let last_step = self.daily_prices.len() - 2;
+        self.queued_until = self.queued_until.max(self.current_index);
+        while self.queued_until <= last_step {
+            let step = self.queued_until;
+            self.queued_until += 1;
+            if self.audio.is_idle() {
+                let lead = scheduler.step_time(step) - now;
+                if lead < 0.0 {
+                    continue;
+                }
+                self.audio.send(AudioCommand::Wait(lead));
+            }
+            let (voices, cadence) = self.step_voices(step);
+            let step_start = step as f64 * sonifier.step_seconds;
+            // Le dernier pas n'est pas coupé : le fondu final et l'accord de conclusion le prolongent
+            let length = (step < last_step).then_some(sonifier.step_seconds);
+            self.audio.send(AudioCommand::Step { voices, step_start, length });
+            if let Some(cadence) = cadence {
+                self.audio.send(AudioCommand::Play(cadence));
+            }
+        }
+    }
+
+    fn restart_playback(&mut self) {
+        self.seek(0);
+        self.image_animation = AnimatedImage::new();
+        self.particles.clear();
+    }
+
+    fn start_recording(&mut self, ctx: &egui::Context) {
+        // L'enregistrement repart du premier jour pour capturer toute la période
+        self.restart_playback();
+        self.playing = true;
+        self.recorder = Some(SessionRecorder::new(self.recording_format, ctx.input(|i| i.time), self.export_loudness));
+        self.export_status = Some(tr("recording").to_string());
+    }
+
+    fn stop_recording(&mut self) {
+        if let Some(recorder) = self.recorder.take() {
+            self.encoding = Some(recorder.finish_in_background());
+            self.export_status = Some(tr_args("recording-encoding", &[("percent", "0")]));
+        }
+    }
+
+    // Avancement de l'encodage lancé par stop_recording, puis le fichier écrit
+    fn receive_recording_events(&mut self) {
+        let Some(encoding) = &self.encoding else {
+            return;
+        };
+        while let Ok(event) = encoding.try_recv() {
+            match event {
+                RecordingEvent::Progress(fraction) => {
+                    let percent = format!("{:.0}", fraction * 100.0);
+                    self.export_status = Some(tr_args("recording-encoding", &[("percent", &percent)]));
+                },
+                RecordingEvent::Done(result) => {
+                    self.export_status = Some(match result {
+                        Ok(path) => {
+                            notify::notify(tr("notification-recording-done"), &path.display().to_string());
+                            tr_args("saved", &[("path", &path.display().to_string())])
+                        },
+                        Err(e) => tr_args("recording-failed", &[("error", &e.to_string())]),
+                    });
+                    self.encoding = None;
+                    return;
+                },
+            }
+        }
+    }
+
+    // Récupère les captures d'écran demandées (export PNG et enregistrement)
+    fn handle_screenshots(&mut self, ctx: &egui::Context) {
+        let screenshot = ctx.input(|i| {
+            i.events.iter().find_map(|event| match event {
+                egui::Event::Screenshot { image, .. } => Some(image.clone()),
+                _ => None,
+            })
+        });
+
+        let (Some(screenshot), Some(plot_rect)) = (screenshot, self.plot_rect) else {
+            return;
+        };
+
+        if let Some(recorder) = &mut self.recorder {
+            recorder.push_frame(ctx.input(|i| i.time), &screenshot, plot_rect, ctx.pixels_per_point());
+        }
+
+        if self.export_requested {
+            self.export_requested = false;
+            self.export_status = Some(match save_chart_png(
+                &screenshot,
+                plot_rect,
+                ctx.pixels_per_point(),
+                self.export_width,
+            ) {
+                Ok(path) => tr_args("saved", &[("path", &path.display().to_string())]),
+                Err(e) => tr_args("export-failed", &[("error", &e.to_string())]),
+            });
+        }
+    }
+}
+
+impl eframe::App for ChartApp {
+    fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        crate::profile_function!();
+        self.receive_decoded_images(ctx);
+        self.receive_audio_events();
+        self.receive_recording_events();
+        self.handle_screenshots(ctx);
+        if let Some(sentiment) = &mut self.sentiment {
+            sentiment.receive(ctx, &self.daily_prices);
+        }
+        // Temps réellement écoulé, borné pour ne pas faire sauter les animations après une pause de rendu
+        let dt = ctx.input(|i| i.stable_dt).min(MAX_FRAME_SECONDS);
+        let now = ctx.input(|i| i.time);
+        if self.limiter.take() > AUDIBLE_REDUCTION_DB {
+            self.limiter_lit_until = now + LIMITER_HOLD_SECONDS;
+        }
+        if let Some(order_book) = &mut self.order_book {
+            order_book.update(ctx, now, self.playing);
+        }
+        if let Some(spread) = &mut self.spread {
+            spread.update(ctx, now, self.playing);
+        }
+        if let Some(live) = &mut self.live {
+            live.update(ctx, now, true);
+        }
+        // Les couches de fond s'effacent sous chaque note puis reviennent
+        if self.order_book.is_some() || self.spread.is_some() {
+            let layer_gain = audio::ducking_gain(now, self.note_until, self.sound.duck_depth, self.sound.duck_release);
+            if layer_gain != self.layer_gain {
+                self.layer_gain = layer_gain;
+                self.set_layer_volume();
+            }
+            if layer_gain < 1.0 {
+                ctx.request_repaint_after(StdDuration::from_secs_f64((self.note_until - now).max(0.0)));
+            }
+        }
+        let floating = !reduce_motion() && self.playing && self.current_index > 0 && self.current_index < self.daily_prices.len() - 1;
+        self.image_animation.animate(dt, floating);
+        self.particles.update(dt);
+        let mut image_animating = false;
+
+        // F11 bascule le mode présentation, Échap permet d'en sortir
+        if ctx.input(|i| i.key_pressed(egui::Key::F11)) {
+            self.set_presentation_mode(ctx, !self.presentation_mode);
+        } else if self.presentation_mode && ctx.input(|i| i.key_pressed(egui::Key::Escape)) {
+            self.set_presentation_mode(ctx, false);
+        }
+        self.handle_shortcuts(ctx);
+
+        if !self.presentation_mode {
+            let jump = self.news.as_mut().and_then(|news| news.show(ctx, &self.daily_prices));
+            if let Some(index) = jump {
+                self.seek(index);
+                self.playing = true;
+            }
+        }
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            // Ajouter le bouton en haut à gauche (masqué en mode présentation)
+            if !self.presentation_mode {
+                ui.horizontal_wrapped(|ui| {
+                    let home_label = if self.popped_out { tr("dock-chart") } else { tr("back-home") };
+                    if ui.button(
+                        egui::RichText::new(home_label)
+                            .size(16.0)
+                            .color(Color32::from_rgb(255, 215, 0))
+                    ).clicked() {
+                        // On utilisera cette information dans MainApp
+                        self.should_return_home = true;
+                    }
+                    // Pas de fenêtres natives dans le navigateur : egui les y intégrerait à la page
+                    if !self.popped_out && !ctx.embed_viewports() && ui.button(tr("pop-out")).on_hover_text(tr("pop-out-hint")).clicked() {
+                        self.should_pop_out = true;
+                    }
+                    if !self.popped_out && !ctx.embed_viewports() && ui.button(tr("mini-ticker")).on_hover_text(tr("mini-ticker-hint")).clicked() {
+                        self.should_open_ticker = true;
+                    }
+                    let play_label = if self.playing { tr("pause") } else { tr("play") };
+                    if ui.button(play_label).on_hover_text(tr("playback-shortcuts")).clicked() {
+                        self.playing = !self.playing;
+                    }
+                    let volume_slider = egui::Slider::new(&mut self.volume, 0.0..=1.0).show_value(false).text(tr("volume"));
+                    if ui.add_enabled(self.audio_error.is_none(), volume_slider).changed() {
+                        self.audio.send(AudioCommand::SetVolume(self.volume));
+                        self.set_layer_volume();
+                        if let Some(live) = &self.live {
+                            live.set_volume(self.volume);
+                        }
+                    }
+                    // Voyant du limiteur : allumé quand des crêtes viennent d'être rabotées
+                    let limiting = ctx.input(|i| i.time) < self.limiter_lit_until;
+                    let limiter_color = if limiting { Color32::from_rgb(255, 165, 0) } else { Color32::GRAY };
+                    ui.label(egui::RichText::new(tr("limiter")).size(11.0).strong().color(limiter_color))
+                        .on_hover_text(tr("limiter-hint"));
+                    ui.label(
+                        egui::RichText::new(tr("presentation-hint"))
+                            .size(12.0)
+                            .color(Color32::GRAY)
+                    );
+
+                    ui.separator();
+
+                    // Export du graphique en PNG, à la taille capturée ou réduit
+                    let max_width = self.plot_rect.map_or(0, |rect| captured_width(rect, ctx.pixels_per_point()));
+                    let full_label = tr_args("export-full-width", &[("width", &max_width.to_string())]);
+                    egui::ComboBox::from_id_source("export_width")
+                        .selected_text(match self.export_width {
+                            Some(width) if width < max_width => format!("{} px", width),
+                            _ => full_label.clone(),
+                        })
+                        .show_ui(ui, |ui| {
+                            ui.selectable_value(&mut self.export_width, None, full_label);
+                            for width in PNG_WIDTHS.into_iter().filter(|width| *width < max_width) {
+                                ui.selectable_value(&mut self.export_width, Some(width), format!("{} px", width));
+                            }
+                        })
+                        .response
+                        .on_hover_text(tr("export-width-hint"));
+                    if ui.button(tr("save-chart")).clicked() {
+                        self.export_requested = true;
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot);
+                    }
+                    // Notes calculées (jour, variation, hauteur, vélocité, durée) pour d'autres outils d'analyse
+                    ui.menu_button(tr("export-notes"), |ui| {
+                        for extension in ["csv", "json"] {
+                            if ui.button(extension.to_uppercase()).clicked() {
+                                let rows = audio::note_rows(&self.daily_prices, &self.sonifier().sonify(&self.daily_prices));
+                                self.export_status = Some(match save_note_list(&self.coin, &rows, extension) {
+                                    Ok(path) => tr_args("saved", &[("path", &path.display().to_string())]),
+                                    Err(e) => tr_args("export-failed", &[("error", &e.to_string())]),
+                                });
+                                ui.close_menu();
+                            }
+                        }
+                    }).response.on_hover_text(tr("export-notes-hint"));
+                    if ui.button(tr("save-session")).clicked() {
+                        self.export_status = Some(match save_session(&self.to_session()) {
+                            Ok(path) => tr_args("saved", &[("path", &path.display().to_string())]),
+                            Err(e) => tr_args("session-save-failed", &[("error", &e.to_string())]),
+                        });
+                    }
+                    // Recette à partager : copiée dans le presse-papiers et enregistrée dans recipes/
+                    if let Some(recipe) = self.to_recipe() {
+                        if ui.button(tr("share-recipe")).on_hover_text(tr("share-recipe-hint")).clicked() {
+                            self.export_status = Some(match recipe.to_json().and_then(|json| Ok((json, save_recipe(&recipe)?))) {
+                                Ok((json, path)) => {
+                                    ctx.output_mut(|o| o.copied_text = json);
+                                    tr_args("recipe-copied", &[("path", &path.display().to_string())])
+                                },
+                                Err(e) => tr_args("recipe-save-failed", &[("error", &e.to_string())]),
+                            });
+                        }
+                    }
+                    ui.add_enabled(!reduce_motion(), egui::Checkbox::new(&mut self.particles.enabled, tr("particles")))
+                        .on_disabled_hover_text(tr("particles-reduced-motion"));
+                    ui.checkbox(&mut self.captions, tr("captions")).on_hover_text(tr("captions-hint"));
+                    ui.checkbox(&mut self.show_note_log, tr("note-log")).on_hover_text(tr("note-log-hint"));
+                    ui.checkbox(&mut self.hover_preview, tr("hover-preview")).on_hover_text(tr("hover-preview-hint"));
+                    ui.menu_button(tr("sound-options"), |ui| self.show_sound_options(ui));
+                    if !self.events.is_empty() {
+                        ui.checkbox(&mut self.show_events, tr("event-markers"));
+                    }
+                    let mut sentiment_on = self.sentiment.is_some();
+                    if ui.toggle_value(&mut sentiment_on, tr("sentiment-voice")).on_hover_text(tr("sentiment-hint")).changed() {
+                        self.sentiment = sentiment_on.then(SentimentVoice::new);
+                    }
+                    if let Some(sentiment) = &mut self.sentiment {
+                        sentiment.show_status(ui, self.current_index);
+                    }
+                    if let Some(symbol) = order_book_symbol(&self.coin) {
+                        let mut order_book_on = self.order_book.is_some();
+                        if ui.toggle_value(&mut order_book_on, tr("orderbook-layer")).on_hover_text(tr("orderbook-hint")).changed() {
+                            self.order_book = order_book_on.then(|| OrderBookLayer::new(symbol, self.volume * self.layer_gain));
+                        }
+                    }
+                    if let Some(order_book) = &mut self.order_book {
+                        order_book.show_status(ui);
+                    }
+                    if let Some(symbol) = spread_symbol(&self.coin) {
+                        let mut spread_on = self.spread.is_some();
+                        if ui.toggle_value(&mut spread_on, tr("spread-layer")).on_hover_text(tr("spread-hint")).changed() {
+                            self.spread = spread_on.then(|| SpreadLayer::new(symbol, self.volume * self.layer_gain));
+                        }
+                    }
+                    if let Some(spread) = &mut self.spread {
+                        spread.show_status(ui);
+                    }
+                    if let Some(symbol) = spread_symbol(&self.coin) {
+                        let mut live_on = self.live.is_some();
+                        if ui.toggle_value(&mut live_on, tr("live-layer")).on_hover_text(tr("live-hint")).changed() {
+                            self.live = live_on.then(|| LiveLayer::new(symbol, self.volume));
+                        }
+                        ui.checkbox(&mut self.continue_live, tr("continue-live")).on_hover_text(tr("continue-live-hint"));
+                    }
+                    if let Some(live) = &mut self.live {
+                        live.show_status(ui);
+                    }
+                    let mut news_open = self.news.is_some();
+                    if self.coin != PORTFOLIO_ID && ui.toggle_value(&mut news_open, tr("news")).changed() {
+                        self.news = news_open.then(|| NewsPanel::new(&self.coin));
+                    }
+
+                    ui.selectable_value(&mut self.view_mode, ViewMode::Price, tr("mode-price"));
+                    ui.selectable_value(&mut self.view_mode, ViewMode::Drawdown, tr("mode-drawdown"));
+                    ui.separator();
+                    ui.selectable_value(&mut self.visualization, Visualization::Chart, tr("view-chart"));
+                    ui.selectable_value(&mut self.visualization, Visualization::Calendar, tr("view-calendar"));
+
+                    // Enregistrement de la session en GIF ou MP4
+                    // Pas de nouvel enregistrement tant que le précédent s'encode
+                    ui.add_enabled_ui(self.recorder.is_none() && self.encoding.is_none(), |ui| {
+                        egui::ComboBox::from_id_source("recording_format")
+                            .selected_text(self.recording_format.label())
+                            .show_ui(ui, |ui| {
+                                for format in [RecordingFormat::Gif, RecordingFormat::Mp4] {
+                                    ui.selectable_value(&mut self.recording_format, format, format.label());
+                                }
+                            });
+                    });
+                    if self.recorder.is_none() {
+                        if ui.add_enabled(self.encoding.is_none(), egui::Button::new(tr("record"))).clicked() {
+                            self.start_recording(ctx);
+                        }
+                    } else if ui.button(egui::RichText::new(tr("stop-recording")).color(Color32::from_rgb(255, 88, 88))).clicked() {
+                        self.stop_recording();
+                    }
+
+                    if let Some(error) = &self.audio_error {
+                        ui.label(
+                            egui::RichText::new(tr("no-audio"))
+                                .size(12.0)
+                                .color(Color32::from_rgb(255, 190, 70))
+                        ).on_hover_text(error);
+                    }
+                    if let Some(error) = &self.mirror_error {
+                        ui.label(
+                            egui::RichText::new(tr("mirror-unavailable"))
+                                .size(12.0)
+                                .color(Color32::from_rgb(255, 190, 70))
+                        ).on_hover_text(error);
+                    }
+
+                    if !self.image_errors.is_empty() {
+                        ui.label(
+                            egui::RichText::new(tr_args("images-missing", &[("count", self.image_errors.len().to_string())]))
+                                .size(12.0)
+                                .color(Color32::from_rgb(255, 190, 70))
+                        ).on_hover_text(self.image_errors.join("\n"));
+                    }
+
+                    if let Some(status) = &self.export_status {
+                        ui.label(egui::RichText::new(status).size(12.0).color(secondary_text_color(ui)));
+                    }
+                });
+            }
+
+            self.show_ticker_header(ui);
+            if let Some(fundamentals) = self.fundamentals.as_mut().filter(|_| !self.presentation_mode) {
+                fundamentals.show(ui);
+            }
+            if let Some(caption) = self.caption.as_ref().filter(|_| self.captions) {
+                ui.label(egui::RichText::new(caption).size(20.0).strong());
+            }
+
+            if self.show_note_log && !self.presentation_mode {
+                self.show_note_log_panel(ui);
+            }
+
+            if !self.presentation_mode {
+                if let Some(stats) = &self.stats {
+                    egui::CollapsingHeader::new(tr("statistics"))
+                        .default_open(false)
+                        .show(ui, |ui| stats.show(ui, &self.currency));
+                }
+            }
+
+            if self.visualization == Visualization::Calendar {
+                let calendar_rect = self.show_calendar(ui);
+                self.plot_rect = Some(calendar_rect);
+            } else {
+                crate::profile_scope!("plot");
+                let max_buckets = self.plot_rect.map_or(1000, |rect| rect.width().max(1.0) as usize);
+                let cache = self.refresh_plot_cache(max_buckets);
+                let values = cache.values.clone();
+                // egui_plot consomme ses points : seules les séries déjà décimées sont recopiées
+                let current_data = cache.points.clone();
+                let green_segments = cache.green_segments.clone();
+                let red_segments = cache.red_segments.clone();
+
+                // Bandes des week-ends sur toute la hauteur de la courbe (zéro compris, comme l'axe)
+                let weekends = if self.sound.weekend_timbre { weekend_runs(&self.daily_prices) } else { Vec::new() };
+                let (band_low, band_high) = values.iter().fold((0.0f64, 0.0f64), |(low, high), value| (low.min(*value), high.max(*value)));
+                // Intervalles où les sources de l'indice divergent, repérés sur la courbe
+                let disagreements: Vec<[f64; 2]> = self.daily_prices
+                    .iter()
+                    .zip(values.iter())
+                    .enumerate()
+                    .filter(|(_, (day, _))| day.sources_disagree())
+                    .map(|(index, (_, value))| [index as f64 * 2.0, *value])
+                    .collect();
+                let label_prices = self.daily_prices.clone();
+                let events = if self.show_events { self.events.clone() } else { Vec::new() };
+                let label_events = events.clone();
+                let axis_prices = self.daily_prices.clone();
+                let playhead_x = self.current_index as f64 * 2.0;
+                let view_mode = self.view_mode;
+                let currency = self.currency.clone();
+                let accent = self.accent;
+                let palette = ChartPalette::current();
+                let full_range = (-2.0, self.daily_prices.len() as f64 * 2.0);
+                let zoomed = self.view_x.1 - self.view_x.0 < (full_range.1 - full_range.0) * 0.95;
+                let pending_view = self.pending_view.take();
+                let plot_height = if zoomed {
+                    ui.available_height() - MINIMAP_HEIGHT - ui.spacing().item_spacing.y
+                } else {
+                    ui.available_height()
+                };
+                // Un Id par crypto et par fenêtre : un graphique détaché garde son propre zoom et son propre survol
+                let plot_response = Plot::new(("chart_plot", &self.coin, ui.ctx().viewport_id()))
+                    .height(plot_height)
+                    .width(ui.available_width())
+                    .include_y(0.0)
+                    .include_x(full_range.0)
+                    .include_x(full_range.1)
+                    // Zoom et déplacement horizontaux uniquement (double-clic pour réinitialiser)
+                    .allow_drag([true, false])
+                    .allow_zoom([true, false])
+                    .allow_scroll(false)
+                    .label_formatter(move |_name, value| {
+                        let day_index = (value.x / 2.0) as usize;
+                        if day_index >= label_prices.len() {
+                            return String::new();
+                        }
+                        let formatted_date = label_prices[day_index].short_label();
+                        let mut label = match view_mode {
+                            ViewMode::Price => tr_args("plot-price-label", &[
+                                ("date", &formatted_date),
+                                ("currency", &currency),
+                                ("value", &format!("{:.1}", value.y)),
+                            ]),
+                            ViewMode::Drawdown => tr_args("plot-drawdown-label", &[
+                                ("date", &formatted_date),
+                                ("value", &format!("{:.2}", value.y)),
+                            ]),
+                        };
+                        if let Some(spread) = label_prices[day_index].source_spread.filter(|_| label_prices[day_index].sources_disagree()) {
+                            label.push_str(&format!("\n{}", tr_args("index-disagreement", &[("spread", &format!("{:.1}", spread))])));
+                        }
+                        for (_, title) in label_events.iter().filter(|(index, _)| *index == day_index) {
+                            label.push_str(&format!("\n📅 {}", title));
+                        }
+                        label
+                    })
+                    .x_axis_formatter(move |x, _range, _precision| {
+                        let day_index = (x.value / 2.0) as usize;
+                        if day_index >= axis_prices.len() {
+                            return String::new();
+                        }
+                        axis_prices[day_index].short_label()
+                    })
+                    .show(ui, |plot_ui| {
+                        // Déplacement demandé depuis la minimap
+                        if let Some((x_min, x_max)) = pending_view {
+                            let bounds = plot_ui.plot_bounds();
+                            plot_ui.set_plot_bounds(PlotBounds::from_min_max(
+                                [x_min, bounds.min()[1]],
+                                [x_max, bounds.max()[1]],
+                            ));
+                        }
+
+                        for (first, last) in weekends {
+                            let (x_min, x_max) = (first as f64 * 2.0 - 1.0, last as f64 * 2.0 + 1.0);
+                            plot_ui.polygon(Polygon::new(PlotPoints::new(vec![
+                                [x_min, band_low],
+                                [x_max, band_low],
+                                [x_max, band_high],
+                                [x_min, band_high],
+                            ]))
+                                .fill_color(Color32::GRAY.gamma_multiply(0.12))
+                                .stroke(egui::Stroke::NONE));
+                        }
+
+                        for segment in green_segments {
+                            plot_ui.line(Line::new(PlotPoints::new(segment))
+                                .color(palette.up)
+                                .width(palette.line_width));
+                        }
+                        for segment in red_segments {
+                            plot_ui.line(Line::new(PlotPoints::new(segment))
+                                .color(palette.down)
+                                .width(palette.line_width));
+                        }
+
+                        plot_ui.points(egui_plot::Points::new(PlotPoints::new(current_data))
+                            .color(accent)
+                            .radius(palette.point_radius)
+                            .filled(true));
+
+                        if !disagreements.is_empty() {
+                            plot_ui.points(egui_plot::Points::new(PlotPoints::new(disagreements))
+                                .shape(egui_plot::MarkerShape::Diamond)
+                                .color(Color32::from_rgb(255, 170, 0))
+                                .radius(palette.point_radius + 3.0)
+                                .filled(false));
+                        }
+
+                        // Repères du calendrier crypto, détaillés dans l'infobulle du jour
+                        for (index, _) in events {
+                            plot_ui.vline(egui_plot::VLine::new(index as f64 * 2.0)
+                                .color(Color32::from_rgb(170, 130, 255).gamma_multiply(0.7))
+                                .style(LineStyle::dashed_loose())
+                                .width(1.0));
+                        }
+
+                        // Tête de lecture
+                        plot_ui.vline(egui_plot::VLine::new(playhead_x)
+                            .color(accent.gamma_multiply(0.5))
+                            .width(1.0));
+                    });
+                accessible_name(plot_response.response.clone(), egui::WidgetType::Other, tr("a11y-plot"));
+                self.plot_rect = Some(plot_response.response.rect);
+                // Même jour que l'infobulle du graphique
+                let hovered_day = plot_response.response.hover_pos()
+                    .map(|pos| plot_response.transform.value_from_position(pos).x / 2.0)
+                    .filter(|day| *day >= 0.0)
+                    .map(|day| day as usize);
+                self.preview_hovered_day(ctx, hovered_day);
+
+                let bounds = plot_response.transform.bounds();
+                self.view_x = (bounds.min()[0], bounds.max()[0]);
+                if zoomed {
+                    self.show_minimap(ui, &values, full_range);
+                }
+            }
+
+            if self.current_index > 0 {
+                let current_price = self.daily_prices[self.current_index].price;
+                let previous_price = self.daily_prices[self.current_index - 1].price;
+                let is_bullish = current_price >= previous_price;
+
+                let base_size = 400.0;
+                let screen_rect = ui.max_rect();
+                // On garde l'image dans la fenêtre, même pour les plus gros mouvements
+                let min_dimension = screen_rect.width().min(screen_rect.height());
+                let max_size = (min_dimension - 40.0).min(min_dimension * 0.75).max(0.0);
+                let scaled_size = (base_size * self.image_animation.scale).min(max_size);
+                let image_size = [scaled_size, scaled_size];
+
+                let image_rect = egui::Rect::from_min_size(
+                    egui::pos2(
+                        screen_rect.right() - image_size[0] - 20.0,
+                        screen_rect.bottom() - image_size[1] - 20.0 + self.image_animation.float_offset,
+                    ),
+                    image_size.into(),
+                );
+
+                let textures = if is_bullish {
+                    &self.bull_textures
+                } else {
+                    &self.bear_textures
+                };
+
+                if let Some(texture) = textures.get(self.current_texture_index).and_then(|t| t.as_ref()) {
+                    image_animating = !reduce_motion() && texture.is_animating(now);
+                    let opacity = self.image_animation.opacity * texture.fade_in(now);
+                    let image = Image::new(texture.frame_at(self.image_animation.elapsed))
+                        .tint(Color32::from_white_alpha((255.0 * opacity) as u8));
+                    ui.put(image_rect, image);
+                }
+            }
+
+            // Particules par-dessus le graphique
+            self.particles.paint(ui.painter());
+        });
+
+        // Animation des points, à partir de l'échéance du pas
+        let step_started_at = *self.step_started_at.get_or_insert(now);
+        self.point_progress = if reduce_motion() {
+            1.0
+        } else {
+            ((now - step_started_at) as f32 / POINT_SECONDS).clamp(0.0, 1.0)
+        };
+
+        // Capture régulière des images pendant l'enregistrement
+        if let Some(recorder) = &mut self.recorder {
+            if recorder.wants_frame(now) {
+                ctx.send_viewport_cmd(egui::ViewportCommand::Screenshot);
+            }
+        }
+
+        let sonifier = self.sonifier();
+        let voices_changed = self.refresh_voice_cache(&sonifier);
+        if self.playing && self.current_index < self.daily_prices.len() - 1 {
+            let current_index = self.current_index;
+            if self.scheduler.is_none() {
+                self.queued_until = current_index;
+            }
+            let scheduler = self.scheduler.get_or_insert_with(|| {
+                StepScheduler::new(current_index, now + sonifier.step_seconds, sonifier.step_seconds)
+            });
+            if scheduler.step_seconds() != sonifier.step_seconds {
+                // Les pas déjà en file gardent l'ancien rythme : ils sont retirés et refaits au nouveau
+                scheduler.retime(current_index, sonifier.step_seconds);
+                self.queued_until = current_index;
+                self.audio.send(AudioCommand::Seek);
+            } else if voices_changed && self.queued_until > current_index {
+                // Réglages de son changés : les pas en file sont refaits avec les nouvelles voix
+                self.queued_until = current_index;
+                self.audio.send(AudioCommand::Seek);
+            }
+            self.queue_steps(&sonifier, now);
+        } else if self.scheduler.take().is_some() && !self.playing {
+            // Pause : les pas mis d'avance ne doivent pas continuer à jouer
+            self.audio.send(AudioCommand::Pause);
+        }
+
+        // Rafraîchissement continu seulement si quelque chose bouge à l'écran
+        let images_loading = self.bull_textures.iter().chain(&self.bear_textures).any(Option::is_none);
+        let animating = floating
+            || image_animating
+            || !self.image_animation.is_settled()
+            || self.particles.is_active()
+            || self.point_progress < 1.0
+            || self.recorder.is_some()
+            || self.encoding.is_some();
+
+        // Les pas suivent l'horloge ; seule la fin de la lecture attend que la dernière note se taise
+        let sounding = !self.audio.is_idle();
+        let finished = !sounding && self.current_index >= self.daily_prices.len() - 1;
+
+        // Fin de la lecture : on termine l'enregistrement en cours
+        if self.recorder.is_some() && finished {
+            self.stop_recording();
+        }
+        // Mode hybride : une fois la dernière note jouée, le direct continue depuis le dernier prix
+        // (Binance cote en dollars : dans une autre devise, la première barre sert de référence)
+        if self.continue_live && self.playing && !self.live_continued && finished {
+            self.live_continued = true;
+            if let Some(symbol) = spread_symbol(&self.coin).filter(|_| self.live.is_none()) {
+                let last_price = self.daily_prices.last().map(|day| day.price).filter(|_| self.currency == "usd");
+                self.live = Some(LiveLayer::new(symbol, self.volume).with_previous_close(last_price));
+            }
+        }
+
+        let due_step = self.scheduler.and_then(|scheduler| Some((scheduler, scheduler.due_step(now, self.current_index)?)));
+        if let Some((scheduler, step)) = due_step {
+            crate::profile_scope!("audio_step");
+            // Après un blocage, la tête de lecture rattrape l'horloge sans rejouer les pas manqués
+            self.current_index = step.min(self.daily_prices.len() - 2);
+            let step_at = scheduler.step_time(self.current_index);
+            let current_price = self.daily_prices[self.current_index].price;
+            let next_price = self.daily_prices[self.current_index + 1].price;
+            let price_change = ((next_price - current_price) / current_price) * 100.0;
+            // Notes tirées du cache, rempli plus haut dans la frame
+            let notes = self.voice_cache.as_ref().map_or(&[][..], |cache| cache.notes.as_slice());
+            let note = notes[self.current_index].clone();
+            self.caption = self.captions.then(|| step_caption(
+                self.current_index + 2,
+                price_change,
+                &note,
+                self.current_index.checked_sub(1).map(|previous| &notes[previous]),
+            ));
+            if self.note_log.len() >= NOTE_LOG_LINES {
+                self.note_log.pop_front();
+            }
+            self.note_log.push_back(note_log_line(&self.daily_prices[self.current_index + 1], price_change, &note));
+
+            // Début du pas : l'écho joue à cet instant, la note du jour peut commencer un peu après.
+            // L'audio est déjà dans les sinks, mis en file par queue_steps
+            let step_start = self.current_index as f64 * sonifier.step_seconds;
+            let note_end = note.start + note.duration - step_start;
+            self.note_until = step_at + note_end;
+            let recorded = self.recorder.is_some().then(|| self.step_voices(self.current_index));
+            if let (Some(recorder), Some((voices, cadence))) = (&mut self.recorder, recorded) {
+                if let Some(cadence) = cadence {
+                    recorder.push_note(step_at + note_end, cadence);
+                }
+                for voice in voices {
+                    recorder.push_note(step_at + voice.start - step_start, voice);
+                }
+                if let Some(caption) = &self.caption {
+                    recorder.push_caption(step_at, caption.clone());
+                }
+            }
+
+            // Reset des animations, la taille finale dépend de l'ampleur du mouvement
+            self.image_animation.target_scale = AnimatedImage::scale_for_change(price_change);
+            self.image_animation.scale = self.image_animation.target_scale * 0.8;
+            self.image_animation.opacity = 0.0;
+            self.image_animation.elapsed = 0.0;
+            self.step_started_at = Some(step_at);
+            self.point_progress = 0.0;
+
+            if let Some(plot_rect) = self.plot_rect {
+                self.particles.burst(plot_rect, price_change, self.current_index);
+            }
+
+            self.current_index += 1;
+
+            // Image choisie d'après le jour atteint, quel que soit le chemin pris pour y arriver
+            self.current_texture_index = if current_price < next_price {
+                self.image_sequencer.bull_index(self.current_index)
+            } else {
+                self.image_sequencer.bear_index(self.current_index)
+            };
+        }
+
+        // Les images encore en décodage arrivent par le canal, on le consulte régulièrement
+        // La note encore en cours est guettée pour la fin de la lecture
+        let next_step_at = self.scheduler.map(|scheduler| scheduler.step_time(self.current_index));
+        let wake_at = next_step_at.or((images_loading || sounding).then_some(now + POLL_SECONDS));
+        schedule_repaint(ctx, animating, wake_at, now);
+    }
+}
+
+// Rafraîchit tout de suite pendant une animation, sinon seulement à l'instant demandé
+fn schedule_repaint(ctx: &egui::Context, animating: bool, wake_at: Option<f64>, now: f64) {
+    if animating {
+        ctx.request_repaint();
+    } else if let Some(at) = wake_at {
+        ctx.request_repaint_after(std::time::Duration::from_secs_f64((at - now).max(0.0)));
+    }
+}
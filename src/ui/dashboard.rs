@@ -0,0 +1,231 @@
+use super::a11y::shortcut_pressed;
+use super::widgets::{secondary_text_color, ChartPalette};
+use crate::data::{
+    coin_accent, coin_display_name, format_price, ChartData, CoinGeckoProvider, PriceProvider, DEFAULT_CURRENCY, DEFAULT_DAYS,
+};
+use crate::task::{FetchEvent, FetchPipeline, FetchRequest};
+use crate::i18n::{tr, tr_args};
+use eframe::egui::{self, Color32};
+use egui_plot::{Line, Plot, PlotPoints};
+use std::collections::HashMap;
+use std::sync::Arc;
+
+pub const DASHBOARD_COINS: [&str; 6] = ["bitcoin", "ethereum", "ripple", "solana", "cardano", "dogecoin"];
+const DASHBOARD_STEP_SECONDS: f64 = 0.5;
+
+// Petit graphique du tableau de bord, avec sa propre tête de lecture (sans son)
+struct MiniChart {
+    data: ChartData,
+    // Instant de réception, la tête de lecture en découle
+    loaded_at: f64,
+}
+
+impl MiniChart {
+    fn elapsed_steps(&self, now: f64) -> f64 {
+        ((now - self.loaded_at) / DASHBOARD_STEP_SECONDS).max(0.0)
+    }
+
+    // La lecture reboucle une fois arrivée au bout
+    fn playhead(&self, now: f64) -> usize {
+        self.elapsed_steps(now) as usize % self.data.daily_prices.len().max(1)
+    }
+
+    // Délai avant que la tête de lecture n'avance d'un jour
+    fn next_step_in(&self, now: f64) -> f64 {
+        (1.0 - self.elapsed_steps(now).fract()) * DASHBOARD_STEP_SECONDS
+    }
+
+    pub fn show(&self, ui: &mut egui::Ui, size: egui::Vec2, now: f64) {
+        let prices = &self.data.daily_prices;
+        let playhead = self.playhead(now);
+        let Some(current) = prices.get(playhead) else {
+            return;
+        };
+        let first_price = prices[0].price;
+        let change = (current.price - first_price) / first_price * 100.0;
+        let palette = ChartPalette::current();
+        let color = palette.change_color(change);
+
+        ui.allocate_ui(size, |ui| {
+            ui.vertical(|ui| {
+                ui.horizontal(|ui| {
+                    ui.label(egui::RichText::new(coin_display_name(&self.data.coin)).strong().color(coin_accent(&self.data.coin)));
+                    ui.label(egui::RichText::new(format_price(current.price, &self.data.currency)).color(secondary_text_color(ui)));
+                    ui.label(egui::RichText::new(format!("{:+.2}%", change)).color(color));
+                });
+
+                let points: Vec<[f64; 2]> = prices[..=playhead]
+                    .iter()
+                    .enumerate()
+                    .map(|(day, p)| [day as f64, p.price])
+                    .collect();
+                Plot::new(format!("mini_{}", self.data.coin))
+                    .height(size.y - 30.0)
+                    .width(size.x)
+                    .include_x(0.0)
+                    .include_x(prices.len() as f64)
+                    .show_axes([false, true])
+                    .allow_drag(false)
+                    .allow_zoom(false)
+                    .allow_scroll(false)
+                    .show(ui, |plot_ui| {
+                        plot_ui.line(Line::new(PlotPoints::new(points)).color(color).width(palette.line_width));
+                        plot_ui.vline(egui_plot::VLine::new(playhead as f64)
+                            .color(coin_accent(&self.data.coin).gamma_multiply(0.5))
+                            .width(1.0));
+                    });
+            });
+        });
+    }
+}
+
+pub struct DashboardPage {
+    selected: Vec<bool>,
+    charts: HashMap<String, MiniChart>,
+    pending: Vec<String>,
+    // Message d'erreur par crypto, effacé quand l'utilisateur réessaie
+    errors: HashMap<String, String>,
+    // Abandonne les chargements encore en cours quand le tableau de bord est fermé
+    fetches: FetchPipeline,
+    provider: Arc<dyn PriceProvider>,
+    pub should_return_home: bool,
+}
+
+impl DashboardPage {
+    pub fn new(provider: Arc<dyn PriceProvider>) -> Self {
+        Self {
+            // Par défaut, les 4 premières cryptos sont affichées
+            selected: DASHBOARD_COINS.iter().enumerate().map(|(i, _)| i < 4).collect(),
+            charts: HashMap::new(),
+            pending: Vec::new(),
+            errors: HashMap::new(),
+            fetches: FetchPipeline::new(),
+            provider,
+            should_return_home: false,
+        }
+    }
+
+    fn fetch_missing(&mut self, ctx: &egui::Context) {
+        for (coin, selected) in DASHBOARD_COINS.iter().zip(&self.selected) {
+            let coin = coin.to_string();
+            if !*selected
+                || self.charts.contains_key(&coin)
+                || self.pending.contains(&coin)
+                || self.errors.contains_key(&coin)
+            {
+                continue;
+            }
+            self.pending.push(coin.clone());
+            self.fetches.fetch(ctx, FetchRequest {
+                provider: self.provider.clone(),
+                coin,
+                days: DEFAULT_DAYS,
+                currency: DEFAULT_CURRENCY.to_string(),
+                range: None,
+            });
+        }
+    }
+
+    pub fn update(&mut self, ctx: &egui::Context) {
+        crate::profile_function!();
+        for event in self.fetches.poll() {
+            let FetchEvent::Done { coin, result, .. } = event else {
+                continue;
+            };
+            self.pending.retain(|c| *c != coin);
+            match result {
+                Ok(data) => {
+                    self.charts.insert(coin, MiniChart { data, loaded_at: ctx.input(|i| i.time) });
+                },
+                Err(e) => {
+                    self.errors.insert(coin, e.to_string());
+                },
+            }
+        }
+        self.fetch_missing(ctx);
+
+        let now = ctx.input(|i| i.time);
+
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button(
+                    egui::RichText::new(tr("back-home"))
+                        .size(16.0)
+                        .color(Color32::from_rgb(255, 215, 0))
+                ).clicked() || shortcut_pressed(ctx, egui::Key::Backspace) {
+                    self.should_return_home = true;
+                }
+                ui.separator();
+
+                // Entre 1 et 6 cryptos affichées
+                let selected_count = self.selected.iter().filter(|s| **s).count();
+                for (coin, selected) in DASHBOARD_COINS.iter().zip(self.selected.iter_mut()) {
+                    let locked = *selected && selected_count == 1;
+                    ui.add_enabled(!locked, egui::Checkbox::new(selected, coin_display_name(coin)));
+                }
+            });
+            ui.add_space(8.0);
+
+            let coins: Vec<&str> = DASHBOARD_COINS
+                .iter()
+                .zip(&self.selected)
+                .filter(|(_, selected)| **selected)
+                .map(|(coin, _)| *coin)
+                .collect();
+            let columns = (if coins.len() > 4 { 3 } else { 2 }).min(coins.len().max(1));
+            let rows = coins.len().div_ceil(columns);
+            let spacing = 12.0;
+            let cell = egui::vec2(
+                (ui.available_width() - spacing * (columns as f32 - 1.0)) / columns as f32,
+                (ui.available_height() - spacing * (rows as f32 - 1.0)) / rows.max(1) as f32,
+            );
+
+            let mut retry = None;
+            for row in coins.chunks(columns) {
+                ui.horizontal(|ui| {
+                    ui.spacing_mut().item_spacing.x = spacing;
+                    for coin in row {
+                        match self.charts.get(*coin) {
+                            Some(chart) => chart.show(ui, cell, now),
+                            None => {
+                                ui.allocate_ui(cell, |ui| {
+                                    ui.set_min_size(cell);
+                                    ui.vertical_centered(|ui| {
+                                        ui.add_space(cell.y / 2.0 - 30.0);
+                                        match self.errors.get(*coin) {
+                                            Some(error) => {
+                                                ui.colored_label(Color32::from_rgb(255, 88, 88), error);
+                                                if ui.button(tr("retry")).clicked() {
+                                                    retry = Some(coin.to_string());
+                                                }
+                                            },
+                                            None => {
+                                                ui.label(tr_args("dashboard-loading", &[("coin", coin_display_name(coin))]));
+                                            },
+                                        }
+                                    });
+                                });
+                            },
+                        }
+                    }
+                });
+                ui.add_space(spacing);
+            }
+            if let Some(coin) = retry {
+                self.errors.remove(&coin);
+            }
+        });
+
+        // Pas de rafraîchissement continu : on se réveille pour le prochain pas d'une des têtes de lecture
+        let next_step = self.charts.values().map(|chart| chart.next_step_in(now)).reduce(f64::min);
+        if let Some(delay) = next_step {
+            ctx.request_repaint_after(std::time::Duration::from_secs_f64(delay));
+        }
+    }
+}
+
+impl Default for DashboardPage {
+    fn default() -> Self {
+        Self::new(Arc::new(CoinGeckoProvider))
+    }
+}
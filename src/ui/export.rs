@@ -0,0 +1,252 @@
+use super::captions::write_captions_srt;
+use crate::audio::{write_note_list, write_notes_wav, NoteEvent, NoteRow};
+use crate::error::{Error, Result};
+use crate::task::spawn_background;
+use chrono::Utc;
+use eframe::egui;
+use std::path::{Path, PathBuf};
+use std::sync::mpsc;
+
+pub const RECORDING_FPS: f64 = 10.0;
+pub const RECORDING_MAX_WIDTH: u32 = 960;
+// Avancement d'un encodage MP4 une fois les images écrites, puis une fois la bande son mixée
+const MP4_FRAMES_SHARE: f32 = 0.7;
+const MP4_AUDIO_SHARE: f32 = 0.8;
+
+// Découpe la zone du graphique dans une capture d'écran
+fn crop_screenshot(
+    screenshot: &egui::ColorImage,
+    rect: egui::Rect,
+    pixels_per_point: f32,
+) -> Option<image::RgbaImage> {
+    let region = screenshot.region(&rect, Some(pixels_per_point));
+    image::RgbaImage::from_raw(
+        region.size[0] as u32,
+        region.size[1] as u32,
+        region.as_raw().to_vec(),
+    )
+}
+
+// Largeurs proposées pour l'image, en dessous de la taille capturée
+pub const PNG_WIDTHS: [u32; 5] = [640, 960, 1280, 1920, 2560];
+
+// Largeur en pixels de la zone du graphique dans une capture d'écran, la plus grande image possible
+pub fn captured_width(plot_rect: egui::Rect, pixels_per_point: f32) -> u32 {
+    (plot_rect.width() * pixels_per_point).round().max(1.0) as u32
+}
+
+// Enregistre la zone du graphique, réduite à la largeur demandée. L'image vient de la capture de la
+// fenêtre : elle n'est jamais agrandie, une interpolation n'ajoutant aucun détail
+pub fn save_chart_png(
+    screenshot: &egui::ColorImage,
+    plot_rect: egui::Rect,
+    pixels_per_point: f32,
+    width: Option<u32>,
+) -> Result<PathBuf> {
+    let buffer = crop_screenshot(screenshot, plot_rect, pixels_per_point)
+        .ok_or_else(|| Error::Export("invalid screenshot buffer".to_string()))?;
+    let width = width.map_or(buffer.width(), |width| width.min(buffer.width()));
+    let image = if width < buffer.width() {
+        let height = (width as f32 * buffer.height() as f32 / buffer.width() as f32).round() as u32;
+        image::imageops::resize(&buffer, width, height.max(1), image::imageops::FilterType::Lanczos3)
+    } else {
+        buffer
+    };
+
+    std::fs::create_dir_all("exports")?;
+    let path = Path::new("exports").join(format!("chart_{}.png", Utc::now().format("%Y%m%d_%H%M%S")));
+    image.save(&path)?;
+    Ok(path)
+}
+
+// Liste des notes de la lecture, en CSV ou en JSON selon `extension`
+pub fn save_note_list(coin: &str, rows: &[NoteRow], extension: &str) -> Result<PathBuf> {
+    std::fs::create_dir_all("exports")?;
+    let path = Path::new("exports").join(format!("notes_{}_{}.{}", coin, Utc::now().format("%Y%m%d_%H%M%S"), extension));
+    write_note_list(rows, &path)?;
+    Ok(path)
+}
+
+#[derive(Clone, Copy, PartialEq)]
+pub enum RecordingFormat {
+    Gif,
+    Mp4,
+}
+
+impl RecordingFormat {
+    pub fn label(&self) -> &'static str {
+        match self {
+            RecordingFormat::Gif => "GIF",
+            RecordingFormat::Mp4 => "MP4",
+        }
+    }
+}
+
+// Encodage d'un enregistrement en arrière-plan : part faite (0 à 1), puis le fichier écrit
+pub enum RecordingEvent {
+    Progress(f32),
+    Done(Result<PathBuf>),
+}
+
+// Capture les images et les notes jouées pendant la lecture
+pub struct SessionRecorder {
+    format: RecordingFormat,
+    started_at: f64,
+    last_capture: f64,
+    frames: Vec<(f64, image::RgbaImage)>,
+    notes: Vec<NoteEvent>,
+    // Sous-titres affichés pendant l'enregistrement, avec leur instant d'apparition
+    captions: Vec<(f64, String)>,
+    // Sonie visée de la bande son, en LUFS
+    loudness: Option<f64>,
+}
+
+impl SessionRecorder {
+    pub fn new(format: RecordingFormat, now: f64, loudness: Option<f64>) -> Self {
+        Self {
+            format,
+            loudness,
+            started_at: now,
+            last_capture: f64::NEG_INFINITY,
+            frames: Vec::new(),
+            notes: Vec::new(),
+            captions: Vec::new(),
+        }
+    }
+
+    pub fn wants_frame(&mut self, now: f64) -> bool {
+        if now - self.last_capture >= 1.0 / RECORDING_FPS {
+            self.last_capture = now;
+            true
+        } else {
+            false
+        }
+    }
+
+    pub fn push_frame(&mut self, now: f64, screenshot: &egui::ColorImage, rect: egui::Rect, pixels_per_point: f32) {
+        if let Some(frame) = crop_screenshot(screenshot, rect, pixels_per_point) {
+            let frame = if frame.width() > RECORDING_MAX_WIDTH {
+                let height = frame.height() * RECORDING_MAX_WIDTH / frame.width();
+                image::imageops::resize(&frame, RECORDING_MAX_WIDTH, height, image::imageops::FilterType::Triangle)
+            } else {
+                frame
+            };
+            self.frames.push((now - self.started_at, frame));
+        }
+    }
+
+    // La note est recalée sur l'instant où elle a réellement été jouée
+    pub fn push_note(&mut self, now: f64, note: NoteEvent) {
+        self.notes.push(NoteEvent { start: now - self.started_at, ..note });
+    }
+
+    pub fn push_caption(&mut self, now: f64, text: String) {
+        self.captions.push((now - self.started_at, text));
+    }
+
+    // Rééchantillonne les captures à cadence fixe
+    fn constant_rate_frames(&self) -> Vec<&image::RgbaImage> {
+        let duration = self.frames.last().map(|(t, _)| *t).unwrap_or(0.0);
+        let slots = (duration * RECORDING_FPS).ceil() as usize + 1;
+        let mut frames = Vec::with_capacity(slots);
+        let mut current = 0;
+        for slot in 0..slots {
+            let t = slot as f64 / RECORDING_FPS;
+            while current + 1 < self.frames.len() && self.frames[current + 1].0 <= t {
+                current += 1;
+            }
+            frames.push(&self.frames[current].1);
+        }
+        frames
+    }
+
+    // L'encodage dure à peu près autant que l'enregistrement : il tourne sur son propre thread et
+    // l'interface lit son avancement sur le canal renvoyé
+    pub fn finish_in_background(self) -> mpsc::Receiver<RecordingEvent> {
+        let (tx, rx) = mpsc::channel();
+        spawn_background(move || {
+            let progress = |fraction: f32| {
+                tx.send(RecordingEvent::Progress(fraction)).ok();
+            };
+            let result = self.finish(&progress);
+            tx.send(RecordingEvent::Done(result)).ok();
+        });
+        rx
+    }
+
+    // `progress` reçoit la part des images encodées ; en MP4, la bande son et ffmpeg occupent la fin
+    pub fn finish(self, progress: &dyn Fn(f32)) -> Result<PathBuf> {
+        if self.frames.is_empty() {
+            return Err(Error::Export("no frames were captured".to_string()));
+        }
+
+        std::fs::create_dir_all("exports")?;
+        let stem = format!("session_{}", Utc::now().format("%Y%m%d_%H%M%S"));
+
+        match self.format {
+            RecordingFormat::Gif => {
+                let path = Path::new("exports").join(format!("{}.gif", stem));
+                let file = std::fs::File::create(&path)?;
+                let mut encoder = image::codecs::gif::GifEncoder::new(file);
+                encoder.set_repeat(image::codecs::gif::Repeat::Infinite)?;
+                let delay = image::Delay::from_numer_denom_ms((1000.0 / RECORDING_FPS) as u32, 1);
+                let frames = self.constant_rate_frames();
+                let count = frames.len();
+                encoder.encode_frames(frames.into_iter().enumerate().map(|(i, frame)| {
+                    progress(i as f32 / count as f32);
+                    image::Frame::from_parts(frame.clone(), 0, 0, delay)
+                }))?;
+                // Un GIF n'a pas de piste de sous-titres : ils l'accompagnent dans un fichier SRT du même nom
+                if !self.captions.is_empty() {
+                    let duration = self.frames.last().map_or(0.0, |(t, _)| *t);
+                    write_captions_srt(&self.captions, duration, &path.with_extension("srt"))?;
+                }
+                Ok(path)
+            },
+            RecordingFormat::Mp4 => {
+                // Les images et l'audio sont écrits dans un dossier temporaire puis assemblés par ffmpeg
+                let work_dir = Path::new("exports").join(format!("{}_frames", stem));
+                std::fs::create_dir_all(&work_dir)?;
+                let frames = self.constant_rate_frames();
+                for (i, frame) in frames.iter().enumerate() {
+                    progress(MP4_FRAMES_SHARE * i as f32 / frames.len() as f32);
+                    frame.save(work_dir.join(format!("frame_{:05}.png", i)))?;
+                }
+                let duration = frames.len() as f64 / RECORDING_FPS;
+                let audio_path = work_dir.join("audio.wav");
+                write_notes_wav(&self.notes, duration, self.loudness, &audio_path)?;
+                progress(MP4_AUDIO_SHARE);
+
+                let path = Path::new("exports").join(format!("{}.mp4", stem));
+                let mut command = std::process::Command::new("ffmpeg");
+                command
+                    .arg("-y")
+                    .args(["-framerate", &RECORDING_FPS.to_string()])
+                    .arg("-i")
+                    .arg(work_dir.join("frame_%05d.png"))
+                    .arg("-i")
+                    .arg(&audio_path);
+                // Sous-titres en piste désactivable, lisibles par les lecteurs vidéo et les plateformes
+                if !self.captions.is_empty() {
+                    let captions_path = work_dir.join("captions.srt");
+                    write_captions_srt(&self.captions, duration, &captions_path)?;
+                    command
+                        .arg("-i")
+                        .arg(&captions_path)
+                        .args(["-map", "0:v", "-map", "1:a", "-map", "2:s", "-c:s", "mov_text"]);
+                }
+                let status = command
+                    .args(["-c:v", "libx264", "-pix_fmt", "yuv420p", "-vf", "pad=ceil(iw/2)*2:ceil(ih/2)*2"])
+                    .args(["-c:a", "aac", "-shortest"])
+                    .arg(&path)
+                    .status()
+                    .map_err(|e| Error::Export(format!("ffmpeg not available ({}), frames kept in {}", e, work_dir.display())))?;
+                if !status.success() {
+                    return Err(Error::Export(format!("ffmpeg failed, frames kept in {}", work_dir.display())));
+                }
+                std::fs::remove_dir_all(&work_dir)?;
+                Ok(path)
+            },
+        }
+    }
+}
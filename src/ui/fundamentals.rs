@@ -0,0 +1,87 @@
+use super::widgets::{secondary_text_color, ChartPalette};
+use crate::data::{coin_meta, fetch_coin_fundamentals_async, format_compact, format_price, CoinFundamentals};
+use crate::error::Result;
+use crate::i18n::tr;
+use crate::task::spawn_request;
+use eframe::egui;
+use std::sync::mpsc;
+
+// Bandeau des chiffres clés sous l'en-tête du graphique, chargé à l'ouverture de la page
+pub struct FundamentalsBand {
+    coin: String,
+    currency: String,
+    request: Option<mpsc::Receiver<Result<CoinFundamentals>>>,
+    fundamentals: Option<CoinFundamentals>,
+    error: Option<String>,
+}
+
+impl FundamentalsBand {
+    pub fn new(coin: &str, currency: &str) -> Self {
+        Self {
+            coin: coin.to_string(),
+            currency: currency.to_string(),
+            request: None,
+            fundamentals: None,
+            error: None,
+        }
+    }
+
+    fn receive_fundamentals(&mut self, ctx: &egui::Context) {
+        if self.fundamentals.is_none() && self.error.is_none() && self.request.is_none() {
+            let (coin, currency) = (self.coin.clone(), self.currency.clone());
+            self.request = Some(spawn_request(ctx, async move { fetch_coin_fundamentals_async(&coin, &currency).await }));
+        }
+        let Some(request) = &self.request else {
+            return;
+        };
+        if let Ok(result) = request.try_recv() {
+            self.request = None;
+            match result {
+                Ok(fundamentals) => self.fundamentals = Some(fundamentals),
+                Err(e) => self.error = Some(e.to_string()),
+            }
+        }
+    }
+
+    pub fn show(&mut self, ui: &mut egui::Ui) {
+        self.receive_fundamentals(ui.ctx());
+
+        ui.horizontal_wrapped(|ui| {
+            let secondary = secondary_text_color(ui);
+            if let Some(error) = &self.error {
+                ui.label(egui::RichText::new(tr("fundamentals-failed")).size(12.0).color(secondary)).on_hover_text(error);
+                if ui.small_button(tr("retry")).clicked() {
+                    self.error = None;
+                }
+                return;
+            }
+            let Some(fundamentals) = &self.fundamentals else {
+                ui.label(egui::RichText::new(tr("fundamentals-loading")).size(12.0).color(secondary));
+                return;
+            };
+
+            let symbol = coin_meta(&self.coin).map_or("", |meta| meta.symbol);
+            let fields = [
+                ("fundamentals-rank", fundamentals.market_cap_rank.map(|rank| format!("#{}", rank))),
+                ("fundamentals-market-cap", fundamentals.market_cap.map(|cap| format_compact(cap, &self.currency))),
+                ("fundamentals-supply", fundamentals.circulating_supply.map(|supply| format_compact(supply, symbol).trim_end().to_string())),
+                ("fundamentals-high-24h", fundamentals.high_24h.map(|high| format_price(high, &self.currency))),
+                ("fundamentals-low-24h", fundamentals.low_24h.map(|low| format_price(low, &self.currency))),
+            ];
+            for (label, value) in fields {
+                let Some(value) = value else {
+                    continue;
+                };
+                ui.label(egui::RichText::new(tr(label)).size(12.0).color(secondary));
+                ui.label(egui::RichText::new(value).size(13.0).strong());
+                ui.add_space(8.0);
+            }
+            // Distance au plus haut historique, colorée comme une baisse quand on en est loin
+            if let (Some(ath), Some(change)) = (fundamentals.ath, fundamentals.ath_change) {
+                ui.label(egui::RichText::new(tr("fundamentals-ath")).size(12.0).color(secondary));
+                ui.label(egui::RichText::new(format_price(ath, &self.currency)).size(13.0).strong());
+                ui.label(egui::RichText::new(format!("{:+.1}%", change)).size(13.0).color(ChartPalette::current().change_color(change)));
+            }
+        });
+    }
+}
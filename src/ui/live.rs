@@ -0,0 +1,255 @@
+use super::widgets::secondary_text_color;
+use crate::audio::{self, NoteEvent, Sonifier, ViewMode, STEP_SECONDS};
+use crate::data::{fetch_exchange_price_async, format_price, Bar, BarAggregator, BarLength, DailyPrice, Exchange};
+use crate::i18n::{tr, tr_args};
+#[cfg(not(target_arch = "wasm32"))]
+use crate::task::{runtime, CancelToken};
+use eframe::egui;
+use rodio::{OutputStream, Sink};
+use std::sync::{Arc, Mutex};
+use std::time::Duration as StdDuration;
+
+// Deux relevés par seconde : même les barres d'une seconde reçoivent plusieurs prix
+const POLL_SECONDS: f64 = 0.5;
+const MAX_QUEUED: usize = 2;
+const DEFAULT_BAR_LENGTH: BarLength = BarLength::FiveSeconds;
+
+// Barres, dernier prix et sortie son, partagés avec le thread qui relève le prix
+struct LiveState {
+    aggregator: BarAggregator,
+    // Clôture de la dernière barre jouée, point de départ de la note suivante
+    previous_close: Option<f64>,
+    last_price: Option<f64>,
+    bars: usize,
+    error: Option<String>,
+    audible: bool,
+    sink: Option<Sink>,
+}
+
+impl LiveState {
+    // Nouveau relevé ; chaque barre terminée devient une note, jouée si le direct est audible
+    fn record(&mut self, time: f64, price: f64) {
+        self.last_price = Some(price);
+        let Some(bar) = self.aggregator.push(time, price) else {
+            return;
+        };
+        self.bars += 1;
+        let note = self.previous_close.and_then(|previous| bar_note(previous, &bar, self.aggregator.length()));
+        self.previous_close = Some(bar.close);
+        if let Some(sink) = self.sink.as_ref().filter(|_| self.audible) {
+            if let Some(note) = note.filter(|_| sink.len() < MAX_QUEUED) {
+                sink.append(audio::note_source(&note));
+            }
+        }
+    }
+}
+
+// Mode direct : le prix actuel sur Binance, relevé en continu et regroupé en barres de longueur
+// choisie ; chaque barre terminée joue une note, comme un jour de la lecture. En natif, le relevé et
+// les notes tournent sur un thread à part et continuent sans frames (fenêtre cachée dans la zone de
+// notification) ; dans le navigateur, ils suivent les frames
+pub struct LiveLayer {
+    state: Arc<Mutex<LiveState>>,
+    // Le flux audio reste sur le thread de l'interface ; son sink est dans l'état partagé
+    _stream: Option<OutputStream>,
+    #[cfg(not(target_arch = "wasm32"))]
+    poller: CancelToken,
+    #[cfg(target_arch = "wasm32")]
+    symbol: String,
+    #[cfg(target_arch = "wasm32")]
+    request: Option<std::sync::mpsc::Receiver<crate::error::Result<f64>>>,
+    #[cfg(target_arch = "wasm32")]
+    next_poll: f64,
+}
+
+impl LiveLayer {
+    pub fn new(symbol: String, volume: f32) -> Self {
+        let (stream, sink, error) = match audio::open_sound_output(volume) {
+            Ok((stream, sink)) => (Some(stream), Some(sink), None),
+            Err(e) => (None, None, Some(e.to_string())),
+        };
+        let state = Arc::new(Mutex::new(LiveState {
+            aggregator: BarAggregator::new(DEFAULT_BAR_LENGTH),
+            previous_close: None,
+            last_price: None,
+            bars: 0,
+            error,
+            audible: false,
+            sink,
+        }));
+        Self {
+            #[cfg(not(target_arch = "wasm32"))]
+            poller: poll_in_background(symbol, Arc::clone(&state)),
+            #[cfg(target_arch = "wasm32")]
+            symbol,
+            #[cfg(target_arch = "wasm32")]
+            request: None,
+            #[cfg(target_arch = "wasm32")]
+            next_poll: 0.0,
+            state,
+            _stream: stream,
+        }
+    }
+
+    // Reprend à partir d'un prix connu (fin de la lecture de l'historique) : la première barre joue déjà une note
+    pub fn with_previous_close(self, price: Option<f64>) -> Self {
+        if let Ok(mut state) = self.state.lock() {
+            state.previous_close = price;
+        }
+        self
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        if let Some(sink) = self.state.lock().ok().as_ref().and_then(|state| state.sink.as_ref()) {
+            sink.set_volume(volume);
+        }
+    }
+
+    pub fn last_price(&self) -> Option<f64> {
+        self.state.lock().ok().and_then(|state| state.last_price)
+    }
+
+    pub fn bar_length(&self) -> BarLength {
+        self.state.lock().map_or(DEFAULT_BAR_LENGTH, |state| state.aggregator.length())
+    }
+
+    // Les notes ne jouent que si `audible` ; le dernier choix vaut aussi quand les frames s'arrêtent
+    pub fn update(&mut self, ctx: &egui::Context, now: f64, audible: bool) {
+        if let Ok(mut state) = self.state.lock() {
+            state.audible = audible;
+        }
+        #[cfg(target_arch = "wasm32")]
+        self.poll(ctx, now);
+        #[cfg(not(target_arch = "wasm32"))]
+        let _ = now;
+        // Le statut suit les relevés
+        ctx.request_repaint_after(StdDuration::from_secs_f64(POLL_SECONDS));
+    }
+
+    // Sans threads dans le navigateur : une requête par frame au plus, relancée à intervalle régulier
+    #[cfg(target_arch = "wasm32")]
+    fn poll(&mut self, ctx: &egui::Context, now: f64) {
+        use crate::task::spawn_request;
+        use std::sync::mpsc::TryRecvError;
+
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        if self.request.is_none() && state.error.is_none() && now >= self.next_poll {
+            let symbol = self.symbol.clone();
+            self.request = Some(spawn_request(ctx, async move { fetch_exchange_price_async(Exchange::Binance, &symbol).await }));
+            self.next_poll = now + POLL_SECONDS;
+        }
+        let Some(request) = &self.request else {
+            return;
+        };
+        match request.try_recv() {
+            Ok(Ok(price)) => {
+                self.request = None;
+                state.record(now, price);
+            },
+            Ok(Err(e)) => {
+                state.error = Some(e.to_string());
+                self.request = None;
+            },
+            Err(TryRecvError::Empty) => {},
+            Err(TryRecvError::Disconnected) => self.request = None,
+        }
+    }
+
+    // Dernier prix, nombre de barres et choix de leur longueur
+    pub fn show_status(&mut self, ui: &mut egui::Ui) {
+        let Ok(mut state) = self.state.lock() else {
+            return;
+        };
+        let mut bar_length = state.aggregator.length();
+        egui::ComboBox::from_id_source("live_bar_length")
+            .selected_text(tr(bar_length.label_key()))
+            .width(60.0)
+            .show_ui(ui, |ui| {
+                for option in BarLength::ALL {
+                    ui.selectable_value(&mut bar_length, option, tr(option.label_key()));
+                }
+            });
+        if bar_length != state.aggregator.length() {
+            state.aggregator.set_length(bar_length);
+            state.previous_close = None;
+            state.bars = 0;
+        }
+
+        let text = if let Some(error) = &state.error {
+            let retry = ui.small_button(tr("retry")).on_hover_text(error).clicked();
+            if retry {
+                state.error = None;
+            }
+            tr("live-failed").to_string()
+        } else if let Some(price) = state.last_price {
+            tr_args("live-status", &[("price", &format_price(price, "usd")), ("bars", &state.bars.to_string())])
+        } else {
+            tr("live-loading").to_string()
+        };
+        ui.label(egui::RichText::new(text).size(12.0).color(secondary_text_color(ui)));
+    }
+}
+
+#[cfg(not(target_arch = "wasm32"))]
+impl Drop for LiveLayer {
+    fn drop(&mut self) {
+        self.poller.cancel();
+    }
+}
+
+// Relève le prix sur un thread jusqu'à l'annulation du jeton renvoyé ; après un échec, il attend que
+// « Réessayer » efface l'erreur
+#[cfg(not(target_arch = "wasm32"))]
+fn poll_in_background(symbol: String, state: Arc<Mutex<LiveState>>) -> CancelToken {
+    let poller = CancelToken::new();
+    let cancel = poller.clone();
+    std::thread::spawn(move || {
+        let started = std::time::Instant::now();
+        while !cancel.is_cancelled() {
+            let next_poll = std::time::Instant::now() + StdDuration::from_secs_f64(POLL_SECONDS);
+            if state.lock().is_ok_and(|state| state.error.is_none()) {
+                let price = runtime().and_then(|runtime| runtime.block_on(fetch_exchange_price_async(Exchange::Binance, &symbol)));
+                let Ok(mut state) = state.lock() else {
+                    return;
+                };
+                match price {
+                    Ok(price) => state.record(started.elapsed().as_secs_f64(), price),
+                    Err(e) => state.error = Some(e.to_string()),
+                }
+            }
+            std::thread::sleep(next_poll.saturating_duration_since(std::time::Instant::now()));
+        }
+    });
+    poller
+}
+
+// Note d'une barre : la clôture précédente puis la barre (avec ses extrêmes) jouées comme deux jours
+// consécutifs, la note durant au plus la barre
+pub fn bar_note(previous_close: f64, bar: &Bar, length: BarLength) -> Option<NoteEvent> {
+    bar_note_with(Sonifier::new(ViewMode::Price), previous_close, bar, length)
+}
+
+// Même note avec les réglages de son d'un autre sonificateur (radio) ; son tempo est remplacé par celui des barres
+pub fn bar_note_with(sonifier: Sonifier, previous_close: f64, bar: &Bar, length: BarLength) -> Option<NoteEvent> {
+    let (sonifier, prices) = bar_series(sonifier, previous_close, bar, length);
+    sonifier.sonify(&prices).pop()
+}
+
+// Note de la barre suivie de sa voix d'harmonie, quand elle est activée
+pub fn bar_voices(sonifier: Sonifier, previous_close: f64, bar: &Bar, length: BarLength) -> Vec<NoteEvent> {
+    let (sonifier, prices) = bar_series(sonifier, previous_close, bar, length);
+    let mut notes = sonifier.sonify(&prices);
+    notes.extend(sonifier.harmony(&prices));
+    notes
+}
+
+fn bar_series(sonifier: Sonifier, previous_close: f64, bar: &Bar, length: BarLength) -> (Sonifier, [DailyPrice; 2]) {
+    let sonifier = Sonifier::new(sonifier.view_mode).with_options(sonifier.options).with_speed(STEP_SECONDS / length.seconds().min(STEP_SECONDS));
+    let prices = [
+        DailyPrice::new(String::new(), previous_close),
+        DailyPrice { range: Some((bar.low, bar.high)), ..DailyPrice::new(String::new(), bar.close) },
+    ];
+    (sonifier, prices)
+}
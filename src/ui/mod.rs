@@ -0,0 +1,20 @@
+// Pages et widgets egui
+pub mod a11y;
+pub mod browse;
+pub mod captions;
+pub mod chart;
+pub mod dashboard;
+pub mod export;
+pub mod fundamentals;
+pub mod live;
+pub mod news;
+pub mod orderbook;
+pub mod overview;
+pub mod recipe;
+pub mod selection;
+pub mod sentiment;
+pub mod session;
+pub mod spread;
+pub mod textures;
+pub mod ticker;
+pub mod widgets;
@@ -0,0 +1,95 @@
+use super::a11y::{accessible_name, focus_outline};
+use super::widgets::secondary_text_color;
+use crate::data::{day_index, fetch_headlines_async, DailyPrice, Headline};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui;
+use std::sync::mpsc;
+
+// Titres récents de la crypto à droite du graphique ; un clic sur un titre place la lecture à sa date
+pub struct NewsPanel {
+    coin: String,
+    request: Option<mpsc::Receiver<Result<Vec<Headline>>>>,
+    headlines: Option<Vec<Headline>>,
+    error: Option<String>,
+}
+
+impl NewsPanel {
+    pub fn new(coin: &str) -> Self {
+        Self {
+            coin: coin.to_string(),
+            request: None,
+            headlines: None,
+            error: None,
+        }
+    }
+
+    fn receive_headlines(&mut self, ctx: &egui::Context) {
+        if self.headlines.is_none() && self.error.is_none() && self.request.is_none() {
+            let coin = self.coin.clone();
+            self.request = Some(spawn_request(ctx, async move { fetch_headlines_async(&coin).await }));
+        }
+        let Some(request) = &self.request else {
+            return;
+        };
+        if let Ok(result) = request.try_recv() {
+            self.request = None;
+            match result {
+                Ok(headlines) => self.headlines = Some(headlines),
+                Err(e) => self.error = Some(e.to_string()),
+            }
+        }
+    }
+
+    // Renvoie l'indice du jour à rejoindre quand un titre est cliqué ; à appeler avant le panneau central
+    pub fn show(&mut self, ctx: &egui::Context, prices: &[DailyPrice]) -> Option<usize> {
+        self.receive_headlines(ctx);
+
+        let mut chosen = None;
+        egui::SidePanel::right("news_panel").default_width(280.0).show(ctx, |ui| {
+            ui.heading(tr("news-title"));
+            ui.add_space(4.0);
+
+            if let Some(error) = &self.error {
+                ui.colored_label(egui::Color32::from_rgb(255, 88, 88), error);
+                if ui.button(tr("retry")).clicked() {
+                    self.error = None;
+                }
+                return;
+            }
+            let Some(headlines) = &self.headlines else {
+                ui.label(tr("news-loading"));
+                return;
+            };
+            if headlines.is_empty() {
+                ui.label(tr("news-empty"));
+                return;
+            }
+
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                for headline in headlines {
+                    let index = day_index(prices, &headline.date);
+                    let details = match &headline.source {
+                        Some(source) => format!("{} · {}", headline.date, source),
+                        None => headline.date.clone(),
+                    };
+                    ui.label(egui::RichText::new(details).size(11.0).color(secondary_text_color(ui)));
+                    ui.horizontal_wrapped(|ui| {
+                        let title = egui::Button::new(egui::RichText::new(&headline.title).strong()).frame(false).wrap(true);
+                        let hint = if index.is_some() { tr("news-jump-hint") } else { tr("news-out-of-range") };
+                        if focus_outline(ui.add_enabled(index.is_some(), title)).on_hover_text(hint).on_disabled_hover_text(hint).clicked() {
+                            chosen = index;
+                        }
+                        if !headline.link.is_empty() {
+                            let link = ui.hyperlink_to("↗", &headline.link);
+                            accessible_name(link, egui::WidgetType::Link, &tr_args("a11y-open-article", &[("title", &headline.title)]));
+                        }
+                    });
+                    ui.separator();
+                }
+            });
+        });
+        chosen
+    }
+}
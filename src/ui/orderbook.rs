@@ -0,0 +1,115 @@
+use super::widgets::secondary_text_color;
+use crate::audio::{self, book_texture, wall_burst};
+use crate::data::{fetch_order_book_async, format_price, BookSide, OrderBook, Wall};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui;
+use rodio::{OutputStream, Sink, Source};
+use std::sync::mpsc;
+use std::time::Duration as StdDuration;
+
+// Un relevé du carnet par seconde, chacun joué pendant une seconde
+const POLL_SECONDS: f64 = 1.0;
+// Au-delà, la texture accumulée prendrait du retard sur le carnet
+const MAX_QUEUED: usize = 2;
+
+// Couche texturée sous la mélodie : le carnet d'ordres actuel de la crypto, en souffle stéréo
+pub struct OrderBookLayer {
+    symbol: String,
+    request: Option<mpsc::Receiver<Result<OrderBook>>>,
+    next_poll: f64,
+    book: Option<OrderBook>,
+    walls: Vec<Wall>,
+    error: Option<String>,
+    // Sortie à part : les notes de la lecture attendent leur tour dans le sink principal
+    output: Option<(OutputStream, Sink)>,
+}
+
+impl OrderBookLayer {
+    pub fn new(symbol: String, volume: f32) -> Self {
+        let (output, error) = match audio::open_sound_output(volume) {
+            Ok(output) => (Some(output), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Self {
+            symbol,
+            request: None,
+            next_poll: 0.0,
+            book: None,
+            walls: Vec::new(),
+            error,
+            output,
+        }
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        if let Some((_, sink)) = &self.output {
+            sink.set_volume(volume);
+        }
+    }
+
+    // Relève le carnet à intervalle régulier ; pendant la lecture, chaque relevé ajoute sa texture
+    pub fn update(&mut self, ctx: &egui::Context, now: f64, playing: bool) {
+        if self.request.is_none() && self.error.is_none() && now >= self.next_poll {
+            let symbol = self.symbol.clone();
+            self.request = Some(spawn_request(ctx, async move { fetch_order_book_async(&symbol).await }));
+            self.next_poll = now + POLL_SECONDS;
+        }
+        ctx.request_repaint_after(StdDuration::from_secs_f64((self.next_poll - now).max(0.0)));
+
+        let Some(request) = &self.request else {
+            return;
+        };
+        let Ok(result) = request.try_recv() else {
+            return;
+        };
+        self.request = None;
+        let book = match result {
+            Ok(book) => book,
+            Err(e) => {
+                self.error = Some(e.to_string());
+                return;
+            },
+        };
+        self.walls = self.book.as_ref().map_or_else(Vec::new, |previous| book.new_walls(previous));
+
+        if let Some((_, sink)) = self.output.as_ref().filter(|_| playing) {
+            if sink.len() < MAX_QUEUED {
+                let mut layer: Box<dyn Source<Item = f32> + Send> =
+                    Box::new(book_texture(book.imbalance(), StdDuration::from_secs_f64(POLL_SECONDS)));
+                for side in [BookSide::Bid, BookSide::Ask] {
+                    if self.walls.iter().any(|wall| wall.side == side) {
+                        layer = Box::new(layer.mix(wall_burst(side == BookSide::Bid)));
+                    }
+                }
+                sink.append(layer);
+            }
+        }
+        self.book = Some(book);
+    }
+
+    // Part des achats dans le carnet et dernier mur apparu
+    pub fn show_status(&mut self, ui: &mut egui::Ui) {
+        let text = if let Some(error) = &self.error {
+            if ui.small_button(tr("retry")).on_hover_text(error).clicked() {
+                self.error = None;
+            }
+            tr("orderbook-failed").to_string()
+        } else if let Some(book) = &self.book {
+            let bids = format!("{:.0}", (book.imbalance() + 1.0) * 50.0);
+            let mut text = tr_args("orderbook-status", &[("bids", bids)]);
+            if let Some(wall) = self.walls.iter().max_by(|a, b| a.size.total_cmp(&b.size)) {
+                let side = match wall.side {
+                    BookSide::Bid => tr("orderbook-bid-wall"),
+                    BookSide::Ask => tr("orderbook-ask-wall"),
+                };
+                text.push_str(&tr_args("orderbook-wall", &[("side", side.to_string()), ("price", format_price(wall.price, "usd"))]));
+            }
+            text
+        } else {
+            tr("orderbook-loading").to_string()
+        };
+        ui.label(egui::RichText::new(text).size(12.0).color(secondary_text_color(ui)));
+    }
+}
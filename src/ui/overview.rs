@@ -0,0 +1,172 @@
+use super::a11y::shortcut_pressed;
+use super::widgets::{secondary_text_color, ChartPalette};
+use crate::data::{
+    coin_accent, fetch_global_market_async, fetch_top_coins_async, format_compact, format_price, top_movers, CoinId, GlobalMarket, MarketCoin,
+    TOP_COINS,
+};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui::{self, Color32};
+use std::sync::mpsc;
+
+// Hausses et baisses affichées de chaque côté, parmi les 100 plus grosses capitalisations
+const MOVERS: usize = 10;
+
+// Vue d'ensemble du marché : capitalisation totale, dominance de Bitcoin, volume sur 24 h, puis les
+// plus fortes hausses et baisses du jour, chacune sonifiable en un clic
+pub struct OverviewPage {
+    currency: String,
+    global_request: Option<mpsc::Receiver<Result<GlobalMarket>>>,
+    coins_request: Option<mpsc::Receiver<Result<Vec<MarketCoin>>>>,
+    global: Option<GlobalMarket>,
+    coins: Vec<MarketCoin>,
+    error: Option<String>,
+    pub should_return_home: bool,
+}
+
+impl OverviewPage {
+    pub fn new(currency: &str) -> Self {
+        Self {
+            currency: currency.to_string(),
+            global_request: None,
+            coins_request: None,
+            global: None,
+            coins: Vec::new(),
+            error: None,
+            should_return_home: false,
+        }
+    }
+
+    fn receive_data(&mut self, ctx: &egui::Context) {
+        if self.error.is_none() {
+            if self.global.is_none() && self.global_request.is_none() {
+                let currency = self.currency.clone();
+                self.global_request = Some(spawn_request(ctx, async move { fetch_global_market_async(&currency).await }));
+            }
+            if self.coins.is_empty() && self.coins_request.is_none() {
+                let currency = self.currency.clone();
+                self.coins_request = Some(spawn_request(ctx, async move { fetch_top_coins_async(&currency, TOP_COINS).await }));
+            }
+        }
+        if let Some(Ok(result)) = self.global_request.as_ref().map(mpsc::Receiver::try_recv) {
+            self.global_request = None;
+            match result {
+                Ok(global) => self.global = Some(global),
+                Err(e) => self.error = Some(e.to_string()),
+            }
+        }
+        if let Some(Ok(result)) = self.coins_request.as_ref().map(mpsc::Receiver::try_recv) {
+            self.coins_request = None;
+            match result {
+                Ok(coins) => self.coins = coins,
+                Err(e) => self.error = Some(e.to_string()),
+            }
+        }
+    }
+
+    // Renvoie l'identifiant et le nom de la crypto à sonifier
+    pub fn update(&mut self, ctx: &egui::Context) -> Option<(CoinId, String)> {
+        crate::profile_function!();
+        self.receive_data(ctx);
+
+        let mut chosen = None;
+        egui::CentralPanel::default().show(ctx, |ui| {
+            ui.horizontal(|ui| {
+                if ui.button(
+                    egui::RichText::new(tr("back-home"))
+                        .size(16.0)
+                        .color(Color32::from_rgb(255, 215, 0))
+                ).clicked() || shortcut_pressed(ctx, egui::Key::Backspace) {
+                    self.should_return_home = true;
+                }
+                ui.separator();
+                ui.heading(tr("overview-title"));
+            });
+            ui.add_space(8.0);
+
+            if let Some(error) = &self.error {
+                ui.colored_label(Color32::from_rgb(255, 88, 88), error);
+                if ui.button(tr("retry")).clicked() {
+                    self.error = None;
+                }
+                return;
+            }
+
+            match &self.global {
+                Some(global) => self.show_global(ui, global),
+                None => {
+                    ui.label(tr("browse-loading"));
+                },
+            }
+            ui.add_space(12.0);
+            if self.coins.is_empty() {
+                return;
+            }
+
+            let (gainers, losers) = top_movers(&self.coins, MOVERS);
+            egui::ScrollArea::vertical().show(ui, |ui| {
+                ui.columns(2, |columns| {
+                    let sides = [("overview-gainers", gainers, "overview_gainers"), ("overview-losers", losers, "overview_losers")];
+                    for (ui, (title, movers, id)) in columns.iter_mut().zip(sides) {
+                        ui.label(egui::RichText::new(tr(title)).size(18.0).strong());
+                        if let Some(coin) = self.show_movers(ui, id, &movers) {
+                            chosen = Some(coin);
+                        }
+                    }
+                });
+            });
+        });
+        chosen
+    }
+
+    fn show_global(&self, ui: &mut egui::Ui, global: &GlobalMarket) {
+        let palette = ChartPalette::current();
+        ui.horizontal_wrapped(|ui| {
+            for (label, value, color) in [
+                ("overview-market-cap", format_compact(global.total_market_cap, &self.currency), None),
+                ("overview-market-cap-change", format!("{:+.2}%", global.market_cap_change_24h), Some(palette.change_color(global.market_cap_change_24h))),
+                ("overview-btc-dominance", format!("{:.1}%", global.btc_dominance), None),
+                ("overview-volume", format_compact(global.total_volume, &self.currency), None),
+            ] {
+                egui::Frame::group(ui.style()).show(ui, |ui| {
+                    ui.vertical(|ui| {
+                        ui.label(egui::RichText::new(tr(label)).size(12.0).color(secondary_text_color(ui)));
+                        let value = egui::RichText::new(value).size(20.0).strong();
+                        ui.label(match color {
+                            Some(color) => value.color(color),
+                            None => value,
+                        });
+                    });
+                });
+            }
+        });
+    }
+
+    // Une ligne par crypto : nom, prix, variation et bouton de sonification
+    fn show_movers(&self, ui: &mut egui::Ui, id: &str, movers: &[&MarketCoin]) -> Option<(CoinId, String)> {
+        if movers.is_empty() {
+            ui.label(egui::RichText::new(tr("overview-none")).color(secondary_text_color(ui)));
+            return None;
+        }
+        let mut chosen = None;
+        egui::Grid::new(id).striped(true).spacing([12.0, 6.0]).show(ui, |ui| {
+            for coin in movers {
+                ui.label(egui::RichText::new(format!("{} ({})", coin.name, coin.symbol.to_uppercase()))
+                    .strong()
+                    .color(coin_accent(&coin.id)));
+                ui.label(coin.current_price.map_or("-".to_string(), |price| format_price(price, &self.currency)));
+                let change = coin.price_change_percentage_24h.unwrap_or(0.0);
+                ui.colored_label(ChartPalette::current().change_color(change), format!("{:+.2}%", change));
+                if ui.button(tr("overview-sonify"))
+                    .on_hover_text(tr_args("overview-sonify-hint", &[("coin", &coin.name)]))
+                    .clicked()
+                {
+                    chosen = Some((coin.id.clone(), coin.name.clone()));
+                }
+                ui.end_row();
+            }
+        });
+        chosen
+    }
+}
@@ -0,0 +1,114 @@
+use crate::audio::{SoundOptions, ViewMode};
+use crate::data::{CoinId, DateRange};
+use crate::error::{Error, Result};
+use chrono::{DateTime, Duration, Utc};
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const RECIPES_DIR: &str = "recipes";
+
+// Incrémentée quand le format change de façon incompatible
+// (2 : période fixe et options de son à la place des derniers jours)
+const RECIPE_VERSION: u32 = 2;
+
+// De quoi reproduire une sonification chez quelqu'un d'autre : contrairement à une session, les prix
+// ne sont pas inclus mais retéléchargés, la recette tient donc en une ligne. La période est fixe et
+// les options de son voyagent avec elle : la même recette rejoue la même chose, plus tard comme ailleurs
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct Recipe {
+    pub version: u32,
+    pub coin: CoinId,
+    pub range: DateRange,
+    pub currency: String,
+    pub preset: ViewMode,
+    pub speed: f64,
+    pub sound: SoundOptions,
+}
+
+// Recette de la version 1 : les `days` derniers jours au moment de l'ouvrir, avec le son de chacun
+#[derive(Deserialize)]
+struct RecipeV1 {
+    coin: CoinId,
+    days: i64,
+    currency: String,
+    preset: ViewMode,
+    speed: f64,
+}
+
+impl Recipe {
+    pub fn new(coin: CoinId, range: DateRange, currency: String, preset: ViewMode, speed: f64, sound: SoundOptions) -> Self {
+        Self { version: RECIPE_VERSION, coin, range, currency, preset, speed, sound }
+    }
+
+    // JSON compact, à coller dans un message
+    pub fn to_json(&self) -> Result<String> {
+        Ok(serde_json::to_string(self)?)
+    }
+
+    // Relit une recette collée ou chargée ; les valeurs absurdes sont refusées plutôt que corrigées
+    pub fn from_json(text: &str) -> Result<Self> {
+        #[derive(Deserialize)]
+        struct Version {
+            version: u32,
+        }
+        let text = text.trim();
+        let mut recipe = match serde_json::from_str::<Version>(text)?.version {
+            version if version > RECIPE_VERSION => return Err(Error::Unsupported("recipes made by a newer version")),
+            1 => Self::from_v1(serde_json::from_str(text)?, Utc::now())?,
+            _ => serde_json::from_str::<Recipe>(text)?,
+        };
+        if recipe.coin.trim().is_empty() || recipe.currency.trim().is_empty() {
+            return Err(Error::InvalidRecipe("missing coin or currency".to_string()));
+        }
+        if recipe.range.end <= recipe.range.start {
+            return Err(Error::InvalidRecipe(format!("range {} to {}", recipe.range.start, recipe.range.end)));
+        }
+        if !(recipe.speed > 0.0 && recipe.speed.is_finite()) {
+            return Err(Error::InvalidRecipe(format!("speed {}", recipe.speed)));
+        }
+        recipe.coin = recipe.coin.trim().to_lowercase();
+        recipe.currency = recipe.currency.trim().to_lowercase();
+        Ok(recipe)
+    }
+
+    // Les anciennes recettes gardent leur sens d'origine : les derniers jours à l'ouverture, avec le son par défaut
+    fn from_v1(recipe: RecipeV1, now: DateTime<Utc>) -> Result<Self> {
+        if recipe.days <= 0 {
+            return Err(Error::InvalidRecipe(format!("{} days", recipe.days)));
+        }
+        let range = DateRange { start: (now - Duration::days(recipe.days)).timestamp(), end: now.timestamp() };
+        Ok(Self::new(recipe.coin, range, recipe.currency, recipe.preset, recipe.speed, SoundOptions::default()))
+    }
+}
+
+// Écrit la recette dans recipes/<crypto>_<début>_<fin>_<devise>.json, en remplaçant la même recette
+pub fn save_recipe(recipe: &Recipe) -> Result<PathBuf> {
+    std::fs::create_dir_all(RECIPES_DIR)?;
+    let date = |timestamp: i64| DateTime::<Utc>::from_timestamp(timestamp, 0).map_or(timestamp.to_string(), |date| date.format("%Y%m%d").to_string());
+    let path = Path::new(RECIPES_DIR).join(format!(
+        "{}_{}_{}_{}.json",
+        recipe.coin,
+        date(recipe.range.start),
+        date(recipe.range.end),
+        recipe.currency,
+    ));
+    std::fs::write(&path, recipe.to_json()?)?;
+    Ok(path)
+}
+
+pub fn load_recipe(path: &Path) -> Result<Recipe> {
+    Recipe::from_json(&std::fs::read_to_string(path)?)
+}
+
+// Recettes enregistrées, par ordre alphabétique
+pub fn list_recipes() -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(RECIPES_DIR) else {
+        return Vec::new();
+    };
+    let mut recipes: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    recipes.sort();
+    recipes
+}
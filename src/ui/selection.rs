@@ -0,0 +1,124 @@
+use super::a11y::{accessible_name, focus_outline};
+use super::textures::TextureCache;
+use super::widgets::secondary_text_color;
+use crate::data::{AssetPack, CoinId};
+use crate::i18n::{tr, tr_args};
+use eframe::egui::{self, Color32, Image};
+use std::collections::HashMap;
+
+pub struct SelectionPage {
+    // Portrait de chaque crypto déjà demandée, None si elle n'en a pas ou s'il est illisible
+    portraits: HashMap<CoinId, Option<egui::TextureHandle>>,
+}
+
+impl SelectionPage {
+    pub fn new() -> Self {
+        Self {
+            portraits: HashMap::new(),
+        }
+    }
+
+    // Un portrait illisible laisse la carte sans image au lieu d'interrompre l'application
+    pub fn load_images_if_needed(&mut self, ctx: &egui::Context, asset_pack: &AssetPack, textures: &TextureCache, coins: &[CoinId]) {
+        for coin in coins {
+            if self.portraits.contains_key(coin) {
+                continue;
+            }
+            let texture = asset_pack.portrait(coin).and_then(|path| {
+                textures.load_image(&path, ctx, [300.0, 300.0])
+                    .map_err(|e| println!("{}", tr_args("log-image-failed", &[
+                        ("path", &path.display().to_string()),
+                        ("error", &e.to_string()),
+                    ])))
+                    .ok()
+            });
+            self.portraits.insert(coin.clone(), texture);
+        }
+    }
+
+    pub fn portrait(&self, coin: &str) -> Option<egui::TextureHandle> {
+        self.portraits.get(coin).cloned().flatten()
+    }
+}
+
+impl Default for SelectionPage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+// Ce que l'utilisateur a fait sur une carte de la page de sélection
+#[derive(Clone, Copy, PartialEq)]
+pub enum CardAction {
+    Open,
+    ToggleFavorite,
+}
+
+// Carte d'une crypto sur la page de sélection ; les favoris ont une étoile pleine et un léger fond
+pub fn coin_card(
+    ui: &mut egui::Ui,
+    texture: Option<&egui::TextureHandle>,
+    label: &str,
+    color: Color32,
+    tagline: &str,
+    width: f32,
+    favorite: bool,
+) -> Option<CardAction> {
+    let text_scale = (width / 250.0).clamp(0.6, 1.0);
+    let mut action = None;
+
+    ui.allocate_ui(egui::vec2(width, width + 80.0), |ui| {
+        ui.set_width(width);
+        if favorite {
+            ui.painter().rect_filled(ui.max_rect().expand(6.0), 10.0, color.gamma_multiply(0.12));
+        }
+        ui.vertical_centered(|ui| {
+            let clicked = match texture {
+                Some(texture) => {
+                    let image = Image::new(texture)
+                        .fit_to_exact_size([width, width].into())
+                        .rounding(8.0);
+                    let response = ui.add(egui::ImageButton::new(image)
+                        .frame(true)
+                        .selected(false)
+                    );
+                    accessible_name(response, egui::WidgetType::Button, label).clicked()
+                },
+                None => ui.add_sized([width, width], egui::Button::new(label)).clicked(),
+            };
+            if clicked {
+                action = Some(CardAction::Open);
+            }
+            
+            ui.add_space(10.0);
+            ui.horizontal(|ui| {
+                let star = if favorite { "★" } else { "☆" };
+                let star_width = 24.0 * text_scale;
+                let label_width = ui.fonts(|f| {
+                    f.layout_no_wrap(label.to_string(), egui::FontId::proportional(24.0 * text_scale), color).size().x
+                });
+                ui.add_space(((ui.available_width() - label_width - star_width) / 2.0).max(0.0));
+                ui.colored_label(
+                    color,
+                    egui::RichText::new(label)
+                        .size(24.0 * text_scale)
+                        .strong()
+                );
+                let star_button = egui::Button::new(egui::RichText::new(star).size(20.0 * text_scale).color(Color32::from_rgb(255, 215, 0)))
+                    .frame(false);
+                let star_hint = tr(if favorite { "unpin-favorite" } else { "pin-favorite" });
+                let star_response = focus_outline(ui.add(star_button));
+                if accessible_name(star_response, egui::WidgetType::Button, &format!("{} ({})", star_hint, label)).on_hover_text(star_hint).clicked() {
+                    action = Some(CardAction::ToggleFavorite);
+                }
+            });
+            ui.label(
+                egui::RichText::new(tagline)
+                    .size(16.0 * text_scale)
+                    .color(secondary_text_color(ui))
+            );
+        });
+    });
+
+    action
+}
@@ -0,0 +1,72 @@
+use super::widgets::secondary_text_color;
+use crate::data::{align_sentiment, fetch_sentiment_async, sentiment_label_key, DailyPrice, SentimentPoint};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui;
+use std::sync::mpsc;
+
+// Deuxième voix de la lecture : le sentiment du marché, joué sous les notes du prix
+pub struct SentimentVoice {
+    request: Option<mpsc::Receiver<Result<Vec<SentimentPoint>>>>,
+    // Un score par jour de la série, une fois l'indice reçu
+    scores: Option<Vec<Option<f64>>>,
+    error: Option<String>,
+}
+
+impl SentimentVoice {
+    pub fn new() -> Self {
+        Self {
+            request: None,
+            scores: None,
+            error: None,
+        }
+    }
+
+    pub fn receive(&mut self, ctx: &egui::Context, prices: &[DailyPrice]) {
+        if self.scores.is_none() && self.error.is_none() && self.request.is_none() {
+            let days = prices.len();
+            self.request = Some(spawn_request(ctx, fetch_sentiment_async(days)));
+        }
+        let Some(request) = &self.request else {
+            return;
+        };
+        if let Ok(result) = request.try_recv() {
+            self.request = None;
+            match result {
+                Ok(points) => self.scores = Some(align_sentiment(prices, &points)),
+                Err(e) => self.error = Some(e.to_string()),
+            }
+        }
+    }
+
+    pub fn scores(&self) -> Option<&[Option<f64>]> {
+        self.scores.as_deref()
+    }
+
+    // Score du jour joué, ou état du chargement
+    pub fn show_status(&mut self, ui: &mut egui::Ui, index: usize) {
+        let text = if let Some(error) = &self.error {
+            if ui.small_button(tr("retry")).on_hover_text(error).clicked() {
+                self.error = None;
+            }
+            tr("sentiment-failed").to_string()
+        } else {
+            match self.scores.as_ref().map(|scores| scores.get(index).copied().flatten()) {
+                None => tr("sentiment-loading").to_string(),
+                Some(None) => tr("sentiment-missing").to_string(),
+                Some(Some(score)) => tr_args("sentiment-score", &[
+                    ("score", format!("{:.0}", score)),
+                    ("label", tr(sentiment_label_key(score)).to_string()),
+                ]),
+            }
+        };
+        ui.label(egui::RichText::new(text).size(12.0).color(secondary_text_color(ui)));
+    }
+}
+
+impl Default for SentimentVoice {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -0,0 +1,80 @@
+use crate::audio::ViewMode;
+use crate::data::ChartData;
+use crate::error::{Error, Result};
+use crate::ui::chart::Visualization;
+use chrono::Utc;
+use serde::{Deserialize, Serialize};
+use std::path::{Path, PathBuf};
+
+pub const SESSIONS_DIR: &str = "sessions";
+
+// Incrémentée quand le format change de façon incompatible
+const SESSION_VERSION: u32 = 1;
+
+// Graphique ouvert sauvegardé tel quel : série chargée, position de lecture, preset et vue
+#[derive(Clone, Serialize, Deserialize)]
+pub struct SavedSession {
+    pub version: u32,
+    pub data: ChartData,
+    pub view_mode: ViewMode,
+    pub visualization: Visualization,
+    pub position: usize,
+    pub speed: f64,
+    pub volume: f32,
+    // Plage horizontale affichée, None si le graphique n'avait pas encore été dessiné
+    pub view_x: Option<(f64, f64)>,
+}
+
+impl SavedSession {
+    pub fn new(
+        data: ChartData,
+        view_mode: ViewMode,
+        visualization: Visualization,
+        position: usize,
+        speed: f64,
+        volume: f32,
+        view_x: Option<(f64, f64)>,
+    ) -> Self {
+        Self { version: SESSION_VERSION, data, view_mode, visualization, position, speed, volume, view_x }
+    }
+}
+
+// Écrit la session dans sessions/<crypto>_<date>.json
+pub fn save_session(session: &SavedSession) -> Result<PathBuf> {
+    std::fs::create_dir_all(SESSIONS_DIR)?;
+    let path = Path::new(SESSIONS_DIR).join(format!(
+        "{}_{}.json",
+        session.data.coin,
+        Utc::now().format("%Y%m%d_%H%M%S"),
+    ));
+    std::fs::write(&path, serde_json::to_string_pretty(session)?)?;
+    Ok(path)
+}
+
+pub fn load_session(path: &Path) -> Result<SavedSession> {
+    parse_session(&std::fs::read_to_string(path)?)
+}
+
+pub fn parse_session(json: &str) -> Result<SavedSession> {
+    let session: SavedSession = serde_json::from_str(json)?;
+    if session.version > SESSION_VERSION {
+        return Err(Error::Unsupported("sessions saved by a newer version"));
+    }
+    if session.data.daily_prices.is_empty() {
+        return Err(Error::NoData(session.data.coin));
+    }
+    Ok(session)
+}
+
+// Sessions sauvegardées, les plus récentes en premier
+pub fn list_sessions() -> Vec<PathBuf> {
+    let Ok(entries) = std::fs::read_dir(SESSIONS_DIR) else {
+        return Vec::new();
+    };
+    let mut sessions: Vec<PathBuf> = entries
+        .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+        .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+        .collect();
+    sessions.sort_by_key(|path| std::cmp::Reverse(path.metadata().and_then(|m| m.modified()).ok()));
+    sessions
+}
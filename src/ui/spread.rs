@@ -0,0 +1,121 @@
+use super::widgets::secondary_text_color;
+use crate::audio::{self, spread_beating, SPREAD_DEAD_ZONE};
+use crate::data::{fetch_exchange_price_async, format_price, spread_percent, Exchange};
+use crate::error::Result;
+use crate::i18n::{tr, tr_args};
+use crate::task::spawn_request;
+use eframe::egui;
+use rodio::{OutputStream, Sink};
+use std::sync::mpsc;
+use std::time::Duration as StdDuration;
+
+// Un relevé des deux plateformes par seconde, chacun joué pendant une seconde
+const POLL_SECONDS: f64 = 1.0;
+const MAX_QUEUED: usize = 2;
+
+// Écart de prix de la crypto entre deux plateformes : silence tant qu'elles sont alignées, puis deux
+// notes de plus en plus désaccordées quand l'écart se creuse
+pub struct SpreadLayer {
+    symbol: String,
+    // Une requête par plateforme, lancées ensemble
+    requests: Vec<(Exchange, mpsc::Receiver<Result<f64>>)>,
+    prices: [Option<f64>; 2],
+    next_poll: f64,
+    error: Option<String>,
+    output: Option<(OutputStream, Sink)>,
+}
+
+impl SpreadLayer {
+    pub fn new(symbol: String, volume: f32) -> Self {
+        let (output, error) = match audio::open_sound_output(volume) {
+            Ok(output) => (Some(output), None),
+            Err(e) => (None, Some(e.to_string())),
+        };
+        Self {
+            symbol,
+            requests: Vec::new(),
+            prices: [None; 2],
+            next_poll: 0.0,
+            error,
+            output,
+        }
+    }
+
+    pub fn set_volume(&self, volume: f32) {
+        if let Some((_, sink)) = &self.output {
+            sink.set_volume(volume);
+        }
+    }
+
+    fn spread(&self) -> Option<f64> {
+        match self.prices {
+            [Some(binance), Some(coinbase)] => Some(spread_percent(binance, coinbase)),
+            _ => None,
+        }
+    }
+
+    // Relève les deux prix à intervalle régulier ; pendant la lecture, chaque paire de relevés
+    // ajoute une seconde de battement
+    pub fn update(&mut self, ctx: &egui::Context, now: f64, playing: bool) {
+        if self.requests.is_empty() && self.error.is_none() && now >= self.next_poll {
+            self.requests = Exchange::ALL
+                .into_iter()
+                .map(|exchange| {
+                    let symbol = self.symbol.clone();
+                    (exchange, spawn_request(ctx, async move { fetch_exchange_price_async(exchange, &symbol).await }))
+                })
+                .collect();
+            self.next_poll = now + POLL_SECONDS;
+        }
+        ctx.request_repaint_after(StdDuration::from_secs_f64((self.next_poll - now).max(0.0)));
+
+        if self.requests.is_empty() {
+            return;
+        }
+        let mut pending = Vec::new();
+        for (exchange, request) in std::mem::take(&mut self.requests) {
+            match request.try_recv() {
+                Ok(Ok(price)) => self.prices[exchange as usize] = Some(price),
+                Ok(Err(e)) => self.error = Some(format!("{}: {}", exchange.name(), e)),
+                Err(mpsc::TryRecvError::Empty) => pending.push((exchange, request)),
+                Err(mpsc::TryRecvError::Disconnected) => {},
+            }
+        }
+        // Le battement attend les deux prix ; une plateforme en erreur abandonne le relevé
+        if self.error.is_some() {
+            return;
+        }
+        self.requests = pending;
+        if !self.requests.is_empty() {
+            return;
+        }
+
+        if let Some((_, sink)) = self.output.as_ref().filter(|_| playing) {
+            let beating = self.spread().and_then(|spread| spread_beating(spread, StdDuration::from_secs_f64(POLL_SECONDS)));
+            if let Some(beating) = beating.filter(|_| sink.len() < MAX_QUEUED) {
+                sink.append(beating);
+            }
+        }
+    }
+
+    // Prix sur chaque plateforme et écart entre les deux
+    pub fn show_status(&mut self, ui: &mut egui::Ui) {
+        let text = if let Some(error) = &self.error {
+            if ui.small_button(tr("retry")).on_hover_text(error).clicked() {
+                self.error = None;
+            }
+            tr("spread-failed").to_string()
+        } else if let [Some(binance), Some(coinbase)] = self.prices {
+            let spread = spread_percent(binance, coinbase);
+            let key = if spread.abs() < SPREAD_DEAD_ZONE { "spread-aligned" } else { "spread-status" };
+            tr_args(key, &[
+                ("binance", &format_price(binance, "usd")),
+                ("coinbase", &format_price(coinbase, "usd")),
+                ("spread", &format!("{:+.3}", spread)),
+            ])
+        } else {
+            tr("spread-loading").to_string()
+        };
+        ui.label(egui::RichText::new(text).size(12.0).color(secondary_text_color(ui)));
+    }
+}
@@ -0,0 +1,297 @@
+use crate::data::{load_logo_async, read_asset, CoinId, DailyPrice, ReactionImage, PORTFOLIO_ID};
+use crate::error::{Error, Result};
+use crate::i18n::tr_args;
+use crate::task::spawn_request;
+use eframe::egui;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::SeedableRng;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::{mpsc, Arc, Mutex};
+
+// Taille de décodage des logos, affichés sur les cartes et dans l'en-tête des graphiques
+const LOGO_SIZE: [f32; 2] = [250.0, 250.0];
+
+// Ordre de passage des images haussières et baissières, mélangé une fois pour toutes à partir d'une
+// graine. L'image d'un pas ne dépend que de son numéro : deux rendus des mêmes données montrent les
+// mêmes images, même après un saut ou la reprise d'une session
+pub struct ImageSequencer {
+    bull_order: Vec<usize>,
+    bear_order: Vec<usize>,
+}
+
+impl ImageSequencer {
+    pub fn new(bull_count: usize, bear_count: usize, seed: u64) -> Self {
+        let mut rng = StdRng::seed_from_u64(seed);
+        let mut bull_order: Vec<usize> = (0..bull_count).collect();
+        let mut bear_order: Vec<usize> = (0..bear_count).collect();
+        bull_order.shuffle(&mut rng);
+        bear_order.shuffle(&mut rng);
+        Self { bull_order, bear_order }
+    }
+
+    pub fn bull_index(&self, step: usize) -> usize {
+        self.bull_order.get(step % self.bull_order.len().max(1)).copied().unwrap_or(0)
+    }
+
+    pub fn bear_index(&self, step: usize) -> usize {
+        self.bear_order.get(step % self.bear_order.len().max(1)).copied().unwrap_or(0)
+    }
+}
+
+// Graine des choix visuels d'un graphique (ordre des images, particules), tirée de la crypto et de
+// la période affichée. FNV-1a plutôt que le hacheur de la bibliothèque standard, qui peut changer
+// d'une version de Rust à l'autre
+pub fn visual_seed(coin: &str, prices: &[DailyPrice]) -> u64 {
+    const OFFSET: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    let first = prices.first().map_or("", |price| price.date.as_str());
+    let last = prices.last().map_or("", |price| price.date.as_str());
+    [coin, first, last]
+        .iter()
+        // Séparateur pour que ("ab", "c") et ("a", "bc") ne se confondent pas
+        .flat_map(|part| part.bytes().chain(std::iter::once(0)))
+        .fold(OFFSET, |hash, byte| (hash ^ byte as u64).wrapping_mul(PRIME))
+}
+
+// Fonction utilitaire pour charger les images
+pub fn load_image_from_path(
+    path: &Path,
+    ctx: &egui::Context,
+    size: [f32; 2],
+) -> Result<egui::TextureHandle> {
+    crate::profile_function!();
+    let image = image::load_from_memory(&read_asset(path)?)?;
+    Ok(ctx.load_texture(path.to_string_lossy(), color_image_from(&image, size), egui::TextureOptions::default()))
+}
+
+// Redimensionne une image décodée et la convertit au format egui
+fn color_image_from(image: &image::DynamicImage, size: [f32; 2]) -> egui::ColorImage {
+    let image = image.resize(
+        size[0] as u32,
+        size[1] as u32,
+        image::imageops::FilterType::Triangle,
+    );
+    let size = [image.width() as _, image.height() as _];
+    let image_buffer = image.to_rgba8();
+    let pixels = image_buffer.as_flat_samples();
+    egui::ColorImage::from_rgba_unmultiplied(size, pixels.as_slice())
+}
+
+// Image de réaction, éventuellement animée (GIF ou planche de sprites)
+#[derive(Clone)]
+pub struct AnimatedTexture {
+    frames: Vec<egui::TextureHandle>,
+    frame_durations: Vec<f32>,
+    // Instant d'envoi au GPU, pour le fondu d'apparition
+    loaded_at: f64,
+}
+
+impl AnimatedTexture {
+    const FADE_IN_SECONDS: f64 = 0.4;
+
+    pub fn frame_at(&self, time: f32) -> &egui::TextureHandle {
+        let total: f32 = self.frame_durations.iter().sum();
+        if self.frames.len() == 1 || total <= 0.0 {
+            return &self.frames[0];
+        }
+
+        let mut t = time % total;
+        for (frame, duration) in self.frames.iter().zip(&self.frame_durations) {
+            if t < *duration {
+                return frame;
+            }
+            t -= duration;
+        }
+        &self.frames[self.frames.len() - 1]
+    }
+
+    // Opacité du fondu d'apparition, de 0 à 1
+    pub fn fade_in(&self, now: f64) -> f32 {
+        ((now - self.loaded_at) / Self::FADE_IN_SECONDS).clamp(0.0, 1.0) as f32
+    }
+
+    // GIF en cours de lecture ou fondu pas encore terminé : l'affichage change d'une frame à l'autre
+    pub fn is_animating(&self, now: f64) -> bool {
+        self.frames.len() > 1 || self.fade_in(now) < 1.0
+    }
+}
+
+// Textures déjà envoyées au GPU, partagées par toutes les pages et indexées par chemin :
+// revenir sur un graphique ou changer de crypto ne redécode jamais une image
+#[derive(Clone, Default)]
+pub struct TextureCache {
+    textures: Arc<Mutex<HashMap<PathBuf, AnimatedTexture>>>,
+    logos: Arc<Mutex<HashMap<CoinId, LogoState>>>,
+}
+
+// Logo officiel d'une crypto : téléchargement en cours, texture prête ou échec (pas de nouvel essai)
+enum LogoState {
+    Loading(mpsc::Receiver<Result<Vec<u8>>>),
+    Ready(egui::TextureHandle),
+    Failed,
+}
+
+impl TextureCache {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, path: &Path) -> Option<AnimatedTexture> {
+        self.textures.lock().ok()?.get(path).cloned()
+    }
+
+    pub fn insert(&self, path: PathBuf, texture: AnimatedTexture) {
+        if let Ok(mut textures) = self.textures.lock() {
+            textures.insert(path, texture);
+        }
+    }
+
+    // Logo officiel de la crypto, téléchargé au premier appel ; None tant qu'il n'est pas arrivé
+    pub fn logo(&self, ctx: &egui::Context, coin: &str) -> Option<egui::TextureHandle> {
+        if coin == PORTFOLIO_ID {
+            return None;
+        }
+        let mut logos = self.logos.lock().ok()?;
+        let state = logos
+            .entry(coin.to_string())
+            .or_insert_with(|| LogoState::Loading(spawn_request(ctx, load_logo_async(coin.to_string()))));
+
+        if let LogoState::Loading(request) = state {
+            let Ok(result) = request.try_recv() else {
+                return None;
+            };
+            let texture = result
+                .and_then(|bytes| Ok(image::load_from_memory(&bytes)?))
+                .map(|image| ctx.load_texture(format!("logo_{}", coin), color_image_from(&image, LOGO_SIZE), egui::TextureOptions::default()));
+            *state = match texture {
+                Ok(texture) => LogoState::Ready(texture),
+                Err(e) => {
+                    println!("{}", tr_args("log-logo-failed", &[("coin", coin), ("error", &e.to_string())]));
+                    LogoState::Failed
+                },
+            };
+        }
+        match state {
+            LogoState::Ready(texture) => Some(texture.clone()),
+            LogoState::Loading(_) | LogoState::Failed => None,
+        }
+    }
+
+    // Image fixe (portrait), chargée depuis le disque seulement au premier appel
+    pub fn load_image(&self, path: &Path, ctx: &egui::Context, size: [f32; 2]) -> Result<egui::TextureHandle> {
+        if let Some(texture) = self.get(path) {
+            return Ok(texture.frame_at(0.0).clone());
+        }
+        let handle = load_image_from_path(path, ctx, size)?;
+        self.insert(path.to_path_buf(), AnimatedTexture {
+            frames: vec![handle.clone()],
+            frame_durations: vec![0.0],
+            loaded_at: ctx.input(|i| i.time),
+        });
+        Ok(handle)
+    }
+}
+
+// Flèche de couleur générée, affichée à la place d'une image de réaction illisible
+pub fn placeholder_texture(ctx: &egui::Context, name: &str, color: egui::Color32, pointing_up: bool, now: f64) -> AnimatedTexture {
+    const SIZE: usize = 200;
+    let mut image = egui::ColorImage::new([SIZE, SIZE], egui::Color32::TRANSPARENT);
+    for y in 0..SIZE {
+        // Coordonnées normalisées, la pointe de la flèche est en v = 0.1
+        let v = if pointing_up { y as f32 / SIZE as f32 } else { 1.0 - y as f32 / SIZE as f32 };
+        for x in 0..SIZE {
+            let u = (x as f32 / SIZE as f32 - 0.5).abs();
+            let in_head = (0.1..0.55).contains(&v) && u <= (v - 0.1) / 0.45 * 0.4;
+            let in_shaft = (0.55..0.9).contains(&v) && u <= 0.12;
+            if in_head || in_shaft {
+                image.pixels[y * SIZE + x] = color;
+            }
+        }
+    }
+
+    AnimatedTexture {
+        frames: vec![ctx.load_texture(name, image, egui::TextureOptions::default())],
+        frame_durations: vec![0.0],
+        loaded_at: now,
+    }
+}
+
+// Images décodées hors du thread de l'interface, prêtes à être envoyées au GPU
+pub struct DecodedImage {
+    name: String,
+    frames: Vec<egui::ColorImage>,
+    frame_durations: Vec<f32>,
+}
+
+impl DecodedImage {
+    pub fn upload(self, ctx: &egui::Context, now: f64) -> AnimatedTexture {
+        crate::profile_function!();
+        let single = self.frames.len() == 1;
+        let frames = self.frames
+            .into_iter()
+            .enumerate()
+            .map(|(i, frame)| {
+                let name = if single { self.name.clone() } else { format!("{}#{}", self.name, i) };
+                ctx.load_texture(name, frame, egui::TextureOptions::default())
+            })
+            .collect();
+        AnimatedTexture {
+            frames,
+            frame_durations: self.frame_durations,
+            loaded_at: now,
+        }
+    }
+}
+
+pub fn decode_reaction_image(
+    reaction: &ReactionImage,
+    size: [f32; 2],
+) -> Result<DecodedImage> {
+    use image::AnimationDecoder;
+    crate::profile_function!();
+
+    let path = &reaction.path;
+    let mut frames = Vec::new();
+    let mut frame_durations = Vec::new();
+
+    let is_gif = path.extension().is_some_and(|ext| ext.eq_ignore_ascii_case("gif"));
+    if is_gif {
+        let decoder = image::codecs::gif::GifDecoder::new(std::io::Cursor::new(read_asset(path)?))?;
+        for frame in decoder.into_frames().collect_frames()? {
+            let (numer, denom) = frame.delay().numer_denom_ms();
+            // Les GIF sans délai sont joués à 10 images par seconde, comme dans les navigateurs
+            let duration = numer as f32 / denom.max(1) as f32 / 1000.0;
+            frame_durations.push(if duration > 0.01 { duration } else { 0.1 });
+            let image = image::DynamicImage::ImageRgba8(frame.into_buffer());
+            frames.push(color_image_from(&image, size));
+        }
+    } else {
+        let image = image::load_from_memory(&read_asset(path)?)?;
+        match reaction.sprite {
+            Some((count, fps)) if count > 1 => {
+                let frame_width = image.width() / count;
+                for i in 0..count {
+                    let frame = image.crop_imm(i * frame_width, 0, frame_width, image.height());
+                    frames.push(color_image_from(&frame, size));
+                    frame_durations.push(1.0 / fps.max(1.0));
+                }
+            },
+            _ => {
+                frames.push(color_image_from(&image, size));
+                frame_durations.push(0.0);
+            },
+        }
+    }
+
+    if frames.is_empty() {
+        return Err(Error::EmptyImage);
+    }
+    Ok(DecodedImage {
+        name: path.to_string_lossy().into_owned(),
+        frames,
+        frame_durations,
+    })
+}
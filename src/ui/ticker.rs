@@ -0,0 +1,92 @@
+use super::a11y::shortcut_pressed;
+use super::live::LiveLayer;
+use super::widgets::{secondary_text_color, sparkline, ChartPalette};
+use crate::data::{coin_display_name, format_price, spread_symbol, DailyPrice};
+use crate::i18n::tr;
+use eframe::egui;
+
+// Les notes du direct jouent en fond, nettement sous le volume du graphique
+const SOUND_GAIN: f32 = 0.3;
+const SPARKLINE_SIZE: egui::Vec2 = egui::vec2(120.0, 40.0);
+
+// Bandeau compact : la courbe et le prix actuel d'une crypto, dans une petite fenêtre sans bordure
+// gardée au premier plan. Le prix suit le direct de Binance quand le cours est en dollars (les
+// paires y sont en USDT), et peut jouer ses notes en sourdine
+pub struct MiniTicker {
+    coin: String,
+    currency: String,
+    prices: Vec<f64>,
+    live: Option<LiveLayer>,
+    sound: bool,
+}
+
+impl MiniTicker {
+    pub const SIZE: egui::Vec2 = egui::vec2(280.0, 96.0);
+
+    pub fn new(coin: &str, currency: &str, prices: &[DailyPrice], volume: f32) -> Self {
+        let live = spread_symbol(coin).filter(|_| currency == "usd").map(|symbol| {
+            let last_price = prices.last().map(|day| day.price);
+            LiveLayer::new(symbol, volume * SOUND_GAIN).with_previous_close(last_price)
+        });
+        Self {
+            coin: coin.to_string(),
+            currency: currency.to_string(),
+            prices: prices.iter().map(|day| day.price).collect(),
+            live,
+            sound: false,
+        }
+    }
+
+    // Historique chargé, suivi du dernier prix relevé en direct
+    fn displayed_prices(&self) -> Vec<f64> {
+        let mut prices = self.prices.clone();
+        prices.extend(self.live.as_ref().and_then(LiveLayer::last_price));
+        prices
+    }
+
+    // Vrai quand l'utilisateur quitte le mode compact (✕, double-clic ou Échap)
+    pub fn update(&mut self, ctx: &egui::Context) -> bool {
+        let now = ctx.input(|i| i.time);
+        if let Some(live) = &mut self.live {
+            live.update(ctx, now, self.sound);
+        }
+
+        let mut close = shortcut_pressed(ctx, egui::Key::Escape);
+        let frame = egui::Frame::window(&ctx.style()).rounding(0.0).inner_margin(8.0);
+        egui::CentralPanel::default().frame(frame).show(ctx, |ui| {
+            // Sans barre de titre, la fenêtre se déplace en faisant glisser le bandeau
+            let background = ui.interact(ui.max_rect(), ui.id().with("ticker_drag"), egui::Sense::click_and_drag());
+            if background.drag_started() {
+                ctx.send_viewport_cmd(egui::ViewportCommand::StartDrag);
+            }
+            close |= background.double_clicked();
+
+            ui.horizontal(|ui| {
+                ui.label(egui::RichText::new(coin_display_name(&self.coin)).strong());
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    close |= ui.small_button("✕").on_hover_text(tr("ticker-close")).clicked();
+                    if self.live.is_some() {
+                        ui.checkbox(&mut self.sound, tr("ticker-sound")).on_hover_text(tr("ticker-sound-hint"));
+                    }
+                });
+            });
+
+            let prices = self.displayed_prices();
+            let (Some(first), Some(last)) = (prices.first().copied(), prices.last().copied()) else {
+                return;
+            };
+            ui.horizontal(|ui| {
+                sparkline(ui, &prices, SPARKLINE_SIZE);
+                ui.vertical(|ui| {
+                    ui.label(egui::RichText::new(format_price(last, &self.currency)).size(18.0).strong());
+                    let change = (last - first) / first * 100.0;
+                    ui.label(egui::RichText::new(format!("{:+.2}%", change)).color(ChartPalette::current().change_color(change)));
+                    if self.live.as_ref().is_some_and(|live| live.last_price().is_some()) {
+                        ui.label(egui::RichText::new(tr("ticker-live")).size(11.0).color(secondary_text_color(ui)));
+                    }
+                });
+            });
+        });
+        close
+    }
+}
@@ -0,0 +1,359 @@
+use crate::data::{format_price, PriceStats};
+use crate::i18n::tr;
+use eframe::egui::{self, Color32};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use std::sync::atomic::{AtomicBool, Ordering};
+
+// Texte secondaire, lisible avec le thème clair comme avec le thème sombre
+pub fn secondary_text_color(ui: &egui::Ui) -> Color32 {
+    ui.visuals().strong_text_color().gamma_multiply(0.85)
+}
+
+// Palette renforcée choisie avec le thème contrasté, partagée par tous les graphiques
+static HIGH_CONTRAST: AtomicBool = AtomicBool::new(false);
+
+// Couleurs des hausses et des baisses et épaisseur des tracés
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ChartPalette {
+    pub up: Color32,
+    pub down: Color32,
+    pub line_width: f32,
+    pub point_radius: f32,
+}
+
+impl ChartPalette {
+    pub const STANDARD: ChartPalette = ChartPalette {
+        up: Color32::from_rgb(46, 189, 89),
+        down: Color32::from_rgb(255, 88, 88),
+        line_width: 1.5,
+        point_radius: 0.5,
+    };
+
+    // Couleurs saturées et traits épais, lisibles sur le fond noir du thème contrasté
+    pub const HIGH_CONTRAST: ChartPalette = ChartPalette {
+        up: Color32::from_rgb(0, 255, 80),
+        down: Color32::from_rgb(255, 30, 60),
+        line_width: 3.0,
+        point_radius: 2.5,
+    };
+
+    pub fn current() -> Self {
+        if HIGH_CONTRAST.load(Ordering::Relaxed) {
+            Self::HIGH_CONTRAST
+        } else {
+            Self::STANDARD
+        }
+    }
+
+    pub fn set_high_contrast(enabled: bool) {
+        HIGH_CONTRAST.store(enabled, Ordering::Relaxed);
+    }
+
+    pub fn change_color(&self, change: f64) -> Color32 {
+        if change >= 0.0 {
+            self.up
+        } else {
+            self.down
+        }
+    }
+}
+
+// Mouvements réduits : images immobiles, pas de particules, pour les personnes sensibles aux animations
+static REDUCE_MOTION: AtomicBool = AtomicBool::new(false);
+
+pub fn reduce_motion() -> bool {
+    REDUCE_MOTION.load(Ordering::Relaxed)
+}
+
+pub fn set_reduce_motion(ctx: &egui::Context, enabled: bool) {
+    REDUCE_MOTION.store(enabled, Ordering::Relaxed);
+    // Dépliages et défilements d'egui instantanés eux aussi
+    ctx.style_mut(|style| style.animation_time = if enabled { 0.0 } else { egui::Style::default().animation_time });
+}
+
+// Courbe de prix, verte ou rouge selon le sens de la variation
+pub fn sparkline(ui: &mut egui::Ui, prices: &[f64], size: egui::Vec2) {
+    let (rect, _) = ui.allocate_exact_size(size, egui::Sense::hover());
+    let (Some(first), Some(last)) = (prices.first(), prices.last()) else {
+        return;
+    };
+    let (min, max) = prices.iter().fold((f64::INFINITY, f64::NEG_INFINITY), |(min, max), p| (min.min(*p), max.max(*p)));
+    let range = (max - min).max(f64::EPSILON);
+    let step = rect.width() / (prices.len().max(2) - 1) as f32;
+    let points: Vec<egui::Pos2> = prices
+        .iter()
+        .enumerate()
+        .map(|(i, price)| egui::pos2(rect.left() + i as f32 * step, rect.bottom() - ((price - min) / range) as f32 * rect.height()))
+        .collect();
+    let palette = ChartPalette::current();
+    let color = palette.change_color(last - first);
+    ui.painter().add(egui::Shape::line(points, egui::Stroke::new(palette.line_width * 0.8, color)));
+}
+
+// Squelette d'un graphique pendant le chargement : en-tête et courbe grisés, parcourus d'un reflet
+// qui reste immobile avec les mouvements réduits
+pub fn paint_skeleton_chart(painter: &egui::Painter, rect: egui::Rect, time: f64) {
+    const SHIMMER_SECONDS: f64 = 1.5;
+    const POINTS: usize = 40;
+
+    let base = Color32::from_gray(70);
+    painter.rect_filled(rect, 8.0, Color32::from_gray(40));
+    let inner = rect.shrink(16.0);
+    painter.rect_filled(egui::Rect::from_min_size(inner.min, egui::vec2(inner.width() * 0.4, 18.0)), 4.0, base);
+    painter.rect_filled(egui::Rect::from_min_size(inner.min + egui::vec2(0.0, 26.0), egui::vec2(inner.width() * 0.25, 12.0)), 4.0, base);
+
+    // Courbe factice, la même à chaque image
+    let plot = egui::Rect::from_min_max(inner.min + egui::vec2(0.0, 54.0), inner.max);
+    let points: Vec<egui::Pos2> = (0..POINTS)
+        .map(|i| {
+            let t = i as f32 / (POINTS - 1) as f32;
+            let wave = (t * 9.0).sin() * 0.25 + (t * 3.0).cos() * 0.2;
+            egui::pos2(plot.left() + t * plot.width(), plot.center().y - wave * plot.height())
+        })
+        .collect();
+    painter.add(egui::Shape::line(points, egui::Stroke::new(3.0, base)));
+
+    if !reduce_motion() {
+        let phase = (time / SHIMMER_SECONDS).fract() as f32;
+        let x = rect.left() - rect.width() * 0.2 + phase * rect.width() * 1.4;
+        let band = egui::Rect::from_x_y_ranges(x..=x + rect.width() * 0.15, rect.y_range()).intersect(rect);
+        painter.rect_filled(band, 0.0, Color32::from_white_alpha(12));
+    }
+}
+
+pub struct AnimatedImage {
+    pub scale: f32,
+    pub target_scale: f32,
+    pub opacity: f32,
+    pub target_opacity: f32,
+    pub float_offset: f32,
+    pub float_time: f32,
+    pub elapsed: f32,
+}
+
+impl AnimatedImage {
+    pub fn new() -> Self {
+        Self {
+            scale: 0.8,
+            target_scale: 1.0,
+            opacity: 0.0,
+            target_opacity: 1.0,
+            float_offset: 0.0,
+            float_time: 0.0,
+            elapsed: 0.0,
+        }
+    }
+
+    // 0.2% donne un tout petit taureau, 12% un ours immense
+    pub fn scale_for_change(price_change: f64) -> f32 {
+        const MIN_SCALE: f32 = 0.4;
+        const MAX_SCALE: f32 = 1.6;
+        const SCALE_PER_PERCENT: f32 = 0.1;
+
+        (MIN_SCALE + price_change.abs() as f32 * SCALE_PER_PERCENT).clamp(MIN_SCALE, MAX_SCALE)
+    }
+
+    // Le flottement n'avance que pendant la lecture, sinon l'image reste immobile
+    pub fn animate(&mut self, dt: f32, floating: bool) {
+        const ANIMATION_SPEED: f32 = 8.0;
+        const FLOAT_SPEED: f32 = 2.0;
+        const FLOAT_AMPLITUDE: f32 = 10.0;
+
+        // Mouvements réduits : l'image prend directement sa taille et reste sur la première frame
+        if reduce_motion() {
+            self.scale = self.target_scale;
+            self.opacity = self.target_opacity;
+            self.float_offset = 0.0;
+            return;
+        }
+
+        self.scale += (self.target_scale - self.scale) * dt * ANIMATION_SPEED;
+        self.opacity += (self.target_opacity - self.opacity) * dt * ANIMATION_SPEED;
+        
+        self.elapsed += dt;
+        if floating {
+            self.float_time += dt * FLOAT_SPEED;
+            self.float_offset = FLOAT_AMPLITUDE * self.float_time.sin();
+        }
+    }
+
+    // Apparition terminée : taille et opacité ont atteint leur cible
+    pub fn is_settled(&self) -> bool {
+        const EPSILON: f32 = 0.001;
+        (self.target_scale - self.scale).abs() < EPSILON && (self.target_opacity - self.opacity).abs() < EPSILON
+    }
+}
+
+impl Default for AnimatedImage {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+struct Particle {
+    pos: egui::Pos2,
+    vel: egui::Vec2,
+    angle: f32,
+    spin: f32,
+    size: f32,
+    life: f32,
+    max_life: f32,
+    color: Color32,
+    // Éclat (triangle) pour les baisses, confetti (rectangle) pour les hausses
+    shard: bool,
+}
+
+pub struct ParticleSystem {
+    particles: Vec<Particle>,
+    // Graine des gerbes, combinée au numéro du pas pour que chacune soit tirée de la même façon
+    seed: u64,
+    pub enabled: bool,
+}
+
+impl ParticleSystem {
+    const THRESHOLD_PERCENT: f64 = 5.0;
+    const PARTICLES_PER_PERCENT: f64 = 20.0;
+    const MAX_PARTICLES: usize = 400;
+    const GRAVITY: f32 = 400.0;
+
+    pub fn new(seed: u64) -> Self {
+        Self {
+            particles: Vec::new(),
+            seed,
+            enabled: true,
+        }
+    }
+
+    // Efface les gerbes en cours, par exemple quand la lecture repart du début
+    pub fn clear(&mut self) {
+        self.particles.clear();
+    }
+
+    // Déclenche une gerbe de particules si le mouvement dépasse le seuil ; ses tirages ne dépendent
+    // que de la graine et du pas `step`, pas des gerbes précédentes
+    pub fn burst(&mut self, rect: egui::Rect, price_change: f64, step: usize) {
+        if !self.enabled || reduce_motion() || price_change.abs() < Self::THRESHOLD_PERCENT {
+            return;
+        }
+
+        let rng = &mut StdRng::seed_from_u64(self.seed ^ (step as u64).wrapping_mul(0x9e37_79b9_7f4a_7c15));
+        let count = ((price_change.abs() * Self::PARTICLES_PER_PERCENT) as usize)
+            .min(Self::MAX_PARTICLES.saturating_sub(self.particles.len()));
+        let is_up = price_change > 0.0;
+
+        for _ in 0..count {
+            let (pos, vel, color) = if is_up {
+                // Confettis verts qui tombent du haut du graphique
+                let greens = [
+                    Color32::from_rgb(46, 189, 89),
+                    Color32::from_rgb(120, 230, 140),
+                    Color32::from_rgb(255, 215, 0),
+                ];
+                (
+                    egui::pos2(rng.gen_range(rect.left()..rect.right()), rect.top()),
+                    egui::vec2(rng.gen_range(-60.0..60.0), rng.gen_range(-50.0..100.0)),
+                    greens[rng.gen_range(0..greens.len())],
+                )
+            } else {
+                // Éclats rouges projetés depuis le centre
+                let angle = rng.gen_range(0.0..std::f32::consts::TAU);
+                let speed = rng.gen_range(150.0..450.0);
+                let reds = [
+                    Color32::from_rgb(255, 88, 88),
+                    Color32::from_rgb(200, 30, 30),
+                    Color32::from_rgb(255, 140, 120),
+                ];
+                (
+                    rect.center(),
+                    egui::vec2(angle.cos(), angle.sin()) * speed,
+                    reds[rng.gen_range(0..reds.len())],
+                )
+            };
+
+            let max_life = rng.gen_range(1.0..2.0);
+            self.particles.push(Particle {
+                pos,
+                vel,
+                angle: rng.gen_range(0.0..std::f32::consts::TAU),
+                spin: rng.gen_range(-8.0..8.0),
+                size: rng.gen_range(3.0..8.0),
+                life: max_life,
+                max_life,
+                color,
+                shard: !is_up,
+            });
+        }
+    }
+
+    pub fn update(&mut self, dt: f32) {
+        for particle in &mut self.particles {
+            particle.vel.y += Self::GRAVITY * dt;
+            particle.pos += particle.vel * dt;
+            particle.angle += particle.spin * dt;
+            particle.life -= dt;
+        }
+        self.particles.retain(|p| p.life > 0.0);
+        if !self.enabled {
+            self.particles.clear();
+        }
+    }
+
+    pub fn is_active(&self) -> bool {
+        !self.particles.is_empty()
+    }
+
+    pub fn paint(&self, painter: &egui::Painter) {
+        for particle in &self.particles {
+            let alpha = (particle.life / particle.max_life).clamp(0.0, 1.0);
+            let color = particle.color.gamma_multiply(alpha);
+            let (sin, cos) = particle.angle.sin_cos();
+            let rotate = |x: f32, y: f32| particle.pos + egui::vec2(x * cos - y * sin, x * sin + y * cos);
+            let s = particle.size;
+
+            let points = if particle.shard {
+                vec![rotate(0.0, -s * 1.5), rotate(s * 0.6, s), rotate(-s * 0.6, s * 0.8)]
+            } else {
+                vec![rotate(-s, -s * 0.5), rotate(s, -s * 0.5), rotate(s, s * 0.5), rotate(-s, s * 0.5)]
+            };
+            painter.add(egui::Shape::convex_polygon(points, color, egui::Stroke::NONE));
+        }
+    }
+}
+
+impl Default for ParticleSystem {
+    fn default() -> Self {
+        Self::new(0)
+    }
+}
+
+impl PriceStats {
+    pub fn show(&self, ui: &mut egui::Ui, currency: &str) {
+        egui::Grid::new("price_stats")
+            .num_columns(4)
+            .spacing([24.0, 4.0])
+            .show(ui, |ui| {
+                ui.label(tr("stat-min"));
+                ui.label(format_price(self.min, currency));
+                ui.label(tr("stat-total-return"));
+                ui.label(format!("{:+.2}%", self.total_return));
+                ui.end_row();
+
+                ui.label(tr("stat-max"));
+                ui.label(format_price(self.max, currency));
+                ui.label(tr("stat-max-drawdown"));
+                ui.label(format!("-{:.2}%", self.max_drawdown));
+                ui.end_row();
+
+                ui.label(tr("stat-mean"));
+                ui.label(format_price(self.mean, currency));
+                ui.label(tr("stat-volatility"));
+                ui.label(format!("{:.1}%", self.annualized_volatility));
+                ui.end_row();
+
+                ui.label(tr("stat-median"));
+                ui.label(format_price(self.median, currency));
+                ui.end_row();
+            });
+    }
+}
@@ -0,0 +1,56 @@
+use eth_price_sonifier::data::{AlertCondition, AlertRule, AlertTracker, ChartData, DailyPrice};
+
+fn reading(coin: &str, latest_price: f64, change_24h: f64) -> ChartData {
+    ChartData {
+        coin: coin.to_string(),
+        currency: "usd".to_string(),
+        daily_prices: vec![DailyPrice::new("2024-03-31".to_string(), latest_price)],
+        latest_price,
+        change_24h,
+    }
+}
+
+fn rule(coin: &str, condition: AlertCondition) -> AlertRule {
+    AlertRule { coin: coin.to_string(), currency: "usd".to_string(), condition }
+}
+
+#[test]
+fn conditions_compare_price_and_daily_change() {
+    let data = reading("bitcoin", 100_500.0, -6.0);
+    assert!(rule("bitcoin", AlertCondition::Above(100_000.0)).is_met(&data));
+    assert!(!rule("bitcoin", AlertCondition::Below(100_000.0)).is_met(&data));
+    assert!(rule("bitcoin", AlertCondition::DropsPercent(5.0)).is_met(&data));
+    assert!(!rule("bitcoin", AlertCondition::RisesPercent(5.0)).is_met(&data));
+}
+
+#[test]
+fn rules_fire_once_per_crossing() {
+    let mut tracker = AlertTracker::new();
+    assert!(tracker.set_rules(&[rule("bitcoin", AlertCondition::Above(100_000.0))]));
+
+    assert!(tracker.observe(&reading("bitcoin", 99_000.0, 0.0)).is_empty());
+    assert_eq!(tracker.observe(&reading("bitcoin", 101_000.0, 0.0)).len(), 1);
+    // Toujours au-dessus : pas de nouvelle alarme
+    assert!(tracker.observe(&reading("bitcoin", 102_000.0, 0.0)).is_empty());
+    // Redescendue puis remontée : la règle s'est réarmée
+    assert!(tracker.observe(&reading("bitcoin", 98_000.0, 0.0)).is_empty());
+    assert_eq!(tracker.observe(&reading("bitcoin", 100_000.0, 0.0)).len(), 1);
+}
+
+#[test]
+fn readings_only_match_their_coin() {
+    let mut tracker = AlertTracker::new();
+    tracker.set_rules(&[rule("ethereum", AlertCondition::DropsPercent(5.0))]);
+    assert!(tracker.observe(&reading("bitcoin", 50_000.0, -10.0)).is_empty());
+    assert_eq!(tracker.observe(&reading("ethereum", 3_000.0, -10.0)).len(), 1);
+}
+
+#[test]
+fn unchanged_rules_keep_their_state() {
+    let rules = [rule("bitcoin", AlertCondition::Above(100_000.0))];
+    let mut tracker = AlertTracker::new();
+    tracker.set_rules(&rules);
+    tracker.observe(&reading("bitcoin", 101_000.0, 0.0));
+    assert!(!tracker.set_rules(&rules));
+    assert!(tracker.observe(&reading("bitcoin", 101_000.0, 0.0)).is_empty());
+}
@@ -0,0 +1,60 @@
+use eth_price_sonifier::audio::{mix_notes, Sonifier, SoundOptions, ViewMode};
+use eth_price_sonifier::data::provider::chart_data_from_prices;
+use eth_price_sonifier::data::DailyPrice;
+
+const HOUR_MS: f64 = 3_600_000.0;
+
+#[test]
+fn intraday_points_give_daily_range_and_volume() {
+    // Deux jours de points horaires ; le second n'a qu'un point, donc pas d'amplitude
+    let body = format!(
+        r#"{{"prices":[[0,100.0],[{h},120.0],[{h2},90.0],[{d},110.0]],"total_volumes":[[0,5.0],[{h2},7.0],[{d},9.0]]}}"#,
+        h = HOUR_MS,
+        h2 = 2.0 * HOUR_MS,
+        d = 24.0 * HOUR_MS,
+    );
+    let data = chart_data_from_prices("bitcoin", "usd", serde_json::from_str(&body).unwrap()).unwrap();
+    assert_eq!(data.daily_prices.len(), 2);
+    let first = &data.daily_prices[0];
+    assert_eq!(first.price, 100.0);
+    assert_eq!(first.range, Some((90.0, 120.0)));
+    assert_eq!(first.volume, Some(7.0));
+    assert!((first.range_percent() - 30.0).abs() < 1e-9);
+    assert_eq!(data.daily_prices[1].range, None);
+    assert_eq!(data.daily_prices[1].volume, Some(9.0));
+
+    // Les plugins sans volumes restent acceptés
+    let data = chart_data_from_prices("bitcoin", "usd", serde_json::from_str(r#"{"prices":[[0,1.0]]}"#).unwrap()).unwrap();
+    assert_eq!(data.daily_prices[0].volume, None);
+}
+
+fn day(price: f64, volume: Option<f64>, range: Option<(f64, f64)>) -> DailyPrice {
+    DailyPrice { volume, range, ..DailyPrice::new("2024-01-01".to_string(), price) }
+}
+
+#[test]
+fn arpeggio_adds_a_note_per_available_metric() {
+    let prices = [
+        day(100.0, Some(10.0), Some((95.0, 105.0))),
+        day(105.0, Some(40.0), Some((95.0, 110.0))),
+        day(104.0, Some(10.0), None),
+        day(103.0, None, None),
+    ];
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(SoundOptions { arpeggio: true, ..SoundOptions::default() });
+    let notes = sonifier.sonify(&prices);
+    assert_eq!(notes[0].frequencies.len(), 3);
+    // Quatre fois la médiane : deux octaves au-dessus du La, resserrées sous le haut de la plage
+    assert!(notes[0].frequencies[1] > 1320.0 && notes[0].frequencies[1] < 1760.0);
+    assert!(notes[0].frequencies[2] > 220.0);
+    assert_eq!(notes[1].frequencies.len(), 2);
+    assert_eq!(notes[1].frequencies[1], 440.0);
+    assert_eq!(notes[2].frequencies.len(), 1);
+    assert!(notes.iter().all(|note| note.arpeggio.is_some_and(|seconds| seconds < sonifier.step_seconds)));
+
+    let plain = Sonifier::new(ViewMode::Price).sonify(&prices);
+    assert!(plain.iter().all(|note| note.frequencies.len() == 1 && note.arpeggio.is_none()));
+
+    let duration = notes.len() as f64 * sonifier.step_seconds;
+    let samples = mix_notes(&notes, duration);
+    assert!(samples.iter().all(|sample| sample.is_finite() && sample.abs() <= 1.0));
+}
@@ -0,0 +1,124 @@
+use eth_price_sonifier::audio::{AudioCommand, AudioEngine, AudioSource, EngineEvent, EngineOutput, LimiterMeter, NoteEvent, Timbre};
+use eth_price_sonifier::error::Error;
+use rodio::{Source, StreamError};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+// Sortie sans carte son : garde la trace de ce que le moteur lui demande
+#[derive(Clone, Default)]
+struct FakeOutput {
+    log: Arc<Mutex<Vec<String>>>,
+    queued: Arc<Mutex<usize>>,
+}
+
+impl EngineOutput for FakeOutput {
+    fn append(&mut self, source: AudioSource) {
+        let frame = source.channels() as f64 * source.sample_rate() as f64;
+        let seconds = source.count() as f64 / frame;
+        self.log.lock().unwrap().push(format!("append {:.1}", seconds));
+        *self.queued.lock().unwrap() += 1;
+    }
+
+    fn clear(&mut self) {
+        self.log.lock().unwrap().push("clear".to_string());
+        *self.queued.lock().unwrap() = 0;
+    }
+
+    fn set_volume(&mut self, volume: f32) {
+        self.log.lock().unwrap().push(format!("volume {}", volume));
+    }
+
+    fn queued(&self) -> usize {
+        *self.queued.lock().unwrap()
+    }
+}
+
+fn note(start: f64, duration: f64) -> NoteEvent {
+    NoteEvent {
+        start,
+        duration,
+        frequencies: vec![440.0],
+        velocity: 1.0,
+        fade_out: 0.0,
+        arpeggio: None,
+        timbre: Timbre::Sine,
+        sample: None,
+    }
+}
+
+fn wait_until(condition: impl Fn() -> bool) {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    while !condition() {
+        assert!(Instant::now() < deadline, "le moteur n'a pas répondu");
+        std::thread::sleep(Duration::from_millis(5));
+    }
+}
+
+#[test]
+fn commands_reach_the_output_in_order() {
+    let output = FakeOutput::default();
+    let log = Arc::clone(&output.log);
+    let mut engine = AudioEngine::spawn(Box::new(move || Ok(Box::new(output) as Box<dyn EngineOutput>)), 0.8, LimiterMeter::new());
+
+    engine.send(AudioCommand::Wait(0.5));
+    engine.send(AudioCommand::Step { voices: vec![note(2.0, 3.0)], step_start: 2.0, length: Some(1.0) });
+    engine.send(AudioCommand::Play(note(0.0, 0.2)));
+    engine.send(AudioCommand::SetVolume(0.3));
+    wait_until(|| log.lock().unwrap().len() == 5);
+    assert!(!engine.is_idle());
+
+    engine.send(AudioCommand::Pause);
+    wait_until(|| engine.is_idle());
+    assert_eq!(
+        *log.lock().unwrap(),
+        ["volume 0.8", "append 0.5", "append 1.0", "append 0.2", "volume 0.3", "clear"]
+    );
+}
+
+// Fenêtre cachée : plus aucune commande n'arrive, le moteur complète seul la file à mesure qu'elle se vide
+#[test]
+fn pending_steps_are_queued_as_the_output_plays() {
+    let output = FakeOutput::default();
+    let (log, queued) = (Arc::clone(&output.log), Arc::clone(&output.queued));
+    let mut engine = AudioEngine::spawn(Box::new(move || Ok(Box::new(output) as Box<dyn EngineOutput>)), 1.0, LimiterMeter::new());
+    for step in 0..10 {
+        engine.send(AudioCommand::Step { voices: vec![note(step as f64, 0.5)], step_start: step as f64, length: Some(1.0) });
+    }
+    let appended = || log.lock().unwrap().iter().filter(|line| line.starts_with("append")).count();
+    wait_until(|| engine.is_idle() || appended() > 0);
+    std::thread::sleep(Duration::from_millis(100));
+    assert!(appended() < 10);
+
+    // Chaque son terminé en fait entrer un autre, jusqu'au dernier pas
+    while appended() < 10 {
+        let before = appended();
+        *queued.lock().unwrap() -= 1;
+        wait_until(|| appended() > before);
+    }
+    *queued.lock().unwrap() = 0;
+    wait_until(|| engine.is_idle());
+
+    // Un arrêt abandonne aussi ce qui attendait dans le moteur
+    for step in 0..10 {
+        engine.send(AudioCommand::Step { voices: vec![note(step as f64, 0.5)], step_start: step as f64, length: Some(1.0) });
+    }
+    engine.send(AudioCommand::Pause);
+    *queued.lock().unwrap() = 0;
+    wait_until(|| engine.is_idle());
+    let after_pause = appended();
+    std::thread::sleep(Duration::from_millis(100));
+    assert_eq!(appended(), after_pause);
+}
+
+#[test]
+fn failed_outputs_are_reported_and_leave_the_engine_silent() {
+    let mut engine = AudioEngine::spawn(Box::new(|| Err(Error::AudioOutput(StreamError::NoDevice))), 1.0, LimiterMeter::new());
+    engine.send(AudioCommand::Play(note(0.0, 0.2)));
+    engine.send(AudioCommand::Mirror(Box::new(|| Err(Error::AudioOutput(StreamError::NoDevice)))));
+    wait_until(|| engine.is_idle());
+
+    let events: Vec<EngineEvent> = std::iter::from_fn(|| engine.try_event()).collect();
+    assert_eq!(events.len(), 2);
+    assert!(matches!(&events[0], EngineEvent::OutputFailed(_)));
+    assert!(matches!(&events[1], EngineEvent::MirrorFailed(_)));
+}
@@ -0,0 +1,59 @@
+use eth_price_sonifier::audio::{note_name, NoteEvent, Timbre};
+use eth_price_sonifier::data::DailyPrice;
+use eth_price_sonifier::ui::captions::{captions_to_srt, note_log_line, step_caption};
+
+fn note(frequencies: &[f32], velocity: f32) -> NoteEvent {
+    NoteEvent {
+        start: 0.0,
+        duration: 0.2,
+        frequencies: frequencies.to_vec(),
+        velocity,
+        fade_out: 0.0,
+        arpeggio: None,
+        timbre: Timbre::Sine,
+        sample: None,
+    }
+}
+
+#[test]
+fn names_the_nearest_note() {
+    assert_eq!(note_name(440.0), "A4");
+    assert_eq!(note_name(261.63), "C4");
+    assert_eq!(note_name(554.37), "C#5");
+    assert_eq!(note_name(530.0), "C5");
+}
+
+#[test]
+fn describes_change_note_and_loudness() {
+    let previous = note(&[440.0], 0.5);
+    let current = note(&[523.25], 0.65);
+    assert_eq!(step_caption(14, 3.1, &current, Some(&previous)), "Day 14: +3.1%, note C5, louder");
+    assert_eq!(step_caption(15, -0.4, &previous, Some(&current)), "Day 15: -0.4%, note A4, softer");
+    assert_eq!(step_caption(2, 0.0, &previous, None), "Day 2: +0.0%, note A4, same volume");
+}
+
+#[test]
+fn lists_every_note_of_a_chord() {
+    let chord = note(&[440.0, 554.37, 659.25], 0.5);
+    assert!(step_caption(3, 1.0, &chord, None).contains("note A4 C#5 E5"));
+}
+
+#[test]
+fn each_caption_lasts_until_the_next() {
+    let captions = vec![(0.0, "first".to_string()), (1.5, "second".to_string())];
+    assert_eq!(
+        captions_to_srt(&captions, 62.345),
+        "1\n00:00:00,000 --> 00:00:01,500\nfirst\n\n2\n00:00:01,500 --> 00:01:02,345\nsecond\n"
+    );
+    assert_eq!(captions_to_srt(&[], 1.0), "");
+}
+
+#[test]
+fn log_line_sums_up_the_step() {
+    let day = DailyPrice::new("2024-03-14".to_string(), 102.0);
+    let mut played = note(&[659.26], 0.8);
+    played.duration = 0.8;
+    assert_eq!(note_log_line(&day, 2.34, &played), "14/03  +2.3%  E5  0.8s");
+    let intraday = DailyPrice::new("2024-03-14 09:35".to_string(), 99.0);
+    assert_eq!(note_log_line(&intraday, -0.05, &note(&[440.0, 523.25], 0.5)), "09:35  -0.1%  A4 C5  0.2s");
+}
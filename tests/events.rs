@@ -0,0 +1,37 @@
+use eth_price_sonifier::data::{builtin_events, event_markers, DailyPrice, MarketEvent};
+
+fn prices(dates: &[&str]) -> Vec<DailyPrice> {
+    dates.iter().map(|date| DailyPrice::new(date.to_string(), 1.0)).collect()
+}
+
+fn event(date: &str, title: &str, coins: &[&str]) -> MarketEvent {
+    MarketEvent { date: date.to_string(), title: title.to_string(), coins: coins.iter().map(|c| c.to_string()).collect() }
+}
+
+#[test]
+fn markers_keep_the_coin_and_the_loaded_range() {
+    let events = [
+        event("2024-01-03", "Upgrade", &["ethereum"]),
+        event("2024-01-02", "Crash", &[]),
+        event("2024-01-02", "Halving", &["bitcoin"]),
+        event("2023-06-01", "Too old", &[]),
+    ];
+    let prices = prices(&["2024-01-01", "2024-01-02", "2024-01-03"]);
+    assert_eq!(event_markers(&events, "ethereum", &prices), vec![(1, "Crash".to_string()), (2, "Upgrade".to_string())]);
+    assert_eq!(event_markers(&events, "bitcoin", &prices), vec![(1, "Crash".to_string()), (1, "Halving".to_string())]);
+}
+
+#[test]
+fn builtin_calendar_is_well_formed() {
+    let events = builtin_events();
+    assert!(events.iter().any(|event| event.title.contains("halving") && event.concerns("bitcoin")));
+    for event in &events {
+        assert!(chrono::NaiveDate::parse_from_str(&event.date, "%Y-%m-%d").is_ok(), "{}", event.date);
+    }
+}
+
+#[test]
+fn personal_events_default_to_every_coin() {
+    let events: Vec<MarketEvent> = serde_json::from_str(r#"[{"date": "2024-02-01", "title": "Conference talk"}]"#).unwrap();
+    assert!(events[0].concerns("solana"));
+}
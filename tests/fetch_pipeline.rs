@@ -0,0 +1,121 @@
+use eframe::egui;
+use eth_price_sonifier::data::{FetchStage, MockProvider, MockScenario, PriceProvider};
+use eth_price_sonifier::error::Error;
+use eth_price_sonifier::task::{CancelToken, FetchEvent, FetchPipeline, FetchRequest, RequestId};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+fn request(coin: &str, days: i64) -> FetchRequest {
+    FetchRequest {
+        provider: Arc::new(MockProvider::new(MockScenario::Sine)),
+        coin: coin.to_string(),
+        days,
+        currency: "usd".to_string(),
+        range: None,
+    }
+}
+
+// Attend que toutes les requêtes en cours soient terminées et renvoie leurs messages
+fn wait_for(pipeline: &mut FetchPipeline, ids: &[RequestId]) -> Vec<FetchEvent> {
+    let deadline = Instant::now() + Duration::from_secs(5);
+    let mut events = Vec::new();
+    while ids.iter().any(|id| pipeline.is_pending(*id)) {
+        assert!(Instant::now() < deadline, "fetch did not finish in time");
+        events.extend(pipeline.poll());
+        std::thread::sleep(Duration::from_millis(5));
+    }
+    events.extend(pipeline.poll());
+    events
+}
+
+#[test]
+fn concurrent_requests_get_their_own_results() {
+    let ctx = egui::Context::default();
+    let mut pipeline = FetchPipeline::new();
+    let bitcoin = pipeline.fetch(&ctx, request("bitcoin", 30));
+    let ethereum = pipeline.fetch(&ctx, request("ethereum", 7));
+    assert_ne!(bitcoin, ethereum);
+
+    let events = wait_for(&mut pipeline, &[bitcoin, ethereum]);
+    let mut done = 0;
+    for event in events {
+        if let FetchEvent::Done { id, coin, result } = event {
+            let data = result.unwrap();
+            assert_eq!(data.coin, coin);
+            let expected = if id == bitcoin { ("bitcoin", 31) } else { ("ethereum", 8) };
+            assert_eq!((coin.as_str(), data.daily_prices.len()), expected);
+            done += 1;
+        }
+    }
+    assert_eq!(done, 2);
+}
+
+#[test]
+fn progress_comes_before_the_result() {
+    let ctx = egui::Context::default();
+    let mut pipeline = FetchPipeline::new();
+    let id = pipeline.fetch(&ctx, request("ripple", 30));
+    let events = wait_for(&mut pipeline, &[id]);
+    assert!(matches!(events.first(), Some(FetchEvent::Progress { .. })));
+    assert!(matches!(events.last(), Some(FetchEvent::Done { .. })));
+}
+
+#[test]
+fn stages_advance_up_to_ready() {
+    let ctx = egui::Context::default();
+    let mut pipeline = FetchPipeline::new();
+    let id = pipeline.fetch(&ctx, request("cardano", 30));
+    let stages: Vec<FetchStage> = wait_for(&mut pipeline, &[id])
+        .into_iter()
+        .filter_map(|event| match event {
+            FetchEvent::Progress { stage, .. } => Some(stage),
+            FetchEvent::Done { .. } => None,
+        })
+        .collect();
+    assert_eq!(stages.first(), Some(&FetchStage::Requesting));
+    assert_eq!(stages.last(), Some(&FetchStage::Ready));
+    assert!(stages.windows(2).all(|pair| pair[0] <= pair[1]));
+    assert!(stages.windows(2).all(|pair| pair[0].fraction() <= pair[1].fraction()));
+}
+
+#[test]
+fn errors_are_typed_results() {
+    let ctx = egui::Context::default();
+    let mut pipeline = FetchPipeline::new();
+    let id = pipeline.fetch(&ctx, request("bitcoin", 0));
+    let events = wait_for(&mut pipeline, &[id]);
+    assert!(events.iter().any(|event| matches!(event, FetchEvent::Done { result: Err(_), .. })));
+}
+
+#[test]
+fn cancelled_requests_are_dropped() {
+    let ctx = egui::Context::default();
+    let mut pipeline = FetchPipeline::new();
+    let cancelled = pipeline.fetch(&ctx, request("bitcoin", 30));
+    let kept = pipeline.fetch(&ctx, request("ethereum", 30));
+    pipeline.cancel(cancelled);
+    assert!(!pipeline.is_pending(cancelled));
+
+    let events = wait_for(&mut pipeline, &[kept]);
+    for event in events {
+        match event {
+            FetchEvent::Progress { id, .. } | FetchEvent::Done { id, .. } => assert_ne!(id, cancelled),
+        }
+    }
+}
+
+#[test]
+fn cancelled_sources_stop_before_their_next_stage() {
+    let cancel = CancelToken::new();
+    let stages = Mutex::new(Vec::new());
+    // Annulée pendant la réception : la lecture n'est jamais atteinte
+    let progress = |stage: FetchStage| {
+        stages.lock().unwrap().push(stage);
+        if stage == FetchStage::Received {
+            cancel.cancel();
+        }
+    };
+    let result = MockProvider::new(MockScenario::Sine).fetch_chart_data_reporting("bitcoin", 30, "usd", &progress, &cancel);
+    assert!(matches!(result, Err(Error::Cancelled)));
+    assert_eq!(*stages.lock().unwrap(), vec![FetchStage::Received]);
+}
@@ -0,0 +1,71 @@
+use eth_price_sonifier::audio::mapping::change_to_frequency;
+use eth_price_sonifier::audio::{harmonize, normalize_frequency, HarmonyInterval, Scale, Sonifier, SoundOptions, ViewMode};
+use eth_price_sonifier::data::DailyPrice;
+
+fn close(a: f32, b: f32) -> bool {
+    (a - b).abs() < 0.01
+}
+
+#[test]
+fn harmony_follows_the_scale() {
+    // Depuis La 440 en la majeur : tierce Do dièse, quinte Mi, octave
+    assert!(close(harmonize(440.0, HarmonyInterval::Third, false, Scale::Major), 554.37));
+    assert!(close(harmonize(440.0, HarmonyInterval::Fifth, false, Scale::Major), 659.26));
+    assert!(close(harmonize(440.0, HarmonyInterval::Octave, false, Scale::Major), 880.0));
+    assert!(close(harmonize(440.0, HarmonyInterval::Octave, true, Scale::Major), 220.0));
+    // En la mineur, la tierce au-dessus du La est un Do
+    assert!(close(harmonize(440.0, HarmonyInterval::Third, false, Scale::Minor), 523.25));
+    // Depuis Si, la tierce de la gamme majeure est mineure (Ré)
+    assert!(close(harmonize(493.88, HarmonyInterval::Third, false, Scale::Major), 587.33));
+    // Une note hors gamme est d'abord ramenée sur la gamme : La dièse devient La
+    assert!(close(harmonize(466.16, HarmonyInterval::Fifth, false, Scale::Major), 659.26));
+
+    // En chromatique, l'intervalle est fixe : quatre demi-tons pour la tierce
+    assert!(close(harmonize(466.16, HarmonyInterval::Third, false, Scale::Chromatic), 587.33));
+    assert!(close(harmonize(440.0, HarmonyInterval::Fifth, true, Scale::Chromatic), 293.66));
+}
+
+#[test]
+fn harmony_voice_doubles_the_melody_when_enabled() {
+    let prices: Vec<DailyPrice> =
+        [100.0, 103.0, 98.0, 98.0].iter().map(|&price| DailyPrice::new("2024-01-01".to_string(), price)).collect();
+    let plain = Sonifier::new(ViewMode::Price);
+    assert!(plain.harmony(&prices).is_empty());
+
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(SoundOptions {
+        harmony: Some(HarmonyInterval::Fifth),
+        ..SoundOptions::default()
+    });
+    let notes = sonifier.sonify(&prices);
+    let harmony = sonifier.harmony(&prices);
+    assert_eq!(harmony.len(), notes.len());
+    for (voice, note) in harmony.iter().zip(&notes) {
+        assert_eq!(voice.start, note.start);
+        assert_eq!(voice.duration, note.duration);
+        assert!(voice.velocity < note.velocity);
+        assert!(voice.frequencies[0] > note.frequencies[0]);
+    }
+}
+
+// Dans la plage, l'harmonie reste à intervalle fixe de la mélodie ; vers les bornes, elle est ramenée
+// une seule fois dans la plage, à partir de la hauteur brute
+#[test]
+fn harmony_is_normalized_once() {
+    let prices: Vec<DailyPrice> =
+        [100.0, 100.5, 99.8, 100.0, 92.0].iter().map(|&price| DailyPrice::new("2024-01-01".to_string(), price)).collect();
+    let options = SoundOptions { harmony: Some(HarmonyInterval::Fifth), scale: Scale::Chromatic, ..SoundOptions::default() };
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(options);
+    let notes = sonifier.sonify(&prices);
+    let harmony = sonifier.harmony(&prices);
+
+    let semitones = |frequency: f32| 12.0 * (frequency / 440.0).log2();
+    for (voice, note) in harmony.iter().zip(&notes).take(3) {
+        assert!((semitones(voice.frequencies[0]) - semitones(note.frequencies[0]).round() - 7.0).abs() < 0.01);
+    }
+
+    // Chute de 8 % : la mélodie est comprimée vers l'aigu, l'harmonie part de la hauteur d'origine
+    let raw = change_to_frequency(-8.0);
+    let expected = normalize_frequency(harmonize(raw, HarmonyInterval::Fifth, false, Scale::Chromatic), options.min_frequency, options.max_frequency);
+    assert!(close(harmony[3].frequencies[0], expected));
+    assert!(close(notes[3].frequencies[0], normalize_frequency(raw, options.min_frequency, options.max_frequency)));
+}
@@ -0,0 +1,76 @@
+use eth_price_sonifier::data::http::{get_text, get_text_cancellable};
+use eth_price_sonifier::data::{set_http_policy, HttpPolicy};
+use eth_price_sonifier::error::Error;
+use eth_price_sonifier::task::CancelToken;
+use std::io::{Read, Write};
+use std::net::TcpListener;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::Duration;
+
+#[test]
+fn missing_fields_keep_their_defaults() {
+    let policy: HttpPolicy = serde_json::from_str(r#"{"retries": 4}"#).unwrap();
+    assert_eq!(policy, HttpPolicy { retries: 4, ..HttpPolicy::default() });
+}
+
+#[test]
+fn backoff_doubles_between_attempts() {
+    assert_eq!(HttpPolicy::backoff(1), Duration::from_millis(500));
+    assert_eq!(HttpPolicy::backoff(2), Duration::from_millis(1000));
+    assert_eq!(HttpPolicy::backoff(3), Duration::from_millis(2000));
+}
+
+// Un serveur qui accepte les connexions sans jamais répondre : chaque tentative expire
+#[test]
+fn silent_server_times_out_after_every_retry() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let counted = connections.clone();
+    std::thread::spawn(move || {
+        let mut open = Vec::new();
+        for stream in listener.incoming().flatten() {
+            counted.fetch_add(1, Ordering::SeqCst);
+            open.push(stream);
+        }
+    });
+
+    set_http_policy(HttpPolicy { connect_timeout: 1, read_timeout: 1, retries: 1 });
+    let url = format!("http://{}/", address);
+    let result = eth_price_sonifier::task::runtime().unwrap().block_on(get_text(|client| client.get(&url)));
+    assert!(result.is_err());
+    assert_eq!(connections.load(Ordering::SeqCst), 2);
+}
+
+// Un serveur saturé (503) justifie une nouvelle tentative, mais pas une fois la requête annulée
+#[test]
+fn cancelled_requests_skip_their_retries() {
+    let listener = TcpListener::bind("127.0.0.1:0").unwrap();
+    let address = listener.local_addr().unwrap();
+    let connections = Arc::new(AtomicUsize::new(0));
+    let cancel = CancelToken::new();
+    let (counted, cancel_on_first) = (connections.clone(), cancel.clone());
+    std::thread::spawn(move || {
+        for mut stream in listener.incoming().flatten() {
+            counted.fetch_add(1, Ordering::SeqCst);
+            cancel_on_first.cancel();
+            // La requête est lue avant de répondre, sans quoi la connexion fermée passe pour une erreur réseau
+            let mut request = [0; 1024];
+            let mut read = 0;
+            while let Ok(count @ 1..) = stream.read(&mut request[read..]) {
+                read += count;
+                if request[..read].ends_with(b"\r\n\r\n") {
+                    break;
+                }
+            }
+            stream.write_all(b"HTTP/1.1 503 Service Unavailable\r\nContent-Length: 0\r\nConnection: close\r\n\r\n").ok();
+        }
+    });
+
+    let url = format!("http://{}/", address);
+    let request = get_text_cancellable(|client| client.get(&url), &cancel);
+    let result = eth_price_sonifier::task::runtime().unwrap().block_on(request);
+    assert!(matches!(result, Err(Error::Cancelled)), "{:?}", result);
+    assert_eq!(connections.load(Ordering::SeqCst), 1);
+}
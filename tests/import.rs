@@ -0,0 +1,71 @@
+use eth_price_sonifier::audio::{notes_to_csv, NoteRow};
+use eth_price_sonifier::data::parse_price_csv;
+use eth_price_sonifier::error::Error;
+
+#[test]
+fn header_columns_are_found_by_name() {
+    let csv = "Volume,Close,Date\n1000,101.5,2024-03-02\n900,100,2024-03-01\n1200,103,2024-03-03\n";
+    let data = parse_price_csv("mine", "usd", csv).unwrap();
+    let days: Vec<(&str, f64)> = data.daily_prices.iter().map(|day| (day.date.as_str(), day.price)).collect();
+    assert_eq!(days, [("2024-03-01", 100.0), ("2024-03-02", 101.5), ("2024-03-03", 103.0)]);
+    assert_eq!(data.daily_prices[0].volume, Some(900.0));
+    assert_eq!(data.latest_price, 103.0);
+    assert_eq!(data.coin, "mine");
+}
+
+#[test]
+fn headerless_rows_read_date_then_price() {
+    // Horodatages en secondes, séparateur point-virgule
+    let csv = "1709251200;50\n1709337600;55\n";
+    let data = parse_price_csv("file", "eur", csv).unwrap();
+    assert_eq!(data.daily_prices.len(), 2);
+    assert_eq!(data.daily_prices[1].date, "2024-03-02");
+    assert_eq!(data.currency, "eur");
+}
+
+#[test]
+fn several_points_in_a_day_give_the_intraday_view() {
+    let csv = "timestamp,price\n2024-03-01T10:00:00Z,10\n2024-03-01T10:05:00Z,11\n2024-03-01T10:10:00Z,12\n";
+    let data = parse_price_csv("file", "usd", csv).unwrap();
+    assert!(data.is_intraday());
+    assert_eq!(data.daily_prices.len(), 3);
+}
+
+#[test]
+fn exported_note_lists_open_again() {
+    let rows: Vec<NoteRow> = [("2024-03-01", 10.0), ("2024-03-02", 12.0)]
+        .iter()
+        .map(|(date, price)| NoteRow {
+            date: date.to_string(),
+            price: *price,
+            change: 0.0,
+            frequency: 440.0,
+            note: "A4".to_string(),
+            velocity: 0.8,
+            start: 0.0,
+            duration: 0.5,
+        })
+        .collect();
+    let data = parse_price_csv("notes", "usd", &notes_to_csv(&rows)).unwrap();
+    let prices: Vec<f64> = data.daily_prices.iter().map(|day| day.price).collect();
+    assert_eq!(prices, [10.0, 12.0]);
+}
+
+#[test]
+fn unreadable_rows_are_errors() {
+    assert!(parse_price_csv("file", "usd", "date,price\n2024-03-01,10\nnot a row\n").is_err());
+    assert!(parse_price_csv("file", "usd", "date,price\n").is_err());
+}
+
+// Un fichier glissé est lu dans l'interface : une valeur absurde est refusée au lieu de faire planter l'application
+#[test]
+fn out_of_range_values_are_rejected() {
+    for timestamp in ["inf", "1e20", "99999999999999999999", "NaN"] {
+        let csv = format!("date,price\n2024-03-01,100\n{},101\n", timestamp);
+        assert!(matches!(parse_price_csv("file", "usd", &csv), Err(Error::InvalidCsv(_))), "{}", timestamp);
+    }
+    for price in ["NaN", "inf", "-inf"] {
+        let csv = format!("date,price\n2024-03-01,100\n2024-03-02,{}\n", price);
+        assert!(matches!(parse_price_csv("file", "usd", &csv), Err(Error::InvalidCsv(_))), "{}", price);
+    }
+}
@@ -0,0 +1,44 @@
+use eth_price_sonifier::data::{median_index, parse_candles, parse_klines, ChartData, DailyPrice};
+
+fn chart(prices: &[(&str, f64)]) -> ChartData {
+    ChartData {
+        coin: "ethereum".to_string(),
+        currency: "usd".to_string(),
+        daily_prices: prices.iter().map(|&(date, price)| DailyPrice::new(date.to_string(), price)).collect(),
+        latest_price: prices.last().map_or(0.0, |&(_, price)| price),
+        change_24h: 0.0,
+    }
+}
+
+#[test]
+fn exchange_candles_are_read_in_time_order() {
+    let klines = r#"[[1704067200000,"2300.50","2400.0","2280.0","2350.0","1000.0",1704153599999,"0",10,"0","0","0"],
+        [1704153600000,"2350.00","2360.0","2300.0","2310.0","900.0",1704239999999,"0",8,"0","0","0"]]"#;
+    assert_eq!(parse_klines(klines).unwrap().prices, vec![(1704067200000.0, 2300.5), (1704153600000.0, 2350.0)]);
+
+    // Coinbase renvoie les bougies les plus récentes d'abord, le prix d'ouverture en quatrième position
+    let candles = "[[1704153600, 2300.0, 2360.0, 2351.0, 2310.0, 900.0], [1704067200, 2280.0, 2400.0, 2301.0, 2350.0, 1000.0]]";
+    assert_eq!(parse_candles(candles).unwrap().prices, vec![(1704067200000.0, 2301.0), (1704153600000.0, 2351.0)]);
+    assert!(parse_candles("{\"message\":\"NotFound\"}").is_err());
+}
+
+#[test]
+fn index_takes_the_median_and_marks_disagreements() {
+    let gecko = chart(&[("2024-01-01", 100.0), ("2024-01-02", 110.0), ("2024-01-03", 120.0)]);
+    let binance = chart(&[("2024-01-01", 100.2), ("2024-01-02", 130.0), ("2024-01-03", 120.5)]);
+    let coinbase = chart(&[("2024-01-02", 111.0), ("2024-01-03", 119.5)]);
+    let index = median_index("ethereum", "usd", &[gecko, binance, coinbase]).unwrap();
+
+    let prices: Vec<f64> = index.daily_prices.iter().map(|day| day.price).collect();
+    // Deux sources : moyenne des deux ; trois : valeur du milieu, l'aberration de Binance est écartée
+    assert_eq!(prices, vec![100.1, 111.0, 120.0]);
+    assert!(!index.daily_prices[0].sources_disagree());
+    assert!(index.daily_prices[1].sources_disagree());
+    assert!(!index.daily_prices[2].sources_disagree());
+    assert_eq!(index.latest_price, 120.0);
+
+    // Une seule source : pas d'écart mesuré
+    let alone = median_index("ethereum", "usd", &[chart(&[("2024-01-01", 100.0)])]).unwrap();
+    assert_eq!(alone.daily_prices[0].source_spread, None);
+    assert!(median_index("ethereum", "usd", &[]).is_err());
+}
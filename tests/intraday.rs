@@ -0,0 +1,37 @@
+use eth_price_sonifier::audio::{Sonifier, ViewMode, INTRADAY_SPEED};
+use eth_price_sonifier::data::provider::{chart_data_from_prices, intraday_chart_data};
+
+const MINUTE_MS: f64 = 60_000.0;
+
+fn five_minute_points(count: usize) -> String {
+    let prices: Vec<String> = (0..count).map(|i| format!("[{},{}]", i as f64 * 5.0 * MINUTE_MS, 100.0 + i as f64)).collect();
+    format!(r#"{{"prices":[{}]}}"#, prices.join(","))
+}
+
+#[test]
+fn last_day_keeps_every_five_minute_point() {
+    let body = five_minute_points(288);
+    let data = intraday_chart_data("bitcoin", "usd", serde_json::from_str(&body).unwrap()).unwrap();
+    assert!(data.is_intraday());
+    assert_eq!(data.daily_prices.len(), 288);
+    assert_eq!(data.daily_prices[0].date, "1970-01-01 00:00");
+    assert_eq!(data.daily_prices[1].short_label(), "00:05");
+    assert!(!data.daily_prices[0].is_weekend());
+
+    // Regroupée par jour, la même série ne donne qu'un point
+    let daily = chart_data_from_prices("bitcoin", "usd", serde_json::from_str(&body).unwrap()).unwrap();
+    assert!(!daily.is_intraday());
+    assert_eq!(daily.daily_prices.len(), 1);
+    assert_eq!(daily.daily_prices[0].short_label(), "01/01");
+}
+
+#[test]
+fn last_day_plays_ten_points_per_second() {
+    let sonifier = Sonifier::new(ViewMode::Price).with_speed(INTRADAY_SPEED);
+    assert!((sonifier.step_seconds - 0.1).abs() < 1e-9);
+    let data = intraday_chart_data("bitcoin", "usd", serde_json::from_str(&five_minute_points(288)).unwrap()).unwrap();
+    let notes = sonifier.sonify(&data.daily_prices);
+    let last = notes.last().unwrap();
+    // Une journée entière en moins d'une minute
+    assert!(last.start + last.duration < 60.0);
+}
@@ -0,0 +1,35 @@
+use eth_price_sonifier::audio::{limited, Limiter, LimiterMeter, ToneSource, AUDIO_SAMPLE_RATE};
+use rodio::Source;
+
+#[test]
+fn quiet_signals_pass_through_untouched() {
+    let meter = LimiterMeter::new();
+    let mut limiter = Limiter::new(AUDIO_SAMPLE_RATE, Some(meter.clone()));
+    for n in 0..AUDIO_SAMPLE_RATE {
+        let sample = 0.3 * (n as f32 * 0.05).sin();
+        assert_eq!(limiter.process(sample), sample);
+    }
+    assert_eq!(meter.take(), 0.0);
+}
+
+#[test]
+fn loud_peaks_are_held_below_the_threshold() {
+    let meter = LimiterMeter::new();
+    // Une sinusoïde poussée bien au-delà de 0 dBFS
+    let loud = ToneSource::new(vec![220.0], AUDIO_SAMPLE_RATE).amplify(2.5).take_duration(std::time::Duration::from_secs(1));
+    let samples: Vec<f32> = limited(loud, &meter).collect();
+    let peak = samples.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+    assert!(peak <= 10f32.powf(-3.0 / 20.0) + 1e-4, "{}", peak);
+    assert!(meter.take() > 6.0);
+    // Relevée, la mesure repart de zéro
+    assert_eq!(meter.take(), 0.0);
+}
+
+#[test]
+fn knee_starts_compressing_gently() {
+    let mut limiter = Limiter::new(AUDIO_SAMPLE_RATE, None);
+    // -5 dBFS, dans le coude : à peine réduit
+    let level = 10f32.powf(-5.0 / 20.0);
+    let out = (0..100).map(|_| limiter.process(level)).last().unwrap();
+    assert!(out < level && out > level * 0.9);
+}
@@ -0,0 +1,75 @@
+use eth_price_sonifier::audio::mapping::change_to_frequency;
+use eth_price_sonifier::audio::{Sonifier, SoundOptions, ViewMode};
+use eth_price_sonifier::data::{BarAggregator, BarLength, DailyPrice};
+use eth_price_sonifier::ui::live::{bar_note, bar_note_with};
+
+#[test]
+fn ticks_are_grouped_into_bars() {
+    let mut bars = BarAggregator::new(BarLength::FiveSeconds);
+    assert_eq!(bars.push(10.2, 100.0), None);
+    assert_eq!(bars.push(11.0, 104.0), None);
+    assert_eq!(bars.push(14.9, 98.0), None);
+    // Le premier relevé de l'intervalle suivant termine la barre
+    let bar = bars.push(15.1, 101.0).unwrap();
+    assert_eq!(bar.start, 10.0);
+    assert_eq!((bar.open, bar.high, bar.low, bar.close), (100.0, 104.0, 98.0, 98.0));
+    assert_eq!(bar.ticks, 3);
+    assert_eq!(bars.current().unwrap().open, 101.0);
+
+    // Un intervalle sans relevé ne donne pas de barre
+    let bar = bars.push(31.0, 102.0).unwrap();
+    assert_eq!(bar.start, 15.0);
+    assert_eq!(bars.current().unwrap().start, 30.0);
+
+    // Changer de longueur repart de zéro
+    bars.set_length(BarLength::OneMinute);
+    assert!(bars.current().is_none());
+    assert_eq!(bars.push(61.0, 100.0), None);
+    assert_eq!(bars.push(119.0, 100.0), None);
+    assert_eq!(bars.push(120.0, 100.0).unwrap().ticks, 2);
+}
+
+#[test]
+fn each_bar_plays_like_a_day() {
+    let mut bars = BarAggregator::new(BarLength::OneSecond);
+    bars.push(0.0, 100.0);
+    let bar = bars.push(1.0, 102.0).unwrap();
+    let note = bar_note(99.0, &bar, BarLength::OneSecond).unwrap();
+    assert_eq!(note.frequencies[0], change_to_frequency((100.0 - 99.0) / 99.0 * 100.0));
+    // Une note par barre, sans déborder sur la suivante
+    assert!(note.duration <= BarLength::OneSecond.seconds());
+    let long = bar_note(99.0, &bar, BarLength::OneMinute).unwrap();
+    assert!(long.duration > note.duration);
+}
+
+#[test]
+fn first_live_bar_continues_from_the_last_historical_price() {
+    let history: Vec<DailyPrice> =
+        [100.0, 104.0, 103.0].iter().map(|&price| DailyPrice::new("2024-01-01".to_string(), price)).collect();
+    let mut bars = BarAggregator::new(BarLength::FiveSeconds);
+    bars.push(0.0, 105.0);
+    let bar = bars.push(5.0, 105.0).unwrap();
+
+    // Le direct reprend là où l'historique s'arrête : la première note compare la barre au dernier jour
+    let note = bar_note(history.last().unwrap().price, &bar, BarLength::FiveSeconds).unwrap();
+    let mut continued = history.clone();
+    continued.push(DailyPrice::new("2024-01-04".to_string(), bar.close));
+    let expected = Sonifier::new(ViewMode::Price).sonify(&continued).pop().unwrap();
+    assert_eq!(note.frequencies[0], expected.frequencies[0]);
+    assert_eq!(note.frequencies[0], change_to_frequency((105.0 - 103.0) / 103.0 * 100.0));
+}
+
+// Radio : les réglages de son sont repris, mais le tempo reste celui des barres
+#[test]
+fn radio_bars_keep_the_sound_options() {
+    let mut bars = BarAggregator::new(BarLength::FiveSeconds);
+    bars.push(0.0, 100.0);
+    let bar = bars.push(5.0, 100.0).unwrap();
+    let options = SoundOptions { max_frequency: 300.0, ..SoundOptions::default() };
+    let slow = Sonifier::new(ViewMode::Price).with_options(options).with_speed(0.1);
+    let note = bar_note_with(slow, 50.0, &bar, BarLength::FiveSeconds).unwrap();
+    assert_eq!(note, bar_note_with(Sonifier::new(ViewMode::Price).with_options(options), 50.0, &bar, BarLength::FiveSeconds).unwrap());
+    assert!(note.duration <= BarLength::FiveSeconds.seconds());
+    assert!(note.frequencies[0] <= 300.0);
+    assert_ne!(note.frequencies, bar_note(50.0, &bar, BarLength::FiveSeconds).unwrap().frequencies);
+}
@@ -0,0 +1,45 @@
+use eth_price_sonifier::audio::{integrated_loudness, normalize_loudness, AUDIO_SAMPLE_RATE};
+
+fn sine(frequency: f32, amplitude: f32, seconds: f32) -> Vec<f32> {
+    let len = (seconds * AUDIO_SAMPLE_RATE as f32) as usize;
+    (0..len)
+        .map(|n| amplitude * (2.0 * std::f32::consts::PI * frequency * n as f32 / AUDIO_SAMPLE_RATE as f32).sin())
+        .collect()
+}
+
+#[test]
+fn full_scale_sine_matches_the_reference_level() {
+    // Valeur de référence de BS.1770 : une sinusoïde de 997 Hz à 0 dBFS sur un canal mesure -3,01 LUFS
+    let loudness = integrated_loudness(&sine(997.0, 1.0, 5.0), AUDIO_SAMPLE_RATE).unwrap();
+    assert!((loudness + 3.01).abs() < 0.1, "{}", loudness);
+}
+
+#[test]
+fn silence_and_short_signals_have_no_loudness() {
+    assert_eq!(integrated_loudness(&vec![0.0; AUDIO_SAMPLE_RATE as usize], AUDIO_SAMPLE_RATE), None);
+    assert_eq!(integrated_loudness(&sine(440.0, 0.5, 0.1), AUDIO_SAMPLE_RATE), None);
+
+    let mut silence = vec![0.0; AUDIO_SAMPLE_RATE as usize];
+    assert_eq!(normalize_loudness(&mut silence, AUDIO_SAMPLE_RATE, -16.0), 1.0);
+}
+
+#[test]
+fn quiet_and_loud_signals_end_up_at_the_same_level() {
+    let mut quiet = sine(440.0, 0.02, 4.0);
+    let mut loud = sine(440.0, 0.6, 4.0);
+    normalize_loudness(&mut quiet, AUDIO_SAMPLE_RATE, -20.0);
+    normalize_loudness(&mut loud, AUDIO_SAMPLE_RATE, -20.0);
+    for samples in [&quiet, &loud] {
+        let loudness = integrated_loudness(samples, AUDIO_SAMPLE_RATE).unwrap();
+        assert!((loudness + 20.0).abs() < 0.1, "{}", loudness);
+    }
+}
+
+#[test]
+fn peaks_stay_below_the_ceiling() {
+    // -6 LUFS demanderait de pousser la sinusoïde au-delà de 0 dBFS
+    let mut samples = sine(440.0, 0.1, 2.0);
+    normalize_loudness(&mut samples, AUDIO_SAMPLE_RATE, -6.0);
+    let peak = samples.iter().fold(0.0f32, |peak, sample| peak.max(sample.abs()));
+    assert!(peak <= 0.9, "{}", peak);
+}
@@ -0,0 +1,80 @@
+use eth_price_sonifier::data::{format_compact, parse_coin_details, parse_global, top_movers, CoinFundamentals, MarketCoin};
+
+#[test]
+fn market_entries_tolerate_missing_fields() {
+    let body = r#"[
+        {"id": "bitcoin", "symbol": "btc", "name": "Bitcoin", "current_price": 65000.5, "market_cap": 1.28e12,
+         "market_cap_rank": 1, "price_change_percentage_24h": -1.25, "sparkline_in_7d": {"price": [64000.0, 65000.5]}},
+        {"id": "new-token", "symbol": "new", "name": "New Token", "current_price": null, "market_cap": null,
+         "market_cap_rank": null, "price_change_percentage_24h": null}
+    ]"#;
+    let coins: Vec<MarketCoin> = serde_json::from_str(body).unwrap();
+    assert_eq!(coins.len(), 2);
+    assert_eq!(coins[0].market_cap_rank, Some(1));
+    assert_eq!(coins[0].sparkline_in_7d.as_ref().map(|s| s.price.len()), Some(2));
+    assert!(coins[1].current_price.is_none() && coins[1].sparkline_in_7d.is_none());
+}
+
+#[test]
+fn global_market_is_read_in_the_app_currency() {
+    let body = r#"{"data": {"active_cryptocurrencies": 10000,
+        "total_market_cap": {"usd": 2.45e12, "eur": 2.26e12},
+        "total_volume": {"usd": 9.8e10, "eur": 9.0e10},
+        "market_cap_percentage": {"btc": 52.34, "eth": 16.9},
+        "market_cap_change_percentage_24h_usd": -1.5}}"#;
+    let global = parse_global(body, "eur").unwrap();
+    assert_eq!(global.total_market_cap, 2.26e12);
+    assert_eq!(global.total_volume, 9.0e10);
+    assert_eq!(global.btc_dominance, 52.34);
+    assert_eq!(global.market_cap_change_24h, -1.5);
+    assert!(parse_global(body, "chf").is_err());
+    assert_eq!(format_compact(global.total_market_cap, "eur"), "2.3T EUR");
+}
+
+#[test]
+fn movers_are_split_by_direction() {
+    let coin = |id: &str, change: Option<f64>| MarketCoin {
+        id: id.to_string(),
+        symbol: id.to_string(),
+        name: id.to_string(),
+        current_price: Some(1.0),
+        market_cap: None,
+        market_cap_rank: None,
+        price_change_percentage_24h: change,
+        sparkline_in_7d: None,
+    };
+    let coins = vec![coin("a", Some(3.0)), coin("b", Some(-8.0)), coin("c", None), coin("d", Some(12.0)), coin("e", Some(-1.0))];
+    let ids = |movers: Vec<&MarketCoin>| movers.iter().map(|coin| coin.id.clone()).collect::<Vec<_>>();
+    let (gainers, losers) = top_movers(&coins, 2);
+    assert_eq!(ids(gainers), ["d", "a"]);
+    assert_eq!(ids(losers), ["b", "e"]);
+    // Un marché entièrement en hausse n'a pas de baisses à montrer
+    let (_, losers) = top_movers(&coins[..1], 5);
+    assert!(losers.is_empty());
+}
+
+#[test]
+fn coin_details_keep_the_app_currency() {
+    let body = r#"{"id": "ethereum", "symbol": "eth", "market_cap_rank": 2, "market_data": {
+        "market_cap": {"usd": 4.1e11, "eur": 3.8e11},
+        "circulating_supply": 120200000.5,
+        "high_24h": {"usd": 3450.0, "eur": 3190.0},
+        "low_24h": {"usd": 3300.0, "eur": null},
+        "ath": {"usd": 4878.26},
+        "ath_change_percentage": {"usd": -30.2}}}"#;
+    let usd = parse_coin_details(body, "usd").unwrap();
+    assert_eq!(usd.market_cap_rank, Some(2));
+    assert_eq!(usd.market_cap, Some(4.1e11));
+    assert_eq!(usd.circulating_supply, Some(120200000.5));
+    assert_eq!((usd.high_24h, usd.low_24h), (Some(3450.0), Some(3300.0)));
+    assert_eq!((usd.ath, usd.ath_change), (Some(4878.26), Some(-30.2)));
+
+    // Une valeur nulle ou absente dans la devise choisie reste vide, sans faire échouer le reste
+    let eur = parse_coin_details(body, "eur").unwrap();
+    assert_eq!(eur.high_24h, Some(3190.0));
+    assert_eq!(eur.low_24h, None);
+    assert_eq!(eur.ath, None);
+
+    let unlisted = parse_coin_details(r#"{"id": "new-token", "market_cap_rank": null}"#, "usd").unwrap();
+    assert_eq!(unlisted, CoinFundamentals::default());
+}
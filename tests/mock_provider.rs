@@ -0,0 +1,52 @@
+use chrono::NaiveDate;
+use eth_price_sonifier::audio::{Sonifier, ViewMode};
+use eth_price_sonifier::data::{drawdown_series, MockProvider, MockScenario, PriceProvider};
+
+fn provider(scenario: MockScenario) -> MockProvider {
+    MockProvider {
+        end: NaiveDate::from_ymd_opt(2024, 3, 31).unwrap(),
+        ..MockProvider::new(scenario)
+    }
+}
+
+#[test]
+fn series_covers_the_requested_range() {
+    for scenario in MockScenario::ALL {
+        let data = provider(scenario).fetch_chart_data("bitcoin", 30, "usd").unwrap();
+        assert_eq!(data.daily_prices.len(), 31);
+        assert_eq!(data.daily_prices.first().unwrap().date, "2024-03-01");
+        assert_eq!(data.daily_prices.last().unwrap().date, "2024-03-31");
+        assert_eq!(data.latest_price, data.daily_prices.last().unwrap().price);
+        assert!(data.daily_prices.iter().all(|p| p.price > 0.0));
+    }
+}
+
+#[test]
+fn same_seed_gives_the_same_notes() {
+    for scenario in MockScenario::ALL {
+        let first = provider(scenario).fetch_chart_data("ethereum", 90, "usd").unwrap();
+        let second = provider(scenario).fetch_chart_data("ethereum", 90, "usd").unwrap();
+        let sonifier = Sonifier::new(ViewMode::Price);
+        assert_eq!(sonifier.sonify(&first.daily_prices), sonifier.sonify(&second.daily_prices));
+    }
+}
+
+#[test]
+fn coins_get_different_series() {
+    let mock = provider(MockScenario::RandomWalk);
+    let bitcoin = mock.fetch_chart_data("bitcoin", 30, "usd").unwrap();
+    let ethereum = mock.fetch_chart_data("ethereum", 30, "usd").unwrap();
+    assert_ne!(bitcoin.latest_price, ethereum.latest_price);
+}
+
+#[test]
+fn crash_scenario_has_a_deep_drawdown() {
+    let data = provider(MockScenario::Crash).fetch_chart_data("bitcoin", 60, "usd").unwrap();
+    let deepest = drawdown_series(&data.daily_prices).into_iter().fold(0.0, f64::min);
+    assert!(deepest < -50.0, "deepest drawdown was {:.1}%", deepest);
+}
+
+#[test]
+fn empty_range_is_an_error() {
+    assert!(provider(MockScenario::Sine).fetch_chart_data("bitcoin", 0, "usd").is_err());
+}
@@ -0,0 +1,67 @@
+use eth_price_sonifier::app::NavigationHistory;
+
+#[test]
+fn back_and_forward_walk_the_visited_pages() {
+    let mut history = NavigationHistory::new("selection");
+    assert!(!history.can_go_back() && !history.can_go_forward());
+    for page in ["btc", "overview", "eth"] {
+        history.navigate(page);
+    }
+
+    assert!(history.back() && history.moved_back());
+    assert_eq!(*history.current(), "overview");
+    assert!(history.back());
+    assert_eq!(*history.current(), "btc");
+    assert!(history.forward() && !history.moved_back());
+    assert_eq!(*history.current(), "overview");
+    assert!(history.can_go_forward());
+
+    assert!(history.back() && history.back());
+    assert_eq!(*history.current(), "selection");
+    assert!(!history.back());
+}
+
+// Comme un lien suivi après un retour en arrière : les pages suivantes sont oubliées
+#[test]
+fn navigating_clears_the_forward_pages() {
+    let mut history = NavigationHistory::new("selection");
+    history.navigate("btc");
+    history.navigate("eth");
+    history.back();
+    history.navigate("browse");
+    assert!(!history.can_go_forward());
+    assert!(history.back());
+    assert_eq!(*history.current(), "btc");
+}
+
+#[test]
+fn same_page_is_not_recorded_twice() {
+    let mut history = NavigationHistory::new("selection");
+    history.navigate("btc");
+    history.navigate("btc");
+    history.back();
+    assert_eq!(*history.current(), "selection");
+}
+
+#[test]
+fn pages_start_with_the_closest_ones() {
+    let mut history = NavigationHistory::new(0);
+    for page in 1..=4 {
+        history.navigate(page);
+    }
+    history.back();
+    assert_eq!(history.pages().copied().collect::<Vec<_>>(), vec![3, 2, 1, 0, 4]);
+}
+
+#[test]
+fn oldest_pages_are_forgotten() {
+    let mut history = NavigationHistory::new(0);
+    for page in 1..=NavigationHistory::<usize>::MAX_PAGES + 10 {
+        history.navigate(page);
+    }
+    let mut steps = 0;
+    while history.back() {
+        steps += 1;
+    }
+    assert_eq!(steps, NavigationHistory::<usize>::MAX_PAGES);
+}
@@ -0,0 +1,41 @@
+use eth_price_sonifier::data::{day_index, parse_headlines, DailyPrice};
+
+const FEED: &str = r#"<?xml version="1.0" encoding="UTF-8"?>
+<rss version="2.0"><channel><title>Bitcoin crypto - Google News</title>
+<item><title>Bitcoin slides below $60k</title><link>https://example.com/slide</link>
+<pubDate>Tue, 02 Jan 2024 09:30:00 GMT</pubDate><source url="https://example.com">Example Wire</source></item>
+<item><title>Undated rumour</title><link>https://example.com/rumour</link></item>
+<item><title>ETF approved &amp; bitcoin rallies</title><link>https://example.com/etf</link>
+<pubDate>Wed, 10 Jan 2024 22:00:00 GMT</pubDate></item>
+</channel></rss>"#;
+
+fn prices(dates: &[&str]) -> Vec<DailyPrice> {
+    dates.iter().map(|date| DailyPrice::new(date.to_string(), 1.0)).collect()
+}
+
+#[test]
+fn feed_items_are_parsed_newest_first() {
+    let headlines = parse_headlines(FEED).unwrap();
+    assert_eq!(headlines.len(), 2);
+    assert_eq!(headlines[0].title, "ETF approved & bitcoin rallies");
+    assert_eq!(headlines[0].date, "2024-01-10");
+    assert_eq!(headlines[0].source, None);
+    assert_eq!(headlines[1].source.as_deref(), Some("Example Wire"));
+    assert_eq!(headlines[1].link, "https://example.com/slide");
+}
+
+#[test]
+fn malformed_feeds_are_errors() {
+    assert!(parse_headlines("<rss><channel>").is_err());
+}
+
+#[test]
+fn headlines_map_to_the_first_day_after_publication() {
+    let prices = prices(&["2024-01-01", "2024-01-03", "2024-01-04"]);
+    assert_eq!(day_index(&prices, "2024-01-01"), Some(0));
+    // Jour manquant dans la série : la lecture reprend au jour suivant
+    assert_eq!(day_index(&prices, "2024-01-02"), Some(1));
+    assert_eq!(day_index(&prices, "2023-12-31"), None);
+    assert_eq!(day_index(&prices, "2024-01-05"), None);
+    assert_eq!(day_index(&[], "2024-01-01"), None);
+}
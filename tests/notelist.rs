@@ -0,0 +1,51 @@
+use eth_price_sonifier::audio::{note_rows, notes_to_csv, write_note_list, HarmonyInterval, Sonifier, SoundOptions, ViewMode};
+use eth_price_sonifier::data::DailyPrice;
+
+fn prices() -> Vec<DailyPrice> {
+    [("2024-03-13", 100.0), ("2024-03-14", 102.0), ("2024-03-15", 99.0)]
+        .iter()
+        .map(|&(date, price)| DailyPrice::new(date.to_string(), price))
+        .collect()
+}
+
+#[test]
+fn each_day_gets_one_row() {
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(SoundOptions {
+        harmony: Some(HarmonyInterval::Third),
+        ..SoundOptions::default()
+    });
+    let prices = prices();
+    let mut notes = sonifier.sonify(&prices);
+    let melody = note_rows(&prices, &notes);
+    // Les voix ajoutées après la mélodie ne donnent pas de lignes en plus
+    notes.extend(sonifier.harmony(&prices));
+    let rows = note_rows(&prices, &notes);
+    assert_eq!(rows, melody);
+
+    assert_eq!(rows.len(), 2);
+    assert_eq!(rows[0].date, "2024-03-14");
+    assert!((rows[0].change - 2.0).abs() < 1e-9);
+    assert_eq!(rows[0].frequency, notes[0].frequencies[0]);
+    assert!(!rows[0].note.is_empty());
+    assert!(rows[1].change < 0.0);
+    assert!(rows[1].start > rows[0].start);
+
+    let csv = notes_to_csv(&rows);
+    let lines: Vec<&str> = csv.lines().collect();
+    assert_eq!(lines[0], "date,price,change,frequency,note,velocity,start,duration");
+    assert!(lines[1].starts_with("2024-03-14,102,2.0000,"));
+    assert_eq!(lines.len(), 3);
+}
+
+#[test]
+fn json_is_chosen_by_extension() {
+    let prices = prices();
+    let rows = note_rows(&prices, &Sonifier::new(ViewMode::Price).sonify(&prices));
+    let path = std::env::temp_dir().join(format!("sonifier_notes_{}.json", std::process::id()));
+    write_note_list(&rows, &path).unwrap();
+    let json: serde_json::Value = serde_json::from_str(&std::fs::read_to_string(&path).unwrap()).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    assert_eq!(json.as_array().unwrap().len(), 2);
+    assert_eq!(json[1]["date"], "2024-03-15");
+    assert_eq!(json[1]["price"], 99.0);
+}
@@ -0,0 +1,61 @@
+use eth_price_sonifier::audio::ducking_gain;
+use eth_price_sonifier::data::{order_book_symbol, parse_depth, BookSide, OrderBook};
+
+fn book(bids: &[(f64, f64)], asks: &[(f64, f64)]) -> OrderBook {
+    OrderBook { bids: bids.to_vec(), asks: asks.to_vec() }
+}
+
+#[test]
+fn parses_binance_depth() {
+    let body = r#"{"lastUpdateId":1,"bids":[["100.5","2.0"],["100.0","oops"]],"asks":[["101.0","1.5"]]}"#;
+    let book = parse_depth(body).unwrap();
+    assert_eq!(book.bids, vec![(100.5, 2.0)]);
+    assert_eq!(book.asks, vec![(101.0, 1.5)]);
+    assert!(parse_depth("<html>").is_err());
+}
+
+#[test]
+fn imbalance_leans_toward_the_heavier_side() {
+    assert_eq!(book(&[(100.0, 1.0)], &[(100.0, 1.0)]).imbalance(), 0.0);
+    assert!(book(&[(100.0, 3.0)], &[(100.0, 1.0)]).imbalance() > 0.0);
+    assert!(book(&[(100.0, 1.0)], &[(100.0, 3.0)]).imbalance() < 0.0);
+    assert_eq!(OrderBook::default().imbalance(), 0.0);
+}
+
+#[test]
+fn only_new_large_levels_are_walls() {
+    let levels = |extra: f64| {
+        let mut bids: Vec<(f64, f64)> = (0..10).map(|i| (100.0 - i as f64, 1.0)).collect();
+        bids[3].1 = extra;
+        bids
+    };
+    let asks: Vec<(f64, f64)> = (0..10).map(|i| (101.0 + i as f64, 1.0)).collect();
+    let before = book(&levels(1.0), &asks);
+    let after = book(&levels(50.0), &asks);
+
+    let walls = after.new_walls(&before);
+    assert_eq!(walls.len(), 1);
+    assert_eq!(walls[0].side, BookSide::Bid);
+    assert_eq!(walls[0].price, 97.0);
+    // Le même mur au relevé suivant n'est plus nouveau
+    assert!(after.new_walls(&after).is_empty());
+}
+
+#[test]
+fn known_coins_map_to_usdt_pairs() {
+    assert_eq!(order_book_symbol("bitcoin").as_deref(), Some("BTCUSDT"));
+    assert_eq!(order_book_symbol("not-a-coin"), None);
+}
+
+#[test]
+fn background_layers_duck_under_notes_and_recover() {
+    // Pendant la note : gain réduit de la profondeur
+    assert!((ducking_gain(1.0, 2.0, 0.5, 0.3) - 0.5).abs() < 1e-6);
+    // À mi-retour : à mi-chemin
+    assert!((ducking_gain(2.15, 2.0, 0.5, 0.3) - 0.75).abs() < 1e-6);
+    assert_eq!(ducking_gain(2.3, 2.0, 0.5, 0.3), 1.0);
+    assert_eq!(ducking_gain(5.0, 2.0, 0.5, 0.0), 1.0);
+    // Sans profondeur, le fond ne bouge pas
+    assert_eq!(ducking_gain(1.0, 2.0, 0.0, 0.3), 1.0);
+    assert_eq!(ducking_gain(1.0, 2.0, 1.0, 0.3), 0.0);
+}
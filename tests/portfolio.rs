@@ -0,0 +1,44 @@
+use eth_price_sonifier::data::{portfolio_series, ChartData, DailyPrice, Holding, PORTFOLIO_ID};
+
+fn series(coin: &str, prices: &[(&str, f64)], change_24h: f64) -> ChartData {
+    ChartData {
+        coin: coin.to_string(),
+        currency: "usd".to_string(),
+        daily_prices: prices.iter().map(|(date, price)| DailyPrice::new(date.to_string(), *price)).collect(),
+        latest_price: prices.last().unwrap().1,
+        change_24h,
+    }
+}
+
+fn holding(coin: &str, amount: f64) -> Holding {
+    Holding { coin: coin.to_string(), amount }
+}
+
+#[test]
+fn total_value_is_weighted_by_holdings() {
+    let bitcoin = series("bitcoin", &[("2024-03-01", 60_000.0), ("2024-03-02", 66_000.0)], 10.0);
+    let ethereum = series("ethereum", &[("2024-03-01", 3_000.0), ("2024-03-02", 3_000.0)], 0.0);
+    let data = portfolio_series(&[holding("bitcoin", 0.5), holding("ethereum", 10.0)], &[bitcoin, ethereum]).unwrap();
+
+    assert_eq!(data.coin, PORTFOLIO_ID);
+    let values: Vec<f64> = data.daily_prices.iter().map(|p| p.price).collect();
+    assert_eq!(values, vec![60_000.0, 63_000.0]);
+    assert_eq!(data.latest_price, 63_000.0);
+    assert!((data.change_24h - 5.0).abs() < 1e-9);
+}
+
+#[test]
+fn only_shared_days_are_kept() {
+    let bitcoin = series("bitcoin", &[("2024-03-01", 1.0), ("2024-03-02", 2.0), ("2024-03-03", 3.0)], 0.0);
+    let ethereum = series("ethereum", &[("2024-03-02", 1.0), ("2024-03-03", 1.0)], 0.0);
+    let data = portfolio_series(&[holding("bitcoin", 1.0), holding("ethereum", 1.0)], &[bitcoin, ethereum]).unwrap();
+    let dates: Vec<&str> = data.daily_prices.iter().map(|p| p.date.as_str()).collect();
+    assert_eq!(dates, vec!["2024-03-02", "2024-03-03"]);
+}
+
+#[test]
+fn missing_or_empty_holdings_are_errors() {
+    let bitcoin = series("bitcoin", &[("2024-03-01", 1.0)], 0.0);
+    assert!(portfolio_series(&[holding("ethereum", 1.0)], std::slice::from_ref(&bitcoin)).is_err());
+    assert!(portfolio_series(&[holding("bitcoin", 0.0)], &[bitcoin]).is_err());
+}
@@ -0,0 +1,94 @@
+use eth_price_sonifier::app::{ChartOptions, LaunchOptions, Settings};
+use eth_price_sonifier::audio::{NoteLength, SoundOptions, ViewMode};
+use eth_price_sonifier::data::{ChartData, DailyPrice, DateRange};
+use eth_price_sonifier::ui::recipe::Recipe;
+
+// Du 1er janvier 2024 à 00:00 au 31 mars 2024 à 23:59:59, en UTC
+const FIRST_QUARTER: DateRange = DateRange { start: 1_704_067_200, end: 1_711_929_599 };
+
+#[test]
+fn recipe_survives_a_json_roundtrip() {
+    let sound = SoundOptions { note_length: NoteLength::LongerForBigMoves, sub_bass: true, max_frequency: 880.0, ..SoundOptions::default() };
+    let recipe = Recipe::new("bitcoin".to_string(), FIRST_QUARTER, "eur".to_string(), ViewMode::Drawdown, 1.5, sound);
+    let json = recipe.to_json().unwrap();
+    assert!(!json.contains('\n'));
+    assert_eq!(Recipe::from_json(&json).unwrap(), recipe);
+}
+
+#[test]
+fn pasted_recipes_are_normalized() {
+    let json = r#"  {"version":2,"coin":" Solana ","range":{"start":1704067200,"end":1711929599},"currency":"USD","preset":"Price","speed":2.0,"sound":{}}
+"#;
+    let recipe = Recipe::from_json(json).unwrap();
+    assert_eq!((recipe.coin.as_str(), recipe.currency.as_str()), ("solana", "usd"));
+    assert_eq!(recipe.sound, SoundOptions::default());
+}
+
+// Les recettes d'avant les périodes fixes s'ouvrent toujours, sur les derniers jours
+#[test]
+fn version_one_recipes_cover_the_last_days() {
+    let json = r#"{"version":1,"coin":"ethereum","days":30,"currency":"usd","preset":"Price","speed":1.0}"#;
+    let recipe = Recipe::from_json(json).unwrap();
+    assert_eq!(recipe.range.days(), 30);
+    assert!(recipe.range.end >= chrono::Utc::now().timestamp() - 60);
+}
+
+#[test]
+fn invalid_recipes_are_rejected() {
+    let valid = Recipe::new("ethereum".to_string(), FIRST_QUARTER, "usd".to_string(), ViewMode::Price, 1.0, SoundOptions::default());
+    let broken = [
+        Recipe { version: valid.version + 1, ..valid.clone() },
+        Recipe { range: DateRange { start: FIRST_QUARTER.end, end: FIRST_QUARTER.start }, ..valid.clone() },
+        Recipe { speed: 0.0, ..valid.clone() },
+        Recipe { coin: " ".to_string(), ..valid.clone() },
+    ];
+    for recipe in broken {
+        assert!(Recipe::from_json(&recipe.to_json().unwrap()).is_err(), "{:?}", recipe);
+    }
+    assert!(Recipe::from_json("not a recipe").is_err());
+    assert!(Recipe::from_json(r#"{"version":1,"coin":"ethereum","days":0,"currency":"usd","preset":"Price","speed":1.0}"#).is_err());
+}
+
+// La période d'un graphique recoupe exactement la même série, même rechargée avec des jours en plus
+#[test]
+fn date_ranges_trim_to_the_same_days() {
+    let days = |dates: &[&str]| dates.iter().map(|date| DailyPrice::new(date.to_string(), 100.0)).collect::<Vec<_>>();
+    let shown = days(&["2024-01-01", "2024-01-02", "2024-03-31"]);
+    let range = DateRange::of_prices(&shown).unwrap();
+    assert_eq!(range, FIRST_QUARTER);
+    assert_eq!(range.days(), 91);
+
+    let refetched = ChartData {
+        coin: "bitcoin".to_string(),
+        currency: "usd".to_string(),
+        daily_prices: days(&["2023-12-31", "2024-01-01", "2024-01-02", "2024-03-31", "2024-04-01"]),
+        latest_price: 100.0,
+        change_24h: 0.0,
+    };
+    let trimmed = range.trim(refetched).unwrap();
+    let dates: Vec<&str> = trimmed.daily_prices.iter().map(|day| day.date.as_str()).collect();
+    assert_eq!(dates, ["2024-01-01", "2024-01-02", "2024-03-31"]);
+
+    // Vue sur 24 h : la plage s'arrête à la minute
+    let intraday = DateRange::of_prices(&days(&["2024-01-01 10:00", "2024-01-02 09:55"])).unwrap();
+    assert!(intraday.is_intraday());
+    assert_eq!(intraday.end - intraday.start, 86_159);
+}
+
+// Une recette ne vaut que pour son graphique : le chargement suivant reprend les options du moment
+#[test]
+fn recipes_leave_the_next_fetch_unchanged() {
+    let launch = LaunchOptions { days: Some(7), currency: "usd".to_string(), speed: 1.0, ..LaunchOptions::default() };
+    let settings = Settings::default();
+    let before = ChartOptions::current(&launch, &settings);
+
+    let sound = SoundOptions { sub_bass: !settings.sound.sub_bass, ..settings.sound };
+    let recipe = Recipe::new("bitcoin".to_string(), FIRST_QUARTER, "eur".to_string(), ViewMode::Drawdown, 2.0, sound);
+    let opened = ChartOptions::from_recipe(&recipe);
+    assert!(opened.is_recipe() && !before.is_recipe());
+    assert_eq!((opened.days, opened.range, opened.currency.as_str()), (91, Some(FIRST_QUARTER), "eur"));
+    assert_eq!((opened.speed, opened.preset, opened.sound), (2.0, ViewMode::Drawdown, sound));
+
+    assert_eq!(ChartOptions::current(&launch, &settings), before);
+    assert_eq!((before.days, before.range, before.currency.as_str(), before.speed), (7, None, "usd", 1.0));
+}
@@ -0,0 +1,18 @@
+use eth_price_sonifier::ui::export::{RecordingEvent, RecordingFormat, SessionRecorder};
+use std::time::Duration;
+
+#[test]
+fn background_encoding_reports_its_result() {
+    // Rien de capturé : l'encodage échoue, mais l'échec arrive bien par le canal
+    let events = SessionRecorder::new(RecordingFormat::Gif, 0.0, None).finish_in_background();
+    let done = std::iter::from_fn(|| events.recv_timeout(Duration::from_secs(5)).ok())
+        .find_map(|event| match event {
+            RecordingEvent::Done(result) => Some(result),
+            RecordingEvent::Progress(fraction) => {
+                assert!((0.0..=1.0).contains(&fraction));
+                None
+            },
+        })
+        .expect("the encoder thread sends its result");
+    assert!(done.is_err());
+}
@@ -0,0 +1,69 @@
+use eth_price_sonifier::audio::{mix_notes, Sample, Sonifier, SoundPack, ViewMode, AUDIO_SAMPLE_RATE};
+use eth_price_sonifier::data::DailyPrice;
+use std::path::{Path, PathBuf};
+
+fn write_wav(path: &Path, channels: u16, sample_rate: u32, frames: usize, value: i16) {
+    let spec = hound::WavSpec { channels, sample_rate, bits_per_sample: 16, sample_format: hound::SampleFormat::Int };
+    let mut writer = hound::WavWriter::create(path, spec).unwrap();
+    for _ in 0..frames * channels as usize {
+        writer.write_sample(value).unwrap();
+    }
+    writer.finalize().unwrap();
+}
+
+fn pack_dir(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("sonifier_pack_{}_{}", name, std::process::id()));
+    std::fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+#[test]
+fn samples_are_converted_to_mono_at_the_output_rate() {
+    let dir = pack_dir("convert");
+    // Une demi-seconde en stéréo à 22,05 kHz
+    write_wav(&dir.join("stereo.wav"), 2, 22_050, 11_025, i16::MAX / 2);
+    let sample = Sample::load(&dir.join("stereo.wav")).unwrap();
+    assert_eq!(sample.name, "stereo.wav");
+    assert_eq!(sample.samples.len(), AUDIO_SAMPLE_RATE as usize / 2);
+    assert!((sample.seconds() - 0.5).abs() < 1e-3);
+    assert!(sample.samples.iter().all(|value| (value - 0.5).abs() < 1e-3));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[test]
+fn pack_picks_the_highest_tier_reached() {
+    let dir = pack_dir("tiers");
+    for file in ["up.wav", "big_up.wav", "down.wav"] {
+        write_wav(&dir.join(file), 1, AUDIO_SAMPLE_RATE, 4410, 1000);
+    }
+    std::fs::write(
+        dir.join("manifest.json"),
+        r#"{"name":"Test","replace_synth":true,"up":[{"file":"big_up.wav","min_change":5.0},"up.wav"],"down":[{"file":"down.wav","min_change":2.0}]}"#,
+    )
+    .unwrap();
+    let pack = SoundPack::load(&dir).unwrap();
+    assert_eq!(pack.name, "Test");
+    assert!(pack.replace_synth);
+    assert_eq!(pack.sample_for(1.0).unwrap().name, "up.wav");
+    assert_eq!(pack.sample_for(0.0).unwrap().name, "up.wav");
+    assert_eq!(pack.sample_for(7.5).unwrap().name, "big_up.wav");
+    assert_eq!(pack.sample_for(-3.0).unwrap().name, "down.wav");
+    // Sous le premier palier de baisse, pas d'échantillon : la synthèse joue
+    assert!(pack.sample_for(-1.0).is_none());
+
+    let prices = [DailyPrice::new("2024-01-01".to_string(), 100.0), DailyPrice::new("2024-01-02".to_string(), 110.0)];
+    let sonifier = Sonifier::new(ViewMode::Price);
+    let note = &sonifier.sonify(&prices)[0];
+    let sample_note = pack.sample_note(note, 10.0).unwrap();
+    assert_eq!(sample_note.start, note.start);
+    assert!(sample_note.frequencies.is_empty());
+    // L'échantillon (0,1 s) s'arrête avant la fin du pas
+    assert!((sample_note.duration - 0.1).abs() < 1e-9);
+    let samples = mix_notes(&[sample_note], sonifier.step_seconds);
+    assert!(samples[..4410].iter().all(|value| value.abs() > 0.0));
+    assert!(samples[4410..].iter().all(|value| value.abs() < 1e-3));
+
+    std::fs::write(dir.join("manifest.json"), r#"{"up":["missing.wav"]}"#).unwrap();
+    assert!(SoundPack::load(&dir).is_err());
+    std::fs::remove_dir_all(&dir).unwrap();
+}
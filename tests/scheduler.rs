@@ -0,0 +1,63 @@
+use eth_price_sonifier::audio::{step_source, NoteEvent, StepScheduler, Timbre, AUDIO_SAMPLE_RATE};
+use rodio::Source;
+
+#[test]
+fn steps_stay_on_the_grid_despite_late_frames() {
+    let scheduler = StepScheduler::new(0, 10.0, 0.5);
+    assert_eq!(scheduler.due_step(9.99, 0), None);
+    // Frame en retard de 0,2 s : le pas suivant reste à 10,5 et non à 10,7
+    assert_eq!(scheduler.due_step(10.2, 0), Some(0));
+    assert_eq!(scheduler.step_time(1), 10.5);
+    assert_eq!(scheduler.due_step(10.49, 1), None);
+    assert_eq!(scheduler.due_step(10.5, 1), Some(1));
+}
+
+#[test]
+fn stall_skips_to_the_latest_due_step() {
+    let scheduler = StepScheduler::new(3, 0.0, 1.0);
+    assert_eq!(scheduler.due_step(4.5, 4), Some(7));
+    // Jamais en arrière de la position de lecture
+    assert_eq!(scheduler.due_step(4.5, 9), None);
+}
+
+#[test]
+fn retime_keeps_the_next_deadline() {
+    let mut scheduler = StepScheduler::new(0, 0.0, 2.0);
+    scheduler.retime(2, 1.0);
+    assert_eq!(scheduler.step_time(2), 4.0);
+    assert_eq!(scheduler.step_time(3), 5.0);
+    assert_eq!(scheduler.step_seconds(), 1.0);
+    assert_eq!(scheduler.due_step(5.2, 3), Some(3));
+}
+
+fn note(start: f64, duration: f64) -> NoteEvent {
+    NoteEvent {
+        start,
+        duration,
+        frequencies: vec![440.0],
+        velocity: 1.0,
+        fade_out: 0.0,
+        arpeggio: None,
+        timbre: Timbre::Sine,
+        sample: None,
+    }
+}
+
+fn seconds(source: Box<dyn Source<Item = f32> + Send>) -> f64 {
+    let frame = source.channels() as f64 * source.sample_rate() as f64;
+    source.count() as f64 / frame
+}
+
+#[test]
+fn queued_steps_last_exactly_one_step() {
+    let frame = 1.0 / AUDIO_SAMPLE_RATE as f64;
+    // Note courte : le pas est complété de silence
+    let short = seconds(step_source(&[note(4.0, 0.2)], 4.0, Some(0.5)));
+    assert!((short - 0.5).abs() <= frame);
+    // Note qui déborde, retardée dans le pas : elle est coupée à la fin du pas
+    let long = seconds(step_source(&[note(4.1, 1.0), note(4.0, 0.1)], 4.0, Some(0.5)));
+    assert!((long - 0.5).abs() <= frame);
+    // Sans longueur imposée, le dernier pas va jusqu'au bout de sa note
+    let last = seconds(step_source(&[note(4.1, 1.0)], 4.0, None));
+    assert!((last - 1.1).abs() <= 2.0 * frame);
+}
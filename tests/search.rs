@@ -0,0 +1,42 @@
+use eth_price_sonifier::data::{search_coins, CoinListing};
+
+fn listing(id: &str, symbol: &str, name: &str) -> CoinListing {
+    CoinListing { id: id.to_string(), symbol: symbol.to_string(), name: name.to_string() }
+}
+
+fn list() -> Vec<CoinListing> {
+    vec![
+        listing("bitcoin-cash", "bch", "Bitcoin Cash"),
+        listing("bitcoin", "btc", "Bitcoin"),
+        listing("wrapped-bitcoin", "wbtc", "Wrapped Bitcoin"),
+        listing("ethereum", "eth", "Ethereum"),
+        listing("solana", "sol", "Solana"),
+    ]
+}
+
+fn ids(query: &str) -> Vec<String> {
+    search_coins(&list(), query, 10).into_iter().map(|coin| coin.id.clone()).collect()
+}
+
+#[test]
+fn exact_symbol_comes_first() {
+    assert_eq!(ids("BTC").first().map(String::as_str), Some("bitcoin"));
+    assert_eq!(ids("eth").first().map(String::as_str), Some("ethereum"));
+}
+
+#[test]
+fn prefixes_beat_substrings() {
+    assert_eq!(ids("bitc"), vec!["bitcoin", "bitcoin-cash", "wrapped-bitcoin"]);
+}
+
+#[test]
+fn letters_in_order_match_loosely() {
+    assert_eq!(ids("slna"), vec!["solana"]);
+    assert!(ids("xyz").is_empty());
+    assert!(ids("  ").is_empty());
+}
+
+#[test]
+fn results_are_limited() {
+    assert_eq!(search_coins(&list(), "b", 2).len(), 2);
+}
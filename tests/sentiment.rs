@@ -0,0 +1,37 @@
+use eth_price_sonifier::audio::{Sonifier, ViewMode};
+use eth_price_sonifier::data::{align_sentiment, parse_fear_greed, sentiment_label_key, DailyPrice};
+
+fn prices(dates: &[&str]) -> Vec<DailyPrice> {
+    dates.iter().map(|date| DailyPrice::new(date.to_string(), 1.0)).collect()
+}
+
+#[test]
+fn fear_greed_history_is_parsed_and_aligned() {
+    // 2024-01-02 et 2024-01-01 à minuit UTC, plus une entrée illisible
+    let body = r#"{"name": "Fear and Greed Index", "data": [
+        {"value": "71", "value_classification": "Greed", "timestamp": "1704153600"},
+        {"value": "n/a", "value_classification": "", "timestamp": "1704067200"},
+        {"value": "20", "value_classification": "Extreme Fear", "timestamp": "1704067200"}
+    ]}"#;
+    let points = parse_fear_greed(body).unwrap();
+    assert_eq!(points.len(), 2);
+    assert_eq!((points[0].date.as_str(), points[0].score), ("2024-01-02", 71.0));
+
+    let scores = align_sentiment(&prices(&["2023-12-31", "2024-01-01", "2024-01-02"]), &points);
+    assert_eq!(scores, vec![None, Some(20.0), Some(71.0)]);
+    assert_eq!(sentiment_label_key(20.0), "sentiment-extreme-fear");
+    assert_eq!(sentiment_label_key(71.0), "sentiment-greed");
+}
+
+#[test]
+fn sentiment_voice_follows_the_price_rhythm() {
+    let sonifier = Sonifier::new(ViewMode::Price);
+    let notes = sonifier.sonify_sentiment(&[Some(50.0), None, Some(10.0), Some(90.0)]);
+    assert_eq!(notes.len(), 3);
+    assert!(notes[0].is_none());
+    let (fear, greed) = (notes[1].as_ref().unwrap(), notes[2].as_ref().unwrap());
+    assert_eq!(fear.start, sonifier.step_seconds);
+    // Plus le marché est avide, plus la voix monte, en restant sous le la 440 du prix
+    assert!(fear.frequencies[0] < greed.frequencies[0]);
+    assert!(greed.frequencies[0] <= 440.0);
+}
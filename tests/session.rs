@@ -0,0 +1,43 @@
+use eth_price_sonifier::audio::ViewMode;
+use eth_price_sonifier::data::{MockProvider, MockScenario, PriceProvider};
+use eth_price_sonifier::ui::chart::Visualization;
+use eth_price_sonifier::ui::session::{load_session, SavedSession};
+use std::path::PathBuf;
+
+fn session() -> SavedSession {
+    let data = MockProvider::new(MockScenario::Crash).fetch_chart_data("bitcoin", 30, "eur").unwrap();
+    SavedSession::new(data, ViewMode::Drawdown, Visualization::Calendar, 12, 2.0, 0.4, Some((3.0, 20.0)))
+}
+
+// Fichier propre au test dans le dossier temporaire, les tests tournant en parallèle
+fn write_temp(name: &str, contents: &str) -> PathBuf {
+    let path = std::env::temp_dir().join(format!("sonifier_{}_{}.json", name, std::process::id()));
+    std::fs::write(&path, contents).unwrap();
+    path
+}
+
+#[test]
+fn saved_session_reopens_identically() {
+    let saved = session();
+    let path = write_temp("roundtrip", &serde_json::to_string(&saved).unwrap());
+    let loaded = load_session(&path).unwrap();
+    std::fs::remove_file(&path).ok();
+
+    assert_eq!(loaded.data.coin, "bitcoin");
+    assert_eq!(loaded.data.currency, "eur");
+    assert_eq!(loaded.data.daily_prices.len(), saved.data.daily_prices.len());
+    assert!(loaded.data.daily_prices.iter().zip(&saved.data.daily_prices).all(|(a, b)| a.date == b.date && a.price == b.price));
+    assert_eq!(loaded.view_mode, ViewMode::Drawdown);
+    assert!(loaded.visualization == Visualization::Calendar);
+    assert_eq!((loaded.position, loaded.speed, loaded.volume, loaded.view_x), (12, 2.0, 0.4, Some((3.0, 20.0))));
+}
+
+#[test]
+fn newer_sessions_are_rejected() {
+    let mut saved = session();
+    saved.version += 1;
+    let path = write_temp("newer", &serde_json::to_string(&saved).unwrap());
+    let result = load_session(&path);
+    std::fs::remove_file(&path).ok();
+    assert!(result.is_err());
+}
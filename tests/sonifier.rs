@@ -0,0 +1,281 @@
+use eth_price_sonifier::audio::sonifier::{MAX_FREQUENCY, MIN_FREQUENCY, MIN_VELOCITY};
+use eth_price_sonifier::audio::mapping::change_to_frequency;
+use eth_price_sonifier::audio::{mix_notes, normalize_frequency, preview_voice, Ending, NoteLength, Sonifier, SoundOptions, Timbre, ViewMode, AUDIO_SAMPLE_RATE};
+use eth_price_sonifier::data::{weekend_runs, DailyPrice};
+use proptest::prelude::*;
+
+fn series(prices: &[f64]) -> Vec<DailyPrice> {
+    prices
+        .iter()
+        .enumerate()
+        .map(|(day, price)| DailyPrice::new(format!("2024-01-{:02}", day % 28 + 1), *price))
+        .collect()
+}
+
+fn view_mode() -> impl Strategy<Value = ViewMode> {
+    prop_oneof![Just(ViewMode::Price), Just(ViewMode::Drawdown)]
+}
+
+#[test]
+fn empty_and_single_point_series_are_silent() {
+    for mode in [ViewMode::Price, ViewMode::Drawdown] {
+        let sonifier = Sonifier::new(mode);
+        assert!(sonifier.sonify(&[]).is_empty());
+        assert!(sonifier.sonify(&series(&[42_000.0])).is_empty());
+    }
+}
+
+#[test]
+fn zero_change_plays_the_reference_note_quietly() {
+    let notes = Sonifier::new(ViewMode::Price).sonify(&series(&[100.0, 100.0, 100.0]));
+    assert_eq!(notes.len(), 2);
+    for note in notes {
+        assert_eq!(note.frequencies, vec![440.0]);
+        assert_eq!(note.velocity, MIN_VELOCITY);
+    }
+}
+
+#[test]
+fn zero_price_does_not_produce_nan() {
+    for mode in [ViewMode::Price, ViewMode::Drawdown] {
+        let notes = Sonifier::new(mode).sonify(&series(&[0.0, 10.0, 0.0, 0.0]));
+        for note in notes {
+            assert!(note.frequencies.iter().all(|f| f.is_finite()));
+            assert!(note.velocity.is_finite());
+        }
+    }
+}
+
+#[test]
+fn returning_to_the_peak_plays_a_chord() {
+    let notes = Sonifier::new(ViewMode::Drawdown).sonify(&series(&[100.0, 80.0, 120.0]));
+    assert_eq!(notes[0].frequencies.len(), 1);
+    assert_eq!(notes[1].frequencies.len(), 3);
+}
+
+#[test]
+fn mixed_notes_fill_the_requested_duration() {
+    let sonifier = Sonifier::new(ViewMode::Price).with_speed(4.0);
+    let notes = sonifier.sonify(&series(&[100.0, 110.0, 90.0]));
+    let duration = notes.len() as f64 * sonifier.step_seconds;
+    let samples = mix_notes(&notes, duration);
+    assert_eq!(samples.len(), (duration * AUDIO_SAMPLE_RATE as f64) as usize);
+    assert!(samples.iter().any(|sample| *sample != 0.0));
+    assert!(samples.iter().all(|sample| sample.is_finite() && sample.abs() <= 1.0));
+}
+
+#[test]
+fn note_length_follows_the_size_of_the_move() {
+    let prices = series(&[100.0, 100.5, 115.0]);
+    let fixed = Sonifier::new(ViewMode::Price).sonify(&prices);
+    let longer = Sonifier::new(ViewMode::Price).with_note_length(NoteLength::LongerForBigMoves).sonify(&prices);
+    let shorter = Sonifier::new(ViewMode::Price).with_note_length(NoteLength::ShorterForBigMoves).sonify(&prices);
+    assert_eq!(fixed[0].duration, fixed[1].duration);
+    assert!(longer[0].duration < longer[1].duration);
+    assert!(shorter[0].duration > shorter[1].duration);
+    // Le rythme des pas ne bouge pas, et aucune note ne déborde sur la suivante
+    for notes in [&longer, &shorter] {
+        for (note, reference) in notes.iter().zip(&fixed) {
+            assert_eq!(note.start, reference.start);
+            assert!(note.duration > 0.0 && note.duration <= reference.duration);
+        }
+    }
+}
+
+#[test]
+fn fade_out_ending_stretches_and_silences_the_last_note() {
+    let prices = series(&[100.0, 110.0, 90.0]);
+    let plain = Sonifier::new(ViewMode::Price).with_speed(4.0).sonify(&prices);
+    let sonifier = Sonifier::new(ViewMode::Price).with_speed(4.0).with_ending(Ending::FadeOut);
+    let notes = sonifier.sonify(&prices);
+    assert_eq!(notes[0], plain[0]);
+    assert!(notes[1].duration > plain[1].duration && notes[1].fade_out > 0.0);
+    assert_eq!(sonifier.cadence(&prices), None);
+
+    let last = &notes[1];
+    let samples = mix_notes(&notes, last.start + last.duration);
+    let tail = &samples[samples.len() - 20..];
+    assert!(tail.iter().all(|sample| sample.abs() < 0.01));
+}
+
+#[test]
+fn cadence_is_major_after_a_rise_and_minor_after_a_fall() {
+    let sonifier = Sonifier::new(ViewMode::Price).with_ending(Ending::Cadence);
+    let up = sonifier.cadence(&series(&[100.0, 90.0, 120.0])).unwrap();
+    let down = sonifier.cadence(&series(&[100.0, 130.0, 95.0])).unwrap();
+    assert_eq!(up.start, 2.0 * sonifier.step_seconds);
+    assert_eq!(up.frequencies.len(), 3);
+    // La tierce fait la différence : do dièse en majeur, do en mineur
+    assert!(up.frequencies[1] > down.frequencies[1]);
+    assert_eq!(sonifier.cadence(&series(&[100.0])), None);
+    assert_eq!(Sonifier::new(ViewMode::Price).cadence(&series(&[100.0, 120.0])), None);
+}
+
+#[test]
+fn echo_replays_the_previous_note_just_before_each_note() {
+    let prices = series(&[100.0, 110.0, 90.0, 95.0]);
+    let plain = Sonifier::new(ViewMode::Price);
+    assert!(plain.echoes(&plain.sonify(&prices)).is_empty());
+
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(SoundOptions { echo: true, ..SoundOptions::default() });
+    let notes = sonifier.sonify(&prices);
+    let echoes = sonifier.echoes(&notes);
+    assert_eq!(echoes.len(), notes.len() - 1);
+    // Le premier jour n'a pas de veille : sa note reste en place
+    assert_eq!(notes[0].start, 0.0);
+    for (index, echo) in echoes.iter().enumerate() {
+        let (previous, note) = (&notes[index], &notes[index + 1]);
+        assert_eq!(echo.frequencies, previous.frequencies);
+        assert!((echo.start - (index + 1) as f64 * sonifier.step_seconds).abs() < 1e-9);
+        assert!((echo.start + echo.duration - note.start).abs() < 1e-9);
+        assert!(echo.velocity < note.velocity);
+        // La note du jour ne déborde pas sur le pas suivant
+        assert!(note.start + note.duration <= echo.start + sonifier.note_seconds + 1e-9);
+    }
+}
+
+#[test]
+fn hover_preview_plays_the_day_quietly_and_briefly() {
+    let prices = series(&[100.0, 110.0, 95.0, 96.0]);
+    let sonifier = Sonifier::new(ViewMode::Price);
+    for note in sonifier.sonify(&prices).iter().chain(&sonifier.harmony(&prices)) {
+        let preview = preview_voice(note);
+        assert_eq!(preview.frequencies, note.frequencies);
+        assert_eq!(preview.start, note.start);
+        assert!(preview.velocity < note.velocity);
+        assert!(preview.duration <= note.duration && preview.duration > 0.0);
+    }
+}
+
+#[test]
+fn weekend_days_get_their_own_timbre() {
+    // Le 1er janvier 2024 est un lundi : le 6 et le 7 forment le premier week-end
+    let prices = series(&[100.0; 14]);
+    assert_eq!(weekend_runs(&prices), vec![(5, 6), (12, 13)]);
+
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(SoundOptions { weekend_timbre: true, ..SoundOptions::default() });
+    let notes = sonifier.sonify(&prices);
+    // Chaque note joue le jour atteint : la note d'indice 4 mène au samedi
+    let hollow: Vec<usize> = notes.iter().enumerate().filter(|(_, note)| note.timbre == Timbre::Hollow).map(|(index, _)| index).collect();
+    assert_eq!(hollow, vec![4, 5, 11, 12]);
+    assert!(Sonifier::new(ViewMode::Price).sonify(&prices).iter().all(|note| note.timbre == Timbre::Sine));
+
+    let samples = mix_notes(&notes, notes.len() as f64 * sonifier.step_seconds);
+    assert!(samples.iter().all(|sample| sample.is_finite() && sample.abs() <= 1.0));
+}
+
+#[test]
+fn sub_bass_swells_through_a_downtrend_and_releases_on_the_first_rise() {
+    let prices = series(&[100.0, 99.0, 98.0, 97.0, 96.0, 97.0, 96.0]);
+    let options = SoundOptions { sub_bass: true, sub_bass_days: 3, ..SoundOptions::default() };
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(options);
+    let layer = sonifier.sub_bass(&prices);
+    assert_eq!(layer.len(), prices.len() - 1);
+    // Deux premiers jours de baisse : rien encore
+    assert!(layer[0].is_none() && layer[1].is_none());
+    let (third, fourth) = (layer[2].as_ref().unwrap(), layer[3].as_ref().unwrap());
+    assert!(fourth.velocity > third.velocity);
+    assert!(third.frequencies.iter().all(|frequency| *frequency < 100.0));
+    assert_eq!(third.start, 2.0 * sonifier.step_seconds);
+    // Premier jour de hausse : extinction en fondu, puis plus rien même si la baisse reprend
+    let release = layer[4].as_ref().unwrap();
+    assert!(release.fade_out > 0.0 && release.duration < sonifier.note_seconds);
+    assert!(layer[5].is_none());
+
+    assert!(Sonifier::new(ViewMode::Price).sub_bass(&prices).iter().all(Option::is_none));
+}
+
+#[test]
+fn long_trends_glide_endlessly_in_the_middle_register() {
+    // Hausse de 6 % par jour : une demi-octave de glissando par pas une fois la tendance installée
+    let rally: Vec<f64> = (0..8).map(|day| 100.0 * 1.06f64.powi(day)).collect();
+    let sonifier = Sonifier::new(ViewMode::Price).with_options(SoundOptions { shepard: true, ..SoundOptions::default() });
+    let notes = sonifier.sonify(&series(&rally));
+    assert!(notes[..2].iter().all(|note| note.timbre == Timbre::Sine));
+    let glides = &notes[2..];
+    for note in glides {
+        let (from, to) = (note.frequencies[0], note.frequencies[1]);
+        assert_eq!(note.timbre, Timbre::Shepard);
+        // La hausse fait descendre, sans jamais sortir du médium
+        assert!(to < from && (220.0..=880.0).contains(&from) && (220.0..=880.0).contains(&to));
+        assert!(((from / to).log2() - 0.5).abs() < 1e-3);
+    }
+    // Chaque pas repart là où le précédent s'est arrêté, à l'octave près
+    for pair in glides.windows(2) {
+        let octaves = (pair[1].frequencies[0] / pair[0].frequencies[1]).log2();
+        assert!((octaves - octaves.round()).abs() < 1e-3);
+    }
+    let samples = mix_notes(&notes, notes.len() as f64 * sonifier.step_seconds);
+    assert!(samples.iter().any(|sample| *sample != 0.0));
+    assert!(samples.iter().all(|sample| sample.is_finite() && sample.abs() <= 1.0));
+
+    // Baisse prolongée : le glissando monte
+    let slide: Vec<f64> = rally.iter().rev().copied().collect();
+    let notes = sonifier.sonify(&series(&slide));
+    assert!(notes[2..].iter().all(|note| note.frequencies[1] > note.frequencies[0]));
+
+    assert!(Sonifier::new(ViewMode::Price).sonify(&series(&rally)).iter().all(|note| note.timbre != Timbre::Shepard));
+}
+
+#[test]
+fn pitches_are_squeezed_into_the_chosen_range() {
+    // Krach de 60 % et envolée de 300 % : hors de toute plage agréable avec la formule brute
+    let prices = series(&[100.0, 40.0, 160.0, 161.0]);
+    let options = SoundOptions { min_frequency: 110.0, max_frequency: 1760.0, ..SoundOptions::default() };
+    let notes = Sonifier::new(ViewMode::Price).with_options(options).sonify(&prices);
+    for note in &notes {
+        assert!(note.frequencies.iter().all(|frequency| (110.0..=1760.0).contains(frequency)));
+    }
+    // Les petits mouvements gardent leur hauteur et l'ordre des notes est conservé
+    assert_eq!(notes[2].frequencies[0], change_to_frequency(1.0 / 160.0 * 100.0));
+    assert!(notes[0].frequencies[0] > notes[2].frequencies[0] && notes[2].frequencies[0] > notes[1].frequencies[0]);
+    assert!(normalize_frequency(20_000.0, 110.0, 1760.0) > normalize_frequency(8_000.0, 110.0, 1760.0));
+
+    // Plage sans le La : elle devient le centre des notes
+    assert_eq!(normalize_frequency(440.0, 1000.0, 4000.0), 2000.0);
+}
+
+proptest! {
+    #[test]
+    fn one_note_per_transition_in_order(
+        prices in prop::collection::vec(0.0001f64..1e7, 0..200),
+        mode in view_mode(),
+        speed in 0.25f64..8.0,
+    ) {
+        let sonifier = Sonifier::new(mode).with_speed(speed);
+        let notes = sonifier.sonify(&series(&prices));
+        prop_assert_eq!(notes.len(), prices.len().saturating_sub(1));
+        for (index, note) in notes.iter().enumerate() {
+            prop_assert!((note.start - index as f64 * sonifier.step_seconds).abs() < 1e-9);
+            prop_assert!(note.duration > 0.0);
+        }
+    }
+
+    #[test]
+    fn huge_spikes_stay_audible(
+        prices in prop::collection::vec(prop_oneof![0.0f64..1e-6, 1e-6f64..1e12], 2..100),
+        mode in view_mode(),
+    ) {
+        for note in Sonifier::new(mode).sonify(&series(&prices)) {
+            prop_assert!(!note.frequencies.is_empty());
+            for frequency in &note.frequencies {
+                prop_assert!((MIN_FREQUENCY..=MAX_FREQUENCY).contains(frequency));
+            }
+            prop_assert!((MIN_VELOCITY..=1.0).contains(&note.velocity));
+        }
+    }
+
+    #[test]
+    fn bigger_moves_are_louder(base in 1.0f64..1e5, small in 0.0f64..50.0, extra in 0.0f64..50.0) {
+        let prices = [base, base * (1.0 + small / 100.0), base * (1.0 + (small + extra) / 100.0)];
+        let notes = Sonifier::new(ViewMode::Price).sonify(&series(&[prices[0], prices[1], prices[0], prices[2]]));
+        prop_assert!(notes[2].velocity >= notes[0].velocity);
+    }
+
+    #[test]
+    fn rises_sound_lower_than_drops(base in 1.0f64..1e5, change in 0.1f64..50.0) {
+        let notes = Sonifier::new(ViewMode::Price)
+            .sonify(&series(&[base, base * (1.0 + change / 100.0), base]));
+        prop_assert!(notes[0].frequencies[0] < notes[1].frequencies[0]);
+    }
+}
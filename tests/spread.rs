@@ -0,0 +1,35 @@
+use eth_price_sonifier::audio::{spread_to_beat_frequency, SPREAD_DEAD_ZONE};
+use eth_price_sonifier::data::{parse_ticker, spread_percent, spread_symbol};
+
+#[test]
+fn parses_exchange_tickers() {
+    // Binance puis Coinbase : le prix arrive en chaîne dans les deux cas
+    assert_eq!(parse_ticker(r#"{"symbol":"BTCUSDT","price":"64250.10000000"}"#).unwrap(), 64250.1);
+    assert_eq!(parse_ticker(r#"{"trade_id":1,"price":"64231.5","size":"0.01","bid":"64231.4"}"#).unwrap(), 64231.5);
+    assert!(parse_ticker(r#"{"price":"n/a"}"#).is_err());
+    assert!(parse_ticker("<html>").is_err());
+}
+
+#[test]
+fn spread_is_relative_to_the_mid_price() {
+    assert_eq!(spread_percent(100.0, 100.0), 0.0);
+    assert!((spread_percent(101.0, 99.0) - 2.0).abs() < 1e-9);
+    assert!((spread_percent(99.0, 101.0) + 2.0).abs() < 1e-9);
+    assert_eq!(spread_percent(0.0, 0.0), 0.0);
+}
+
+#[test]
+fn beating_starts_past_the_dead_zone_and_is_capped() {
+    assert_eq!(spread_to_beat_frequency(0.0), None);
+    assert_eq!(spread_to_beat_frequency(SPREAD_DEAD_ZONE / 2.0), None);
+    let narrow = spread_to_beat_frequency(0.1).unwrap();
+    let wide = spread_to_beat_frequency(-0.5).unwrap();
+    assert!(narrow > 0.0 && wide > narrow);
+    assert_eq!(spread_to_beat_frequency(5.0), spread_to_beat_frequency(10.0));
+}
+
+#[test]
+fn known_coins_share_a_symbol() {
+    assert_eq!(spread_symbol("ethereum").as_deref(), Some("ETH"));
+    assert_eq!(spread_symbol("not-a-coin"), None);
+}
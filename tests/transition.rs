@@ -0,0 +1,21 @@
+use eframe::egui;
+use eth_price_sonifier::app::{PageTransition, TransitionDirection};
+
+#[test]
+fn cover_slides_away_in_the_transition_direction() {
+    let screen = egui::Rect::from_min_size(egui::pos2(0.0, 0.0), egui::vec2(800.0, 600.0));
+    let forward = PageTransition::new(10.0, TransitionDirection::Forward);
+    assert_eq!(forward.cover(screen, 10.0), screen);
+    // En avançant, la nouvelle page entre par la droite : le voile garde le bord gauche
+    let halfway = forward.cover(screen, 10.0 + PageTransition::SECONDS / 2.0);
+    assert_eq!(halfway.left(), 0.0);
+    assert!(halfway.right() < 400.0 && halfway.right() > 0.0);
+    assert_eq!(forward.cover(screen, 10.0 + PageTransition::SECONDS).width(), 0.0);
+    assert!(!forward.is_done(10.1) && forward.is_done(10.0 + PageTransition::SECONDS));
+
+    let back = PageTransition::new(10.0, TransitionDirection::Back);
+    let halfway = back.cover(screen, 10.0 + PageTransition::SECONDS / 2.0);
+    assert_eq!(halfway.right(), 800.0);
+    assert!(halfway.left() > 400.0);
+    assert!(back.progress(9.0) == 0.0 && back.progress(20.0) == 1.0);
+}
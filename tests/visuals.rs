@@ -0,0 +1,41 @@
+use eth_price_sonifier::data::DailyPrice;
+use eth_price_sonifier::ui::textures::{visual_seed, ImageSequencer};
+
+fn day(date: &str) -> DailyPrice {
+    DailyPrice { date: date.to_string(), price: 100.0, volume: None, range: None, source_spread: None }
+}
+
+fn sequence(sequencer: &ImageSequencer, steps: usize) -> Vec<(usize, usize)> {
+    (0..steps).map(|step| (sequencer.bull_index(step), sequencer.bear_index(step))).collect()
+}
+
+#[test]
+fn seed_depends_on_coin_and_period_only() {
+    let period = [day("2024-01-01"), day("2024-01-02"), day("2024-01-31")];
+    let same_ends = [day("2024-01-01"), day("2024-01-15"), day("2024-01-31")];
+    assert_eq!(visual_seed("bitcoin", &period), visual_seed("bitcoin", &same_ends));
+    assert_ne!(visual_seed("bitcoin", &period), visual_seed("ethereum", &period));
+    assert_ne!(visual_seed("bitcoin", &period), visual_seed("bitcoin", &period[..2]));
+}
+
+#[test]
+fn same_seed_gives_same_images() {
+    let seed = visual_seed("bitcoin", &[day("2024-01-01"), day("2024-01-31")]);
+    let sequencer = ImageSequencer::new(5, 4, seed);
+    let first = sequence(&sequencer, 20);
+    assert_eq!(first, sequence(&ImageSequencer::new(5, 4, seed), 20));
+    // Un saut directement à un pas y montre la même image qu'une lecture depuis le début
+    assert_eq!((sequencer.bull_index(13), sequencer.bear_index(13)), first[13]);
+
+    // Chaque image passe une fois par tour avant de revenir
+    let bulls: Vec<usize> = first.iter().map(|(bull, _)| *bull).collect();
+    let mut round = bulls[..5].to_vec();
+    round.sort();
+    assert_eq!(round, vec![0, 1, 2, 3, 4]);
+    assert_eq!(bulls[..5], bulls[5..10]);
+}
+
+#[test]
+fn empty_pools_return_first_index() {
+    assert_eq!(sequence(&ImageSequencer::new(0, 0, 7), 3), vec![(0, 0); 3]);
+}